@@ -0,0 +1,51 @@
+use std::collections::HashMap;
+
+/// Helper for generating indented source code line by line.
+pub(crate) struct Generator {
+    level: usize,
+    output: String,
+}
+
+impl Generator {
+    pub(crate) fn new() -> Self {
+        Self {
+            level: 0,
+            output: String::new(),
+        }
+    }
+    pub(crate) fn begin(&mut self, line: &str) {
+        self.line(line);
+        self.level += 1;
+    }
+    pub(crate) fn end(&mut self, line: &str) {
+        self.level -= 1;
+        if !line.is_empty() {
+            self.line(line);
+        }
+    }
+    pub(crate) fn line(&mut self, line: &str) {
+        if !line.is_empty() {
+            for _ in 0..self.level {
+                self.output += "    ";
+            }
+            self.output += line;
+        }
+        self.output += "\n";
+    }
+}
+
+impl From<Generator> for String {
+    fn from(gen: Generator) -> Self {
+        gen.output
+    }
+}
+
+/// Type of the target language for the given builtin type. Unmapped
+/// builtin types are rejected by [`super::check_builtin_types`] so the
+/// name is only used as fallback.
+pub(crate) fn builtin_type<'a>(
+    builtin_types: &'a HashMap<String, String>,
+    name: &'a str,
+) -> &'a str {
+    builtin_types.get(name).map_or(name, String::as_str)
+}
@@ -0,0 +1,549 @@
+use crate::idl;
+use crate::schema::{self, FQTN};
+
+pub fn gen(doc: &schema::Document) -> String {
+    let mut output = String::new();
+    output += "# API Documentation\n";
+    output += "\n";
+    output += "## Namespaces\n";
+    output += "\n";
+    gen_namespace_index(&doc.ns, &mut output);
+    gen_namespace(&doc.ns, &mut output);
+    output
+}
+
+fn namespace_title(ns: &schema::Namespace) -> String {
+    if ns.path.is_empty() {
+        "(root)".to_string()
+    } else {
+        ns.path.join("::")
+    }
+}
+
+fn namespace_anchor(ns: &schema::Namespace) -> String {
+    if ns.path.is_empty() {
+        "namespace".to_string()
+    } else {
+        format!("namespace.{}", ns.path.join("."))
+    }
+}
+
+fn type_anchor(fqtn: &FQTN) -> String {
+    let mut parts = fqtn.ns.clone();
+    parts.push(fqtn.name.clone());
+    format!("type.{}", parts.join("."))
+}
+
+fn service_anchor(ns: &schema::Namespace, service: &schema::Service) -> String {
+    let mut parts = ns.path.clone();
+    parts.push(service.name.clone());
+    format!("service.{}", parts.join("."))
+}
+
+fn type_link(fqtn: &FQTN) -> String {
+    format!("[{}](#{})", fqtn_display(fqtn), type_anchor(fqtn))
+}
+
+fn fqtn_display(fqtn: &FQTN) -> String {
+    if fqtn.ns.is_empty() {
+        fqtn.name.clone()
+    } else {
+        format!("{}::{}", fqtn.ns.join("::"), fqtn.name)
+    }
+}
+
+fn is_empty(ns: &schema::Namespace) -> bool {
+    ns.constants.is_empty() && ns.types.is_empty() && ns.services.is_empty()
+}
+
+fn gen_namespace_index(ns: &schema::Namespace, output: &mut String) {
+    // The root namespace is only listed if it contains any items.
+    if !ns.path.is_empty() || !is_empty(ns) {
+        *output += &format!(
+            "{}- [{}](#{})\n",
+            "  ".repeat(ns.path.len().saturating_sub(1)),
+            namespace_title(ns),
+            namespace_anchor(ns)
+        );
+    }
+    for child_ns in ns.namespaces.values() {
+        gen_namespace_index(child_ns, output);
+    }
+}
+
+fn gen_namespace(ns: &schema::Namespace, output: &mut String) {
+    if !ns.path.is_empty() || !is_empty(ns) {
+        *output += "\n";
+        *output += &format!("<a id=\"{}\"></a>\n", namespace_anchor(ns));
+        *output += "\n";
+        *output += &format!("## Namespace `{}`\n", namespace_title(ns));
+        if is_empty(ns) {
+            *output += "\n";
+            *output += "This namespace contains no types or services.\n";
+        }
+        if !ns.constants.is_empty() {
+            *output += "\n";
+            gen_constants(ns, output);
+        }
+        for type_ in ns.types.values() {
+            *output += "\n";
+            gen_type(type_, output);
+        }
+        for service in ns.services.values() {
+            *output += "\n";
+            gen_service(ns, service, output);
+        }
+    }
+    for child_ns in ns.namespaces.values() {
+        gen_namespace(child_ns, output);
+    }
+}
+
+fn gen_constants(ns: &schema::Namespace, output: &mut String) {
+    *output += "### Constants\n";
+    *output += "\n";
+    *output += "| Constant | Type | Value |\n";
+    *output += "|----------|------|-------|\n";
+    for constant in ns.constants.values() {
+        *output += &format!(
+            "| `{}` | {} | `{}` |\n",
+            constant.fqtn.name,
+            gen_typeref(&constant.type_),
+            constant.value.to_string().replace('|', "\\|"),
+        );
+    }
+}
+
+fn gen_type(type_: &schema::UserDefinedType, output: &mut String) {
+    match type_ {
+        schema::UserDefinedType::Alias(alias) => gen_alias(&alias.borrow(), output),
+        schema::UserDefinedType::Newtype(newtype) => gen_newtype(&newtype.borrow(), output),
+        schema::UserDefinedType::Enum(enum_) => gen_enum(&enum_.borrow(), output),
+        schema::UserDefinedType::Struct(struct_) => gen_struct(&struct_.borrow(), output),
+        schema::UserDefinedType::Fieldset(fieldset) => gen_fieldset(&fieldset.borrow(), output),
+    }
+}
+
+fn gen_heading(kind: &str, fqtn: &FQTN, generics: &[String], output: &mut String) {
+    let generics = if generics.is_empty() {
+        "".to_string()
+    } else {
+        format!("<{}>", generics.join(", "))
+    };
+    *output += &format!("<a id=\"{}\"></a>\n", type_anchor(fqtn));
+    *output += "\n";
+    *output += &format!("### {} `{}{}`\n", kind, fqtn.name, generics);
+    *output += "\n";
+}
+
+fn gen_deprecation(deprecation: &schema::Deprecation) -> String {
+    let mut text = "**Deprecated**".to_string();
+    if let Some(since) = &deprecation.since {
+        text += &format!(" since {}", escape(since));
+    }
+    if let Some(note) = &deprecation.note {
+        text += &format!(": {}", escape(note));
+    }
+    text
+}
+
+fn gen_type_deprecation(deprecation: Option<&schema::Deprecation>, output: &mut String) {
+    if let Some(deprecation) = deprecation {
+        *output += &format!("{}\n", gen_deprecation(deprecation));
+        *output += "\n";
+    }
+}
+
+fn gen_alias(alias: &schema::Alias, output: &mut String) {
+    gen_heading("Alias", &alias.fqtn, &alias.generics, output);
+    *output += &format!("Alias of {}.\n", gen_typeref(&alias.type_));
+}
+
+fn gen_newtype(newtype: &schema::Newtype, output: &mut String) {
+    gen_heading("Newtype", &newtype.fqtn, &newtype.generics, output);
+    gen_type_deprecation(newtype.field.deprecated.as_ref(), output);
+    *output += &format!("Newtype of {}.\n", gen_typeref(&newtype.field.type_));
+    let constraints = gen_constraints(&newtype.field);
+    if !constraints.is_empty() {
+        *output += "\n";
+        *output += &format!("Constraints: {}\n", constraints.replace("<br>", ", "));
+    }
+}
+
+fn gen_enum(enum_: &schema::Enum, output: &mut String) {
+    gen_heading("Enum", &enum_.fqtn, &enum_.generics, output);
+    gen_type_deprecation(enum_.deprecated.as_ref(), output);
+    if let Some(extends) = &enum_.extends {
+        *output += &format!(
+            "Extends {}.\n",
+            gen_typeref(&schema::Type::Ref(extends.clone()))
+        );
+        *output += "\n";
+    }
+    if enum_.all_variants.is_empty() {
+        *output += "This enum has no variants.\n";
+        return;
+    }
+    *output += "| Variant | Value | Inherited from |\n";
+    *output += "|---------|-------|----------------|\n";
+    // The variants of `all_variants` are ordered like the enums of the
+    // extends chain and contain the generic arguments of the extends
+    // clauses.
+    let mut inherited_from = vec![None; enum_.variants.len()];
+    let mut extends = enum_.extends_enum();
+    while let Some(extends_enum) = extends {
+        let extends_enum = extends_enum.borrow();
+        inherited_from.extend(std::iter::repeat_n(
+            Some(extends_enum.fqtn.clone()),
+            extends_enum.variants.len(),
+        ));
+        extends = extends_enum.extends_enum();
+    }
+    for (variant, inherited_from) in enum_.all_variants.iter().zip(inherited_from) {
+        let deprecation = match &variant.deprecated {
+            Some(deprecation) => format!("<br>{}", gen_deprecation(deprecation)),
+            None => String::new(),
+        };
+        *output += &format!(
+            "| `{}`{} | {} | {} |\n",
+            variant.name,
+            deprecation,
+            variant
+                .value_type
+                .as_ref()
+                .map(gen_typeref)
+                .unwrap_or_default(),
+            inherited_from.as_ref().map(type_link).unwrap_or_default(),
+        );
+    }
+}
+
+fn gen_struct(struct_: &schema::Struct, output: &mut String) {
+    gen_heading("Struct", &struct_.fqtn, &struct_.generics, output);
+    gen_type_deprecation(struct_.deprecated.as_ref(), output);
+    if let Some(extends) = &struct_.extends {
+        *output += &format!(
+            "Extends {}.\n",
+            gen_typeref(&schema::Type::Ref(extends.clone()))
+        );
+        *output += "\n";
+    }
+    gen_fields(struct_.all_fields.iter().map(|f| (f, f.optional)), output);
+}
+
+fn gen_fieldset(fieldset: &schema::Fieldset, output: &mut String) {
+    gen_heading("Fieldset", &fieldset.fqtn, &fieldset.generics, output);
+    *output += &format!("Fieldset of {}.\n", type_link(&fieldset.r#struct.fqtn()));
+    *output += "\n";
+    gen_fields(
+        fieldset.fields.iter().map(|f| {
+            // The fields are taken from the struct when the fieldset
+            // is resolved which happens before any code is generated.
+            let field = f.field.as_ref().expect("fieldset fields are resolved");
+            (field, f.optional)
+        }),
+        output,
+    );
+}
+
+fn gen_fields<'a>(fields: impl Iterator<Item = (&'a schema::Field, bool)>, output: &mut String) {
+    let fields = fields.collect::<Vec<_>>();
+    if fields.is_empty() {
+        *output += "This type has no fields.\n";
+        return;
+    }
+    *output += "| Field | Type | Required | Constraints | Description |\n";
+    *output += "|-------|------|----------|-------------|-------------|\n";
+    for (field, optional) in fields {
+        let description = field
+            .deprecated
+            .as_ref()
+            .map(gen_deprecation)
+            .into_iter()
+            .chain(field.help.as_deref().map(escape))
+            .collect::<Vec<_>>()
+            .join("<br>");
+        *output += &format!(
+            "| `{}` | {} | {} | {} | {} |\n",
+            field.name,
+            gen_typeref(&field.type_),
+            if optional { "no" } else { "yes" },
+            gen_constraints(field),
+            description,
+        );
+    }
+}
+
+fn gen_range(range: (Option<i64>, Option<i64>)) -> Option<String> {
+    match range {
+        (None, None) => None,
+        (min, max) => Some(format!(
+            "{}..{}",
+            min.map(|v| v.to_string()).unwrap_or_default(),
+            max.map(|v| v.to_string()).unwrap_or_default()
+        )),
+    }
+}
+
+fn gen_constraints(field: &schema::Field) -> String {
+    let mut constraints = Vec::new();
+    if let Some(length) = gen_range(field.length) {
+        constraints.push(format!("length: `{}`", length));
+    }
+    if let Some(range) = gen_range(field.range) {
+        constraints.push(format!("range: `{}`", range));
+    }
+    if let Some(format) = &field.format {
+        constraints.push(format!("format: `{}`", format));
+    }
+    if let Some(default) = &field.default {
+        constraints.push(format!(
+            "default: `{}`",
+            default.to_string().replace('|', "\\|")
+        ));
+    }
+    constraints.join("<br>")
+}
+
+fn gen_annotations(annotations: &schema::Annotations, separator: &str) -> String {
+    let mut items = Vec::new();
+    if let Some(deprecated) = &annotations.deprecated {
+        items.push(gen_deprecation(deprecated));
+    }
+    if let Some(auth) = &annotations.auth {
+        items.push(format!("auth: `{}`", auth.replace('|', "\\|")));
+    }
+    if let Some(timeout) = annotations.timeout {
+        items.push(format!("timeout: `{}`", idl::Value::Duration(timeout)));
+    }
+    if annotations.idempotent {
+        items.push("idempotent".to_string());
+    }
+    if let Some(rate_limit) = &annotations.rate_limit {
+        items.push(format!(
+            "rate limit: `{}`",
+            idl::Value::Rate(rate_limit.requests, rate_limit.per)
+        ));
+    }
+    items.join(separator)
+}
+
+fn gen_method_type(type_: Option<&schema::Type>, stream: bool) -> String {
+    match type_ {
+        Some(type_) if stream => format!("stream {}", gen_typeref(type_)),
+        Some(type_) => gen_typeref(type_),
+        None => "None".to_string(),
+    }
+}
+
+fn gen_service(ns: &schema::Namespace, service: &schema::Service, output: &mut String) {
+    *output += &format!("<a id=\"{}\"></a>\n", service_anchor(ns, service));
+    *output += "\n";
+    *output += &format!("### Service `{}`\n", service.name);
+    *output += "\n";
+    if service.side == idl::ServiceSide::Client {
+        *output += "This service is implemented by the client.\n";
+        *output += "\n";
+    }
+    if !service.annotations.is_empty() {
+        *output += &format!("{}\n", gen_annotations(&service.annotations, ", "));
+        *output += "\n";
+    }
+    if service.methods.is_empty() {
+        *output += "This service has no methods.\n";
+        return;
+    }
+    let annotated = service.methods.iter().any(|m| !m.annotations.is_empty());
+    if annotated {
+        *output += "| Method | Input | Output | Annotations |\n";
+        *output += "|--------|-------|--------|-------------|\n";
+    } else {
+        *output += "| Method | Input | Output |\n";
+        *output += "|--------|-------|--------|\n";
+    }
+    for method in service.methods.iter() {
+        *output += &format!(
+            "| `{}` | {} | {} |",
+            method.name,
+            gen_method_type(method.input.as_ref(), method.input_stream),
+            if method.notify {
+                "*notification*".to_string()
+            } else if let Some(throws) = &method.throws {
+                format!(
+                    "{} throws {}",
+                    gen_method_type(method.output.as_ref(), false),
+                    gen_typeref(throws)
+                )
+            } else {
+                gen_method_type(method.output.as_ref(), method.output_stream)
+            },
+        );
+        if annotated {
+            *output += &format!(" {} |", gen_annotations(&method.annotations, "<br>"));
+        }
+        *output += "\n";
+    }
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('|', "\\|")
+        .replace('\n', "<br>")
+}
+
+pub fn gen_typeref(type_: &schema::Type) -> String {
+    match type_ {
+        schema::Type::None => "None".to_string(),
+        schema::Type::Boolean => "Boolean".to_string(),
+        schema::Type::Integer => "Integer".to_string(),
+        schema::Type::Float => "Float".to_string(),
+        schema::Type::String => "String".to_string(),
+        schema::Type::UUID => "UUID".to_string(),
+        schema::Type::Date => "Date".to_string(),
+        schema::Type::Time => "Time".to_string(),
+        schema::Type::DateTime => "DateTime".to_string(),
+        schema::Type::Decimal => "Decimal".to_string(),
+        schema::Type::Bytes => "Bytes".to_string(),
+        schema::Type::Duration => "Duration".to_string(),
+        schema::Type::Int8 => "Int8".to_string(),
+        schema::Type::Int16 => "Int16".to_string(),
+        schema::Type::Int32 => "Int32".to_string(),
+        schema::Type::Int64 => "Int64".to_string(),
+        schema::Type::UInt8 => "UInt8".to_string(),
+        schema::Type::UInt16 => "UInt16".to_string(),
+        schema::Type::UInt32 => "UInt32".to_string(),
+        schema::Type::UInt64 => "UInt64".to_string(),
+        schema::Type::Option(some) => format!("Option&lt;{}&gt;", gen_typeref(some)),
+        schema::Type::Result(ok, err) => {
+            format!("Result&lt;{}, {}&gt;", gen_typeref(ok), gen_typeref(err))
+        }
+        // complex types
+        schema::Type::Array(array) => format!("[{}]", gen_typeref(&array.item_type)),
+        schema::Type::Map(map) => format!(
+            "{{{}: {}}}",
+            gen_typeref(&map.key_type),
+            gen_typeref(&map.value_type)
+        ),
+        // named
+        schema::Type::Ref(typeref) => {
+            let link = type_link(&typeref.fqtn());
+            let generics = typeref.generics();
+            if !generics.is_empty() {
+                let generics = generics
+                    .iter()
+                    .map(gen_typeref)
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                format!("{}&lt;{}&gt;", link, generics)
+            } else {
+                link
+            }
+        }
+        schema::Type::Generic(name) => format!("`{}`", name),
+        schema::Type::Builtin(name) => format!("`{}`", name),
+    }
+}
+
+#[cfg(test)]
+fn gen_idl(idl: &str) -> String {
+    let idocs = [idl::parse_document(idl).unwrap()];
+    let builtin_types = std::collections::HashSet::default();
+    gen(&schema::Document::from_idl(idocs.iter(), &builtin_types).unwrap())
+}
+
+#[test]
+fn test_gen_links() {
+    let output = gen_idl(
+        r#"
+        struct User { name: String }
+        namespace shop {
+            struct Order { user: ::User, tags: {String: [::User]} }
+            service Orders { get: UUID -> Option<Order> }
+        }
+        "#,
+    );
+    assert!(output.contains("- [(root)](#namespace)\n- [shop](#namespace.shop)\n"));
+    assert!(output.contains("<a id=\"namespace.shop\"></a>\n\n## Namespace `shop`\n"));
+    assert!(output.contains("<a id=\"type.shop.Order\"></a>\n\n### Struct `Order`\n"));
+    assert!(output.contains("| `user` | [User](#type.User) | yes |  |  |\n"));
+    assert!(output.contains("| `tags` | {String: [[User](#type.User)]} | yes |  |  |\n"));
+    assert!(output.contains("<a id=\"service.shop.Orders\"></a>\n"));
+    assert!(output.contains("| `get` | UUID | Option&lt;[shop::Order](#type.shop.Order)&gt; |\n"));
+}
+
+#[test]
+fn test_gen_inherited_enum_variants() {
+    let output = gen_idl(
+        r#"
+        enum Base { A, B(String) }
+        enum Extended extends Base { C(Integer) }
+        enum ExtendedTwice extends Extended { D }
+        enum GenericBase<T> { Value(T) }
+        enum ExtendedGeneric extends GenericBase<Float> { Other }
+        "#,
+    );
+    assert!(output.contains(concat!(
+        "### Enum `ExtendedTwice`\n",
+        "\n",
+        "Extends [Extended](#type.Extended).\n",
+        "\n",
+        "| Variant | Value | Inherited from |\n",
+        "|---------|-------|----------------|\n",
+        "| `D` |  |  |\n",
+        "| `C` | Integer | [Extended](#type.Extended) |\n",
+        "| `A` |  | [Base](#type.Base) |\n",
+        "| `B` | String | [Base](#type.Base) |\n",
+    )));
+    assert!(output.contains(concat!(
+        "Extends [GenericBase](#type.GenericBase)&lt;Float&gt;.\n",
+        "\n",
+        "| Variant | Value | Inherited from |\n",
+        "|---------|-------|----------------|\n",
+        "| `Other` |  |  |\n",
+        "| `Value` | Float | [GenericBase](#type.GenericBase) |\n",
+    )));
+}
+
+#[test]
+fn test_gen_fieldset() {
+    let output = gen_idl(
+        r#"
+        namespace shop {
+            struct Order { id: UUID, note: String (length=1..64), secret: String }
+            fieldset OrderSummary for Order { id, note? }
+        }
+        "#,
+    );
+    assert!(output.contains(concat!(
+        "### Fieldset `OrderSummary`\n",
+        "\n",
+        "Fieldset of [shop::Order](#type.shop.Order).\n",
+        "\n",
+        "| Field | Type | Required | Constraints | Description |\n",
+        "|-------|------|----------|-------------|-------------|\n",
+        "| `id` | UUID | yes |  |  |\n",
+        "| `note` | String | no | length: `1..64` |  |\n",
+    )));
+}
+
+#[test]
+fn test_gen_constraints() {
+    let output = gen_idl(
+        r#"
+        struct Order {
+            quantity: Integer (range=1..),
+            email?: String (format="email"),
+            count: Integer (range=0..10) = 1,
+            label: String = "a|b",
+        }
+        newtype Quantity = Integer (range=1..100, format="qty");
+        "#,
+    );
+    assert!(output.contains("| `quantity` | Integer | yes | range: `1..` |  |\n"));
+    assert!(output.contains("| `email` | String | no | format: `email` |  |\n"));
+    assert!(output.contains("| `count` | Integer | yes | range: `0..10`<br>default: `1` |  |\n"));
+    assert!(output.contains("| `label` | String | yes | default: `\"a\\|b\"` |  |\n"));
+    assert!(output.contains("Newtype of Integer.\n\nConstraints: range: `1..100`, format: `qty`\n"));
+}
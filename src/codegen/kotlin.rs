@@ -0,0 +1,786 @@
+//! Kotlin code generator
+//!
+//! The generated code uses `kotlinx.serialization` and `kotlinx-datetime`
+//! and produces the same JSON as the serde based Rust code. It expects a
+//! `webwire` package providing the following types:
+//!
+//! - `webwire.Result<T, E>` serialized as `{"Ok": ...}` or `{"Err": ...}`
+//! - `webwire.Client` with a
+//!   `suspend fun request(service: String, method: String, data: String): String`
+//!   method and a
+//!   `fun notify(service: String, method: String, data: String)` method
+//!   which sends a notification without waiting for a response.
+//! - `webwire.Provider` with a `val name: String` property and a
+//!   `suspend fun call(method: String, data: String): String` method
+//!   which is implemented for services provided by the client.
+//!
+//! Streaming methods use `kotlinx.coroutines.flow.Flow`. A stream is
+//! transferred as a JSON array of all its values via `request` and
+//! `call`.
+//!
+//! `Decimal` and `Bytes` values are kept as their JSON string (decimal
+//! number and base64) and `Duration` uses `kotlin.time.Duration`.
+//!
+//! Enums are sealed classes with a custom serializer. The serializer of
+//! a generic enum is a class which is constructed with the serializers
+//! of the type arguments, e.g. `Either.Serializer(serializer<User>(),
+//! serializer<String>())`, and is used by the serialization plugin for
+//! fields of generic enum types automatically.
+
+use std::collections::HashMap;
+
+use heck::ToLowerCamelCase;
+
+use crate::{idl, schema};
+
+use super::common::{builtin_type, Generator};
+
+const KEYWORDS: &[&str] = &[
+    "as",
+    "break",
+    "class",
+    "continue",
+    "do",
+    "else",
+    "false",
+    "for",
+    "fun",
+    "if",
+    "in",
+    "interface",
+    "is",
+    "null",
+    "object",
+    "package",
+    "return",
+    "super",
+    "this",
+    "throw",
+    "true",
+    "try",
+    "typealias",
+    "typeof",
+    "val",
+    "var",
+    "when",
+    "while",
+];
+
+#[derive(Clone, Debug)]
+pub struct Options {
+    /// Package of the generated code
+    pub package: String,
+    /// Kotlin types of the builtin types (e.g. `Url` to `java.net.URI`)
+    pub builtin_types: HashMap<String, String>,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            package: DEFAULT_PACKAGE.to_owned(),
+            builtin_types: HashMap::new(),
+        }
+    }
+}
+
+/// Package which is used if no other package is given
+pub const DEFAULT_PACKAGE: &str = "api";
+
+pub fn gen(doc: &schema::Document, options: &Options) -> String {
+    let builtin_types = &options.builtin_types;
+    let mut gen = Generator::new();
+    gen.line("// GENERATED CODE - DO NOT EDIT!");
+    gen.line("");
+    gen.line(&format!(
+        "package {}",
+        options
+            .package
+            .split('.')
+            .map(identifier)
+            .collect::<Vec<_>>()
+            .join(".")
+    ));
+    gen.line("");
+    if has_streaming_methods(&doc.ns) {
+        gen.line("import kotlinx.coroutines.flow.*");
+    }
+    gen.line("import kotlinx.serialization.KSerializer");
+    gen.line("import kotlinx.serialization.SerialName");
+    gen.line("import kotlinx.serialization.Serializable");
+    gen.line("import kotlinx.serialization.SerializationException");
+    gen.line("import kotlinx.serialization.builtins.*");
+    gen.line("import kotlinx.serialization.descriptors.SerialDescriptor");
+    gen.line("import kotlinx.serialization.encoding.Decoder");
+    gen.line("import kotlinx.serialization.encoding.Encoder");
+    gen.line("import kotlinx.serialization.json.*");
+    gen.line("import kotlinx.serialization.serializer");
+    if has_generic_enums(&doc.ns) {
+        gen_nullable_serializer(&mut gen);
+    }
+    gen_namespace(&doc.ns, &mut gen, builtin_types);
+    gen.into()
+}
+
+/// Name of the function making the serializer of a generic parameter
+/// nullable
+const NULLABLE_SERIALIZER: &str = "webwireNullable";
+
+fn has_generic_enums(ns: &schema::Namespace) -> bool {
+    ns.types.values().any(|type_| match type_ {
+        schema::UserDefinedType::Enum(enum_) => !enum_.borrow().generics.is_empty(),
+        _ => false,
+    }) || ns.namespaces.values().any(has_generic_enums)
+}
+
+/// The `nullable` property of `KSerializer` requires a non-nullable type
+/// which is not known for generic parameters.
+fn gen_nullable_serializer(gen: &mut Generator) {
+    gen.line("");
+    gen.line("@Suppress(\"UNCHECKED_CAST\")");
+    gen.line(&format!(
+        "private fun <T> {}(serializer: KSerializer<T>): KSerializer<T?> =",
+        NULLABLE_SERIALIZER
+    ));
+    gen.line("    (serializer as KSerializer<Any>).nullable as KSerializer<T?>");
+}
+
+fn has_streaming_methods(ns: &schema::Namespace) -> bool {
+    ns.services
+        .values()
+        .any(|service| service.methods.iter().any(schema::Method::is_streaming))
+        || ns.namespaces.values().any(has_streaming_methods)
+}
+
+fn identifier(name: &str) -> String {
+    if KEYWORDS.contains(&name) {
+        format!("`{}`", name)
+    } else {
+        name.to_string()
+    }
+}
+
+fn generics(generics: &[String]) -> String {
+    if generics.is_empty() {
+        "".to_string()
+    } else {
+        format!("<{}>", generics.join(", "))
+    }
+}
+
+fn gen_namespace(
+    ns: &schema::Namespace,
+    gen: &mut Generator,
+    builtin_types: &HashMap<String, String>,
+) {
+    for type_ in ns.types.values() {
+        // Kotlin does not allow type aliases to be nested inside of
+        // objects. Aliases are therefore inlined by `gen_typeref`.
+        if matches!(type_, schema::UserDefinedType::Alias(_)) {
+            continue;
+        }
+        gen.line("");
+        gen_type(type_, gen, builtin_types);
+    }
+    for service in ns.services.values() {
+        gen.line("");
+        gen_service(service, gen, builtin_types);
+        gen.line("");
+        match service.side {
+            idl::ServiceSide::Server => gen_consumer(ns, service, gen, builtin_types),
+            idl::ServiceSide::Client => gen_provider(ns, service, gen, builtin_types),
+        }
+    }
+    for child_ns in ns.namespaces.values() {
+        gen.line("");
+        gen.begin(&format!("object {} {{", identifier(child_ns.name())));
+        gen_namespace(child_ns, gen, builtin_types);
+        gen.end("}");
+    }
+}
+
+fn gen_type(
+    type_: &schema::UserDefinedType,
+    gen: &mut Generator,
+    builtin_types: &HashMap<String, String>,
+) {
+    match type_ {
+        schema::UserDefinedType::Alias(_) => {}
+        schema::UserDefinedType::Newtype(newtype) => {
+            gen_newtype(&newtype.borrow(), gen, builtin_types)
+        }
+        schema::UserDefinedType::Enum(enum_) => gen_enum(&enum_.borrow(), gen, builtin_types),
+        schema::UserDefinedType::Struct(struct_) => {
+            gen_struct(&struct_.borrow(), gen, builtin_types)
+        }
+        schema::UserDefinedType::Fieldset(fieldset) => {
+            gen_fieldset(&fieldset.borrow(), gen, builtin_types)
+        }
+    }
+}
+
+fn gen_newtype(
+    newtype: &schema::Newtype,
+    gen: &mut Generator,
+    builtin_types: &HashMap<String, String>,
+) {
+    gen_deprecated(newtype.field.deprecated.as_ref(), gen);
+    gen.line("@Serializable");
+    gen.line("@JvmInline");
+    gen.line(&format!(
+        "value class {}{}(val value: {})",
+        newtype.fqtn.name,
+        generics(&newtype.generics),
+        gen_typeref(&newtype.field.type_, builtin_types)
+    ));
+}
+
+fn gen_enum(enum_: &schema::Enum, gen: &mut Generator, builtin_types: &HashMap<String, String>) {
+    let name = &enum_.fqtn.name;
+    let generics_str = generics(&enum_.generics);
+    let variant_generics = if enum_.generics.is_empty() {
+        "".to_string()
+    } else {
+        format!(
+            "<{}>",
+            enum_
+                .generics
+                .iter()
+                .map(|_| "Nothing")
+                .collect::<Vec<_>>()
+                .join(", ")
+        )
+    };
+    gen_deprecated(enum_.deprecated.as_ref(), gen);
+    gen.line(&format!("@Serializable(with = {}.Serializer::class)", name));
+    let out_generics = if enum_.generics.is_empty() {
+        "".to_string()
+    } else {
+        format!(
+            "<{}>",
+            enum_
+                .generics
+                .iter()
+                .map(|g| format!("out {}", g))
+                .collect::<Vec<_>>()
+                .join(", ")
+        )
+    };
+    gen.begin(&format!("sealed class {}{} {{", name, out_generics));
+    for variant in enum_.all_variants.iter() {
+        gen_deprecated(variant.deprecated.as_ref(), gen);
+        match &variant.value_type {
+            Some(value_type) => gen.line(&format!(
+                "data class {}{}(val value: {}) : {}{}()",
+                variant.name,
+                generics_str,
+                gen_typeref(value_type, builtin_types),
+                name,
+                generics_str
+            )),
+            None => gen.line(&format!(
+                "object {} : {}{}()",
+                variant.name, name, variant_generics
+            )),
+        }
+    }
+    gen.line("");
+    if enum_.generics.is_empty() {
+        gen.begin(&format!("object Serializer : KSerializer<{}> {{", name));
+    } else {
+        let params = enum_
+            .generics
+            .iter()
+            .map(|g| format!("private val {}: KSerializer<{}>", generic_serializer(g), g))
+            .collect::<Vec<_>>()
+            .join(", ");
+        gen.begin(&format!(
+            "class Serializer{}({}) : KSerializer<{}{}> {{",
+            generics_str, params, name, generics_str
+        ));
+    }
+    gen.line("override val descriptor: SerialDescriptor = JsonElement.serializer().descriptor");
+    gen.line("");
+    gen.begin(&format!(
+        "override fun serialize(encoder: Encoder, value: {}{}) {{",
+        name, generics_str
+    ));
+    gen.line("val output = encoder as JsonEncoder");
+    if enum_.all_variants.is_empty() {
+        gen.line(&format!(
+            "throw SerializationException(\"Enum {} has no variants\")",
+            name
+        ));
+    } else {
+        gen.begin("val element = when (value) {");
+        for variant in enum_.all_variants.iter() {
+            match &variant.value_type {
+                Some(value_type) => gen.line(&format!(
+                    "is {} -> JsonObject(mapOf(\"{}\" to output.json.encodeToJsonElement({}, value.value)))",
+                    variant.name,
+                    variant.name,
+                    gen_serializer(value_type, builtin_types)
+                )),
+                None => gen.line(&format!(
+                    "is {} -> JsonPrimitive(\"{}\")",
+                    variant.name, variant.name
+                )),
+            }
+        }
+        gen.end("}");
+        gen.line("output.encodeJsonElement(element)");
+    }
+    gen.end("}");
+    gen.line("");
+    gen.begin(&format!(
+        "override fun deserialize(decoder: Decoder): {}{} {{",
+        name, generics_str
+    ));
+    gen.line("val input = decoder as JsonDecoder");
+    gen.begin("val (variant, value) = when (val element = input.decodeJsonElement()) {");
+    gen.line("is JsonPrimitive -> element.content to null");
+    gen.line(&format!(
+        "is JsonObject -> element.entries.singleOrNull()?.toPair() ?: throw SerializationException(\"Expected exactly one variant of {}\")",
+        name
+    ));
+    gen.line(&format!(
+        "else -> throw SerializationException(\"Unexpected JSON for enum {}\")",
+        name
+    ));
+    gen.end("}");
+    gen.begin("return when (variant) {");
+    for variant in enum_.all_variants.iter() {
+        match &variant.value_type {
+            Some(value_type) => gen.line(&format!(
+                "\"{}\" -> {}(input.json.decodeFromJsonElement({}, value ?: throw SerializationException(\"Missing value of variant {}\")))",
+                variant.name,
+                variant.name,
+                gen_serializer(value_type, builtin_types),
+                variant.name
+            )),
+            None => gen.line(&format!("\"{}\" -> {}", variant.name, variant.name)),
+        }
+    }
+    gen.line("else -> throw SerializationException(\"Unknown variant $variant\")");
+    gen.end("}");
+    gen.end("}");
+    gen.end("}");
+    gen.end("}");
+}
+
+fn gen_struct(
+    struct_: &schema::Struct,
+    gen: &mut Generator,
+    builtin_types: &HashMap<String, String>,
+) {
+    gen_data_class(
+        &struct_.fqtn.name,
+        &struct_.generics,
+        struct_.deprecated.as_ref(),
+        struct_.all_fields.iter().map(|f| (f, f.optional)),
+        gen,
+        builtin_types,
+    );
+}
+
+fn gen_fieldset(
+    fieldset: &schema::Fieldset,
+    gen: &mut Generator,
+    builtin_types: &HashMap<String, String>,
+) {
+    gen_data_class(
+        &fieldset.fqtn.name,
+        &fieldset.generics,
+        None,
+        fieldset
+            .fields
+            .iter()
+            .map(|f| (f.field.as_ref().unwrap(), f.optional)),
+        gen,
+        builtin_types,
+    );
+}
+
+/// Generate a data class for a struct or fieldset. The fields are given
+/// with their `optional` flag as it differs for fieldsets.
+fn gen_data_class<'a>(
+    name: &str,
+    generics_: &[String],
+    deprecation: Option<&schema::Deprecation>,
+    fields: impl Iterator<Item = (&'a schema::Field, bool)>,
+    gen: &mut Generator,
+    builtin_types: &HashMap<String, String>,
+) {
+    let fields = fields.collect::<Vec<_>>();
+    gen_deprecated(deprecation, gen);
+    gen.line("@Serializable");
+    if fields.is_empty() {
+        // Data classes require at least one property.
+        gen.line(&format!("class {}{}", name, generics(generics_)));
+        return;
+    }
+    gen.begin(&format!("data class {}{}(", name, generics(generics_)));
+    for (field, optional) in fields {
+        let (field_name, type_) = (field.name.as_str(), &field.type_);
+        let property_name = field_name.to_lower_camel_case();
+        gen_deprecated(field.deprecated.as_ref(), gen);
+        if property_name != field_name {
+            gen.line(&format!("@SerialName(\"{}\")", field_name));
+        }
+        if optional {
+            gen.line(&format!(
+                "val {}: {}? = null,",
+                identifier(&property_name),
+                gen_typeref(type_, builtin_types)
+            ));
+        } else {
+            gen.line(&format!(
+                "val {}: {},",
+                identifier(&property_name),
+                gen_typeref(type_, builtin_types)
+            ));
+        }
+    }
+    gen.end(")");
+}
+
+fn method_signature(method: &schema::Method, builtin_types: &HashMap<String, String>) -> String {
+    let input = match &method.input {
+        Some(t) if method.input_stream => format!("input: Flow<{}>", gen_typeref(t, builtin_types)),
+        Some(t) => format!("input: {}", gen_typeref(t, builtin_types)),
+        None => String::new(),
+    };
+    let name = identifier(&method.name.to_lower_camel_case());
+    match (&method.output, method.wire_output()) {
+        // Functions returning a `Flow` are not suspending by convention
+        (Some(t), _) if method.output_stream => format!(
+            "fun {}({}): Flow<{}>",
+            name,
+            input,
+            gen_typeref(t, builtin_types)
+        ),
+        (_, Some(t)) => format!(
+            "suspend fun {}({}): {}",
+            name,
+            input,
+            gen_typeref(&t, builtin_types)
+        ),
+        (_, None) if method.notify => format!("fun {}({})", name, input),
+        (_, None) => format!("suspend fun {}({})", name, input),
+    }
+}
+
+fn gen_deprecated(deprecation: Option<&schema::Deprecation>, gen: &mut Generator) {
+    if let Some(deprecation) = deprecation {
+        let message = deprecation
+            .message()
+            .unwrap_or_else(|| "Deprecated".to_owned());
+        let message = serde_json::to_string(&message).expect("strings are always serializable");
+        gen.line(&format!("@Deprecated({})", message.replace('$', "\\$")));
+    }
+}
+
+fn gen_service(
+    service: &schema::Service,
+    gen: &mut Generator,
+    builtin_types: &HashMap<String, String>,
+) {
+    gen_deprecated(service.annotations.deprecated.as_ref(), gen);
+    gen.begin(&format!("interface {} {{", service.name));
+    for method in service.methods.iter() {
+        gen_deprecated(method.annotations.deprecated.as_ref(), gen);
+        gen.line(&method_signature(method, builtin_types));
+    }
+    gen.end("}");
+}
+
+fn gen_consumer(
+    ns: &schema::Namespace,
+    service: &schema::Service,
+    gen: &mut Generator,
+    builtin_types: &HashMap<String, String>,
+) {
+    gen_deprecated(service.annotations.deprecated.as_ref(), gen);
+    gen.begin(&format!(
+        "class {}Consumer(private val client: webwire.Client) : {} {{",
+        service.name, service.name
+    ));
+    let fqsn = if ns.path.is_empty() {
+        service.name.to_owned()
+    } else {
+        format!("{}.{}", ns.path.join("."), service.name)
+    };
+    for (i, method) in service.methods.iter().enumerate() {
+        if i > 0 {
+            gen.line("");
+        }
+        gen_deprecated(method.annotations.deprecated.as_ref(), gen);
+        gen.begin(&format!(
+            "override {} {{",
+            method_signature(method, builtin_types)
+        ));
+        // Streamed inputs and outputs are transferred as lists. A
+        // streamed output is emitted once the response is received.
+        if method.output_stream {
+            gen.begin("return flow {");
+        }
+        let data = match &method.wire_input() {
+            Some(type_) => {
+                gen.line(&format!(
+                    "val data = Json.encodeToString({}, {})",
+                    gen_serializer(type_, builtin_types),
+                    if method.input_stream {
+                        "input.toList()"
+                    } else {
+                        "input"
+                    }
+                ));
+                "data"
+            }
+            None => "\"\"",
+        };
+        if method.notify {
+            gen.line(&format!(
+                "client.notify(\"{}\", \"{}\", {})",
+                fqsn, method.name, data
+            ));
+            gen.end("}");
+            continue;
+        }
+        let request = format!(
+            "client.request(\"{}\", \"{}\", {})",
+            fqsn, method.name, data
+        );
+        match &method.wire_output() {
+            Some(type_) if method.output_stream => {
+                gen.line(&format!("val output = {}", request));
+                gen.line(&format!(
+                    "emitAll(Json.decodeFromString({}, output).asFlow())",
+                    gen_serializer(type_, builtin_types)
+                ));
+                gen.end("}");
+            }
+            Some(type_) => {
+                gen.line(&format!("val output = {}", request));
+                gen.line(&format!(
+                    "return Json.decodeFromString({}, output)",
+                    gen_serializer(type_, builtin_types)
+                ));
+            }
+            None => gen.line(&request),
+        }
+        gen.end("}");
+    }
+    gen.end("}");
+}
+
+fn gen_provider(
+    ns: &schema::Namespace,
+    service: &schema::Service,
+    gen: &mut Generator,
+    builtin_types: &HashMap<String, String>,
+) {
+    gen.begin(&format!(
+        "class {}Provider(private val service: {}) : webwire.Provider {{",
+        service.name, service.name
+    ));
+    let fqsn = if ns.path.is_empty() {
+        service.name.to_owned()
+    } else {
+        format!("{}.{}", ns.path.join("."), service.name)
+    };
+    gen.line(&format!("override val name: String = \"{}\"", fqsn));
+    gen.line("");
+    gen.begin("override suspend fun call(method: String, data: String): String {");
+    gen.begin("return when (method) {");
+    for method in service.methods.iter() {
+        gen.begin(&format!("\"{}\" -> {{", method.name));
+        // Streamed inputs and outputs are transferred as lists.
+        let input = match &method.wire_input() {
+            Some(type_) => {
+                gen.line(&format!(
+                    "val input = Json.decodeFromString({}, data){}",
+                    gen_serializer(type_, builtin_types),
+                    if method.input_stream { ".asFlow()" } else { "" }
+                ));
+                "input"
+            }
+            None => "",
+        };
+        let call = format!(
+            "service.{}({})",
+            identifier(&method.name.to_lower_camel_case()),
+            input
+        );
+        match &method.wire_output() {
+            Some(type_) => {
+                gen.line(&format!(
+                    "val output = {}{}",
+                    call,
+                    if method.output_stream {
+                        ".toList()"
+                    } else {
+                        ""
+                    }
+                ));
+                gen.line(&format!(
+                    "Json.encodeToString({}, output)",
+                    gen_serializer(type_, builtin_types)
+                ));
+            }
+            // Notifications do not return a response
+            None if method.notify => {
+                gen.line(&call);
+                gen.line("\"\"");
+            }
+            None => {
+                gen.line(&call);
+                gen.line("\"null\"");
+            }
+        }
+        gen.end("}");
+    }
+    gen.line("else -> throw IllegalArgumentException(\"Unknown method $method\")");
+    gen.end("}");
+    gen.end("}");
+    gen.end("}");
+}
+
+/// Name of the property holding the serializer of a generic parameter
+fn generic_serializer(name: &str) -> String {
+    let mut chars = name.chars();
+    match chars.next() {
+        Some(first) => format!(
+            "{}{}Serializer",
+            first.to_lowercase().collect::<String>(),
+            chars.as_str()
+        ),
+        None => "serializer".to_owned(),
+    }
+}
+
+/// Generate the serializer of a type. Generic parameters are not
+/// reified, so types containing them are built from the serializers
+/// passed to the serializer of the generic enum.
+fn gen_serializer(type_: &schema::Type, builtin_types: &HashMap<String, String>) -> String {
+    let mut has_generics = false;
+    type_.visit(&mut |t| has_generics |= matches!(t, schema::Type::Generic(_)));
+    if !has_generics {
+        return format!("serializer<{}>()", gen_typeref(type_, builtin_types));
+    }
+    let serializers = |types: &[schema::Type]| {
+        types
+            .iter()
+            .map(|t| gen_serializer(t, builtin_types))
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+    match type_ {
+        schema::Type::Generic(name) => generic_serializer(name),
+        schema::Type::Option(some) => format!(
+            "{}({})",
+            NULLABLE_SERIALIZER,
+            gen_serializer(some, builtin_types)
+        ),
+        schema::Type::Result(ok, err) => format!(
+            "webwire.Result.serializer({}, {})",
+            gen_serializer(ok, builtin_types),
+            gen_serializer(err, builtin_types)
+        ),
+        schema::Type::Array(array) => format!(
+            "ListSerializer({})",
+            gen_serializer(&array.item_type, builtin_types)
+        ),
+        schema::Type::Map(map) => format!(
+            "MapSerializer({}, {})",
+            gen_serializer(&map.key_type, builtin_types),
+            gen_serializer(&map.value_type, builtin_types)
+        ),
+        schema::Type::Ref(schema::TypeRef::Alias(alias)) => {
+            gen_serializer(&alias.target(), builtin_types)
+        }
+        schema::Type::Ref(typeref @ schema::TypeRef::Enum(_)) => format!(
+            "{}.Serializer({})",
+            gen_fqtn(typeref),
+            serializers(typeref.generics())
+        ),
+        schema::Type::Ref(typeref) => format!(
+            "{}.serializer({})",
+            gen_fqtn(typeref),
+            serializers(typeref.generics())
+        ),
+        _ => format!("serializer<{}>()", gen_typeref(type_, builtin_types)),
+    }
+}
+
+fn gen_fqtn(typeref: &schema::TypeRef) -> String {
+    let typeref_fqtn = typeref.fqtn();
+    typeref_fqtn
+        .ns
+        .iter()
+        .map(|ns| identifier(ns))
+        .chain(std::iter::once(typeref_fqtn.name.clone()))
+        .collect::<Vec<_>>()
+        .join(".")
+}
+
+pub fn gen_typeref(type_: &schema::Type, builtin_types: &HashMap<String, String>) -> String {
+    match type_ {
+        schema::Type::None => "JsonNull".to_string(),
+        schema::Type::Boolean => "Boolean".to_string(),
+        schema::Type::Integer => "Long".to_string(),
+        schema::Type::Float => "Double".to_string(),
+        schema::Type::String => "String".to_string(),
+        schema::Type::UUID => "String".to_string(),
+        schema::Type::Date => "kotlinx.datetime.LocalDate".to_string(),
+        schema::Type::Time => "kotlinx.datetime.LocalTime".to_string(),
+        schema::Type::DateTime => "kotlinx.datetime.Instant".to_string(),
+        schema::Type::Decimal => "String".to_string(),
+        schema::Type::Bytes => "String".to_string(),
+        schema::Type::Duration => "kotlin.time.Duration".to_string(),
+        schema::Type::Int8 => "Byte".to_string(),
+        schema::Type::Int16 => "Short".to_string(),
+        schema::Type::Int32 => "Int".to_string(),
+        schema::Type::Int64 => "Long".to_string(),
+        schema::Type::UInt8 => "UByte".to_string(),
+        schema::Type::UInt16 => "UShort".to_string(),
+        schema::Type::UInt32 => "UInt".to_string(),
+        schema::Type::UInt64 => "ULong".to_string(),
+        schema::Type::Option(some) => format!("{}?", gen_typeref(some, builtin_types)),
+        schema::Type::Result(ok, err) => {
+            format!(
+                "webwire.Result<{}, {}>",
+                gen_typeref(ok, builtin_types),
+                gen_typeref(err, builtin_types)
+            )
+        }
+        // complex types
+        schema::Type::Array(array) => {
+            format!("List<{}>", gen_typeref(&array.item_type, builtin_types))
+        }
+        schema::Type::Map(map) => format!(
+            "Map<{}, {}>",
+            gen_typeref(&map.key_type, builtin_types),
+            gen_typeref(&map.value_type, builtin_types)
+        ),
+        // named
+        schema::Type::Ref(schema::TypeRef::Alias(alias)) => {
+            gen_typeref(&alias.target(), builtin_types)
+        }
+        schema::Type::Ref(typeref) => {
+            let fqtn = gen_fqtn(typeref);
+            let generics = typeref.generics();
+            if !generics.is_empty() {
+                let generics = generics
+                    .iter()
+                    .map(|t| gen_typeref(t, builtin_types))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                format!("{}<{}>", fqtn, generics)
+            } else {
+                fqtn
+            }
+        }
+        schema::Type::Generic(name) => name.to_string(),
+        schema::Type::Builtin(name) => builtin_type(builtin_types, name).to_string(),
+    }
+}
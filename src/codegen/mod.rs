@@ -1,2 +1,72 @@
+use std::collections::HashMap;
+
+use crate::schema;
+
+mod common;
+mod output;
+
+pub mod doc;
+pub mod kotlin;
+pub mod plugin;
 pub mod rust;
+pub mod swift;
+pub mod template;
 pub mod ts;
+
+pub use output::Output;
+
+/// Error returned by [`check_builtin_types`]
+#[derive(Debug)]
+pub struct MissingBuiltinTypes {
+    pub names: Vec<String>,
+}
+
+impl std::fmt::Display for MissingBuiltinTypes {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "No mapping for builtin type: {}", self.names.join(", "))
+    }
+}
+
+impl std::error::Error for MissingBuiltinTypes {}
+
+/// Make sure that all builtin types used by the document are mapped
+/// to a type of the target language.
+pub fn check_builtin_types(
+    doc: &schema::Document,
+    builtin_types: &HashMap<String, String>,
+) -> Result<(), MissingBuiltinTypes> {
+    let names = doc
+        .builtin_types()
+        .into_iter()
+        .filter(|name| !builtin_types.contains_key(name))
+        .collect::<Vec<_>>();
+    if names.is_empty() {
+        Ok(())
+    } else {
+        Err(MissingBuiltinTypes { names })
+    }
+}
+
+#[test]
+fn test_check_builtin_types() {
+    let idl = r"
+        struct Link { url: Url, history: [Option<Url>] }
+        struct Account { id: AccountId }
+        service Accounts { get: AccountId -> Account }
+    ";
+    let idocs = [crate::idl::parse_document(idl).unwrap()];
+    let names = ["Url", "AccountId", "Unused"].map(String::from);
+    let doc = schema::Document::from_idl(idocs.iter(), &names.into()).unwrap();
+    let mut builtin_types = HashMap::from([("Url".to_owned(), "string".to_owned())]);
+    assert_eq!(
+        check_builtin_types(&doc, &builtin_types)
+            .unwrap_err()
+            .to_string(),
+        "No mapping for builtin type: AccountId"
+    );
+    builtin_types.insert("AccountId".to_owned(), "number".to_owned());
+    assert!(check_builtin_types(&doc, &builtin_types).is_ok());
+    let code = ts::gen(&doc, &builtin_types);
+    assert!(code.contains("    history: Array<webwire.Option<string>>,\n"));
+    assert!(code.contains("    get(input: number): webwire.Response<Account>,\n"));
+}
@@ -0,0 +1,66 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// Generated files mapped by their path relative to the output directory
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Output {
+    pub files: BTreeMap<String, String>,
+}
+
+impl Output {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    pub fn insert(&mut self, path: impl Into<String>, content: impl Into<String>) {
+        self.files.insert(path.into(), content.into());
+    }
+    pub fn contains(&self, path: &str) -> bool {
+        self.files.contains_key(path)
+    }
+    /// Write all files to the given directory. Files whose contents did
+    /// not change are not touched so build tools relying on modification
+    /// times do not rebuild unnecessarily. Returns the paths of the files
+    /// that were written.
+    pub fn write(&self, dir: &Path) -> io::Result<Vec<String>> {
+        let mut written = Vec::new();
+        for (name, content) in self.files.iter() {
+            let path = dir.join(name);
+            if fs::read(&path).is_ok_and(|current| current == content.as_bytes()) {
+                continue;
+            }
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            fs::write(&path, content)?;
+            written.push(name.clone());
+        }
+        Ok(written)
+    }
+}
+
+impl FromIterator<(String, String)> for Output {
+    fn from_iter<T: IntoIterator<Item = (String, String)>>(iter: T) -> Self {
+        Self {
+            files: iter.into_iter().collect(),
+        }
+    }
+}
+
+#[test]
+fn test_output_write() {
+    let dir = std::env::temp_dir().join(format!("webwire-output-{}", std::process::id()));
+    let mut output = Output::new();
+    output.insert("mod.rs", "pub mod user;\n");
+    output.insert("user/mod.rs", "pub struct User;\n");
+    assert_eq!(output.write(&dir).unwrap(), vec!["mod.rs", "user/mod.rs"]);
+    assert_eq!(output.write(&dir).unwrap(), Vec::<String>::new());
+    output.insert("user/mod.rs", "pub struct User {}\n");
+    assert_eq!(output.write(&dir).unwrap(), vec!["user/mod.rs"]);
+    assert_eq!(
+        fs::read_to_string(dir.join("user/mod.rs")).unwrap(),
+        "pub struct User {}\n"
+    );
+    fs::remove_dir_all(&dir).unwrap();
+}
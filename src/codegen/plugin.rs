@@ -0,0 +1,159 @@
+//! External code generators
+//!
+//! A plugin is an executable which receives a JSON encoded [`Request`]
+//! containing the [`ir::Document`] via stdin and writes a JSON encoded
+//! [`Response`] to stdout. The response contains a list of files which
+//! are written relative to the target directory:
+//!
+//! ```json
+//! {"files": [{"name": "api.py", "content": "..."}]}
+//! ```
+//!
+//! If the plugin fails it should respond with `{"error": "..."}` or
+//! exit with a non-zero exit code.
+
+use std::collections::BTreeMap;
+use std::io::Write;
+use std::path::{Component, Path};
+use std::process::{Command, Stdio};
+
+use serde::{Deserialize, Serialize};
+
+use crate::ir;
+use crate::schema;
+
+use super::Output;
+
+#[derive(Debug, Serialize)]
+pub struct Request<'a> {
+    /// The document including the version of the IR
+    pub document: &'a ir::Document,
+    pub parameters: &'a BTreeMap<String, String>,
+    /// Builtin types mapped to the types of the target language as
+    /// given by the user. The plugin is responsible for rejecting
+    /// builtin types without a mapping.
+    pub builtin_types: &'a BTreeMap<String, String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Response {
+    #[serde(default)]
+    pub files: Vec<File>,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, PartialEq, Eq, Deserialize)]
+pub struct File {
+    pub name: String,
+    pub content: String,
+}
+
+#[derive(Debug)]
+pub struct PluginError {
+    pub message: String,
+}
+
+impl std::fmt::Display for PluginError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for PluginError {}
+
+impl PluginError {
+    fn new(message: String) -> Self {
+        Self { message }
+    }
+}
+
+pub fn gen(
+    doc: &schema::Document,
+    plugin: &Path,
+    parameters: &BTreeMap<String, String>,
+    builtin_types: &BTreeMap<String, String>,
+) -> Result<Output, PluginError> {
+    let document = ir::Document::from(doc);
+    let request = serde_json::to_vec(&Request {
+        document: &document,
+        parameters,
+        builtin_types,
+    })
+    .map_err(|e| PluginError::new(format!("Could not serialize request: {}", e)))?;
+    let mut child = Command::new(plugin)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .map_err(|e| PluginError::new(format!("Could not run plugin {:?}: {}", plugin, e)))?;
+    // The request is written from a separate thread as the plugin might
+    // start writing its response before having read the entire request.
+    let mut stdin = child.stdin.take().unwrap();
+    let writer = std::thread::spawn(move || stdin.write_all(&request));
+    let output = child
+        .wait_with_output()
+        .map_err(|e| PluginError::new(format!("Could not run plugin {:?}: {}", plugin, e)))?;
+    let written = writer.join().unwrap();
+    if !output.status.success() {
+        return Err(PluginError::new(format!(
+            "Plugin {:?} failed: {}",
+            plugin, output.status
+        )));
+    }
+    written.map_err(|e| {
+        PluginError::new(format!(
+            "Could not write request to plugin {:?}: {}",
+            plugin, e
+        ))
+    })?;
+    parse_response(&output.stdout)
+        .map_err(|e| PluginError::new(format!("Plugin {:?} failed: {}", plugin, e)))
+}
+
+fn parse_response(data: &[u8]) -> Result<Output, String> {
+    let response: Response =
+        serde_json::from_slice(data).map_err(|e| format!("Invalid response: {}", e))?;
+    if let Some(error) = response.error {
+        return Err(error);
+    }
+    let mut output = Output::new();
+    for file in response.files {
+        if !is_relative_path(&file.name) {
+            return Err(format!("Invalid file name {:?}", file.name));
+        }
+        if output.contains(&file.name) {
+            return Err(format!("Duplicate file name {:?}", file.name));
+        }
+        output.insert(file.name, file.content);
+    }
+    Ok(output)
+}
+
+/// Check that the path is relative and does not leave the target
+/// directory.
+pub(crate) fn is_relative_path(path: &str) -> bool {
+    let path = Path::new(path);
+    path.components().next().is_some()
+        && path.components().all(|c| matches!(c, Component::Normal(_)))
+}
+
+#[test]
+fn test_parse_response() {
+    assert_eq!(
+        parse_response(br#"{"files": [{"name": "api/user.py", "content": "pass"}]}"#),
+        Ok(Output::from_iter([(
+            "api/user.py".to_string(),
+            "pass".to_string()
+        )]))
+    );
+    assert_eq!(
+        parse_response(br#"{"error": "Unsupported type"}"#),
+        Err("Unsupported type".to_string())
+    );
+    assert!(parse_response(br#"{"files": [{"name": "../x.py", "content": ""}]}"#).is_err());
+    assert!(parse_response(br#"{"files": [{"name": "/etc/x", "content": ""}]}"#).is_err());
+    assert!(parse_response(br#"{"files": [{"name": "", "content": ""}]}"#).is_err());
+    assert!(parse_response(
+        br#"{"files": [{"name": "a", "content": ""}, {"name": "a", "content": ""}]}"#
+    )
+    .is_err());
+}
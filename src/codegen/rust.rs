@@ -1,12 +1,114 @@
-use proc_macro2::TokenStream;
-use quote::quote;
+use std::collections::{HashMap, HashSet};
 
-use crate::schema::{self, TypeRef};
+use heck::ToUpperCamelCase;
+use proc_macro2::{Literal, TokenStream, TokenTree};
+use quote::{quote, ToTokens};
 
-pub fn gen(doc: &schema::Document) -> String {
-    let stream = generate(doc);
-    let code = format!("{}", stream);
-    code
+use crate::idl;
+use crate::schema::{self, TypeRef, FQTN};
+
+use super::common::builtin_type;
+use super::Output;
+
+#[derive(Clone, Debug, Default)]
+pub struct Options {
+    /// Names of the IDL files which are listed in the header of the
+    /// generated code
+    pub sources: Vec<String>,
+    /// Crate used for the `Date`, `Time` and `DateTime` types
+    pub time_crate: TimeCrate,
+    /// Rust types of the builtin types (e.g. `Url` to
+    /// `::url::Url`)
+    pub builtin_types: HashMap<String, String>,
+}
+
+/// Crates which can be used for date and time types
+///
+/// All types are (de)serialized using their ISO 8601 representation.
+/// `Duration` is mapped to `jiff::SignedDuration` regardless of the
+/// crate.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TimeCrate {
+    /// `chrono::NaiveDate`, `chrono::NaiveTime` and
+    /// `chrono::DateTime<Utc>` (requires the `serde` feature)
+    #[default]
+    Chrono,
+    /// `time::Date`, `time::Time` and `time::OffsetDateTime` (requires
+    /// the `serde-human-readable` feature and the `time_0_3` feature of
+    /// `serde_with`)
+    Time,
+    /// `jiff::civil::Date`, `jiff::civil::Time` and `jiff::Timestamp`
+    /// (requires the `serde` feature)
+    Jiff,
+}
+
+/// Error returned by [`check_builtin_types`]
+#[derive(Debug)]
+pub struct InvalidBuiltinType {
+    pub name: String,
+    pub type_: String,
+}
+
+impl std::fmt::Display for InvalidBuiltinType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "The builtin type {} is mapped to `{}` which is not a valid Rust type",
+            self.name, self.type_
+        )
+    }
+}
+
+impl std::error::Error for InvalidBuiltinType {}
+
+/// Make sure that all builtin types are mapped to valid Rust types. The
+/// code generator relies on this check having been done.
+pub fn check_builtin_types(
+    builtin_types: &HashMap<String, String>,
+) -> Result<(), InvalidBuiltinType> {
+    let mut names = builtin_types.keys().collect::<Vec<_>>();
+    names.sort();
+    for name in names {
+        let type_ = &builtin_types[name];
+        if syn::parse_str::<syn::Type>(type_).is_err() {
+            return Err(InvalidBuiltinType {
+                name: name.to_owned(),
+                type_: type_.to_owned(),
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Parse the Rust type of a builtin type which has been checked by
+/// [`check_builtin_types`]
+fn parse_builtin_type(name: &str, options: &Options) -> syn::Type {
+    syn::parse_str(builtin_type(&options.builtin_types, name))
+        .expect("builtin types are checked by check_builtin_types")
+}
+
+pub fn gen(doc: &schema::Document, options: &Options) -> String {
+    let stream = generate(doc, options);
+    format_code(stream, options)
+}
+
+/// Format the generated code and prepend the header. If the code can not
+/// be parsed it is returned unformatted so that the compiler can point
+/// out the error.
+fn format_code(stream: TokenStream, options: &Options) -> String {
+    let code = match syn::parse2::<syn::File>(stream.clone()) {
+        Ok(file) => prettyplease::unparse(&file),
+        Err(_) => format!("{}\n", stream),
+    };
+    let mut header = String::from("// GENERATED CODE - DO NOT EDIT!\n");
+    if !options.sources.is_empty() {
+        header += "//\n";
+        header += "// Generated from:\n";
+        for source in options.sources.iter() {
+            header += &format!("// - {}\n", source);
+        }
+    }
+    format!("{}\n{}", header, code)
 }
 
 fn optional(stream: TokenStream) -> TokenStream {
@@ -15,31 +117,53 @@ fn optional(stream: TokenStream) -> TokenStream {
     }
 }
 
-pub fn generate(doc: &schema::Document) -> TokenStream {
-    let namespace = gen_namespace(&doc.ns);
+pub fn generate(doc: &schema::Document, options: &Options) -> TokenStream {
+    let mut namespace = gen_namespace(&doc.ns, options);
+    namespace.extend(gen_annotation_types(&doc.ns));
     quote! {
         #[allow(dead_code)]
         #namespace
     }
 }
 
-fn gen_namespace(ns: &schema::Namespace) -> TokenStream {
-    let mut stream = TokenStream::new();
-    for type_ in ns.types.values() {
-        let type_stream = gen_type(type_, &ns.path);
-        stream.extend(type_stream);
+/// Generate a module tree with one `mod.rs` file per namespace.
+pub fn gen_files(doc: &schema::Document, options: &Options) -> Output {
+    let mut output = Output::new();
+    gen_namespace_file(&doc.ns, options, &mut output);
+    output
+}
+
+fn gen_namespace_file(ns: &schema::Namespace, options: &Options, output: &mut Output) {
+    let mut stream = gen_namespace_items(ns, options);
+    if ns.path.is_empty() {
+        stream.extend(gen_annotation_types(ns));
     }
-    for service in ns.services.values() {
-        let service_stream = gen_service(service, &ns.path);
-        stream.extend(service_stream);
-        let provider_stream = gen_provider(service, &ns.path);
-        stream.extend(provider_stream);
-        let consumer_stream = gen_consumer(service, &ns.path);
-        stream.extend(consumer_stream);
+    for child_ns in ns.namespaces.values() {
+        let child_ns_name = quote::format_ident!("{}", child_ns.name());
+        stream.extend(quote! {
+            pub mod #child_ns_name;
+        });
+        gen_namespace_file(child_ns, options, output);
     }
+    let path = ns
+        .path
+        .iter()
+        .map(String::as_str)
+        .chain(std::iter::once("mod.rs"))
+        .collect::<Vec<_>>()
+        .join("/");
+    let stream = quote! {
+        #![allow(dead_code)]
+        #stream
+    };
+    output.insert(path, format_code(stream, options));
+}
+
+fn gen_namespace(ns: &schema::Namespace, options: &Options) -> TokenStream {
+    let mut stream = gen_namespace_items(ns, options);
     for child_ns in ns.namespaces.values() {
         let child_ns_name = quote::format_ident!("{}", child_ns.name());
-        let child_ns_stream = gen_namespace(child_ns);
+        let child_ns_stream = gen_namespace(child_ns, options);
         stream.extend(quote! {
             pub mod #child_ns_name {
                 #child_ns_stream
@@ -49,36 +173,219 @@ fn gen_namespace(ns: &schema::Namespace) -> TokenStream {
     stream
 }
 
-fn gen_type(type_: &schema::UserDefinedType, ns: &[String]) -> TokenStream {
+fn gen_namespace_items(ns: &schema::Namespace, options: &Options) -> TokenStream {
+    let mut stream = TokenStream::new();
+    if uses_nested_validation(ns) {
+        stream.extend(quote! {
+            use ::validator::Validate as _;
+        });
+    }
+    for constant in ns.constants.values() {
+        stream.extend(gen_constant(constant, &ns.path, options));
+    }
+    for type_ in ns.types.values() {
+        let type_stream = gen_type(type_, &ns.path, options);
+        stream.extend(type_stream);
+    }
+    for service in ns.services.values() {
+        // Services implemented by the client can only be consumed by the
+        // server.
+        if service.side == idl::ServiceSide::Server {
+            let service_stream = gen_service(service, &ns.path, options);
+            stream.extend(service_stream);
+            let provider_stream = gen_provider(service, &ns.path, options);
+            stream.extend(provider_stream);
+        }
+        let consumer_stream = gen_consumer(service, &ns.path, options);
+        stream.extend(consumer_stream);
+    }
+    stream
+}
+
+fn gen_constant(constant: &schema::Constant, ns: &[String], options: &Options) -> TokenStream {
+    let name = quote::format_ident!("{}", constant.fqtn.name);
+    let (type_, value) = match (&constant.type_, &constant.value) {
+        (schema::Type::String, idl::Value::String(s)) => (quote! { &str }, quote! { #s }),
+        (type_, value) => (gen_typeref(type_, ns, options), gen_value(value, type_, ns)),
+    };
+    let allow_deprecated = gen_allow_deprecated(
+        constant.type_.uses_deprecated() || is_deprecated_variant(&constant.type_, &constant.value),
+    );
+    quote! {
+        #allow_deprecated
+        pub const #name: #type_ = #value;
+    }
+}
+
+fn gen_type(type_: &schema::UserDefinedType, ns: &[String], options: &Options) -> TokenStream {
     match type_ {
-        schema::UserDefinedType::Enum(enum_) => gen_enum(&enum_.borrow(), ns),
-        schema::UserDefinedType::Struct(struct_) => gen_struct(&struct_.borrow(), ns),
-        schema::UserDefinedType::Fieldset(fieldset) => gen_fieldset(&fieldset.borrow(), ns),
+        schema::UserDefinedType::Alias(alias) => gen_alias(&alias.borrow(), ns, options),
+        schema::UserDefinedType::Newtype(newtype) => gen_newtype(&newtype.borrow(), ns, options),
+        schema::UserDefinedType::Enum(enum_) => gen_enum(&enum_.borrow(), ns, options),
+        schema::UserDefinedType::Struct(struct_) => gen_struct(&struct_.borrow(), ns, options),
+        schema::UserDefinedType::Fieldset(fieldset) => {
+            gen_fieldset(&fieldset.borrow(), ns, options)
+        }
+    }
+}
+
+fn gen_generics(generics: &[String]) -> TokenStream {
+    if generics.is_empty() {
+        quote! {}
+    } else {
+        let generics = generics.iter().map(|g| quote::format_ident!("{}", g));
+        quote! { <#(#generics),*> }
+    }
+}
+
+/// Returns the derives for a type with the given field types. `Eq` can
+/// only be derived if none of the types contains a float.
+fn gen_derives<'a>(
+    types: impl Iterator<Item = &'a schema::Type>,
+    options: &Options,
+) -> TokenStream {
+    let mut visited = HashSet::new();
+    let mut derives = quote! { Clone, Debug };
+    if !types
+        .into_iter()
+        .any(|type_| contains_float(type_, options, &mut visited))
+    {
+        derives.extend(quote! { , Eq });
     }
+    derives.extend(quote! { , PartialEq, ::serde::Serialize, ::serde::Deserialize });
+    derives
 }
 
-fn gen_enum(enum_: &schema::Enum, ns: &[String]) -> TokenStream {
+fn contains_float(type_: &schema::Type, options: &Options, visited: &mut HashSet<FQTN>) -> bool {
+    match type_ {
+        schema::Type::Float => true,
+        schema::Type::Builtin(name) => builtin_contains_float(name, options),
+        schema::Type::Option(some) => contains_float(some, options, visited),
+        schema::Type::Result(ok, err) => {
+            contains_float(ok, options, visited) || contains_float(err, options, visited)
+        }
+        schema::Type::Array(array) => contains_float(&array.item_type, options, visited),
+        schema::Type::Map(map) => {
+            contains_float(&map.key_type, options, visited)
+                || contains_float(&map.value_type, options, visited)
+        }
+        schema::Type::Ref(typeref) => {
+            if typeref
+                .generics()
+                .iter()
+                .any(|type_| contains_float(type_, options, visited))
+            {
+                return true;
+            }
+            if !visited.insert(typeref.fqtn()) {
+                return false;
+            }
+            match typeref {
+                TypeRef::Alias(alias) => contains_float(&alias.target(), options, visited),
+                TypeRef::Newtype(newtype) => contains_float(
+                    &newtype.newtype.upgrade().unwrap().borrow().field.type_,
+                    options,
+                    visited,
+                ),
+                TypeRef::Enum(enum_) => enum_
+                    .enum_
+                    .upgrade()
+                    .unwrap()
+                    .borrow()
+                    .all_variants
+                    .iter()
+                    .filter_map(|v| v.value_type.as_ref())
+                    .any(|type_| contains_float(type_, options, visited)),
+                TypeRef::Struct(struct_) => struct_
+                    .struct_
+                    .upgrade()
+                    .unwrap()
+                    .borrow()
+                    .all_fields
+                    .iter()
+                    .any(|f| contains_float(&f.type_, options, visited)),
+                TypeRef::Fieldset(fieldset) => fieldset
+                    .fieldset
+                    .upgrade()
+                    .unwrap()
+                    .borrow()
+                    .fields
+                    .iter()
+                    .any(|f| contains_float(&f.field.as_ref().unwrap().type_, options, visited)),
+                TypeRef::Unresolved { .. } => false,
+            }
+        }
+        _ => false,
+    }
+}
+
+/// Whether the Rust type of a builtin type contains `f32` or `f64`
+fn builtin_contains_float(name: &str, options: &Options) -> bool {
+    fn contains(stream: TokenStream) -> bool {
+        stream.into_iter().any(|tree| match tree {
+            TokenTree::Ident(ident) => ident == "f32" || ident == "f64",
+            TokenTree::Group(group) => contains(group.stream()),
+            _ => false,
+        })
+    }
+    contains(parse_builtin_type(name, options).into_token_stream())
+}
+
+fn gen_enum(enum_: &schema::Enum, ns: &[String], options: &Options) -> TokenStream {
     let name = quote::format_ident!("{}", &enum_.fqtn.name);
-    let variants = gen_enum_variants(enum_, ns);
+    let generics = gen_generics(&enum_.generics);
+    let derives = gen_derives(
+        enum_
+            .all_variants
+            .iter()
+            .filter_map(|v| v.value_type.as_ref()),
+        options,
+    );
+    let variants = gen_enum_variants(enum_, ns, options);
+    let deprecated = gen_deprecated(enum_.deprecated.as_ref());
+    let variant_types = || {
+        enum_
+            .all_variants
+            .iter()
+            .filter_map(|v| v.value_type.as_ref())
+    };
+    let serde_as = gen_serde_as(variant_types(), options);
+    let allow_deprecated = gen_allow_deprecated(variant_types().any(schema::Type::uses_deprecated));
     let mut stream = TokenStream::new();
     stream.extend(quote! {
-        #[derive(Clone, Debug, Eq, PartialEq, ::serde::Serialize, ::serde::Deserialize)]
-        pub enum #name {
+        #serde_as
+        #[derive(#derives)]
+        #deprecated
+        #allow_deprecated
+        pub enum #name #generics {
             #variants
         }
     });
     if let Some(extends) = &enum_.extends {
-        let extends_typeref = gen_typeref_ref(extends, ns);
+        let extends_typeref = gen_typeref_ref(extends, ns, options);
+        let extends_path = gen_typeref_path(extends, ns);
         let mut matches = TokenStream::new();
         let extends_enum = enum_.extends_enum().unwrap();
+        let allow_deprecated = gen_allow_deprecated(
+            enum_.deprecated.is_some()
+                || extends_enum.borrow().deprecated.is_some()
+                || enum_.all_variants.iter().any(|v| v.deprecated.is_some())
+                || variant_types().any(schema::Type::uses_deprecated),
+        );
         for variant in extends_enum.borrow().all_variants.iter() {
             let variant_name = quote::format_ident!("{}", variant.name);
-            matches.extend(quote! {
-                #extends_typeref::#variant_name => #name::#variant_name,
+            matches.extend(match variant.value_type {
+                Some(_) => quote! {
+                    #extends_path::#variant_name(value) => Self::#variant_name(value),
+                },
+                None => quote! {
+                    #extends_path::#variant_name => Self::#variant_name,
+                },
             });
         }
         stream.extend(quote! {
-            impl From<#extends_typeref> for #name {
+            #allow_deprecated
+            impl #generics From<#extends_typeref> for #name #generics {
                 fn from(other: #extends_typeref) -> Self {
                     match other {
                         #matches
@@ -90,64 +397,336 @@ fn gen_enum(enum_: &schema::Enum, ns: &[String]) -> TokenStream {
     stream
 }
 
-fn gen_enum_variants(enum_: &schema::Enum, ns: &[String]) -> TokenStream {
+fn gen_enum_variants(enum_: &schema::Enum, ns: &[String], options: &Options) -> TokenStream {
     let mut stream = TokenStream::new();
-    for variant in enum_.variants.iter() {
-        stream.extend(gen_enum_variant(variant, ns));
-    }
-    if let Some(extends) = enum_.extends_enum() {
-        stream.extend(gen_enum_variants(&extends.borrow(), ns));
+    for variant in enum_.all_variants.iter() {
+        stream.extend(gen_enum_variant(variant, ns, options));
     }
     stream
 }
 
-fn gen_enum_variant(variant: &schema::EnumVariant, ns: &[String]) -> TokenStream {
+fn gen_enum_variant(
+    variant: &schema::EnumVariant,
+    ns: &[String],
+    options: &Options,
+) -> TokenStream {
     let name = quote::format_ident!("{}", variant.name);
+    let deprecated = gen_deprecated(variant.deprecated.as_ref());
     if let Some(value_type) = &variant.value_type {
-        let value_type = gen_typeref(value_type, ns);
+        let serde_attrs = gen_serde_attrs(value_type, false, options);
+        let value_type = gen_typeref(value_type, ns, options);
         quote! {
-            #name(#value_type),
+            #deprecated
+            #name(#serde_attrs #value_type),
         }
     } else {
         quote! {
+            #deprecated
             #name,
         }
     }
 }
 
-fn gen_struct(struct_: &schema::Struct, ns: &[String]) -> TokenStream {
+fn gen_alias(alias: &schema::Alias, ns: &[String], options: &Options) -> TokenStream {
+    let name = quote::format_ident!("{}", &alias.fqtn.name);
+    let generics = gen_generics(&alias.generics);
+    let type_ = gen_typeref(&alias.type_, ns, options);
+    let allow_deprecated = gen_allow_deprecated(alias.type_.uses_deprecated());
+    quote! {
+        #allow_deprecated
+        pub type #name #generics = #type_;
+    }
+}
+
+/// Newtypes use a named field as the `Validate` derive does not
+/// support tuple structs.
+fn gen_newtype(newtype: &schema::Newtype, ns: &[String], options: &Options) -> TokenStream {
+    let name = quote::format_ident!("{}", &newtype.fqtn.name);
+    let generics = gen_generics(&newtype.generics);
+    let serde_as = gen_serde_as(std::iter::once(&newtype.field.type_), options);
+    let derives = gen_derives(std::iter::once(&newtype.field.type_), options);
+    let type_ = gen_field_type(&newtype.field, ns, options);
+    let validation_macros = gen_validation_macros(&newtype.field, false);
+    let serde_attrs = gen_serde_attrs(&newtype.field.type_, false, options);
+    let deprecated = gen_deprecated(newtype.field.deprecated.as_ref());
+    let allow_deprecated = gen_allow_deprecated(newtype.field.type_.uses_deprecated());
+    quote! {
+        #serde_as
+        #[derive(#derives, ::validator::Validate)]
+        #[serde(transparent)]
+        #deprecated
+        #allow_deprecated
+        pub struct #name #generics {
+            #validation_macros
+            #serde_attrs
+            pub value: #type_,
+        }
+    }
+}
+
+fn gen_struct(struct_: &schema::Struct, ns: &[String], options: &Options) -> TokenStream {
     let name = quote::format_ident!("{}", &struct_.fqtn.name);
-    let fields = gen_struct_fields(struct_, ns);
+    let generics = gen_generics(&struct_.generics);
+    let serde_as = gen_serde_as(struct_.all_fields.iter().map(|f| &f.type_), options);
+    let derives = gen_derives(struct_.all_fields.iter().map(|f| &f.type_), options);
+    let fields = gen_struct_fields(struct_, ns, options);
+    let defaults = gen_struct_defaults(struct_, ns, options);
+    let deprecated = gen_deprecated(struct_.deprecated.as_ref());
+    let allow_deprecated = gen_allow_deprecated(
+        struct_
+            .all_fields
+            .iter()
+            .any(|field| field.type_.uses_deprecated()),
+    );
     quote! {
-        #[derive(Clone, Debug, Eq, PartialEq, ::serde::Serialize, ::serde::Deserialize, ::validator::Validate)]
-        pub struct #name {
+        #serde_as
+        #[derive(#derives, ::validator::Validate)]
+        #deprecated
+        #allow_deprecated
+        pub struct #name #generics {
             #fields
         }
+        #defaults
     }
 }
 
-fn gen_struct_fields(struct_: &schema::Struct, ns: &[String]) -> TokenStream {
+fn gen_struct_fields(struct_: &schema::Struct, ns: &[String], options: &Options) -> TokenStream {
     let mut stream = TokenStream::new();
-    for field in struct_.fields.iter() {
-        stream.extend(gen_struct_field(field, ns))
+    // Inherited fields are flattened into the struct.
+    for field in struct_.all_fields.iter() {
+        stream.extend(gen_struct_field(struct_, field, ns, options))
     }
     stream
 }
 
-fn gen_struct_field(field: &schema::Field, ns: &[String]) -> TokenStream {
+fn gen_struct_field(
+    struct_: &schema::Struct,
+    field: &schema::Field,
+    ns: &[String],
+    options: &Options,
+) -> TokenStream {
     let name = quote::format_ident!("{}", field.name);
-    let mut type_ = gen_typeref(&field.type_, ns);
+    let mut type_ = gen_field_type(field, ns, options);
     if field.optional {
         type_ = optional(type_);
     }
-    let validation_macros = gen_validation_macros(field);
+    let validation_macros = gen_validation_macros(field, field.optional);
+    let mut serde_attrs = gen_serde_attrs(&field.type_, field.optional, options);
+    if field.default.is_some() {
+        let default_fn = default_fn_path(struct_, field);
+        serde_attrs.extend(quote! { #[serde(default = #default_fn)] });
+    }
+    let deprecated = gen_deprecated(field.deprecated.as_ref());
     quote! {
         #validation_macros
+        #serde_attrs
+        #deprecated
         pub #name: #type_,
     }
 }
 
-fn gen_validation_macros(field: &schema::Field) -> TokenStream {
+/// Name of the associated function returning the default value of a
+/// field
+fn default_fn_name(field: &schema::Field) -> proc_macro2::Ident {
+    quote::format_ident!("default_{}", field.name)
+}
+
+/// Path of the function returning the default value of a field as used
+/// by `#[serde(default = "...")]`
+fn default_fn_path(struct_: &schema::Struct, field: &schema::Field) -> String {
+    let generics = if struct_.generics.is_empty() {
+        String::new()
+    } else {
+        format!("::<{}>", struct_.generics.join(", "))
+    };
+    format!(
+        "{}{}::{}",
+        struct_.fqtn.name,
+        generics,
+        default_fn_name(field)
+    )
+}
+
+/// Generate the associated functions returning the default values of
+/// the fields which are used by serde. If all other fields are optional
+/// or have a type implementing `Default` a `Default` implementation is
+/// generated, too. For generic structs it requires all type parameters
+/// to implement `Default`.
+fn gen_struct_defaults(struct_: &schema::Struct, ns: &[String], options: &Options) -> TokenStream {
+    let mut fns = TokenStream::new();
+    let mut values = TokenStream::new();
+    let mut implement_default = true;
+    let allow_deprecated = gen_allow_deprecated(
+        struct_.deprecated.is_some()
+            || struct_.all_fields.iter().any(|field| {
+                field.deprecated.is_some()
+                    || field.type_.uses_deprecated()
+                    || field
+                        .default
+                        .as_ref()
+                        .is_some_and(|value| is_deprecated_variant(&field.type_, value))
+            }),
+    );
+    for field in struct_.all_fields.iter() {
+        let name = quote::format_ident!("{}", field.name);
+        if let Some(default) = &field.default {
+            let default_fn = default_fn_name(field);
+            let type_ = gen_field_type(field, ns, options);
+            let value = gen_value(default, &field.type_, ns);
+            fns.extend(quote! {
+                #allow_deprecated
+                fn #default_fn() -> #type_ {
+                    #value
+                }
+            });
+            values.extend(quote! { #name: Self::#default_fn(), });
+        } else if field.optional
+            || has_default(&field.type_)
+            || matches!(field.type_, schema::Type::Generic(_))
+        {
+            values.extend(quote! { #name: Default::default(), });
+        } else {
+            implement_default = false;
+        }
+    }
+    if fns.is_empty() {
+        return fns;
+    }
+    let name = quote::format_ident!("{}", &struct_.fqtn.name);
+    let generics = gen_generics(&struct_.generics);
+    let mut stream = quote! {
+        impl #generics #name #generics {
+            #fns
+        }
+    };
+    if !implement_default {
+        return stream;
+    }
+    let bounds = if struct_.generics.is_empty() {
+        quote! {}
+    } else {
+        let generics = struct_
+            .generics
+            .iter()
+            .map(|g| quote::format_ident!("{}", g));
+        quote! { <#(#generics: Default),*> }
+    };
+    stream.extend(quote! {
+        #allow_deprecated
+        impl #bounds Default for #name #generics {
+            fn default() -> Self {
+                Self {
+                    #values
+                }
+            }
+        }
+    });
+    stream
+}
+
+fn gen_value(value: &idl::Value, type_: &schema::Type, ns: &[String]) -> TokenStream {
+    let number = |literal: Literal, negative: bool| {
+        if negative {
+            quote! { -#literal }
+        } else {
+            quote! { #literal }
+        }
+    };
+    match (value, type_) {
+        (_, schema::Type::Ref(TypeRef::Alias(alias))) => gen_value(value, &alias.target(), ns),
+        (idl::Value::Boolean(b), _) => quote! { #b },
+        (idl::Value::Integer(i), schema::Type::Float) => {
+            number(Literal::f64_unsuffixed(i.unsigned_abs() as f64), *i < 0)
+        }
+        (idl::Value::Integer(i), _) => number(Literal::u64_unsuffixed(i.unsigned_abs()), *i < 0),
+        (idl::Value::Float(f), _) => number(Literal::f64_unsuffixed(f.abs()), *f < 0.0),
+        (idl::Value::String(s), _) => quote! { #s.to_owned() },
+        (idl::Value::Identifier(variant), schema::Type::Ref(typeref)) => {
+            let path = gen_typeref_path(typeref, ns);
+            let variant = quote::format_ident!("{}", variant);
+            quote! { #path::#variant }
+        }
+        _ => unreachable!("values are checked by the schema validation"),
+    }
+}
+
+/// Whether the value refers to a deprecated enum variant
+fn is_deprecated_variant(type_: &schema::Type, value: &idl::Value) -> bool {
+    match (type_, value) {
+        (schema::Type::Ref(TypeRef::Alias(alias)), _) => {
+            is_deprecated_variant(&alias.target(), value)
+        }
+        (schema::Type::Ref(TypeRef::Enum(enum_)), idl::Value::Identifier(name)) => enum_
+            .enum_
+            .upgrade()
+            .unwrap()
+            .borrow()
+            .all_variants
+            .iter()
+            .any(|v| v.name == *name && v.deprecated.is_some()),
+        _ => false,
+    }
+}
+
+/// Whether the generated Rust type implements `Validate`. This is only
+/// the case for structs, fieldsets and newtypes.
+fn implements_validate(type_: &schema::Type) -> bool {
+    match type_ {
+        schema::Type::Ref(TypeRef::Alias(alias)) => implements_validate(&alias.target()),
+        schema::Type::Ref(TypeRef::Struct(_) | TypeRef::Fieldset(_) | TypeRef::Newtype(_)) => true,
+        _ => false,
+    }
+}
+
+/// Whether the generated Rust type implements `Default`
+fn has_default(type_: &schema::Type) -> bool {
+    if let schema::Type::Ref(TypeRef::Alias(alias)) = type_ {
+        return has_default(&alias.target());
+    }
+    matches!(
+        type_,
+        schema::Type::Boolean
+            | schema::Type::Integer
+            | schema::Type::Float
+            | schema::Type::String
+            | schema::Type::Int8
+            | schema::Type::Int16
+            | schema::Type::Int32
+            | schema::Type::Int64
+            | schema::Type::UInt8
+            | schema::Type::UInt16
+            | schema::Type::UInt32
+            | schema::Type::UInt64
+            | schema::Type::Option(_)
+            | schema::Type::Array(_)
+            | schema::Type::Map(_)
+    )
+}
+
+/// Generates `#[serde_as(as = "...")]` for fields whose type uses an
+/// adapter of `serde_with`
+fn gen_serde_attrs(type_: &schema::Type, optional: bool, options: &Options) -> TokenStream {
+    match serde_as_type(type_, options) {
+        // `serde_as` adds `#[serde(default)]` to optional fields.
+        Some(as_type) if optional => {
+            let as_type = format!("Option<{}>", as_type);
+            quote! { #[serde_as(as = #as_type)] }
+        }
+        Some(as_type) => quote! { #[serde_as(as = #as_type)] },
+        None => quote! {},
+    }
+}
+
+fn gen_field_type(field: &schema::Field, ns: &[String], options: &Options) -> TokenStream {
+    match (&field.type_, field.size) {
+        (schema::Type::Integer, Some(8)) => quote! { i8 },
+        (schema::Type::Integer, Some(16)) => quote! { i16 },
+        (schema::Type::Integer, Some(32)) => quote! { i32 },
+        (type_, _) => gen_typeref(type_, ns, options),
+    }
+}
+
+fn gen_validation_macros(field: &schema::Field, optional: bool) -> TokenStream {
     let mut rules = TokenStream::new();
     match field.format.as_deref() {
         Some("email") => rules.extend(quote! { email, }),
@@ -160,49 +739,125 @@ fn gen_validation_macros(field: &schema::Field) -> TokenStream {
         (None, Some(max)) => rules.extend(quote! { length(max=#max), }),
         (None, None) => {}
     }
-    if rules.is_empty() {
-        quote! {}
-    } else {
-        quote! {
-            #[validate(#rules)]
-        }
+    match field.range {
+        (Some(min), Some(max)) => rules.extend(quote! { range(min=#min, max=#max), }),
+        (Some(min), None) => rules.extend(quote! { range(min=#min), }),
+        (None, Some(max)) => rules.extend(quote! { range(max=#max), }),
+        (None, None) => {}
+    }
+    let mut macros = TokenStream::new();
+    if !rules.is_empty() {
+        macros.extend(quote! { #[validate(#rules)] });
+    }
+    if is_validated(&field.type_, optional) {
+        macros.extend(quote! { #[validate] });
     }
+    macros
 }
 
-fn gen_fieldset(fieldset: &schema::Fieldset, ns: &[String]) -> TokenStream {
+/// Whether the field is validated recursively using `#[validate]`. The
+/// `Validate` derive looks at the name of the field type and only
+/// supports types implementing `Validate` as well as options and `Vec`s
+/// of them. Other containers like maps are not validated recursively.
+fn is_validated(type_: &schema::Type, optional: bool) -> bool {
+    match type_ {
+        schema::Type::Option(some) if !optional => implements_validate(some),
+        schema::Type::Array(array) if !optional => implements_validate(&array.item_type),
+        type_ => implements_validate(type_),
+    }
+}
+
+/// Whether a type of the namespace contains a field which is validated
+/// recursively. The `Validate` derive calls `validate` on those fields
+/// which requires the trait to be in scope.
+fn uses_nested_validation(ns: &schema::Namespace) -> bool {
+    ns.types.values().any(|type_| match type_ {
+        schema::UserDefinedType::Newtype(newtype) => {
+            is_validated(&newtype.borrow().field.type_, false)
+        }
+        schema::UserDefinedType::Struct(struct_) => struct_
+            .borrow()
+            .all_fields
+            .iter()
+            .any(|field| is_validated(&field.type_, field.optional)),
+        schema::UserDefinedType::Fieldset(fieldset) => fieldset
+            .borrow()
+            .fields
+            .iter()
+            .any(|field| is_validated(&field.field.as_ref().unwrap().type_, field.optional)),
+        schema::UserDefinedType::Alias(_) | schema::UserDefinedType::Enum(_) => false,
+    })
+}
+
+fn gen_fieldset(fieldset: &schema::Fieldset, ns: &[String], options: &Options) -> TokenStream {
     let name = quote::format_ident!("{}", &fieldset.fqtn.name);
-    let fields = gen_fieldset_fields(fieldset, ns);
+    let generics = gen_generics(&fieldset.generics);
+    let field_types = || {
+        fieldset
+            .fields
+            .iter()
+            .map(|f| &f.field.as_ref().unwrap().type_)
+    };
+    let serde_as = gen_serde_as(field_types(), options);
+    let derives = gen_derives(field_types(), options);
+    let fields = gen_fieldset_fields(fieldset, ns, options);
+    let allow_deprecated = gen_allow_deprecated(field_types().any(schema::Type::uses_deprecated));
     quote! {
-        #[derive(Clone, Debug, Eq, PartialEq, ::serde::Serialize, ::serde::Deserialize, ::validator::Validate)]
-        pub struct #name {
+        #serde_as
+        #[derive(#derives, ::validator::Validate)]
+        #allow_deprecated
+        pub struct #name #generics {
             #fields
         }
     }
 }
 
-fn gen_fieldset_fields(struct_: &schema::Fieldset, ns: &[String]) -> TokenStream {
+fn gen_fieldset_fields(
+    struct_: &schema::Fieldset,
+    ns: &[String],
+    options: &Options,
+) -> TokenStream {
     let mut stream = TokenStream::new();
     for field in struct_.fields.iter() {
-        stream.extend(gen_fieldset_field(field, ns))
+        stream.extend(gen_fieldset_field(field, ns, options))
     }
     stream
 }
 
-fn gen_fieldset_field(field: &schema::FieldsetField, ns: &[String]) -> TokenStream {
+fn gen_fieldset_field(
+    field: &schema::FieldsetField,
+    ns: &[String],
+    options: &Options,
+) -> TokenStream {
     let name = quote::format_ident!("{}", field.name);
-    let mut type_ = gen_typeref(&field.field.as_ref().unwrap().type_, ns);
+    let mut type_ = gen_field_type(field.field.as_ref().unwrap(), ns, options);
     if field.optional {
         type_ = optional(type_);
     }
+    let validation_macros = gen_validation_macros(field.field.as_ref().unwrap(), field.optional);
+    let serde_attrs = gen_serde_attrs(
+        &field.field.as_ref().unwrap().type_,
+        field.optional,
+        options,
+    );
+    let deprecated = gen_deprecated(field.field.as_ref().unwrap().deprecated.as_ref());
     quote! {
+        #validation_macros
+        #serde_attrs
+        #deprecated
         pub #name: #type_,
     }
 }
 
-fn gen_service(service: &schema::Service, ns: &[String]) -> TokenStream {
+fn gen_service(service: &schema::Service, ns: &[String], options: &Options) -> TokenStream {
     let service_name = quote::format_ident!("{}", &service.name);
-    let methods = gen_service_methods(service, ns);
+    let methods = gen_service_methods(service, ns, options);
+    let deprecated = gen_deprecated(service.annotations.deprecated.as_ref());
+    let allow_deprecated =
+        gen_allow_deprecated(service.methods.iter().any(method_uses_deprecated_types));
     quote! {
+        #deprecated
+        #allow_deprecated
         #[::async_trait::async_trait]
         pub trait #service_name {
             type Error: Into<::webwire::ProviderError>;
@@ -211,36 +866,347 @@ fn gen_service(service: &schema::Service, ns: &[String]) -> TokenStream {
     }
 }
 
-fn gen_service_methods(service: &schema::Service, ns: &[String]) -> TokenStream {
+fn gen_service_methods(service: &schema::Service, ns: &[String], options: &Options) -> TokenStream {
     let mut stream = TokenStream::new();
     for method in service.methods.iter() {
-        let signature = gen_service_method_signature(method, ns);
+        let signature = gen_service_method_signature(method, ns, options);
+        let deprecated = gen_deprecated(method.annotations.deprecated.as_ref());
         stream.extend(quote! {
+            #deprecated
             #signature;
         })
     }
     stream
 }
 
-fn gen_service_method_signature(method: &schema::Method, ns: &[String]) -> TokenStream {
+fn gen_deprecated(deprecation: Option<&schema::Deprecation>) -> TokenStream {
+    let Some(deprecation) = deprecation else {
+        return quote! {};
+    };
+    match (&deprecation.since, &deprecation.note) {
+        (Some(since), Some(note)) => quote! { #[deprecated(since = #since, note = #note)] },
+        (Some(since), None) => quote! { #[deprecated(since = #since)] },
+        (None, Some(note)) => quote! { #[deprecated(note = #note)] },
+        (None, None) => quote! { #[deprecated] },
+    }
+}
+
+/// Returns `#[allow(deprecated)]` if the generated item uses deprecated
+/// types, fields, variants or methods. Uses by items which are not
+/// deprecated themselves are reported by `webwire check` instead.
+fn gen_allow_deprecated(uses_deprecated: bool) -> TokenStream {
+    if uses_deprecated {
+        quote! { #[allow(deprecated)] }
+    } else {
+        quote! {}
+    }
+}
+
+fn method_uses_deprecated_types(method: &schema::Method) -> bool {
+    method
+        .input
+        .iter()
+        .chain(method.output.iter())
+        .chain(method.throws.iter())
+        .chain(method.params.iter().flatten().map(|param| &param.type_))
+        .any(schema::Type::uses_deprecated)
+        || method
+            .params
+            .iter()
+            .flatten()
+            .any(|param| param.deprecated.is_some())
+}
+
+fn service_uses_deprecated(service: &schema::Service) -> bool {
+    service.annotations.deprecated.is_some()
+        || service.methods.iter().any(|method| {
+            method.annotations.deprecated.is_some() || method_uses_deprecated_types(method)
+        })
+}
+
+/// Name of the module at the root of the generated code which contains
+/// the `Annotations` and `RateLimit` types
+const ANNOTATIONS_MODULE: &str = "webwire_annotations";
+
+fn ns_uses_annotations(ns: &schema::Namespace) -> bool {
+    ns.services.values().any(|service| {
+        service.side == idl::ServiceSide::Server && !service_annotations_empty(service)
+    }) || ns.namespaces.values().any(ns_uses_annotations)
+}
+
+fn service_annotations_empty(service: &schema::Service) -> bool {
+    service.annotations.is_empty()
+        && service
+            .methods
+            .iter()
+            .all(|method| method.annotations.is_empty())
+}
+
+/// Generates the types used by the `ANNOTATIONS` and `METHOD_ANNOTATIONS`
+/// constants of the providers. They are part of the generated code so
+/// that it does not depend on a particular version of the runtime.
+fn gen_annotation_types(ns: &schema::Namespace) -> TokenStream {
+    if !ns_uses_annotations(ns) {
+        return quote! {};
+    }
+    let module = quote::format_ident!("{}", ANNOTATIONS_MODULE);
+    quote! {
+        pub mod #module {
+            /// Annotations of a service or method
+            #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+            pub struct Annotations {
+                /// The service or method is deprecated
+                pub deprecated: bool,
+                /// Role or scope required to call the service or method
+                pub auth: Option<&'static str>,
+                /// Maximum duration of a call
+                pub timeout: Option<::std::time::Duration>,
+                /// The method can safely be retried
+                pub idempotent: bool,
+                /// Maximum number of requests
+                pub rate_limit: Option<RateLimit>,
+            }
+            /// Maximum number of requests per time period
+            #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+            pub struct RateLimit {
+                pub requests: u64,
+                pub per: ::std::time::Duration,
+            }
+        }
+    }
+}
+
+/// Path of the annotations module relative to the namespace `ns`
+fn gen_annotations_module_path(ns: &[String]) -> TokenStream {
+    let supers = ns.iter().map(|_| quote! { super:: });
+    let module = quote::format_ident!("{}", ANNOTATIONS_MODULE);
+    quote! { #(#supers)* #module }
+}
+
+/// Adapter of `serde_with` for types whose serde implementation does
+/// not produce their JSON representation. Options, arrays, map values
+/// and results use the adapter of their values and `Same` for everything
+/// else (`_` only works inside of the `serde_as` attribute). `None` is
+/// returned if the serde implementation of the type is used as is. User
+/// defined types take care of their fields themselves.
+fn serde_as_type(type_: &schema::Type, options: &Options) -> Option<String> {
+    match type_ {
+        schema::Type::Bytes => Some("::serde_with::base64::Base64".to_owned()),
+        // The serde implementation of `time::OffsetDateTime` does not
+        // use RFC 3339.
+        schema::Type::DateTime if options.time_crate == TimeCrate::Time => {
+            Some("::time::format_description::well_known::Rfc3339".to_owned())
+        }
+        schema::Type::Ref(TypeRef::Alias(alias)) => serde_as_type(&alias.target(), options),
+        schema::Type::Option(some) => Some(format!("Option<{}>", serde_as_type(some, options)?)),
+        schema::Type::Result(ok, _) => Some(format!(
+            "Result<{}, ::serde_with::Same>",
+            serde_as_type(ok, options)?
+        )),
+        schema::Type::Array(array) => Some(format!(
+            "Vec<{}>",
+            serde_as_type(&array.item_type, options)?
+        )),
+        schema::Type::Map(map) => Some(format!(
+            "::std::collections::HashMap<::serde_with::Same, {}>",
+            serde_as_type(&map.value_type, options)?
+        )),
+        _ => None,
+    }
+}
+
+/// Generates `#[serde_as]` for types whose fields use an adapter of
+/// `serde_with`. It must precede the derive of the serde traits.
+fn gen_serde_as<'a>(
+    mut types: impl Iterator<Item = &'a schema::Type>,
+    options: &Options,
+) -> TokenStream {
+    if types.any(|type_| serde_as_type(type_, options).is_some()) {
+        quote! { #[::serde_with::serde_as] }
+    } else {
+        quote! {}
+    }
+}
+
+/// Type argument of `serde_json::from_slice` and the conversion of the
+/// deserialized value. Types with an adapter are deserialized via
+/// `DeserializeAsWrap`.
+fn gen_deserialize_as(
+    type_: &schema::Type,
+    ns: &[String],
+    options: &Options,
+) -> (TokenStream, TokenStream) {
+    let typeref = gen_typeref(type_, ns, options);
+    match serde_as_type(type_, options) {
+        Some(as_type) => {
+            let as_type = syn::parse_str::<syn::Type>(&as_type).expect("adapters are valid types");
+            (
+                quote! { ::serde_with::de::DeserializeAsWrap<#typeref, #as_type> },
+                quote! { .map(::serde_with::de::DeserializeAsWrap::into_inner) },
+            )
+        }
+        None => (typeref, quote! {}),
+    }
+}
+
+/// Turbofish of `serde_json::from_slice` and the conversion of the
+/// deserialized value for responses of consumers. The type is inferred
+/// unless it must be deserialized via an adapter.
+fn gen_consumer_deserialize_as(
+    type_: Option<&schema::Type>,
+    ns: &[String],
+    options: &Options,
+) -> (TokenStream, TokenStream) {
+    match type_ {
+        Some(type_) if serde_as_type(type_, options).is_some() => {
+            let (type_, map) = gen_deserialize_as(type_, ns, options);
+            (quote! { ::<#type_> }, map)
+        }
+        _ => (quote! {}, quote! {}),
+    }
+}
+
+/// Reference to a value which is passed to `serde_json::to_vec`. Types
+/// with an adapter are serialized via `SerializeAsWrap`.
+fn gen_serialize_as(
+    value: TokenStream,
+    type_: Option<&schema::Type>,
+    ns: &[String],
+    options: &Options,
+) -> TokenStream {
+    match type_.and_then(|type_| Some((type_, serde_as_type(type_, options)?))) {
+        Some((type_, as_type)) => {
+            let typeref = gen_typeref(type_, ns, options);
+            let as_type = syn::parse_str::<syn::Type>(&as_type).expect("adapters are valid types");
+            quote! { &::serde_with::ser::SerializeAsWrap::<#typeref, #as_type>::new(#value) }
+        }
+        None => value,
+    }
+}
+
+fn gen_annotations(annotations: &schema::Annotations, module: &TokenStream) -> TokenStream {
+    let deprecated = annotations.deprecated.is_some();
+    let auth = match &annotations.auth {
+        Some(auth) => quote! { Some(#auth) },
+        None => quote! { None },
+    };
+    let timeout = match annotations.timeout {
+        Some(timeout) => {
+            let ms = Literal::u64_unsuffixed(timeout.as_millis() as u64);
+            quote! { Some(::std::time::Duration::from_millis(#ms)) }
+        }
+        None => quote! { None },
+    };
+    let idempotent = annotations.idempotent;
+    let rate_limit = match annotations.rate_limit {
+        Some(rate_limit) => {
+            let requests = Literal::u64_unsuffixed(rate_limit.requests);
+            let per = Literal::u64_unsuffixed(rate_limit.per.as_millis() as u64);
+            quote! {
+                Some(#module::RateLimit {
+                    requests: #requests,
+                    per: ::std::time::Duration::from_millis(#per),
+                })
+            }
+        }
+        None => quote! { None },
+    };
+    quote! {
+        #module::Annotations {
+            deprecated: #deprecated,
+            auth: #auth,
+            timeout: #timeout,
+            idempotent: #idempotent,
+            rate_limit: #rate_limit,
+        }
+    }
+}
+
+/// Generates constants containing the annotations of the service and its
+/// methods which can be consulted by middlewares. Nothing is generated if
+/// neither the service nor its methods are annotated.
+fn gen_provider_annotations(service: &schema::Service, ns: &[String]) -> TokenStream {
+    if service_annotations_empty(service) {
+        return quote! {};
+    }
+    let provider_name = quote::format_ident!("{}Provider", service.name);
+    let module = gen_annotations_module_path(ns);
+    let annotations = gen_annotations(&service.annotations, &module);
+    let method_annotations = service.methods.iter().map(|method| {
+        let name = &method.name;
+        let annotations = gen_annotations(&method.annotations, &module);
+        quote! { (#name, #annotations) }
+    });
+    quote! {
+        impl<F> #provider_name<F> {
+            /// Annotations of the service
+            pub const ANNOTATIONS: #module::Annotations = #annotations;
+            /// Annotations of the methods by method name
+            pub const METHOD_ANNOTATIONS: &'static [(&'static str, #module::Annotations)] = &[
+                #(#method_annotations),*
+            ];
+        }
+    }
+}
+
+fn gen_service_method_signature(
+    method: &schema::Method,
+    ns: &[String],
+    options: &Options,
+) -> TokenStream {
     let name = quote::format_ident!("{}", method.name);
     let input_arg = match &method.input {
+        _ if method.params.is_some() => gen_params(method, ns, options),
+        Some(type_) if method.input_stream => {
+            let input_type = gen_typeref(type_, ns, options);
+            quote! { input: ::futures::stream::BoxStream<'static, Result<#input_type, ::webwire::ProviderError>> }
+        }
         Some(type_) => {
-            let input_type = gen_typeref(type_, ns);
+            let input_type = gen_typeref(type_, ns, options);
             quote! { input: & #input_type }
         }
         None => quote! {},
     };
-    let output = match &method.output {
-        Some(type_) => gen_typeref(type_, ns),
-        None => quote! { () },
+    // Providers return the errors of the method as part of the output.
+    let output = match (&method.output, method.wire_output()) {
+        (Some(type_), _) if method.output_stream => {
+            let output_type = gen_typeref(type_, ns, options);
+            quote! { ::futures::stream::BoxStream<'static, Result<#output_type, Self::Error>> }
+        }
+        (_, Some(type_)) => gen_typeref(&type_, ns, options),
+        (_, None) => quote! { () },
     };
     quote! {
         async fn #name(&self, #input_arg) -> Result<#output, Self::Error>
     }
 }
 
-fn gen_provider(service: &schema::Service, ns: &[String]) -> TokenStream {
+/// Generates the arguments of a method declared with a parameter list.
+/// They are passed by value as they are moved out of the input struct.
+fn gen_params(method: &schema::Method, ns: &[String], options: &Options) -> TokenStream {
+    let mut stream = TokenStream::new();
+    for param in method.params.iter().flatten() {
+        let name = quote::format_ident!("{}", param.name);
+        let mut type_ = gen_field_type(param, ns, options);
+        if param.optional {
+            type_ = optional(type_);
+        }
+        stream.extend(quote! { #name: #type_, });
+    }
+    stream
+}
+
+/// Names of the parameters of a method separated by commas
+fn gen_param_names(method: &schema::Method) -> TokenStream {
+    let names = method
+        .params
+        .iter()
+        .flatten()
+        .map(|param| quote::format_ident!("{}", param.name));
+    quote! { #(#names),* }
+}
+
+fn gen_provider(service: &schema::Service, ns: &[String], options: &Options) -> TokenStream {
     let service_name = quote::format_ident!("{}", service.name);
     let service_name_str = if ns.is_empty() {
         service.name.to_owned()
@@ -248,10 +1214,14 @@ fn gen_provider(service: &schema::Service, ns: &[String]) -> TokenStream {
         format!("{}.{}", ns.join("."), &service.name)
     };
     let provider_name = quote::format_ident!("{}Provider", service.name);
-    let matches = gen_provider_matches(service, ns);
+    let matches = gen_provider_matches(service, ns, options);
+    let annotations = gen_provider_annotations(service, ns);
+    let allow_deprecated = gen_allow_deprecated(service_uses_deprecated(service));
     quote! {
         pub struct #provider_name<F>(pub F);
+        #annotations
         // NamedProvider impl
+        #allow_deprecated
         impl<F: Sync + Send, S: Sync + Send, T: Sync + Send> ::webwire::NamedProvider<S> for #provider_name<F>
         where
             F: Fn(::std::sync::Arc<S>) -> T,
@@ -260,6 +1230,7 @@ fn gen_provider(service: &schema::Service, ns: &[String]) -> TokenStream {
             const NAME: &'static str = #service_name_str;
         }
         // Provider impl
+        #allow_deprecated
         impl<F: Sync + Send, S: Sync + Send, T: Sync + Send> ::webwire::Provider<S> for #provider_name<F>
         where
             F: Fn(::std::sync::Arc<S>) -> T,
@@ -282,66 +1253,190 @@ fn gen_provider(service: &schema::Service, ns: &[String]) -> TokenStream {
     }
 }
 
-fn gen_provider_matches(service: &schema::Service, ns: &[String]) -> TokenStream {
+fn gen_provider_matches(
+    service: &schema::Service,
+    ns: &[String],
+    options: &Options,
+) -> TokenStream {
     let mut stream = TokenStream::new();
     for method in service.methods.iter() {
         let name = quote::format_ident!("{}", method.name);
         let name_str = &method.name;
-        let input = match &method.input {
-            Some(type_) => gen_typeref(type_, ns),
-            None => quote! { () },
+        let (input, input_map) = match &method.wire_input() {
+            Some(type_) => gen_deserialize_as(type_, ns, options),
+            None => (quote! { () }, quote! {}),
         };
-        /*
-        let output = match &method.output {
-            Some(type_) => gen_typeref(type_),
-            None => quote! { () },
+        // Notifications do not return a response so there is no output
+        // which needs to be serialized.
+        let output = if method.notify {
+            quote! {}
+        } else {
+            quote! { let output = }
         };
-        */
         let method_call = match &method.input {
             None => quote! {
-                let output = service.#name().await.map_err(|e| e.into())?;
+                #output service.#name().await.map_err(|e| e.into())?;
             },
             Some(type_) => {
-                let validation = if type_.is_scalar() {
-                    quote! {}
+                let validate = implements_validate(type_);
+                let args = if method.params.is_some() {
+                    let names = gen_param_names(method);
+                    quote! {
+                        let #input { #names } = input;
+                        #output service.#name(#names).await.map_err(|e| e.into())?;
+                    }
+                } else if method.input_stream {
+                    // The values of a streamed input are validated when
+                    // they are consumed by the service.
+                    let item = if validate {
+                        quote! {
+                            |item| ::validator::Validate::validate(&item).map(|()| item).map_err(::webwire::ProviderError::ValidationError)
+                        }
+                    } else {
+                        quote! { Ok }
+                    };
+                    quote! {
+                        let input = ::futures::StreamExt::boxed(::futures::stream::iter(input.into_iter().map(#item)));
+                        #output service.#name(input).await.map_err(|e| e.into())?;
+                    }
                 } else {
+                    quote! {
+                        #output service.#name(&input).await.map_err(|e| e.into())?;
+                    }
+                };
+                let validation = if validate && !method.input_stream {
                     quote! {
                         ::validator::Validate::validate(&input).map_err(::webwire::ProviderError::ValidationError)?;
                     }
+                } else {
+                    quote! {}
                 };
                 quote! {
                     let input = serde_json::from_slice::<#input>(&input)
+                            #input_map
                             .map_err(::webwire::ProviderError::DeserializerError)?;
                     #validation
-                    let output = service.#name(&input).await.map_err(|e| e.into())?;
+                    #args
                 }
             }
         };
-        stream.extend(quote! {
-            #name_str => Box::pin(async move {
-                #method_call
-                let response = serde_json::to_vec(&output)
+        // A streamed output is sent once all its values are available.
+        let collect = if method.output_stream {
+            quote! {
+                let output = ::futures::TryStreamExt::try_collect::<Vec<_>>(output).await.map_err(|e| e.into())?;
+            }
+        } else {
+            quote! {}
+        };
+        let response = if method.notify {
+            quote! {
+                Ok(::bytes::Bytes::new())
+            }
+        } else {
+            let output = gen_serialize_as(
+                quote! { &output },
+                method.wire_output().as_ref(),
+                ns,
+                options,
+            );
+            quote! {
+                let response = serde_json::to_vec(#output)
                     .map_err(|e| ::webwire::ProviderError::SerializerError(e))
                     .map(::bytes::Bytes::from)?;
                 Ok(response)
+            }
+        };
+        stream.extend(quote! {
+            #name_str => Box::pin(async move {
+                #method_call
+                #collect
+                #response
             }),
         });
     }
     stream
 }
 
-fn gen_consumer(service: &schema::Service, ns: &[String]) -> TokenStream {
+fn gen_consumer(service: &schema::Service, ns: &[String], options: &Options) -> TokenStream {
     let consumer_name = quote::format_ident!("{}Consumer", service.name);
-    let consumer_methods = gen_consumer_methods(service, ns);
+    let consumer_methods = gen_consumer_methods(service, ns, options);
+    let consumer_errors = gen_consumer_errors(service, ns, options);
+    let deprecated = gen_deprecated(service.annotations.deprecated.as_ref());
+    let allow_deprecated = gen_allow_deprecated(service_uses_deprecated(service));
     quote! {
+        #consumer_errors
+        #deprecated
         pub struct #consumer_name<'a>(pub &'a (dyn ::webwire::Consumer + ::std::marker::Sync + ::std::marker::Send));
+        #allow_deprecated
         impl<'a> #consumer_name<'a> {
             #consumer_methods
         }
     }
 }
 
-fn gen_consumer_methods(service: &schema::Service, ns: &[String]) -> TokenStream {
+/// Name of the error returned by the consumer of a method which throws
+/// errors, e.g. `UsersGetConsumerError` for the method `get` of the
+/// service `Users`.
+fn consumer_error_name(service: &schema::Service, method: &schema::Method) -> proc_macro2::Ident {
+    quote::format_ident!(
+        "{}{}ConsumerError",
+        service.name,
+        method.name.to_upper_camel_case()
+    )
+}
+
+/// Generates the error enums of the consumer methods which throw errors.
+/// They combine the errors of the consumer with the ones of the method.
+fn gen_consumer_errors(service: &schema::Service, ns: &[String], options: &Options) -> TokenStream {
+    let mut stream = TokenStream::new();
+    for method in service.methods.iter() {
+        let Some(throws) = &method.throws else {
+            continue;
+        };
+        let name = consumer_error_name(service, method);
+        let throws = gen_typeref(throws, ns, options);
+        let service_error = format!("{}.{} failed: {{:?}}", service.name, method.name);
+        let allow_deprecated = gen_allow_deprecated(method_uses_deprecated_types(method));
+        stream.extend(quote! {
+            #[derive(Debug)]
+            #allow_deprecated
+            pub enum #name {
+                Consumer(::webwire::ConsumerError),
+                Service(#throws),
+            }
+            impl From<::webwire::ConsumerError> for #name {
+                fn from(error: ::webwire::ConsumerError) -> Self {
+                    Self::Consumer(error)
+                }
+            }
+            #allow_deprecated
+            impl ::std::fmt::Display for #name {
+                fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                    match self {
+                        Self::Consumer(error) => write!(f, "{}", error),
+                        Self::Service(error) => write!(f, #service_error, error),
+                    }
+                }
+            }
+            #allow_deprecated
+            impl ::std::error::Error for #name {
+                fn source(&self) -> Option<&(dyn ::std::error::Error + 'static)> {
+                    match self {
+                        Self::Consumer(error) => Some(error),
+                        Self::Service(_) => None,
+                    }
+                }
+            }
+        });
+    }
+    stream
+}
+
+fn gen_consumer_methods(
+    service: &schema::Service,
+    ns: &[String],
+    options: &Options,
+) -> TokenStream {
     let mut stream = TokenStream::new();
     let service_name_str = if ns.is_empty() {
         service.name.to_owned()
@@ -349,50 +1444,146 @@ fn gen_consumer_methods(service: &schema::Service, ns: &[String]) -> TokenStream
         format!("{}.{}", ns.join("."), &service.name)
     };
     for method in service.methods.iter() {
-        let signature = gen_consumer_method_signature(method, ns);
+        let signature = gen_consumer_method_signature(service, method, ns, options);
         let method_name_str = &method.name;
-        let serialization = match method.input {
-            Some(_) => quote! {
-                let data: ::bytes::Bytes = serde_json::to_vec(input)
-                    .map_err(|e| ::webwire::ConsumerError::SerializerError(e))?
-                    .into();
-            },
+        let serialization = match &method.input {
+            Some(type_) if method.params.is_some() => {
+                let input_type = gen_typeref(type_, ns, options);
+                let names = gen_param_names(method);
+                quote! {
+                    let data: ::bytes::Bytes = serde_json::to_vec(&#input_type { #names })
+                        .map_err(|e| ::webwire::ConsumerError::SerializerError(e))?
+                        .into();
+                }
+            }
+            // A streamed input is sent once all its values are available.
+            Some(_) if method.input_stream => {
+                let input =
+                    gen_serialize_as(quote! { &input }, method.wire_input().as_ref(), ns, options);
+                quote! {
+                    let input = ::futures::StreamExt::collect::<Vec<_>>(input).await;
+                    let data: ::bytes::Bytes = serde_json::to_vec(#input)
+                        .map_err(|e| ::webwire::ConsumerError::SerializerError(e))?
+                        .into();
+                }
+            }
+            Some(type_) => {
+                let input = gen_serialize_as(quote! { input }, Some(type_), ns, options);
+                quote! {
+                    let data: ::bytes::Bytes = serde_json::to_vec(#input)
+                        .map_err(|e| ::webwire::ConsumerError::SerializerError(e))?
+                        .into();
+                }
+            }
             None => quote! {
                 let data = ::bytes::Bytes::new();
             },
         };
+        if method.notify {
+            // `Consumer::notify` does not report errors as it does not
+            // wait for the message to be delivered.
+            stream.extend(quote! {
+                #signature {
+                    #serialization
+                    self.0.notify(#service_name_str, #method_name_str, data);
+                    Ok(())
+                }
+            });
+            continue;
+        }
+        let response = match &method.wire_output() {
+            Some(output) if method.throws.is_some() => {
+                let output_type = gen_typeref(output, ns, options);
+                let (turbofish, output_map) =
+                    gen_consumer_deserialize_as(Some(output), ns, options);
+                let error_name = consumer_error_name(service, method);
+                quote! {
+                    let response: #output_type = ::serde_json::from_slice #turbofish (&output)
+                        #output_map
+                        .map_err(|e| ::webwire::ConsumerError::DeserializerError(e))?;
+                    response.map_err(#error_name::Service)
+                }
+            }
+            Some(output) if method.output_stream => {
+                let output_type = gen_typeref(output, ns, options);
+                let (turbofish, output_map) =
+                    gen_consumer_deserialize_as(Some(output), ns, options);
+                quote! {
+                    let response: #output_type = ::serde_json::from_slice #turbofish (&output)
+                        #output_map
+                        .map_err(|e| ::webwire::ConsumerError::DeserializerError(e))?;
+                    Ok(::futures::StreamExt::boxed(::futures::stream::iter(response.into_iter().map(Ok))))
+                }
+            }
+            output => {
+                let (turbofish, output_map) =
+                    gen_consumer_deserialize_as(output.as_ref(), ns, options);
+                quote! {
+                    let response = ::serde_json::from_slice #turbofish (&output)
+                        #output_map
+                        .map_err(|e| ::webwire::ConsumerError::DeserializerError(e))?;
+                    Ok(response)
+                }
+            }
+        };
         stream.extend(quote! {
             #signature {
                 #serialization
                 let output = self.0.request(#service_name_str, #method_name_str, data).await?;
-                let response = ::serde_json::from_slice(&output)
-                    .map_err(|e| ::webwire::ConsumerError::DeserializerError(e))?;
-                Ok(response)
+                #response
             }
         })
     }
     stream
 }
 
-fn gen_consumer_method_signature(method: &schema::Method, ns: &[String]) -> TokenStream {
+fn gen_consumer_method_signature(
+    service: &schema::Service,
+    method: &schema::Method,
+    ns: &[String],
+    options: &Options,
+) -> TokenStream {
     let name = quote::format_ident!("{}", method.name);
     let input_arg = match &method.input {
+        _ if method.params.is_some() => gen_params(method, ns, options),
+        Some(type_) if method.input_stream => {
+            let input_type = gen_typeref(type_, ns, options);
+            quote! { input: ::futures::stream::BoxStream<'static, #input_type> }
+        }
         Some(type_) => {
-            let input_type = gen_typeref(type_, ns);
+            let input_type = gen_typeref(type_, ns, options);
             quote! { input: & #input_type }
         }
         None => quote! {},
     };
     let output = match &method.output {
-        Some(type_) => gen_typeref(type_, ns),
+        Some(type_) if method.output_stream => {
+            let output_type = gen_typeref(type_, ns, options);
+            quote! { ::futures::stream::BoxStream<'a, Result<#output_type, ::webwire::ConsumerError>> }
+        }
+        Some(type_) => gen_typeref(type_, ns, options),
         None => quote! { () },
     };
+    let deprecated = gen_deprecated(method.annotations.deprecated.as_ref());
+    if method.notify {
+        return quote! {
+            #deprecated
+            pub fn #name(&self, #input_arg) -> Result<(), ::webwire::ConsumerError>
+        };
+    }
+    let error = if method.throws.is_some() {
+        let error_name = consumer_error_name(service, method);
+        quote! { #error_name }
+    } else {
+        quote! { ::webwire::ConsumerError }
+    };
     quote! {
-        pub async fn #name(&self, #input_arg) -> Result<#output, ::webwire::ConsumerError>
+        #deprecated
+        pub async fn #name(&self, #input_arg) -> Result<#output, #error>
     }
 }
 
-fn gen_typeref(type_: &schema::Type, ns: &[String]) -> TokenStream {
+fn gen_typeref(type_: &schema::Type, ns: &[String], options: &Options) -> TokenStream {
     match type_ {
         schema::Type::None => quote! { () },
         schema::Type::Boolean => quote! { bool },
@@ -400,57 +1591,85 @@ fn gen_typeref(type_: &schema::Type, ns: &[String]) -> TokenStream {
         schema::Type::Float => quote! { f64 },
         schema::Type::String => quote! { String },
         schema::Type::UUID => quote! { ::uuid::Uuid },
-        schema::Type::Date => quote! { ::chrono::Date },
-        schema::Type::Time => quote! { ::chrono::Time },
-        schema::Type::DateTime => quote! { ::chrono::DateTime<::chrono::Utc> },
-        schema::Type::Option(some) => {
-            let some_type = gen_typeref(some, ns);
-            quote! { std::option::Option<#some_type> }
-        }
+        schema::Type::Date => match options.time_crate {
+            TimeCrate::Chrono => quote! { ::chrono::NaiveDate },
+            TimeCrate::Time => quote! { ::time::Date },
+            TimeCrate::Jiff => quote! { ::jiff::civil::Date },
+        },
+        schema::Type::Time => match options.time_crate {
+            TimeCrate::Chrono => quote! { ::chrono::NaiveTime },
+            TimeCrate::Time => quote! { ::time::Time },
+            TimeCrate::Jiff => quote! { ::jiff::civil::Time },
+        },
+        schema::Type::DateTime => match options.time_crate {
+            TimeCrate::Chrono => quote! { ::chrono::DateTime<::chrono::Utc> },
+            TimeCrate::Time => quote! { ::time::OffsetDateTime },
+            TimeCrate::Jiff => quote! { ::jiff::Timestamp },
+        },
+        schema::Type::Decimal => quote! { ::rust_decimal::Decimal },
+        schema::Type::Bytes => quote! { Vec<u8> },
+        // Only `jiff` (de)serializes durations as ISO 8601 strings so it
+        // is used regardless of the time crate.
+        schema::Type::Duration => quote! { ::jiff::SignedDuration },
+        schema::Type::Int8 => quote! { i8 },
+        schema::Type::Int16 => quote! { i16 },
+        schema::Type::Int32 => quote! { i32 },
+        schema::Type::Int64 => quote! { i64 },
+        schema::Type::UInt8 => quote! { u8 },
+        schema::Type::UInt16 => quote! { u16 },
+        schema::Type::UInt32 => quote! { u32 },
+        schema::Type::UInt64 => quote! { u64 },
+        schema::Type::Option(some) => optional(gen_typeref(some, ns, options)),
         schema::Type::Result(ok, err) => {
-            let ok_type = gen_typeref(ok, ns);
-            let err_type = gen_typeref(err, ns);
+            let ok_type = gen_typeref(ok, ns, options);
+            let err_type = gen_typeref(err, ns, options);
             quote! { std::result::Result<#ok_type, #err_type> }
         }
         // complex types
+        // The `Validate` derive recognizes `Vec` and `Option` by name.
         schema::Type::Array(array) => {
-            let item_type = gen_typeref(&array.item_type, ns);
+            let item_type = gen_typeref(&array.item_type, ns, options);
             quote! {
-                std::vec::Vec<#item_type>
+                Vec<#item_type>
             }
         }
         schema::Type::Map(map) => {
-            let key_type = gen_typeref(&map.key_type, ns);
-            let value_type = gen_typeref(&map.value_type, ns);
+            let key_type = gen_typeref(&map.key_type, ns, options);
+            let value_type = gen_typeref(&map.value_type, ns, options);
             quote! {
                 std::collections::HashMap<#key_type, #value_type>
             }
         }
         // named
-        schema::Type::Ref(typeref) => gen_typeref_ref(typeref, ns),
+        schema::Type::Ref(typeref) => gen_typeref_ref(typeref, ns, options),
+        schema::Type::Generic(name) => {
+            let name = quote::format_ident!("{}", name);
+            quote! { #name }
+        }
         schema::Type::Builtin(name) => {
-            // FIXME unwrap... igh!
-            let identifier: TokenStream = ::syn::parse_str(name).unwrap();
-            quote! {
-                #identifier
-            }
+            let type_ = parse_builtin_type(name, options);
+            quote! { #type_ }
         }
     }
 }
 
-fn gen_typeref_ref(typeref: &TypeRef, ns: &[String]) -> TokenStream {
-    let mut generics_stream = TokenStream::new();
-    if !typeref.generics().is_empty() {
-        for generic in typeref.generics().iter() {
-            let type_ = gen_typeref(generic, ns);
-            generics_stream.extend(quote! {
-                #type_,
-            })
-        }
-        generics_stream = quote! {
-            < #generics_stream >
-        }
+fn gen_typeref_ref(typeref: &TypeRef, ns: &[String], options: &Options) -> TokenStream {
+    let path = gen_typeref_path(typeref, ns);
+    if typeref.generics().is_empty() {
+        return path;
     }
+    let generics = typeref
+        .generics()
+        .iter()
+        .map(|t| gen_typeref(t, ns, options));
+    quote! {
+        #path<#(#generics),*>
+    }
+}
+
+/// Path of the referenced type relative to the namespace `ns` without
+/// generics
+fn gen_typeref_path(typeref: &TypeRef, ns: &[String]) -> TokenStream {
     let typeref_fqtn = typeref.fqtn();
     let common_ns = typeref_fqtn
         .ns
@@ -478,8 +1697,208 @@ fn gen_typeref_ref(typeref: &TypeRef, ns: &[String]) -> TokenStream {
         name => {
             let name = quote::format_ident!("{}", name);
             quote! {
-                #relative_ns #name #generics_stream
+                #relative_ns #name
             }
         }
     }
 }
+
+#[test]
+fn test_gen_formatted() {
+    let idocs = [crate::idl::parse_document("struct Point { x: Integer, y: Integer }").unwrap()];
+    let builtin_types = std::collections::HashSet::default();
+    let doc = schema::Document::from_idl(idocs.iter(), &builtin_types).unwrap();
+    let options = Options {
+        sources: vec!["api/point.ww".to_string()],
+        ..Default::default()
+    };
+    let code = gen(&doc, &options);
+    assert!(code.starts_with(
+        "// GENERATED CODE - DO NOT EDIT!\n//\n// Generated from:\n// - api/point.ww\n\n"
+    ));
+    assert!(code.contains("pub struct Point {\n    pub x: i64,\n    pub y: i64,\n}\n"));
+}
+
+#[test]
+fn test_gen_time_crate() {
+    let idl = r#"
+        struct Event {
+            date: Date,
+            start?: DateTime,
+            times: [Time],
+            history: {String: DateTime},
+        }
+        newtype Deadline = DateTime;
+        enum Schedule { At(DateTime), Never }
+        service Events {
+            next: DateTime -> [DateTime],
+        }
+    "#;
+    let idocs = [crate::idl::parse_document(idl).unwrap()];
+    let builtin_types = std::collections::HashSet::default();
+    let doc = schema::Document::from_idl(idocs.iter(), &builtin_types).unwrap();
+    let code = gen(&doc, &Options::default());
+    assert!(code.contains("pub date: ::chrono::NaiveDate,"));
+    assert!(code.contains("pub times: Vec<::chrono::NaiveTime>,"));
+    assert!(!code.contains("serde_with"));
+    let options = Options {
+        time_crate: TimeCrate::Time,
+        ..Default::default()
+    };
+    let code = gen(&doc, &options);
+    // Dates and times use the serde implementation of the `time` crate.
+    assert!(code.contains("    pub date: ::time::Date,\n"));
+    assert!(code.contains("    pub times: Vec<::time::Time>,\n"));
+    let rfc3339 = "::time::format_description::well_known::Rfc3339";
+    assert!(code.contains(&format!(
+        "    #[serde_as(as = \"Option<{}>\")]\n    pub start: Option<::time::OffsetDateTime>,\n",
+        rfc3339
+    )));
+    assert!(code.contains(&format!(
+        "    #[serde_as(as = \"{}\")]\n    pub value: ::time::OffsetDateTime,\n",
+        rfc3339
+    )));
+    assert!(code.contains("#[::serde_with::serde_as]\n#[derive("));
+    let compact = code.split_whitespace().collect::<String>();
+    assert!(compact.contains(&format!(
+        "#[serde_as(as=\"::std::collections::HashMap<::serde_with::Same,{}>\")]pubhistory:",
+        rfc3339
+    )));
+    assert!(compact.contains(&format!(
+        "DeserializeAsWrap<::time::OffsetDateTime,{},>",
+        rfc3339
+    )));
+    assert!(compact.contains(&format!(
+        "SerializeAsWrap::<Vec<::time::OffsetDateTime>,Vec<{}>,>::new(&output)",
+        rfc3339
+    )));
+    let options = Options {
+        time_crate: TimeCrate::Jiff,
+        ..Default::default()
+    };
+    let code = gen(&doc, &options);
+    assert!(code.contains("pub start: Option<::jiff::Timestamp>,"));
+    assert!(!code.contains("serde_with"));
+}
+
+#[test]
+fn test_gen_formats() {
+    let idl = r#"
+        struct File { data: Bytes, parts: [Bytes], checksum?: Bytes }
+        namespace jobs {
+            struct Job { timeout: Duration, retries: {String: Duration} }
+            service Jobs { run: Duration -> [Bytes] }
+        }
+    "#;
+    let idocs = [crate::idl::parse_document(idl).unwrap()];
+    let builtin_types = std::collections::HashSet::default();
+    let doc = schema::Document::from_idl(idocs.iter(), &builtin_types).unwrap();
+    let code = gen(&doc, &Options::default());
+    let base64 = "::serde_with::base64::Base64";
+    assert!(code.contains(&format!(
+        "    #[serde_as(as = \"{}\")]\n    pub data: Vec<u8>,\n",
+        base64
+    )));
+    assert!(code.contains(&format!(
+        "    #[serde_as(as = \"Vec<{}>\")]\n    pub parts: Vec<Vec<u8>>,\n",
+        base64
+    )));
+    assert!(code.contains(&format!(
+        "    #[serde_as(as = \"Option<{}>\")]\n    pub checksum: Option<Vec<u8>>,\n",
+        base64
+    )));
+    // Durations are (de)serialized as ISO 8601 by `jiff` regardless of
+    // the time crate.
+    for time_crate in [TimeCrate::Chrono, TimeCrate::Time, TimeCrate::Jiff] {
+        let code = gen(
+            &doc,
+            &Options {
+                time_crate,
+                ..Default::default()
+            },
+        );
+        assert!(code.contains("        pub timeout: ::jiff::SignedDuration,\n"));
+        assert!(code
+            .contains("pub retries: std::collections::HashMap<String, ::jiff::SignedDuration>,"));
+    }
+    // Method outputs are wrapped as they have no field attributes.
+    let compact = code.split_whitespace().collect::<String>();
+    assert!(compact.contains("to_vec(input)"));
+    assert!(compact.contains(&format!(
+        "SerializeAsWrap::<Vec<Vec<u8>>,Vec<{}>,>::new(&output)",
+        base64
+    )));
+    assert!(compact.contains(&format!("DeserializeAsWrap<Vec<Vec<u8>>,Vec<{}>,>", base64)));
+    // Nothing is generated if no type needs an adapter.
+    let idocs = [crate::idl::parse_document("struct A { a: Integer }").unwrap()];
+    let doc = schema::Document::from_idl(idocs.iter(), &builtin_types).unwrap();
+    assert!(!gen(&doc, &Options::default()).contains("serde_with"));
+}
+
+#[test]
+fn test_check_builtin_types() {
+    let mut builtin_types = HashMap::from([
+        ("Url".to_owned(), "::url::Url".to_owned()),
+        ("Money".to_owned(), "(i64, [u8; 3])".to_owned()),
+    ]);
+    assert!(check_builtin_types(&builtin_types).is_ok());
+    builtin_types.insert("Broken".to_owned(), "not a type!!".to_owned());
+    assert_eq!(
+        check_builtin_types(&builtin_types).unwrap_err().to_string(),
+        "The builtin type Broken is mapped to `not a type!!` which is not a valid Rust type"
+    );
+}
+
+#[test]
+fn test_gen_builtin_float() {
+    let idl = r"
+        struct Price { amount: Money }
+        struct Prices { amounts: [Money] }
+        struct Link { url: Url }
+    ";
+    let idocs = [crate::idl::parse_document(idl).unwrap()];
+    let names = ["Money", "Url"].map(String::from).into();
+    let doc = schema::Document::from_idl(idocs.iter(), &names).unwrap();
+    let options = Options {
+        builtin_types: HashMap::from([
+            ("Money".to_owned(), "Option<f64>".to_owned()),
+            ("Url".to_owned(), "::url::Url".to_owned()),
+        ]),
+        ..Default::default()
+    };
+    let code = gen(&doc, &options).split_whitespace().collect::<String>();
+    assert!(code.contains("#[derive(Clone,Debug,PartialEq,::serde::Serialize,::serde::Deserialize,::validator::Validate)]pubstructPrice{"));
+    assert!(code.contains("#[derive(Clone,Debug,PartialEq,::serde::Serialize,::serde::Deserialize,::validator::Validate)]pubstructPrices{"));
+    assert!(code.contains("#[derive(Clone,Debug,Eq,PartialEq,::serde::Serialize,::serde::Deserialize,::validator::Validate)]pubstructLink{"));
+}
+
+#[test]
+fn test_gen_nested_validation() {
+    let idl = r#"
+        struct Item { name: String (length=1..10) }
+        struct Order {
+            item: Item,
+            items: [Item] (length=1..5),
+            maybe: Option<Item>,
+            gift?: Item,
+            wrapped?: [Item],
+            by_name: {String: Item},
+        }
+    "#;
+    let idocs = [crate::idl::parse_document(idl).unwrap()];
+    let builtin_types = std::collections::HashSet::default();
+    let doc = schema::Document::from_idl(idocs.iter(), &builtin_types).unwrap();
+    let code = gen(&doc, &Options::default());
+    assert!(code.contains("use ::validator::Validate as _;\n"));
+    assert!(code.contains("    #[validate]\n    pub item: Item,\n"));
+    assert!(code.contains(
+        "    #[validate(length(min = 1i64, max = 5i64))]\n    #[validate]\n    pub items: Vec<Item>,\n"
+    ));
+    assert!(code.contains("    #[validate]\n    pub maybe: Option<Item>,\n"));
+    assert!(code.contains("    #[validate]\n    pub gift: Option<Item>,\n"));
+    // The `Validate` derive does not support these types.
+    assert!(code.contains("    pub gift: Option<Item>,\n    pub wrapped: Option<Vec<Item>>,\n"));
+    assert!(code.contains(
+        "    pub wrapped: Option<Vec<Item>>,\n    pub by_name: std::collections::HashMap<String, Item>,\n"
+    ));
+}
@@ -0,0 +1,759 @@
+//! Swift code generator
+//!
+//! The generated code uses `Codable` and produces the same JSON as the
+//! serde based Rust code. It requires Swift 5.10 or newer and expects a
+//! `Webwire` module providing the following types:
+//!
+//! - `Webwire.Null` representing the JSON `null` value
+//! - `Webwire.Result<T, E>` serialized as `{"Ok": ...}` or `{"Err": ...}`
+//! - `Webwire.Date`, `Webwire.Time` and `Webwire.DateTime` serialized as
+//!   ISO 8601 strings
+//! - `Webwire.Decimal` serialized as string and `Webwire.Duration`
+//!   serialized as ISO 8601 string
+//! - `Webwire.Client` protocol with a
+//!   `func request(service: String, method: String, data: Data) async throws -> Data`
+//!   method and a
+//!   `func notify(service: String, method: String, data: Data) throws`
+//!   method which sends a notification without waiting for a response.
+//! - `Webwire.Provider` protocol with a `name: String` property and a
+//!   `func call(method: String, data: Data) async throws -> Data` method
+//!   which is implemented for services provided by the client.
+//! - `Webwire.MethodNotFound` error with a `method: String` property
+//!
+//! Streaming methods use `AsyncThrowingStream`. A stream is transferred
+//! as a JSON array of all its values via `request` and `call`.
+//!
+//! `JSONEncoder` only encodes dictionaries with `String` or `Int` keys
+//! as JSON objects and all other dictionaries as arrays of alternating
+//! keys and values. Maps with keys other than strings are therefore
+//! represented as `[String: V]` containing the keys in their JSON
+//! representation, e.g. `"42"` for an `Int64` key.
+
+use std::collections::HashMap;
+
+use heck::ToLowerCamelCase;
+
+use crate::{idl, schema};
+
+use super::common::{builtin_type, Generator};
+
+const KEYWORDS: &[&str] = &[
+    "any",
+    "as",
+    "associatedtype",
+    "break",
+    "case",
+    "catch",
+    "class",
+    "continue",
+    "default",
+    "defer",
+    "deinit",
+    "do",
+    "else",
+    "enum",
+    "extension",
+    "fallthrough",
+    "false",
+    "fileprivate",
+    "for",
+    "func",
+    "guard",
+    "if",
+    "import",
+    "in",
+    "init",
+    "inout",
+    "internal",
+    "is",
+    "let",
+    "nil",
+    "operator",
+    "private",
+    "protocol",
+    "public",
+    "repeat",
+    "rethrows",
+    "return",
+    "self",
+    "Self",
+    "static",
+    "struct",
+    "subscript",
+    "super",
+    "switch",
+    "throw",
+    "throws",
+    "true",
+    "try",
+    "typealias",
+    "var",
+    "where",
+    "while",
+];
+
+pub fn gen(doc: &schema::Document, builtin_types: &HashMap<String, String>) -> String {
+    let mut gen = Generator::new();
+    gen.line("// GENERATED CODE - DO NOT EDIT!");
+    gen.line("");
+    gen.line("import Foundation");
+    gen.line("import Webwire");
+    gen_namespace(&doc.ns, &mut gen, builtin_types);
+    gen.into()
+}
+
+fn identifier(name: &str) -> String {
+    if KEYWORDS.contains(&name) {
+        format!("`{}`", name)
+    } else {
+        name.to_string()
+    }
+}
+
+/// Convert a `PascalCase` enum variant name to a `lowerCamelCase` case name.
+fn case_name(name: &str) -> String {
+    let mut chars = name.chars();
+    let name = match chars.next() {
+        Some(first) => first.to_lowercase().chain(chars).collect(),
+        None => String::new(),
+    };
+    identifier(&name)
+}
+
+fn generics(generics: &[String]) -> String {
+    if generics.is_empty() {
+        "".to_string()
+    } else {
+        format!(
+            "<{}>",
+            generics
+                .iter()
+                .map(|g| format!("{}: Codable & Equatable", g))
+                .collect::<Vec<_>>()
+                .join(", ")
+        )
+    }
+}
+
+fn gen_namespace(
+    ns: &schema::Namespace,
+    gen: &mut Generator,
+    builtin_types: &HashMap<String, String>,
+) {
+    for type_ in ns.types.values() {
+        gen.line("");
+        gen_type(type_, gen, builtin_types);
+    }
+    for service in ns.services.values() {
+        gen.line("");
+        gen_service(service, gen, builtin_types);
+        gen.line("");
+        match service.side {
+            idl::ServiceSide::Server => gen_consumer(ns, service, gen, builtin_types),
+            idl::ServiceSide::Client => gen_provider(ns, service, gen, builtin_types),
+        }
+    }
+    for child_ns in ns.namespaces.values() {
+        gen.line("");
+        gen.begin(&format!("enum {} {{", identifier(child_ns.name())));
+        gen_namespace(child_ns, gen, builtin_types);
+        gen.end("}");
+    }
+}
+
+fn gen_type(
+    type_: &schema::UserDefinedType,
+    gen: &mut Generator,
+    builtin_types: &HashMap<String, String>,
+) {
+    match type_ {
+        schema::UserDefinedType::Alias(alias) => gen_alias(&alias.borrow(), gen, builtin_types),
+        schema::UserDefinedType::Newtype(newtype) => {
+            gen_newtype(&newtype.borrow(), gen, builtin_types)
+        }
+        schema::UserDefinedType::Enum(enum_) => gen_enum(&enum_.borrow(), gen, builtin_types),
+        schema::UserDefinedType::Struct(struct_) => {
+            gen_struct(&struct_.borrow(), gen, builtin_types)
+        }
+        schema::UserDefinedType::Fieldset(fieldset) => {
+            gen_fieldset(&fieldset.borrow(), gen, builtin_types)
+        }
+    }
+}
+
+fn gen_alias(alias: &schema::Alias, gen: &mut Generator, builtin_types: &HashMap<String, String>) {
+    let generics = if alias.generics.is_empty() {
+        "".to_string()
+    } else {
+        format!("<{}>", alias.generics.join(", "))
+    };
+    gen.line(&format!(
+        "typealias {}{} = {}",
+        alias.fqtn.name,
+        generics,
+        gen_typeref(&alias.type_, builtin_types)
+    ));
+}
+
+fn gen_newtype(
+    newtype: &schema::Newtype,
+    gen: &mut Generator,
+    builtin_types: &HashMap<String, String>,
+) {
+    let type_ = gen_typeref(&newtype.field.type_, builtin_types);
+    gen_deprecated(newtype.field.deprecated.as_ref(), gen);
+    gen.begin(&format!(
+        "struct {}{}: Codable, Equatable {{",
+        newtype.fqtn.name,
+        generics(&newtype.generics)
+    ));
+    gen.line(&format!("var value: {}", type_));
+    gen.line("");
+    gen.begin(&format!("init(_ value: {}) {{", type_));
+    gen.line("self.value = value");
+    gen.end("}");
+    gen.line("");
+    gen.begin("init(from decoder: Decoder) throws {");
+    gen.line("let container = try decoder.singleValueContainer()");
+    gen.line(&format!("value = try container.decode({}.self)", type_));
+    gen.end("}");
+    gen.line("");
+    gen.begin("func encode(to encoder: Encoder) throws {");
+    gen.line("var container = encoder.singleValueContainer()");
+    gen.line("try container.encode(value)");
+    gen.end("}");
+    gen.end("}");
+}
+
+fn gen_enum(enum_: &schema::Enum, gen: &mut Generator, builtin_types: &HashMap<String, String>) {
+    let name = &enum_.fqtn.name;
+    let variants = &enum_.all_variants;
+    gen_deprecated(enum_.deprecated.as_ref(), gen);
+    if !variants.is_empty() && variants.iter().all(|v| v.value_type.is_none()) {
+        // Enums without any values can use the synthesized `Codable`
+        // implementation of string backed enums.
+        gen.begin(&format!(
+            "enum {}{}: String, Codable, Equatable {{",
+            name,
+            generics(&enum_.generics)
+        ));
+        for variant in variants.iter() {
+            gen_deprecated(variant.deprecated.as_ref(), gen);
+            gen.line(&format!(
+                "case {} = \"{}\"",
+                case_name(&variant.name),
+                variant.name
+            ));
+        }
+        gen.end("}");
+        return;
+    }
+    gen.begin(&format!(
+        "enum {}{}: Codable, Equatable {{",
+        name,
+        generics(&enum_.generics)
+    ));
+    for variant in variants.iter() {
+        gen_deprecated(variant.deprecated.as_ref(), gen);
+        match &variant.value_type {
+            Some(value_type) => gen.line(&format!(
+                "case {}({})",
+                case_name(&variant.name),
+                gen_typeref(value_type, builtin_types)
+            )),
+            None => gen.line(&format!("case {}", case_name(&variant.name))),
+        }
+    }
+    let unit_variants = variants
+        .iter()
+        .filter(|v| v.value_type.is_none())
+        .collect::<Vec<_>>();
+    let value_variants = variants
+        .iter()
+        .filter(|v| v.value_type.is_some())
+        .collect::<Vec<_>>();
+    if !value_variants.is_empty() {
+        gen.line("");
+        gen.begin("private enum CodingKeys: String, CodingKey {");
+        for variant in value_variants.iter() {
+            gen.line(&format!(
+                "case {} = \"{}\"",
+                case_name(&variant.name),
+                variant.name
+            ));
+        }
+        gen.end("}");
+    }
+    // Decoding
+    gen.line("");
+    gen.begin("init(from decoder: Decoder) throws {");
+    if !unit_variants.is_empty() {
+        gen.begin("if let container = try? decoder.singleValueContainer(), let variant = try? container.decode(String.self) {");
+        gen.line("switch variant {");
+        for variant in unit_variants.iter() {
+            gen.line(&format!(
+                "case \"{}\": self = .{}",
+                variant.name,
+                case_name(&variant.name)
+            ));
+        }
+        gen.line(&format!(
+            "default: throw DecodingError.dataCorruptedError(in: container, debugDescription: \"Unknown variant \\(variant) of {}\")",
+            name
+        ));
+        gen.line("}");
+        gen.line("return");
+        gen.end("}");
+    }
+    if value_variants.is_empty() {
+        gen.line(&format!(
+            "throw DecodingError.dataCorrupted(DecodingError.Context(codingPath: decoder.codingPath, debugDescription: \"Invalid value for {}\"))",
+            name
+        ));
+    } else {
+        gen.line("let container = try decoder.container(keyedBy: CodingKeys.self)");
+        gen.begin("guard container.allKeys.count == 1, let key = container.allKeys.first else {");
+        gen.line(&format!(
+            "throw DecodingError.dataCorrupted(DecodingError.Context(codingPath: decoder.codingPath, debugDescription: \"Expected exactly one variant of {}\"))",
+            name
+        ));
+        gen.end("}");
+        gen.line("switch key {");
+        for variant in value_variants.iter() {
+            gen.line(&format!(
+                "case .{}: self = .{}(try container.decode({}.self, forKey: .{}))",
+                case_name(&variant.name),
+                case_name(&variant.name),
+                gen_typeref(variant.value_type.as_ref().unwrap(), builtin_types),
+                case_name(&variant.name),
+            ));
+        }
+        gen.line("}");
+    }
+    gen.end("}");
+    // Encoding
+    gen.line("");
+    gen.begin("func encode(to encoder: Encoder) throws {");
+    gen.line("switch self {");
+    for variant in variants.iter() {
+        match &variant.value_type {
+            Some(_) => {
+                gen.begin(&format!("case .{}(let value):", case_name(&variant.name)));
+                gen.line("var container = encoder.container(keyedBy: CodingKeys.self)");
+                gen.line(&format!(
+                    "try container.encode(value, forKey: .{})",
+                    case_name(&variant.name)
+                ));
+                gen.end("");
+            }
+            None => {
+                gen.begin(&format!("case .{}:", case_name(&variant.name)));
+                gen.line("var container = encoder.singleValueContainer()");
+                gen.line(&format!("try container.encode(\"{}\")", variant.name));
+                gen.end("");
+            }
+        }
+    }
+    gen.line("}");
+    gen.end("}");
+    gen.end("}");
+}
+
+fn gen_struct(
+    struct_: &schema::Struct,
+    gen: &mut Generator,
+    builtin_types: &HashMap<String, String>,
+) {
+    gen_codable_struct(
+        &struct_.fqtn.name,
+        &struct_.generics,
+        struct_.deprecated.as_ref(),
+        struct_.all_fields.iter().map(|f| (f, f.optional)),
+        gen,
+        builtin_types,
+    );
+}
+
+fn gen_fieldset(
+    fieldset: &schema::Fieldset,
+    gen: &mut Generator,
+    builtin_types: &HashMap<String, String>,
+) {
+    gen_codable_struct(
+        &fieldset.fqtn.name,
+        &fieldset.generics,
+        None,
+        fieldset
+            .fields
+            .iter()
+            .map(|f| (f.field.as_ref().unwrap(), f.optional)),
+        gen,
+        builtin_types,
+    );
+}
+
+/// Generate a struct for a struct or fieldset. The fields are given with
+/// their `optional` flag as it differs for fieldsets.
+fn gen_codable_struct<'a>(
+    name: &str,
+    generics_: &[String],
+    deprecation: Option<&schema::Deprecation>,
+    fields: impl Iterator<Item = (&'a schema::Field, bool)>,
+    gen: &mut Generator,
+    builtin_types: &HashMap<String, String>,
+) {
+    let fields = fields.collect::<Vec<_>>();
+    gen_deprecated(deprecation, gen);
+    gen.begin(&format!(
+        "struct {}{}: Codable, Equatable {{",
+        name,
+        generics(generics_)
+    ));
+    for (field, optional) in fields.iter() {
+        let opt = if *optional { "?" } else { "" };
+        gen_deprecated(field.deprecated.as_ref(), gen);
+        gen.line(&format!(
+            "var {}: {}{}",
+            identifier(&field.name.to_lower_camel_case()),
+            gen_typeref(&field.type_, builtin_types),
+            opt
+        ));
+    }
+    if !fields.is_empty() {
+        gen.line("");
+        gen.begin("enum CodingKeys: String, CodingKey {");
+        for (field, _) in fields.iter() {
+            let field_name = &field.name;
+            let property_name = field_name.to_lower_camel_case();
+            if &property_name == field_name {
+                gen.line(&format!("case {}", identifier(&property_name)));
+            } else {
+                gen.line(&format!(
+                    "case {} = \"{}\"",
+                    identifier(&property_name),
+                    field_name
+                ));
+            }
+        }
+        gen.end("}");
+    }
+    gen.end("}");
+}
+
+fn method_signature(method: &schema::Method, builtin_types: &HashMap<String, String>) -> String {
+    let input = match &method.input {
+        Some(t) if method.input_stream => format!(
+            "_ input: AsyncThrowingStream<{}, any Error>",
+            gen_typeref(t, builtin_types)
+        ),
+        Some(t) => format!("_ input: {}", gen_typeref(t, builtin_types)),
+        None => String::new(),
+    };
+    let output = match (&method.output, method.wire_output()) {
+        (Some(t), _) if method.output_stream => format!(
+            " -> AsyncThrowingStream<{}, any Error>",
+            gen_typeref(t, builtin_types)
+        ),
+        (_, Some(t)) => format!(" -> {}", gen_typeref(&t, builtin_types)),
+        (_, None) => String::new(),
+    };
+    if method.notify {
+        return format!(
+            "func {}({}) throws",
+            identifier(&method.name.to_lower_camel_case()),
+            input
+        );
+    }
+    format!(
+        "func {}({}) async throws{}",
+        identifier(&method.name.to_lower_camel_case()),
+        input,
+        output
+    )
+}
+
+fn gen_deprecated(deprecation: Option<&schema::Deprecation>, gen: &mut Generator) {
+    match deprecation.map(schema::Deprecation::message) {
+        Some(Some(message)) => gen.line(&format!(
+            "@available(*, deprecated, message: {})",
+            serde_json::to_string(&message).expect("strings are always serializable")
+        )),
+        Some(None) => gen.line("@available(*, deprecated)"),
+        None => {}
+    }
+}
+
+fn gen_service(
+    service: &schema::Service,
+    gen: &mut Generator,
+    builtin_types: &HashMap<String, String>,
+) {
+    gen_deprecated(service.annotations.deprecated.as_ref(), gen);
+    gen.begin(&format!("protocol {} {{", service.name));
+    for method in service.methods.iter() {
+        gen_deprecated(method.annotations.deprecated.as_ref(), gen);
+        gen.line(&method_signature(method, builtin_types));
+    }
+    gen.end("}");
+}
+
+fn gen_consumer(
+    ns: &schema::Namespace,
+    service: &schema::Service,
+    gen: &mut Generator,
+    builtin_types: &HashMap<String, String>,
+) {
+    gen_deprecated(service.annotations.deprecated.as_ref(), gen);
+    gen.begin(&format!(
+        "struct {}Consumer: {} {{",
+        service.name, service.name
+    ));
+    gen.line("let client: any Webwire.Client");
+    let fqsn = if ns.path.is_empty() {
+        service.name.to_owned()
+    } else {
+        format!("{}.{}", ns.path.join("."), service.name)
+    };
+    for method in service.methods.iter() {
+        gen.line("");
+        gen_deprecated(method.annotations.deprecated.as_ref(), gen);
+        gen.begin(&format!("{} {{", method_signature(method, builtin_types)));
+        // Streamed inputs and outputs are transferred as arrays.
+        let data = match &method.input {
+            Some(type_) if method.input_stream => {
+                gen_collect_stream("input", "items", type_, gen, builtin_types);
+                "try JSONEncoder().encode(items)"
+            }
+            Some(_) => "try JSONEncoder().encode(input)",
+            None => "Data()",
+        };
+        if method.notify {
+            gen.line(&format!(
+                "try client.notify(service: \"{}\", method: \"{}\", data: {})",
+                fqsn, method.name, data
+            ));
+            gen.end("}");
+            continue;
+        }
+        let request = format!(
+            "try await client.request(service: \"{}\", method: \"{}\", data: {})",
+            fqsn, method.name, data
+        );
+        match (&method.output, method.wire_output()) {
+            (Some(type_), Some(wire_type)) if method.output_stream => {
+                gen.line(&format!("let output = {}", request));
+                gen.line(&format!(
+                    "let items = try JSONDecoder().decode({}.self, from: output)",
+                    gen_typeref(&wire_type, builtin_types)
+                ));
+                gen_array_stream("return", "items", type_, gen, builtin_types);
+            }
+            (_, Some(type_)) => {
+                gen.line(&format!("let output = {}", request));
+                gen.line(&format!(
+                    "return try JSONDecoder().decode({}.self, from: output)",
+                    gen_typeref(&type_, builtin_types)
+                ));
+            }
+            (_, None) => gen.line(&format!("_ = {}", request)),
+        }
+        gen.end("}");
+    }
+    gen.end("}");
+}
+
+/// Collects the values of the stream `stream` into the array `array`
+fn gen_collect_stream(
+    stream: &str,
+    array: &str,
+    item_type: &schema::Type,
+    gen: &mut Generator,
+    builtin_types: &HashMap<String, String>,
+) {
+    gen.line(&format!(
+        "var {}: [{}] = []",
+        array,
+        gen_typeref(item_type, builtin_types)
+    ));
+    gen.begin(&format!("for try await item in {} {{", stream));
+    gen.line(&format!("{}.append(item)", array));
+    gen.end("}");
+}
+
+/// Generates a stream yielding the values of the array `array`. The
+/// stream is prefixed with `binding`, e.g. `return`.
+fn gen_array_stream(
+    binding: &str,
+    array: &str,
+    item_type: &schema::Type,
+    gen: &mut Generator,
+    builtin_types: &HashMap<String, String>,
+) {
+    gen.begin(&format!(
+        "{} AsyncThrowingStream<{}, any Error> {{ continuation in",
+        binding,
+        gen_typeref(item_type, builtin_types)
+    ));
+    gen.begin(&format!("for item in {} {{", array));
+    gen.line("continuation.yield(item)");
+    gen.end("}");
+    gen.line("continuation.finish()");
+    gen.end("}");
+}
+
+fn gen_provider(
+    ns: &schema::Namespace,
+    service: &schema::Service,
+    gen: &mut Generator,
+    builtin_types: &HashMap<String, String>,
+) {
+    gen.begin(&format!(
+        "struct {}Provider: Webwire.Provider {{",
+        service.name
+    ));
+    gen.line(&format!("let service: any {}", service.name));
+    let fqsn = if ns.path.is_empty() {
+        service.name.to_owned()
+    } else {
+        format!("{}.{}", ns.path.join("."), service.name)
+    };
+    gen.line(&format!("let name = \"{}\"", fqsn));
+    gen.line("");
+    gen.begin("func call(method: String, data: Data) async throws -> Data {");
+    gen.line("switch method {");
+    for method in service.methods.iter() {
+        gen.begin(&format!("case \"{}\":", method.name));
+        // Streamed inputs and outputs are transferred as arrays.
+        let input = match (&method.input, method.wire_input()) {
+            (Some(type_), Some(wire_type)) if method.input_stream => {
+                gen.line(&format!(
+                    "let items = try JSONDecoder().decode({}.self, from: data)",
+                    gen_typeref(&wire_type, builtin_types)
+                ));
+                gen_array_stream("let input =", "items", type_, gen, builtin_types);
+                "input"
+            }
+            (_, Some(type_)) => {
+                gen.line(&format!(
+                    "let input = try JSONDecoder().decode({}.self, from: data)",
+                    gen_typeref(&type_, builtin_types)
+                ));
+                "input"
+            }
+            (_, None) => "",
+        };
+        let call = format!(
+            "try {}service.{}({})",
+            if method.notify { "" } else { "await " },
+            identifier(&method.name.to_lower_camel_case()),
+            input
+        );
+        match (&method.output, method.wire_output()) {
+            (Some(type_), _) if method.output_stream => {
+                gen.line(&format!("let output = {}", call));
+                gen_collect_stream("output", "items", type_, gen, builtin_types);
+                gen.line("return try JSONEncoder().encode(items)");
+            }
+            (_, Some(_)) => {
+                gen.line(&format!("let output = {}", call));
+                gen.line("return try JSONEncoder().encode(output)");
+            }
+            // Notifications do not return a response
+            (_, None) if method.notify => {
+                gen.line(&call);
+                gen.line("return Data()");
+            }
+            (_, None) => {
+                gen.line(&call);
+                gen.line("return Data(\"null\".utf8)");
+            }
+        }
+        gen.end("");
+    }
+    gen.begin("default:");
+    gen.line("throw Webwire.MethodNotFound(method: method)");
+    gen.end("");
+    gen.line("}");
+    gen.end("}");
+    gen.end("}");
+}
+
+/// Whether the key type of a map is a `String` which `JSONEncoder`
+/// encodes as JSON object key
+fn is_string_key(type_: &schema::Type, builtin_types: &HashMap<String, String>) -> bool {
+    match type_ {
+        schema::Type::String => true,
+        schema::Type::Builtin(name) => builtin_type(builtin_types, name) == "String",
+        schema::Type::Ref(schema::TypeRef::Alias(alias)) => {
+            is_string_key(&alias.target(), builtin_types)
+        }
+        _ => false,
+    }
+}
+
+pub fn gen_typeref(type_: &schema::Type, builtin_types: &HashMap<String, String>) -> String {
+    match type_ {
+        schema::Type::None => "Webwire.Null".to_string(),
+        schema::Type::Boolean => "Bool".to_string(),
+        schema::Type::Integer => "Int64".to_string(),
+        schema::Type::Float => "Double".to_string(),
+        schema::Type::String => "String".to_string(),
+        schema::Type::UUID => "UUID".to_string(),
+        schema::Type::Date => "Webwire.Date".to_string(),
+        schema::Type::Time => "Webwire.Time".to_string(),
+        schema::Type::DateTime => "Webwire.DateTime".to_string(),
+        schema::Type::Decimal => "Webwire.Decimal".to_string(),
+        schema::Type::Bytes => "Data".to_string(),
+        schema::Type::Duration => "Webwire.Duration".to_string(),
+        schema::Type::Int8 => "Int8".to_string(),
+        schema::Type::Int16 => "Int16".to_string(),
+        schema::Type::Int32 => "Int32".to_string(),
+        schema::Type::Int64 => "Int64".to_string(),
+        schema::Type::UInt8 => "UInt8".to_string(),
+        schema::Type::UInt16 => "UInt16".to_string(),
+        schema::Type::UInt32 => "UInt32".to_string(),
+        schema::Type::UInt64 => "UInt64".to_string(),
+        schema::Type::Option(some) => format!("{}?", gen_typeref(some, builtin_types)),
+        schema::Type::Result(ok, err) => {
+            format!(
+                "Webwire.Result<{}, {}>",
+                gen_typeref(ok, builtin_types),
+                gen_typeref(err, builtin_types)
+            )
+        }
+        // complex types
+        schema::Type::Array(array) => format!("[{}]", gen_typeref(&array.item_type, builtin_types)),
+        schema::Type::Map(map) if is_string_key(&map.key_type, builtin_types) => format!(
+            "[{}: {}]",
+            gen_typeref(&map.key_type, builtin_types),
+            gen_typeref(&map.value_type, builtin_types)
+        ),
+        schema::Type::Map(map) => {
+            format!("[String: {}]", gen_typeref(&map.value_type, builtin_types))
+        }
+        // named
+        schema::Type::Ref(typeref) => {
+            let typeref_fqtn = typeref.fqtn();
+            let fqtn = typeref_fqtn
+                .ns
+                .iter()
+                .map(|ns| identifier(ns))
+                .chain(std::iter::once(typeref_fqtn.name.clone()))
+                .collect::<Vec<_>>()
+                .join(".");
+            let generics = typeref.generics();
+            if !generics.is_empty() {
+                let generics = generics
+                    .iter()
+                    .map(|t| gen_typeref(t, builtin_types))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                format!("{}<{}>", fqtn, generics)
+            } else {
+                fqtn
+            }
+        }
+        schema::Type::Generic(name) => name.to_string(),
+        schema::Type::Builtin(name) => builtin_type(builtin_types, name).to_string(),
+    }
+}
@@ -0,0 +1,362 @@
+//! Template based code generator
+//!
+//! All files of the template directory are rendered using
+//! [minijinja](https://docs.rs/minijinja) and written to the same
+//! relative path of the target directory. The extensions `.j2` and
+//! `.jinja` are removed from the output file names. Files and
+//! directories starting with an underscore are not rendered but can be
+//! used via `include`, `import` and `extends`.
+//!
+//! The templates have access to the following variables:
+//!
+//! - `document`: the [`ir::Document`]
+//! - `types`: all user defined types
+//! - `services`: all services
+//! - `namespaces`: all namespace paths
+//!
+//! The following filters and functions are available:
+//!
+//! - `fqtn(sep=".")`: format the fully qualified name of a type,
+//!   type reference or service
+//! - `map_type(mapping)`: map a type using a dictionary which contains
+//!   one entry per type kind (e.g. `{"integer": "BIGINT"}`). Container
+//!   types use `{}` as placeholder for the mapped inner types, e.g.
+//!   `{"array": "{}[]", "map": "Map<{}, {}>"}`.
+//! - `snake_case`, `camel_case`, `pascal_case`, `kebab_case` and
+//!   `shouty_snake_case`
+//! - `file(name)`: write the remaining output of the template to the
+//!   given file. This makes it possible to generate multiple files from
+//!   a single template.
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use heck::{ToKebabCase, ToLowerCamelCase, ToShoutySnakeCase, ToSnakeCase, ToUpperCamelCase};
+use minijinja::{context, Environment, ErrorKind, UndefinedBehavior, Value};
+use serde::Deserialize;
+
+use crate::ir;
+use crate::schema;
+
+use super::plugin::is_relative_path;
+use super::Output;
+
+const FILE_MARKER: &str = "\u{0}webwire:file:";
+
+#[derive(Debug)]
+pub struct TemplateError {
+    pub message: String,
+}
+
+impl std::fmt::Display for TemplateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for TemplateError {}
+
+impl From<minijinja::Error> for TemplateError {
+    fn from(error: minijinja::Error) -> Self {
+        Self {
+            message: error.to_string(),
+        }
+    }
+}
+
+/// Render all templates of the given directory.
+pub fn gen(doc: &schema::Document, template_dir: &Path) -> Result<Output, TemplateError> {
+    let mut templates = BTreeMap::new();
+    read_templates(template_dir, "", &mut templates).map_err(|e| TemplateError {
+        message: format!("Could not read templates from {:?}: {}", template_dir, e),
+    })?;
+    render(doc, templates)
+}
+
+fn read_templates(
+    dir: &Path,
+    prefix: &str,
+    templates: &mut BTreeMap<String, String>,
+) -> std::io::Result<()> {
+    for entry in std::fs::read_dir(dir)? {
+        let entry = entry?;
+        let name = format!("{}{}", prefix, entry.file_name().to_string_lossy());
+        if entry.file_type()?.is_dir() {
+            read_templates(&entry.path(), &format!("{}/", name), templates)?;
+        } else {
+            templates.insert(name, std::fs::read_to_string(entry.path())?);
+        }
+    }
+    Ok(())
+}
+
+fn render(
+    doc: &schema::Document,
+    templates: BTreeMap<String, String>,
+) -> Result<Output, TemplateError> {
+    let mut env = Environment::new();
+    env.set_trim_blocks(true);
+    env.set_lstrip_blocks(true);
+    env.set_keep_trailing_newline(true);
+    env.set_undefined_behavior(UndefinedBehavior::Strict);
+    env.add_filter("fqtn", fqtn);
+    env.add_filter("map_type", map_type);
+    env.add_filter("snake_case", |s: &str| s.to_snake_case());
+    env.add_filter("camel_case", |s: &str| s.to_lower_camel_case());
+    env.add_filter("pascal_case", |s: &str| s.to_upper_camel_case());
+    env.add_filter("kebab_case", |s: &str| s.to_kebab_case());
+    env.add_filter("shouty_snake_case", |s: &str| s.to_shouty_snake_case());
+    env.add_function("file", |name: &str| {
+        if !is_relative_path(name) || name.contains('\u{0}') {
+            return Err(invalid_operation(format!("Invalid file name {:?}", name)));
+        }
+        Ok(Value::from_safe_string(format!(
+            "{}{}\u{0}",
+            FILE_MARKER, name
+        )))
+    });
+    let names = templates.keys().cloned().collect::<Vec<_>>();
+    for (name, source) in templates {
+        env.add_template_owned(name, source)?;
+    }
+
+    let document = ir::Document::from(doc);
+    let ctx = context! {
+        document => Value::from_serialize(&document),
+        types => Value::from_serialize(document.types.values().collect::<Vec<_>>()),
+        services => Value::from_serialize(document.services.values().collect::<Vec<_>>()),
+        namespaces => Value::from_serialize(&document.namespaces),
+    };
+
+    let mut files = Output::new();
+    for name in names.iter().filter(|name| !is_partial(name)) {
+        let output = env.get_template(name)?.render(&ctx)?;
+        split_files(&output_name(name), &output, &mut files)?;
+    }
+    Ok(files)
+}
+
+fn is_partial(name: &str) -> bool {
+    name.split('/').any(|part| part.starts_with('_'))
+}
+
+fn output_name(name: &str) -> String {
+    name.strip_suffix(".j2")
+        .or_else(|| name.strip_suffix(".jinja"))
+        .unwrap_or(name)
+        .to_string()
+}
+
+/// Split the output of a template at the markers inserted by the
+/// `file` function. Output preceding the first marker is written to
+/// the default file unless it only consists of whitespace.
+fn split_files(default_name: &str, output: &str, files: &mut Output) -> Result<(), TemplateError> {
+    let mut parts = output.split(FILE_MARKER);
+    let head = parts.next().unwrap_or_default();
+    if !output.contains(FILE_MARKER) || !head.trim().is_empty() {
+        insert_file(default_name.to_string(), head, files)?;
+    }
+    for part in parts {
+        let Some((name, content)) = part.split_once('\u{0}') else {
+            return Err(TemplateError {
+                message: format!("Incomplete file marker in output of {:?}", default_name),
+            });
+        };
+        // Skip the newline following the `file` call.
+        let content = content.strip_prefix('\n').unwrap_or(content);
+        insert_file(name.to_string(), content, files)?;
+    }
+    Ok(())
+}
+
+fn insert_file(name: String, content: &str, files: &mut Output) -> Result<(), TemplateError> {
+    if files.contains(&name) {
+        return Err(TemplateError {
+            message: format!("File {:?} is generated more than once", name),
+        });
+    }
+    files.insert(name, content);
+    Ok(())
+}
+
+fn invalid_operation(message: String) -> minijinja::Error {
+    minijinja::Error::new(ErrorKind::InvalidOperation, message)
+}
+
+fn fqtn(value: Value, sep: Option<&str>) -> Result<String, minijinja::Error> {
+    let fqtn_value = match value.get_attr("fqtn")? {
+        v if v.is_undefined() => value,
+        v => v,
+    };
+    let fqtn = ir::Fqtn::deserialize(fqtn_value)
+        .map_err(|e| invalid_operation(format!("Value has no fully qualified name: {}", e)))?;
+    let mut parts = fqtn.ns;
+    parts.push(fqtn.name);
+    Ok(parts.join(sep.unwrap_or(".")))
+}
+
+fn map_type(value: Value, mapping: Value) -> Result<String, minijinja::Error> {
+    let type_ = ir::Type::deserialize(value)
+        .map_err(|e| invalid_operation(format!("Value is not a type: {}", e)))?;
+    map_type_inner(&type_, &mapping)
+}
+
+fn map_type_inner(type_: &ir::Type, mapping: &Value) -> Result<String, minijinja::Error> {
+    let (kind, args) = match type_ {
+        ir::Type::None => ("none", vec![]),
+        ir::Type::Boolean => ("boolean", vec![]),
+        ir::Type::Integer => ("integer", vec![]),
+        ir::Type::Float => ("float", vec![]),
+        ir::Type::String => ("string", vec![]),
+        ir::Type::UUID => ("uuid", vec![]),
+        ir::Type::Date => ("date", vec![]),
+        ir::Type::Time => ("time", vec![]),
+        ir::Type::DateTime => ("date_time", vec![]),
+        ir::Type::Decimal => ("decimal", vec![]),
+        ir::Type::Bytes => ("bytes", vec![]),
+        ir::Type::Duration => ("duration", vec![]),
+        ir::Type::Int8 => ("int8", vec![]),
+        ir::Type::Int16 => ("int16", vec![]),
+        ir::Type::Int32 => ("int32", vec![]),
+        ir::Type::Int64 => ("int64", vec![]),
+        ir::Type::UInt8 => ("uint8", vec![]),
+        ir::Type::UInt16 => ("uint16", vec![]),
+        ir::Type::UInt32 => ("uint32", vec![]),
+        ir::Type::UInt64 => ("uint64", vec![]),
+        ir::Type::Option { some } => ("option", vec![map_type_inner(some, mapping)?]),
+        ir::Type::Result { ok, err } => (
+            "result",
+            vec![map_type_inner(ok, mapping)?, map_type_inner(err, mapping)?],
+        ),
+        ir::Type::Array { item_type, .. } => ("array", vec![map_type_inner(item_type, mapping)?]),
+        ir::Type::Map {
+            key_type,
+            value_type,
+            ..
+        } => (
+            "map",
+            vec![
+                map_type_inner(key_type, mapping)?,
+                map_type_inner(value_type, mapping)?,
+            ],
+        ),
+        // User defined, generic and builtin types default to their name.
+        ir::Type::Ref(typeref) => ("ref", vec![typeref.fqtn.name.clone()]),
+        ir::Type::Generic { name } => ("generic", vec![name.clone()]),
+        ir::Type::Builtin { name } => ("builtin", vec![name.clone()]),
+    };
+    let pattern = mapping.get_attr(kind)?;
+    let pattern = match pattern.as_str() {
+        Some(pattern) => pattern,
+        None if matches!(kind, "ref" | "generic" | "builtin") => "{}",
+        None => return Err(invalid_operation(format!("No mapping for type {:?}", kind))),
+    };
+    let mut args = args.into_iter();
+    let mut output = String::new();
+    let mut parts = pattern.split("{}").peekable();
+    while let Some(part) = parts.next() {
+        output += part;
+        if parts.peek().is_some() {
+            output += &args.next().unwrap_or_default();
+        }
+    }
+    Ok(output)
+}
+
+#[cfg(test)]
+fn render_test(idl: &str, templates: &[(&str, &str)]) -> Result<BTreeMap<String, String>, String> {
+    let idocs = [crate::idl::parse_document(idl).unwrap()];
+    let builtin_types = std::collections::HashSet::default();
+    let doc = schema::Document::from_idl(idocs.iter(), &builtin_types).unwrap();
+    render(
+        &doc,
+        templates
+            .iter()
+            .map(|(name, source)| (name.to_string(), source.to_string()))
+            .collect(),
+    )
+    .map(|output| output.files)
+    .map_err(|e| e.message)
+}
+
+#[test]
+fn test_template() {
+    let idl = r"
+        namespace user {
+            struct UserAccount {
+                user_id: UUID,
+                tags: [String],
+                email: Option<String>,
+            }
+        }
+    ";
+    let templates = [
+        (
+            "_macros.j2",
+            "{% macro column(field, sql) %}{{ field.name }} {{ field.type | map_type(sql) }}{% endmacro %}",
+        ),
+        (
+            "schema.sql.j2",
+            r#"{% from "_macros.j2" import column %}
+{% set sql = {"uuid": "UUID", "string": "TEXT", "array": "{}[]", "option": "{} NULL"} %}
+{% for type in types %}
+CREATE TABLE {{ type.fqtn.name | snake_case }} (
+{% for field in type.fields %}
+    {{ column(field, sql) }}{{ "," if not loop.last }}
+{% endfor %}
+); -- {{ type | fqtn("::") }}
+{% endfor %}
+"#,
+        ),
+        (
+            "types.j2",
+            r#"{% for type in types %}
+{{ file(type.fqtn.name | kebab_case ~ ".txt") }}
+{{ type.fqtn.name | camel_case }}
+{% endfor %}
+"#,
+        ),
+    ];
+    let files = render_test(idl, &templates).unwrap();
+    assert_eq!(
+        files.keys().collect::<Vec<_>>(),
+        vec!["schema.sql", "user-account.txt"]
+    );
+    assert_eq!(
+        files["schema.sql"],
+        "CREATE TABLE user_account (\n    user_id UUID,\n    tags TEXT[],\n    email TEXT NULL\n); -- user::UserAccount\n"
+    );
+    assert_eq!(files["user-account.txt"], "userAccount\n");
+}
+
+#[test]
+fn test_template_missing_mapping() {
+    let idl = "struct Foo { bar: Integer }";
+    let templates = [(
+        "out.j2",
+        "{{ types[0].fields[0].type | map_type({\"string\": \"str\"}) }}",
+    )];
+    let error = render_test(idl, &templates).unwrap_err();
+    assert!(
+        error.contains("No mapping for type \"integer\""),
+        "{}",
+        error
+    );
+}
+
+#[test]
+fn test_template_invalid_file_name() {
+    let idl = "struct Foo { bar: Integer }";
+    for name in ["/etc/passwd", "../outside.txt", "a/../../b.txt", ""] {
+        let source = format!("{{{{ file({:?}) }}}}", name);
+        let error = render_test(idl, &[("out.j2", &source)]).unwrap_err();
+        assert!(error.contains("Invalid file name"), "{}", error);
+    }
+}
+
+#[test]
+fn test_split_files_incomplete_marker() {
+    let mut files = Output::default();
+    let output = format!("head{}name-without-terminator", FILE_MARKER);
+    assert!(split_files("out", &output, &mut files).is_err());
+}
@@ -1,45 +1,80 @@
-use crate::schema;
+use std::cell::RefCell;
+use std::collections::{BTreeSet, HashMap};
 
-struct Generator {
-    level: usize,
-    output: String,
+use heck::ToUpperCamelCase;
+
+use crate::{idl, schema};
+
+use super::common::{builtin_type, Generator};
+use super::Output;
+
+/// Determines how references to user defined and builtin types are
+/// generated.
+struct Scope<'a> {
+    /// Namespace of the ES module which is being generated or `None` if
+    /// all namespaces are generated into a single file.
+    module: Option<Vec<String>>,
+    /// Namespaces referenced by the module
+    imports: RefCell<BTreeSet<Vec<String>>>,
+    builtin_types: &'a HashMap<String, String>,
 }
 
-impl Generator {
-    fn new() -> Self {
+impl<'a> Scope<'a> {
+    fn new(module: Option<Vec<String>>, builtin_types: &'a HashMap<String, String>) -> Self {
         Self {
-            level: 0,
-            output: String::new(),
-        }
-    }
-    fn begin(&mut self, line: &str) {
-        self.line(line);
-        self.level += 1;
-    }
-    fn end(&mut self, line: &str) {
-        self.level -= 1;
-        if !line.is_empty() {
-            self.line(line);
+            module,
+            imports: RefCell::new(BTreeSet::new()),
+            builtin_types,
         }
     }
-    fn line(&mut self, line: &str) {
-        if !line.is_empty() {
-            for _ in 0..self.level {
-                self.output += "    ";
+    fn reference(&self, ns: &[String], name: &str) -> String {
+        match &self.module {
+            None if ns.is_empty() => name.to_string(),
+            None => format!("{}.{}", ns.join("."), name),
+            Some(module) if module == ns => name.to_string(),
+            Some(_) => {
+                self.imports.borrow_mut().insert(ns.to_vec());
+                format!("{}.{}", module_alias(ns), name)
             }
-            self.output += line;
         }
-        self.output += "\n";
     }
 }
 
-impl From<Generator> for String {
-    fn from(gen: Generator) -> Self {
-        gen.output
+/// Name of the runtime module which is imported by the generated code
+const RUNTIME_MODULE: &str = "webwire";
+
+/// Error returned by [`check_namespaces`]
+#[derive(Debug)]
+pub struct ReservedNamespace {
+    pub name: String,
+}
+
+impl std::fmt::Display for ReservedNamespace {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "The namespace `{}` clashes with the runtime module of the TypeScript code",
+            self.name
+        )
     }
 }
 
-pub fn gen(doc: &schema::Document) -> String {
+impl std::error::Error for ReservedNamespace {}
+
+/// Make sure that no top-level namespace is named like the runtime
+/// module. It would shadow the runtime import of the generated code and
+/// its module would overwrite the runtime next to `index.ts`.
+pub fn check_namespaces(doc: &schema::Document) -> Result<(), ReservedNamespace> {
+    if doc.ns.namespaces.contains_key(RUNTIME_MODULE) {
+        Err(ReservedNamespace {
+            name: RUNTIME_MODULE.to_owned(),
+        })
+    } else {
+        Ok(())
+    }
+}
+
+pub fn gen(doc: &schema::Document, builtin_types: &HashMap<String, String>) -> String {
     let mut gen = Generator::new();
     gen.line("// GENERATED CODE - DO NOT EDIT!");
     gen.line("");
@@ -47,42 +82,204 @@ pub fn gen(doc: &schema::Document) -> String {
     // npm package (which doesn't exist, yet.)
     gen.line("import * as webwire from './webwire'");
     gen.line("");
-    gen_namespace(&doc.ns, &mut gen);
+    gen_namespace(&doc.ns, &Scope::new(None, builtin_types), &mut gen);
     gen.into()
 }
 
-fn gen_namespace(ns: &schema::Namespace, gen: &mut Generator) {
-    for type_ in ns.types.values() {
-        gen.line("");
-        gen_type(type_, gen);
+/// Generate one ES module per namespace. The root namespace is written
+/// to `index.ts` and all other namespaces to a file mirroring their
+/// path (e.g. `user/account.ts`). The modules expect the `webwire`
+/// module next to `index.ts`.
+pub fn gen_files(doc: &schema::Document, builtin_types: &HashMap<String, String>) -> Output {
+    let mut output = Output::new();
+    gen_module(&doc.ns, builtin_types, &mut output);
+    output
+}
+
+fn gen_module(
+    ns: &schema::Namespace,
+    builtin_types: &HashMap<String, String>,
+    output: &mut Output,
+) {
+    let scope = Scope::new(Some(ns.path.clone()), builtin_types);
+    let mut body = Generator::new();
+    gen_namespace_items(ns, &scope, &mut body);
+    let mut gen = Generator::new();
+    gen.line("// GENERATED CODE - DO NOT EDIT!");
+    gen.line("");
+    gen.line(&format!(
+        "import * as webwire from '{}'",
+        module_specifier(&ns.path, RUNTIME_MODULE)
+    ));
+    for import in scope.imports.borrow().iter() {
+        gen.line(&format!(
+            "import * as {} from '{}'",
+            module_alias(import),
+            module_specifier(&ns.path, &module_path(import))
+        ));
     }
-    for service in ns.services.values() {
+    if !ns.namespaces.is_empty() {
         gen.line("");
-        gen_service(service, gen);
-        gen.line("");
-        gen_consumer(ns, service, gen);
     }
+    for child_ns in ns.namespaces.values() {
+        gen.line(&format!(
+            "export * as {} from '{}'",
+            child_ns.name(),
+            module_specifier(&ns.path, &module_path(&child_ns.path))
+        ));
+    }
+    gen.line("");
+    output.insert(
+        format!("{}.ts", module_path(&ns.path)),
+        String::from(gen) + &String::from(body),
+    );
+    for child_ns in ns.namespaces.values() {
+        gen_module(child_ns, builtin_types, output);
+    }
+}
+
+/// Path of the module without extension relative to `index.ts`
+fn module_path(ns: &[String]) -> String {
+    if ns.is_empty() {
+        "index".to_string()
+    } else {
+        ns.join("/")
+    }
+}
+
+/// Name used for importing the module of another namespace, e.g.
+/// `_ns$user$account` for `user::account` and `_ns` for the root
+/// namespace. IDL identifiers can neither start with `_` nor contain `$`,
+/// so the aliases can not clash with each other, with the `webwire`
+/// import or with the names of the types.
+fn module_alias(ns: &[String]) -> String {
+    std::iter::once("_ns")
+        .chain(ns.iter().map(String::as_str))
+        .collect::<Vec<_>>()
+        .join("$")
+}
+
+/// Relative module specifier for importing `path` from the module of
+/// the namespace `from`.
+fn module_specifier(from: &[String], path: &str) -> String {
+    match from.len() {
+        0 | 1 => format!("./{}", path),
+        n => format!("{}{}", "../".repeat(n - 1), path),
+    }
+}
+
+fn gen_namespace(ns: &schema::Namespace, scope: &Scope, gen: &mut Generator) {
+    gen_namespace_items(ns, scope, gen);
     for child_ns in ns.namespaces.values() {
         gen.line("");
         gen.begin(&format!("export namespace {} {{", child_ns.name()));
-        gen_namespace(child_ns, gen);
+        gen_namespace(child_ns, scope, gen);
         gen.end("}");
     }
 }
 
-fn gen_type(type_: &schema::UserDefinedType, gen: &mut Generator) {
+fn gen_namespace_items(ns: &schema::Namespace, scope: &Scope, gen: &mut Generator) {
+    if !ns.constants.is_empty() {
+        gen.line("");
+    }
+    for constant in ns.constants.values() {
+        gen.line(&format!(
+            "export const {}: {} = {}",
+            constant.fqtn.name,
+            gen_typeref_scoped(&constant.type_, scope),
+            gen_value(&constant.value)
+        ));
+    }
+    for type_ in ns.types.values() {
+        gen.line("");
+        gen_type(type_, scope, gen);
+    }
+    for service in ns.services.values() {
+        gen.line("");
+        gen_service(service, scope, gen);
+        gen.line("");
+        // Services implemented by the server are consumed by the client
+        // and vice versa.
+        match service.side {
+            idl::ServiceSide::Server => gen_consumer(ns, service, scope, gen),
+            idl::ServiceSide::Client => gen_provider(ns, service, gen),
+        }
+    }
+}
+
+fn gen_type(type_: &schema::UserDefinedType, scope: &Scope, gen: &mut Generator) {
     match type_ {
-        schema::UserDefinedType::Enum(enum_) => gen_enum(&enum_.borrow(), gen),
-        schema::UserDefinedType::Struct(struct_) => gen_struct(&struct_.borrow(), gen),
-        schema::UserDefinedType::Fieldset(fieldset) => gen_fieldset(&fieldset.borrow(), gen),
+        schema::UserDefinedType::Alias(alias) => gen_alias(&alias.borrow(), scope, gen),
+        schema::UserDefinedType::Newtype(newtype) => gen_newtype(&newtype.borrow(), scope, gen),
+        schema::UserDefinedType::Enum(enum_) => gen_enum(&enum_.borrow(), scope, gen),
+        schema::UserDefinedType::Struct(struct_) => gen_struct(&struct_.borrow(), scope, gen),
+        schema::UserDefinedType::Fieldset(fieldset) => gen_fieldset(&fieldset.borrow(), scope, gen),
     }
 }
 
-fn gen_enum(enum_: &schema::Enum, gen: &mut Generator) {
+fn gen_alias(alias: &schema::Alias, scope: &Scope, gen: &mut Generator) {
+    let generics = if alias.generics.is_empty() {
+        "".to_string()
+    } else {
+        format!("<{}>", alias.generics.join(", "))
+    };
+    gen.line(&format!(
+        "export type {}{} = {}",
+        alias.fqtn.name,
+        generics,
+        gen_typeref_scoped(&alias.type_, scope)
+    ));
+}
+
+/// Newtypes are generated as branded types so that two newtypes
+/// of the same underlying type can not be mixed up.
+fn gen_newtype(newtype: &schema::Newtype, scope: &Scope, gen: &mut Generator) {
+    let generics = if newtype.generics.is_empty() {
+        "".to_string()
+    } else {
+        format!("<{}>", newtype.generics.join(", "))
+    };
+    let type_ = gen_typeref_scoped(&newtype.field.type_, scope);
+    gen_deprecated(newtype.field.deprecated.as_ref(), gen);
+    gen.line(&format!(
+        "export type {}{} = {} & {{ readonly __brand: \"{}\" }}",
+        newtype.fqtn.name,
+        generics,
+        type_,
+        newtype
+            .fqtn
+            .ns
+            .iter()
+            .chain(std::iter::once(&newtype.fqtn.name))
+            .cloned()
+            .collect::<Vec<_>>()
+            .join(".")
+    ));
+    gen.line("");
+    gen.begin(&format!(
+        "export function new{}{}(value: {}): {}{} {{",
+        newtype.fqtn.name, generics, type_, newtype.fqtn.name, generics
+    ));
+    gen.line(&format!(
+        "return value as {}{}",
+        newtype.fqtn.name, generics
+    ));
+    gen.end("}");
+}
+
+fn gen_enum(enum_: &schema::Enum, scope: &Scope, gen: &mut Generator) {
     let enum_name = &enum_.fqtn.name;
+    let generics = if enum_.generics.is_empty() {
+        "".to_string()
+    } else {
+        format!("<{}>", enum_.generics.join(", "))
+    };
     if enum_.all_variants.is_empty() {
         gen.line(&format!("export type _{}Variants = never", enum_name));
-        gen.line(&format!("export type {} = never", enum_.fqtn.name));
+        gen.line(&format!(
+            "export type {}{} = never",
+            enum_.fqtn.name, generics
+        ));
         return;
     }
     gen.line(&format!(
@@ -95,15 +292,17 @@ fn gen_enum(enum_: &schema::Enum, gen: &mut Generator) {
             .collect::<Vec<_>>()
             .join(" | ")
     ));
-    gen.begin(&format!("export type {} =", enum_.fqtn.name));
+    gen_deprecated(enum_.deprecated.as_ref(), gen);
+    gen.begin(&format!("export type {}{} =", enum_.fqtn.name, generics));
     for variant in enum_.all_variants.iter() {
+        gen_deprecated(variant.deprecated.as_ref(), gen);
         gen.line(&match &variant.value_type {
             Some(value_type) => format!(
                 "| {{ [P in Exclude<_{}Variants, \"{}\">]?: never }} & {{ {}: {} }}",
                 enum_name,
                 variant.name,
                 variant.name,
-                gen_typeref(value_type)
+                gen_typeref_scoped(value_type, scope)
             ),
             None => format!("| \"{}\"", variant.name),
         });
@@ -113,29 +312,92 @@ fn gen_enum(enum_: &schema::Enum, gen: &mut Generator) {
     gen.end("");
 }
 
-fn gen_struct(struct_: &schema::Struct, gen: &mut Generator) {
+fn gen_struct(struct_: &schema::Struct, scope: &Scope, gen: &mut Generator) {
     let generics = if struct_.generics.is_empty() {
         "".to_string()
     } else {
         format!("<{}>", struct_.generics.join(", "))
     };
+    let extends = match &struct_.extends {
+        Some(extends) => format!(
+            " extends {}",
+            gen_typeref_scoped(&schema::Type::Ref(extends.clone()), scope)
+        ),
+        None => "".to_string(),
+    };
+    gen_deprecated(struct_.deprecated.as_ref(), gen);
     gen.begin(&format!(
-        "export interface {}{} {{",
-        struct_.fqtn.name, generics
+        "export interface {}{}{} {{",
+        struct_.fqtn.name, generics, extends
     ));
     for field in struct_.fields.iter() {
+        gen_deprecated(field.deprecated.as_ref(), gen);
         let opt = if field.optional { "?" } else { "" };
         gen.line(&format!(
             "{}{}: {},",
             field.name,
             opt,
-            gen_typeref(&field.type_)
+            gen_typeref_scoped(&field.type_, scope)
         ));
     }
     gen.end("}");
+    gen_struct_constructor(struct_, &generics, gen);
 }
 
-fn gen_fieldset(fieldset: &schema::Fieldset, gen: &mut Generator) {
+/// Generate a function creating the struct from an object which may
+/// omit the fields with default values. Defaults are also used for
+/// fields which are explicitly set to `undefined`.
+fn gen_struct_constructor(struct_: &schema::Struct, generics: &str, gen: &mut Generator) {
+    let defaults = struct_
+        .all_fields
+        .iter()
+        .filter_map(|field| Some((field, field.default.as_ref()?)))
+        .collect::<Vec<_>>();
+    if defaults.is_empty() {
+        return;
+    }
+    let type_ = format!("{}{}", struct_.fqtn.name, generics);
+    let names = defaults
+        .iter()
+        .map(|(field, _)| format!("\"{}\"", field.name))
+        .collect::<Vec<_>>()
+        .join(" | ");
+    gen.line("");
+    gen.begin(&format!(
+        "export function new{}{}(value: Omit<{}, {}> & Partial<Pick<{}, {}>>): {} {{",
+        struct_.fqtn.name, generics, type_, names, type_, names, type_
+    ));
+    gen.begin("return {");
+    gen.line("...value,");
+    for (field, value) in defaults {
+        gen.line(&format!(
+            "{0}: value.{0} === undefined ? {1} : value.{0},",
+            field.name,
+            gen_value(value)
+        ));
+    }
+    gen.end("}");
+    gen.end("}");
+}
+
+fn gen_value(value: &idl::Value) -> String {
+    match value {
+        idl::Value::Boolean(b) => b.to_string(),
+        idl::Value::Integer(i) => i.to_string(),
+        idl::Value::Float(f) => f.to_string(),
+        idl::Value::String(s) | idl::Value::Identifier(s) => {
+            serde_json::to_string(s).expect("strings are always serializable")
+        }
+        idl::Value::Range(_, _)
+        | idl::Value::Duration(_)
+        | idl::Value::Rate(_, _)
+        | idl::Value::Constant(_) => {
+            unreachable!("values are checked by the schema validation")
+        }
+    }
+}
+
+fn gen_fieldset(fieldset: &schema::Fieldset, scope: &Scope, gen: &mut Generator) {
     let generics = if fieldset.generics.is_empty() {
         "".to_string()
     } else {
@@ -146,39 +408,157 @@ fn gen_fieldset(fieldset: &schema::Fieldset, gen: &mut Generator) {
         fieldset.fqtn.name, generics
     ));
     for field in fieldset.fields.iter() {
+        gen_deprecated(field.field.as_ref().unwrap().deprecated.as_ref(), gen);
         // FIXME add support for optional fields
         let opt = if field.optional { "?" } else { "" };
         gen.line(&format!(
             "{}{}: {},",
             field.name,
             opt,
-            gen_typeref(&field.field.as_ref().unwrap().type_)
+            gen_typeref_scoped(&field.field.as_ref().unwrap().type_, scope)
         ));
     }
     gen.end("}");
 }
 
-fn method_signature(method: &schema::Method) -> String {
+fn method_signature(method: &schema::Method, scope: &Scope) -> String {
     let input = match &method.input {
-        Some(t) => format!("input: {}", gen_typeref(t)),
+        _ if method.params.is_some() => params_signature(method, scope),
+        Some(t) if method.input_stream => {
+            format!("input: AsyncIterable<{}>", gen_typeref_scoped(t, scope))
+        }
+        Some(t) => format!("input: {}", gen_typeref_scoped(t, scope)),
         None => String::new(),
     };
-    let output = match &method.output {
-        Some(t) => gen_typeref(t),
-        None => "void".to_string(),
+    match &method.output {
+        Some(t) if method.output_stream => format!(
+            "{}({}): AsyncIterable<{}>",
+            method.name,
+            input,
+            gen_typeref_scoped(t, scope)
+        ),
+        Some(t) => format!(
+            "{}({}): webwire.Response<{}>",
+            method.name,
+            input,
+            gen_typeref_scoped(t, scope)
+        ),
+        None if method.notify => format!("{}({}): void", method.name, input),
+        None => format!("{}({}): webwire.Response<void>", method.name, input),
+    }
+}
+
+/// Parameters which may be omitted are optional if no required
+/// parameter follows them. Otherwise `undefined` must be passed.
+fn params_signature(method: &schema::Method, scope: &Scope) -> String {
+    let params = method.params.as_deref().unwrap_or_default();
+    let required = params
+        .iter()
+        .rposition(|param| !param.optional && param.default.is_none());
+    params
+        .iter()
+        .enumerate()
+        .map(|(i, param)| {
+            let type_ = gen_typeref_scoped(&param.type_, scope);
+            if param.optional || param.default.is_some() {
+                if required.is_some_and(|required| i < required) {
+                    format!("{}: {} | undefined", param.name, type_)
+                } else {
+                    format!("{}?: {}", param.name, type_)
+                }
+            } else {
+                format!("{}: {}", param.name, type_)
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Names of the parameters of a method separated by `separator` and
+/// prefixed with `prefix`
+fn param_names(method: &schema::Method, prefix: &str, separator: &str) -> String {
+    method
+        .params
+        .iter()
+        .flatten()
+        .map(|param| format!("{}{}", prefix, param.name))
+        .collect::<Vec<_>>()
+        .join(separator)
+}
+
+fn gen_deprecated(deprecation: Option<&schema::Deprecation>, gen: &mut Generator) {
+    let Some(deprecation) = deprecation else {
+        return;
+    };
+    let message = match (&deprecation.since, &deprecation.note) {
+        (Some(since), Some(note)) => format!(" Since {}: {}", since, note),
+        (Some(since), None) => format!(" Since {}", since),
+        (None, Some(note)) => format!(" {}", note),
+        (None, None) => String::new(),
     };
-    format!("{}({}): webwire.Response<{}>", method.name, input, output)
+    gen.line(&format!(
+        "/** @deprecated{} */",
+        message.replace("*/", "*\\/")
+    ));
+}
+
+/// Name of the exception which carries the errors of a method, e.g.
+/// `UsersGetException` for the method `get` of the service `Users`.
+fn exception_name(service: &schema::Service, method: &schema::Method) -> String {
+    format!(
+        "{}{}Exception",
+        service.name,
+        method.name.to_upper_camel_case()
+    )
+}
+
+/// Generates the exceptions of the methods which throw errors. They are
+/// thrown by the implementations of the service and the consumers.
+fn gen_exceptions(service: &schema::Service, scope: &Scope, gen: &mut Generator) {
+    for method in service.methods.iter() {
+        let Some(throws) = &method.throws else {
+            continue;
+        };
+        let name = exception_name(service, method);
+        gen.begin(&format!("export class {} extends Error {{", name));
+        gen.line(&format!(
+            "readonly error: {}",
+            gen_typeref_scoped(throws, scope)
+        ));
+        gen.begin(&format!(
+            "constructor(error: {}) {{",
+            gen_typeref_scoped(throws, scope)
+        ));
+        gen.line(&format!(
+            "super(`{}.{} failed: ${{JSON.stringify(error)}}`)",
+            service.name, method.name
+        ));
+        gen.line(&format!("this.name = '{}'", name));
+        gen.line("this.error = error");
+        gen.end("}");
+        gen.end("}");
+        gen.line("");
+    }
 }
 
-fn gen_service(service: &schema::Service, gen: &mut Generator) {
+fn gen_service(service: &schema::Service, scope: &Scope, gen: &mut Generator) {
+    gen_exceptions(service, scope, gen);
+    gen_deprecated(service.annotations.deprecated.as_ref(), gen);
     gen.begin(&format!("export interface {} {{", service.name));
     for method in service.methods.iter() {
-        gen.line(&format!("{},", method_signature(method)));
+        gen_deprecated(method.annotations.deprecated.as_ref(), gen);
+        gen.line(&format!("{},", method_signature(method, scope)));
     }
     gen.end("}");
 }
 
-fn gen_consumer(ns: &schema::Namespace, service: &schema::Service, gen: &mut Generator) {
+fn gen_consumer(
+    ns: &schema::Namespace,
+    service: &schema::Service,
+    scope: &Scope,
+    gen: &mut Generator,
+) {
+    gen_deprecated(service.annotations.deprecated.as_ref(), gen);
     gen.begin(&format!(
         "export class {}Consumer implements {} {{",
         service.name, service.name
@@ -188,27 +568,163 @@ fn gen_consumer(ns: &schema::Namespace, service: &schema::Service, gen: &mut Gen
     gen.line("this._client = client");
     gen.end("}");
     for method in service.methods.iter() {
-        gen.begin(&format!("async {} {{", method_signature(method)));
         let fqsn = if ns.path.is_empty() {
             service.name.to_owned()
         } else {
             format!("{}.{}", ns.path.join("."), service.name)
         };
-        let input_param = if method.input.is_some() {
-            ", input"
+        // Parameters are sent as a single object and a streamed input
+        // as an array once all its values are available.
+        let input_param = if method.params.is_some() {
+            format!(", {{ {} }}", param_names(method, "", ", "))
+        } else if method.input_stream {
+            ", items".to_owned()
+        } else if method.input.is_some() {
+            ", input".to_owned()
         } else {
-            ""
+            String::new()
         };
+        gen_deprecated(method.annotations.deprecated.as_ref(), gen);
+        if method.notify {
+            gen.begin(&format!("{} {{", method_signature(method, scope)));
+            gen.line(&format!(
+                "this._client.notify('{}', '{}'{})",
+                fqsn, method.name, input_param,
+            ));
+        } else if method.output_stream {
+            // A streamed output is received as an array.
+            gen.begin(&format!("async *{} {{", method_signature(method, scope)));
+            gen_collect_input(method, scope, gen);
+            gen.line(&format!(
+                "yield* await this._client.request('{}', '{}'{})",
+                fqsn, method.name, input_param,
+            ));
+        } else if let (Some(output), Some(_)) = (method.wire_output(), &method.throws) {
+            // The response is a `Result` whose error is thrown.
+            gen.begin(&format!("async {} {{", method_signature(method, scope)));
+            gen.line(&format!(
+                "const result: {} = await this._client.request('{}', '{}'{})",
+                gen_typeref_scoped(&output, scope),
+                fqsn,
+                method.name,
+                input_param,
+            ));
+            gen.begin("if ('Err' in result) {");
+            gen.line(&format!(
+                "throw new {}(result.Err)",
+                exception_name(service, method)
+            ));
+            gen.end("}");
+            if method.output.is_some() {
+                gen.line("return result.Ok");
+            }
+        } else {
+            gen.begin(&format!("async {} {{", method_signature(method, scope)));
+            gen_collect_input(method, scope, gen);
+            gen.line(&format!(
+                "return await this._client.request('{}', '{}'{})",
+                fqsn, method.name, input_param,
+            ));
+        }
+        gen.end("}");
+    }
+    gen.end("}");
+}
+
+/// Collects the values of a streamed input into the array `items`
+fn gen_collect_input(method: &schema::Method, scope: &Scope, gen: &mut Generator) {
+    if let (Some(input), true) = (&method.input, method.input_stream) {
         gen.line(&format!(
-            "return await this._client.request('{}', '{}'{})",
-            fqsn, method.name, input_param,
+            "const items: Array<{}> = []",
+            gen_typeref_scoped(input, scope)
         ));
+        gen.begin("for await (const item of input) {");
+        gen.line("items.push(item)");
         gen.end("}");
     }
+}
+
+fn gen_provider(ns: &schema::Namespace, service: &schema::Service, gen: &mut Generator) {
+    let fqsn = if ns.path.is_empty() {
+        service.name.to_owned()
+    } else {
+        format!("{}.{}", ns.path.join("."), service.name)
+    };
+    gen.begin(&format!(
+        "export function {}Provider(service: {}): webwire.Provider {{",
+        service.name, service.name
+    ));
+    gen.begin("return {");
+    gen.line(&format!("service: '{}',", fqsn));
+    gen.begin("async call(method: string, input?: any): Promise<unknown> {");
+    gen.line("switch (method) {");
+    for method in service.methods.iter() {
+        // The block of a streamed output scopes its variable to the case.
+        let block = if method.output_stream { " {" } else { "" };
+        gen.line(&format!("case '{}':{}", method.name, block));
+        // Streamed inputs and outputs are transferred as arrays.
+        let input = if method.params.is_some() {
+            param_names(method, "input.", ", ")
+        } else if method.input_stream {
+            "(async function* () { yield* input })()".to_owned()
+        } else if method.input.is_some() {
+            "input".to_owned()
+        } else {
+            String::new()
+        };
+        if method.notify {
+            gen.line(&format!("    service.{}({})", method.name, input));
+            gen.line("    return");
+        } else if method.output_stream {
+            gen.line("    const output: Array<unknown> = []");
+            gen.line(&format!(
+                "    for await (const item of service.{}({})) {{",
+                method.name, input
+            ));
+            gen.line("        output.push(item)");
+            gen.line("    }");
+            gen.line("    return output");
+            gen.line("}");
+        } else if method.throws.is_some() {
+            // Exceptions carrying the errors of the method are sent as
+            // the error of a `Result`.
+            let call = format!("await service.{}({})", method.name, input);
+            gen.line("    try {");
+            if method.output.is_some() {
+                gen.line(&format!("        return {{ Ok: {} }}", call));
+            } else {
+                gen.line(&format!("        {}", call));
+                gen.line("        return { Ok: null }");
+            }
+            gen.line("    } catch (e) {");
+            gen.line(&format!(
+                "        if (e instanceof {}) {{",
+                exception_name(service, method)
+            ));
+            gen.line("            return { Err: e.error }");
+            gen.line("        }");
+            gen.line("        throw e");
+            gen.line("    }");
+        } else {
+            gen.line(&format!(
+                "    return await service.{}({})",
+                method.name, input
+            ));
+        }
+    }
+    gen.line("default:");
+    gen.line("    throw new Error(`Unknown method ${method}`)");
+    gen.line("}");
+    gen.end("},");
+    gen.end("}");
     gen.end("}");
 }
 
-pub fn gen_typeref(type_: &schema::Type) -> String {
+pub fn gen_typeref(type_: &schema::Type, builtin_types: &HashMap<String, String>) -> String {
+    gen_typeref_scoped(type_, &Scope::new(None, builtin_types))
+}
+
+fn gen_typeref_scoped(type_: &schema::Type, scope: &Scope) -> String {
     match type_ {
         schema::Type::None => "null".to_string(),
         schema::Type::Boolean => "boolean".to_string(),
@@ -219,31 +735,45 @@ pub fn gen_typeref(type_: &schema::Type) -> String {
         schema::Type::Date => "webwire.Date".to_string(),
         schema::Type::Time => "webwire.Time".to_string(),
         schema::Type::DateTime => "webwire.DateTime".to_string(),
-        schema::Type::Option(some) => format!("webwire.Option<{}>", gen_typeref(some)),
+        schema::Type::Decimal => "webwire.Decimal".to_string(),
+        schema::Type::Bytes => "webwire.Bytes".to_string(),
+        schema::Type::Duration => "webwire.Duration".to_string(),
+        schema::Type::Int8 => "number".to_string(),
+        schema::Type::Int16 => "number".to_string(),
+        schema::Type::Int32 => "number".to_string(),
+        schema::Type::Int64 => "number".to_string(),
+        schema::Type::UInt8 => "number".to_string(),
+        schema::Type::UInt16 => "number".to_string(),
+        schema::Type::UInt32 => "number".to_string(),
+        schema::Type::UInt64 => "number".to_string(),
+        schema::Type::Option(some) => {
+            format!("webwire.Option<{}>", gen_typeref_scoped(some, scope))
+        }
         schema::Type::Result(ok, err) => {
-            format!("webwire.Result<{}, {}>", gen_typeref(ok), gen_typeref(err))
+            format!(
+                "webwire.Result<{}, {}>",
+                gen_typeref_scoped(ok, scope),
+                gen_typeref_scoped(err, scope)
+            )
         }
         // complex types
-        schema::Type::Array(array) => format!("Array<{}>", gen_typeref(&array.item_type)),
+        schema::Type::Array(array) => {
+            format!("Array<{}>", gen_typeref_scoped(&array.item_type, scope))
+        }
         schema::Type::Map(map) => format!(
             "Map<{}, {}>",
-            gen_typeref(&map.key_type),
-            gen_typeref(&map.value_type)
+            gen_typeref_scoped(&map.key_type, scope),
+            gen_typeref_scoped(&map.value_type, scope)
         ),
         // named
         schema::Type::Ref(typeref) => {
             let typeref_fqtn = typeref.fqtn();
-            let fqtn = if typeref_fqtn.ns.is_empty() {
-                typeref_fqtn.name.clone()
-            } else {
-                let ns = typeref_fqtn.ns.join(".");
-                format!("{}.{}", ns, typeref_fqtn.name)
-            };
+            let fqtn = scope.reference(&typeref_fqtn.ns, &typeref_fqtn.name);
             let generics = typeref.generics();
             if !generics.is_empty() {
                 let generics = generics
                     .iter()
-                    .map(gen_typeref)
+                    .map(|t| gen_typeref_scoped(t, scope))
                     .collect::<Vec<_>>()
                     .join(", ");
                 format!("{}<{}>", fqtn, generics)
@@ -251,6 +781,81 @@ pub fn gen_typeref(type_: &schema::Type) -> String {
                 fqtn
             }
         }
-        schema::Type::Builtin(name) => name.to_string(),
+        schema::Type::Generic(name) => name.to_string(),
+        schema::Type::Builtin(name) => builtin_type(scope.builtin_types, name).to_string(),
     }
 }
+
+#[test]
+fn test_gen_files() {
+    let idl = r"
+        struct Root {}
+        namespace a {
+            struct A { root: ::Root }
+            namespace b {
+                struct B { a: ::a::A }
+            }
+        }
+    ";
+    let idocs = [crate::idl::parse_document(idl).unwrap()];
+    let builtin_types = std::collections::HashSet::default();
+    let doc = schema::Document::from_idl(idocs.iter(), &builtin_types).unwrap();
+    let output = gen_files(&doc, &HashMap::new());
+    assert_eq!(
+        output.files.keys().collect::<Vec<_>>(),
+        vec!["a.ts", "a/b.ts", "index.ts"]
+    );
+    let index = &output.files["index.ts"];
+    assert!(index.contains("import * as webwire from './webwire'\n"));
+    assert!(index.contains("export * as a from './a'\n"));
+    let b = &output.files["a/b.ts"];
+    assert!(b.contains("import * as webwire from '../webwire'\n"));
+    assert!(b.contains("import * as _ns$a from '../a'\n"));
+    assert!(b.contains("    a: _ns$a.A,\n"));
+    assert!(!b.contains("import * as _ns from"));
+}
+
+#[test]
+fn test_gen_files_module_aliases() {
+    let idl = r"
+        struct Root { a_b: a_b::X, a_b_nested: a::b::X }
+        namespace a_b {
+            struct X {}
+        }
+        namespace a {
+            namespace b {
+                struct X { root: ::Root }
+            }
+        }
+    ";
+    let idocs = [crate::idl::parse_document(idl).unwrap()];
+    let builtin_types = std::collections::HashSet::default();
+    let doc = schema::Document::from_idl(idocs.iter(), &builtin_types).unwrap();
+    assert!(check_namespaces(&doc).is_ok());
+    let output = gen_files(&doc, &HashMap::new());
+    let index = &output.files["index.ts"];
+    assert!(index.contains("import * as webwire from './webwire'\n"));
+    assert!(index.contains("import * as _ns$a_b from './a_b'\n"));
+    assert!(index.contains("import * as _ns$a$b from './a/b'\n"));
+    assert!(index.contains("    a_b: _ns$a_b.X,\n"));
+    assert!(index.contains("    a_b_nested: _ns$a$b.X,\n"));
+    let b = &output.files["a/b.ts"];
+    assert!(b.contains("import * as _ns from '../index'\n"));
+    assert!(b.contains("    root: _ns.Root,\n"));
+}
+
+#[test]
+fn test_check_namespaces() {
+    let idl = r"
+        namespace webwire {
+            struct X {}
+        }
+    ";
+    let idocs = [crate::idl::parse_document(idl).unwrap()];
+    let builtin_types = std::collections::HashSet::default();
+    let doc = schema::Document::from_idl(idocs.iter(), &builtin_types).unwrap();
+    assert_eq!(
+        check_namespaces(&doc).unwrap_err().to_string(),
+        "The namespace `webwire` clashes with the runtime module of the TypeScript code"
+    );
+}
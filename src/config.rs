@@ -0,0 +1,265 @@
+//! Project configuration file
+//!
+//! The configuration file `webwire.toml` lists the IDL files of a
+//! project and all artifacts which should be generated from them:
+//!
+//! ```toml
+//! sources = ["api/chat.ww"]
+//! include_paths = ["api/common"]
+//!
+//! [builtin_types.rs]
+//! Url = "::url::Url"
+//!
+//! [[target]]
+//! language = "rs"
+//! output = "server/src/api.rs"
+//! time_crate = "jiff"
+//!
+//! [[target]]
+//! language = "ts"
+//! out_dir = "client/src/api"
+//!
+//! [[target]]
+//! language = "kt"
+//! output = "android/src/main/kotlin/Api.kt"
+//! package = "com.example.chat"
+//!
+//! [[target]]
+//! plugin = "./tools/gen-python"
+//! output = "python/api"
+//! builtin_types = { Url = "str" }
+//! options = { package = "api" }
+//! ```
+//!
+//! All relative paths are relative to the directory containing the
+//! configuration file.
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+
+/// Name of the configuration file which is used by `webwire gen` if no
+/// other arguments are given
+pub const FILENAME: &str = "webwire.toml";
+
+#[derive(Debug)]
+pub struct ConfigError {
+    pub message: String,
+}
+
+impl std::fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+#[derive(Debug, Deserialize, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    /// Root IDL files
+    #[serde(default)]
+    pub sources: Vec<PathBuf>,
+    /// Directories which are searched for included files which can not
+    /// be found relative to the including file
+    #[serde(default)]
+    pub include_paths: Vec<PathBuf>,
+    /// Builtin type mappings per language (e.g. `rs` or `ts`)
+    #[serde(default)]
+    pub builtin_types: BTreeMap<String, BTreeMap<String, String>>,
+    #[serde(default, rename = "target")]
+    pub targets: Vec<Target>,
+}
+
+#[derive(Debug, Deserialize, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct Target {
+    /// Language of the generator (`rs`, `ts`, `kt`, `swift` or `template`)
+    pub language: Option<String>,
+    /// External code generator executable which is used instead of a
+    /// builtin generator
+    pub plugin: Option<PathBuf>,
+    /// Output file or directory for generators producing multiple files
+    pub output: Option<PathBuf>,
+    /// Output directory for generating one file per namespace
+    pub out_dir: Option<PathBuf>,
+    /// Template directory of the template generator
+    pub template: Option<PathBuf>,
+    /// Crate used for date and time types by the Rust generator
+    pub time_crate: Option<String>,
+    /// Package of the code generated by the Kotlin generator
+    pub package: Option<String>,
+    /// Builtin type mappings which take precedence over the ones of the
+    /// language
+    #[serde(default)]
+    pub builtin_types: BTreeMap<String, String>,
+    /// Parameters passed to the plugin
+    #[serde(default)]
+    pub options: BTreeMap<String, String>,
+}
+
+impl Config {
+    pub fn parse(source: &str) -> Result<Self, ConfigError> {
+        let config: Self = toml::from_str(source).map_err(|e| ConfigError {
+            message: e.to_string(),
+        })?;
+        config.validate()?;
+        Ok(config)
+    }
+    /// Load the configuration file and make all paths relative to the
+    /// current working directory.
+    pub fn load(path: &Path) -> Result<Self, ConfigError> {
+        let source = std::fs::read_to_string(path).map_err(|e| ConfigError {
+            message: format!("Could not read {:?}: {}", path, e),
+        })?;
+        let mut config = Self::parse(&source).map_err(|e| ConfigError {
+            message: format!("Invalid configuration file {:?}: {}", path, e),
+        })?;
+        config.resolve_paths(path.parent().unwrap_or(Path::new("")));
+        Ok(config)
+    }
+    fn validate(&self) -> Result<(), ConfigError> {
+        if self.sources.is_empty() {
+            return Err(ConfigError {
+                message: "No sources given".to_owned(),
+            });
+        }
+        if self.targets.is_empty() {
+            return Err(ConfigError {
+                message: "No targets given".to_owned(),
+            });
+        }
+        for (i, target) in self.targets.iter().enumerate() {
+            let error = |message: &str| ConfigError {
+                message: format!("Target #{}: {}", i + 1, message),
+            };
+            match (&target.language, &target.plugin) {
+                (None, None) => return Err(error("Either language or plugin must be given")),
+                (Some(_), Some(_)) => {
+                    return Err(error("The options language and plugin are exclusive"))
+                }
+                _ => {}
+            }
+            if target.output.is_some() && target.out_dir.is_some() {
+                return Err(error("The options output and out_dir are exclusive"));
+            }
+        }
+        Ok(())
+    }
+    fn resolve_paths(&mut self, base_dir: &Path) {
+        let resolve = |path: &mut PathBuf| *path = base_dir.join(&*path);
+        self.sources.iter_mut().for_each(resolve);
+        self.include_paths.iter_mut().for_each(resolve);
+        for target in self.targets.iter_mut() {
+            target.output.iter_mut().for_each(resolve);
+            target.out_dir.iter_mut().for_each(resolve);
+            target.template.iter_mut().for_each(resolve);
+            // Plugins without a directory are looked up in the `PATH`.
+            target
+                .plugin
+                .iter_mut()
+                .filter(|plugin| plugin.components().count() > 1)
+                .for_each(resolve);
+        }
+    }
+    /// Names of all builtin types of all languages and targets
+    pub fn builtin_type_names(&self) -> HashSet<String> {
+        self.builtin_types
+            .values()
+            .chain(self.targets.iter().map(|target| &target.builtin_types))
+            .flat_map(|types| types.keys().cloned())
+            .collect()
+    }
+    /// Builtin type mappings used for the given target
+    pub fn builtin_types(&self, target: &Target) -> HashMap<String, String> {
+        let mut types = HashMap::new();
+        if let Some(language_types) = target
+            .language
+            .as_ref()
+            .and_then(|language| self.builtin_types.get(language))
+        {
+            types.extend(language_types.clone());
+        }
+        types.extend(target.builtin_types.clone());
+        types
+    }
+}
+
+#[test]
+fn test_config() {
+    let mut config = Config::parse(
+        r#"
+        sources = ["api/chat.ww"]
+        include_paths = ["common"]
+
+        [builtin_types.rs]
+        Url = "::url::Url"
+        Money = "crate::Money"
+
+        [[target]]
+        language = "rs"
+        output = "server/api.rs"
+        builtin_types = { Money = "i64" }
+
+        [[target]]
+        plugin = "./gen-python"
+        output = "python"
+        options = { package = "api" }
+
+        [[target]]
+        plugin = "webwire-gen-go"
+
+        [[target]]
+        language = "kt"
+        package = "com.example.api"
+        "#,
+    )
+    .unwrap();
+    config.resolve_paths(Path::new("project"));
+    assert_eq!(config.sources, vec![PathBuf::from("project/api/chat.ww")]);
+    assert_eq!(config.include_paths, vec![PathBuf::from("project/common")]);
+    assert_eq!(config.targets.len(), 4);
+    assert_eq!(
+        config.targets[0].output,
+        Some(PathBuf::from("project/server/api.rs"))
+    );
+    assert_eq!(
+        config.builtin_types(&config.targets[0]),
+        HashMap::from([
+            ("Url".to_owned(), "::url::Url".to_owned()),
+            ("Money".to_owned(), "i64".to_owned()),
+        ])
+    );
+    assert_eq!(
+        config.targets[1].plugin,
+        Some(PathBuf::from("project/./gen-python"))
+    );
+    assert_eq!(config.targets[1].options["package"], "api");
+    assert!(config.builtin_types(&config.targets[1]).is_empty());
+    assert_eq!(
+        config.builtin_type_names(),
+        HashSet::from(["Money".to_owned(), "Url".to_owned()])
+    );
+    assert_eq!(
+        config.targets[2].plugin,
+        Some(PathBuf::from("webwire-gen-go"))
+    );
+    assert_eq!(
+        config.targets[3].package.as_deref(),
+        Some("com.example.api")
+    );
+}
+
+#[test]
+fn test_config_invalid() {
+    let error = |source: &str| Config::parse(source).unwrap_err().message;
+    assert_eq!(error("[[target]]\nlanguage = \"rs\""), "No sources given");
+    assert_eq!(error("sources = [\"a.ww\"]"), "No targets given");
+    assert_eq!(
+        error("sources = [\"a.ww\"]\n[[target]]\noutput = \"a.rs\""),
+        "Target #1: Either language or plugin must be given"
+    );
+    assert!(error("sources = [\"a.ww\"]\nfoo = 1").contains("unknown field `foo`"));
+}
@@ -0,0 +1,157 @@
+use nom::{
+    bytes::complete::tag,
+    character::complete::char,
+    combinator::{cut, map, opt},
+    sequence::{pair, preceded, separated_pair, terminated},
+    IResult,
+};
+
+use crate::common::FilePosition;
+use crate::idl::common::{parse_identifier_with_generics, ws, ws1, Span};
+use crate::idl::field_option::{parse_field_options, FieldOption};
+use crate::idl::r#type::{parse_type, Type};
+
+#[cfg(test)]
+use crate::idl::common::assert_parse;
+
+/// Alternative name of a type (`type UserId = UUID;`)
+#[derive(Clone, Debug, PartialEq)]
+pub struct Alias {
+    pub name: String,
+    pub generics: Vec<String>,
+    pub type_: Type,
+    pub position: FilePosition,
+}
+
+/// Distinct type wrapping another type
+/// (`newtype Email = String (format="email");`)
+#[derive(Clone, Debug, PartialEq)]
+pub struct Newtype {
+    pub name: String,
+    pub generics: Vec<String>,
+    pub type_: Type,
+    pub options: Vec<FieldOption>,
+    pub position: FilePosition,
+}
+
+pub fn parse_alias(input: Span) -> IResult<Span, Alias> {
+    map(
+        preceded(
+            terminated(tag("type"), ws1),
+            cut(terminated(
+                separated_pair(
+                    parse_identifier_with_generics,
+                    preceded(ws, char('=')),
+                    parse_type,
+                ),
+                preceded(ws, char(';')),
+            )),
+        ),
+        |((name, generics), type_)| Alias {
+            name,
+            generics,
+            type_,
+            position: input.into(),
+        },
+    )(input)
+}
+
+pub fn parse_newtype(input: Span) -> IResult<Span, Newtype> {
+    map(
+        preceded(
+            terminated(tag("newtype"), ws1),
+            cut(terminated(
+                separated_pair(
+                    parse_identifier_with_generics,
+                    preceded(ws, char('=')),
+                    pair(parse_type, opt(parse_field_options)),
+                ),
+                preceded(ws, char(';')),
+            )),
+        ),
+        |((name, generics), (type_, options))| Newtype {
+            name,
+            generics,
+            type_,
+            options: options.unwrap_or_default(),
+            position: input.into(),
+        },
+    )(input)
+}
+
+#[test]
+fn test_parse_alias() {
+    use crate::idl::r#type::TypeRef;
+    let contents = [
+        "type UserId = UUID;",
+        "type UserId=UUID;",
+        "type UserId = UUID ;",
+    ];
+    for content in contents.iter() {
+        assert_parse(
+            parse_alias(Span::new(content)),
+            Alias {
+                name: "UserId".to_string(),
+                generics: vec![],
+                type_: Type::Ref(TypeRef {
+                    abs: false,
+                    ns: vec![],
+                    name: "UUID".to_string(),
+                    generics: vec![],
+                }),
+                position: FilePosition { line: 1, column: 1 },
+            },
+        );
+    }
+}
+
+#[test]
+fn test_parse_alias_generic() {
+    let (_, alias) = parse_alias(Span::new("type Page<T> = [T];")).unwrap();
+    assert_eq!(alias.name, "Page");
+    assert_eq!(alias.generics, vec!["T".to_string()]);
+}
+
+#[test]
+fn test_parse_newtype() {
+    use crate::idl::r#type::TypeRef;
+    use crate::idl::value::Value;
+    assert_parse(
+        parse_newtype(Span::new("newtype Email = String (format=\"email\");")),
+        Newtype {
+            name: "Email".to_string(),
+            generics: vec![],
+            type_: Type::Ref(TypeRef {
+                abs: false,
+                ns: vec![],
+                name: "String".to_string(),
+                generics: vec![],
+            }),
+            options: vec![FieldOption {
+                position: FilePosition {
+                    line: 1,
+                    column: 25,
+                },
+                name: "format".to_string(),
+                value: Value::String("email".to_string()),
+            }],
+            position: FilePosition { line: 1, column: 1 },
+        },
+    );
+    let (_, newtype) = parse_newtype(Span::new("newtype OrderId = UUID;")).unwrap();
+    assert!(newtype.options.is_empty());
+}
+
+#[test]
+fn test_parse_alias_invalid() {
+    for content in ["type A = ;", "type A = B", "type A B;"] {
+        assert!(matches!(
+            parse_alias(Span::new(content)),
+            Err(nom::Err::Failure(_))
+        ));
+    }
+    assert!(matches!(
+        parse_newtype(Span::new("newtype A;")),
+        Err(nom::Err::Failure(_))
+    ));
+}
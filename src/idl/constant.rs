@@ -0,0 +1,81 @@
+use nom::{
+    bytes::complete::tag,
+    character::complete::char,
+    combinator::{cut, map},
+    sequence::{preceded, separated_pair, terminated},
+    IResult,
+};
+
+use crate::common::FilePosition;
+use crate::idl::common::{parse_identifier, ws, ws1, Span};
+use crate::idl::r#type::{parse_type, Type};
+use crate::idl::value::{parse_value, Value};
+
+#[cfg(test)]
+use crate::idl::common::assert_parse;
+
+#[derive(Debug, PartialEq)]
+pub struct Constant {
+    pub name: String,
+    pub type_: Type,
+    pub value: Value,
+    pub position: FilePosition,
+}
+
+pub fn parse_constant(input: Span) -> IResult<Span, Constant> {
+    map(
+        preceded(
+            terminated(tag("const"), ws1),
+            cut(terminated(
+                separated_pair(
+                    separated_pair(parse_identifier, preceded(ws, char(':')), parse_type),
+                    preceded(ws, char('=')),
+                    preceded(ws, parse_value),
+                ),
+                preceded(ws, char(';')),
+            )),
+        ),
+        |((name, type_), value)| Constant {
+            name,
+            type_,
+            value,
+            position: input.into(),
+        },
+    )(input)
+}
+
+#[test]
+fn test_parse_constant() {
+    use crate::idl::r#type::TypeRef;
+    let contents = [
+        "const MAX_PAGE_SIZE: Integer = 100;",
+        "const MAX_PAGE_SIZE:Integer=100;",
+        "const MAX_PAGE_SIZE : Integer = 100 ;",
+    ];
+    for content in contents.iter() {
+        assert_parse(
+            parse_constant(Span::new(content)),
+            Constant {
+                name: "MAX_PAGE_SIZE".to_string(),
+                type_: Type::Ref(TypeRef {
+                    abs: false,
+                    ns: vec![],
+                    name: "Integer".to_string(),
+                    generics: vec![],
+                }),
+                value: Value::Integer(100),
+                position: FilePosition { line: 1, column: 1 },
+            },
+        );
+    }
+}
+
+#[test]
+fn test_parse_constant_invalid() {
+    for content in ["const A: Integer = 1", "const A = 1;", "const A: Integer;"] {
+        assert!(matches!(
+            parse_constant(Span::new(content)),
+            Err(nom::Err::Failure(_))
+        ));
+    }
+}
@@ -29,7 +29,7 @@ pub enum DocumentPart {
     NamespacePart(NamespacePart),
 }
 
-pub fn parse_document(input: &str) -> Result<Document, ParseError> {
+pub fn parse_document(input: &str) -> Result<Document, ParseError<'_>> {
     let span = Span::new(input);
     let result = parse_document_content(span);
     match result {
@@ -77,8 +77,8 @@ fn test_parse_document() {
     use crate::idl::namespace::{Namespace, NamespacePart};
     use crate::idl::r#struct::{Field, Struct};
     use crate::idl::r#type::{Type, TypeRef};
-    use crate::idl::service::Service;
-    use crate::idl::value::Value;
+    use crate::idl::service::{Service, ServiceSide};
+    use crate::idl::value::{RangeBound, Value};
     let content = "
         include common.ww;
         struct Person {
@@ -109,8 +109,10 @@ fn test_parse_document() {
                 parts: vec![
                     NamespacePart::Struct(Struct {
                         name: "Person".to_string(),
+                        options: vec![],
                         position: FilePosition { line: 3, column: 9 },
                         generics: vec![],
+                        extends: None,
                         fields: vec![
                             Field {
                                 name: "name".to_string(),
@@ -125,13 +127,17 @@ fn test_parse_document() {
                                     generics: vec![]
                                 }),
                                 optional: false,
+                                default: None,
                                 options: vec![FieldOption {
                                     position: FilePosition {
                                         line: 4,
                                         column: 27,
                                     },
                                     name: "length".to_string(),
-                                    value: Value::Range(Some(1), Some(50))
+                                    value: Value::Range(
+                                        Some(RangeBound::Integer(1)),
+                                        Some(RangeBound::Integer(50))
+                                    )
                                 }],
                             },
                             Field {
@@ -147,14 +153,17 @@ fn test_parse_document() {
                                     generics: vec![],
                                 }),
                                 optional: false,
+                                default: None,
                                 options: vec![],
                             },
                         ],
                     }),
                     NamespacePart::Struct(Struct {
                         name: "Group".to_string(),
+                        options: vec![],
                         position: FilePosition { line: 7, column: 9 },
                         generics: vec![],
+                        extends: None,
                         fields: vec![Field {
                             name: "name".to_string(),
                             position: FilePosition {
@@ -168,11 +177,14 @@ fn test_parse_document() {
                                 generics: vec![]
                             }),
                             optional: false,
+                            default: None,
                             options: vec![],
                         }],
                     }),
                     NamespacePart::Service(Service {
                         name: "Pinger".to_string(),
+                        side: ServiceSide::Server,
+                        options: vec![],
                         position: FilePosition {
                             line: 10,
                             column: 9
@@ -181,17 +193,29 @@ fn test_parse_document() {
                             Method {
                                 name: "ping".to_string(),
                                 input: None,
+                                params: None,
                                 output: None,
+                                throws: None,
+                                input_stream: false,
+                                output_stream: false,
+                                notify: false,
+                                options: vec![],
                             },
                             Method {
                                 name: "get_version".to_string(),
                                 input: None,
+                                params: None,
                                 output: Some(Type::Ref(TypeRef {
                                     abs: false,
                                     ns: vec![],
                                     name: "String".to_string(),
                                     generics: vec![]
                                 })),
+                                throws: None,
+                                input_stream: false,
+                                output_stream: false,
+                                notify: false,
+                                options: vec![],
                             },
                         ],
                     }),
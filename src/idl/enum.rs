@@ -1,7 +1,8 @@
 use nom::{
+    branch::alt,
     bytes::complete::tag,
-    character::complete::char,
-    combinator::{cut, map, opt},
+    character::complete::{char, one_of},
+    combinator::{cut, map, opt, peek, value, verify},
     error::context,
     multi::separated_list0,
     sequence::{pair, preceded, terminated, tuple},
@@ -13,26 +14,31 @@ use crate::idl::common::{
     parse_field_separator, parse_identifier, parse_identifier_with_generics, trailing_comma, ws,
     ws1, Span,
 };
+use crate::idl::field_option::{parse_field_options, FieldOption};
 use crate::idl::r#type::{parse_type, Type};
 
 #[cfg(test)]
 use crate::idl::common::assert_parse;
+#[cfg(test)]
+use crate::idl::value::Value;
 
 use super::{r#type::parse_type_ref, TypeRef};
 
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct Enum {
     pub name: String,
     pub generics: Vec<String>,
     pub extends: Option<TypeRef>,
+    pub options: Vec<FieldOption>,
     pub variants: Vec<EnumVariant>,
     pub position: FilePosition,
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct EnumVariant {
     pub name: String,
     pub value_type: Option<Type>,
+    pub options: Vec<FieldOption>,
 }
 
 pub fn parse_enum(input: Span) -> IResult<Span, Enum> {
@@ -40,12 +46,14 @@ pub fn parse_enum(input: Span) -> IResult<Span, Enum> {
         tuple((
             preceded(terminated(tag("enum"), ws1), parse_identifier_with_generics),
             parse_enum_extends,
+            opt(parse_field_options),
             parse_enum_variants,
         )),
-        |((name, generics), extends, variants)| Enum {
+        |((name, generics), extends, options, variants)| Enum {
             name,
             generics,
             extends,
+            options: options.unwrap_or_default(),
             variants,
             position: input.into(),
         },
@@ -62,7 +70,7 @@ fn parse_enum_extends(input: Span) -> IResult<Span, Option<TypeRef>> {
     )(input)
 }
 
-fn parse_enum_variants(input: Span) -> IResult<Span, Vec<EnumVariant>> {
+pub(crate) fn parse_enum_variants(input: Span) -> IResult<Span, Vec<EnumVariant>> {
     context(
         "enum_variants",
         preceded(
@@ -81,15 +89,56 @@ fn parse_enum_variant(input: Span) -> IResult<Span, EnumVariant> {
         map(
             pair(
                 parse_identifier,
-                opt(preceded(
-                    preceded(ws, char('(')),
-                    cut(terminated(
-                        parse_type,
-                        preceded(trailing_comma, preceded(ws, char(')'))),
-                    )),
+                alt((
+                    map(
+                        preceded(peek(parse_variant_options_start), parse_field_options),
+                        |options| (None, Some(options)),
+                    ),
+                    pair(
+                        opt(preceded(
+                            preceded(ws, char('(')),
+                            cut(terminated(
+                                parse_type,
+                                preceded(trailing_comma, preceded(ws, char(')'))),
+                            )),
+                        )),
+                        opt(parse_field_options),
+                    ),
+                )),
+            ),
+            |(name, (value_type, options))| EnumVariant {
+                name,
+                value_type,
+                options: options.unwrap_or_default(),
+            },
+        ),
+    )(input)
+}
+
+/// Both the value type and the options of a variant are enclosed in
+/// parentheses. The parentheses following the name contain options if
+/// they are empty or start with a lowercase option name like `deprecated`
+/// or `since="1.2"`. Otherwise they contain the value type.
+fn parse_variant_options_start(input: Span) -> IResult<Span, ()> {
+    value(
+        (),
+        preceded(
+            preceded(ws, char('(')),
+            preceded(
+                ws,
+                alt((
+                    value((), char(')')),
+                    value(
+                        (),
+                        pair(
+                            verify(parse_identifier, |name: &str| {
+                                name.starts_with(|c: char| c.is_ascii_lowercase())
+                            }),
+                            preceded(ws, one_of("=,)")),
+                        ),
+                    ),
                 )),
             ),
-            |(name, value_type)| EnumVariant { name, value_type },
         ),
     )(input)
 }
@@ -109,6 +158,7 @@ fn test_parse_enum_0() {
             parse_enum(Span::new(content)),
             Enum {
                 name: "Nothing".to_string(),
+                options: vec![],
                 generics: vec![],
                 position: FilePosition { line: 1, column: 1 },
                 extends: None,
@@ -134,11 +184,13 @@ fn test_parse_enum_1() {
             parse_enum(Span::new(content)),
             Enum {
                 name: "OneThing".to_string(),
+                options: vec![],
                 generics: vec![],
                 position: FilePosition { line: 1, column: 1 },
                 extends: None,
                 variants: vec![EnumVariant {
                     name: "Thing".to_string(),
+                    options: vec![],
                     value_type: None,
                 }],
             },
@@ -165,16 +217,19 @@ fn test_parse_enum_2() {
             parse_enum(Span::new(content)),
             Enum {
                 name: "Direction".to_string(),
+                options: vec![],
                 generics: vec![],
                 position: FilePosition { line: 1, column: 1 },
                 extends: None,
                 variants: vec![
                     EnumVariant {
                         name: "Left".to_string(),
+                        options: vec![],
                         value_type: None,
                     },
                     EnumVariant {
                         name: "Right".to_string(),
+                        options: vec![],
                         value_type: None,
                     },
                 ],
@@ -209,12 +264,14 @@ fn test_parse_enum_with_value() {
             parse_enum(Span::new(content)),
             Enum {
                 name: "Value".to_string(),
+                options: vec![],
                 generics: vec![],
                 position: FilePosition { line: 1, column: 1 },
                 extends: None,
                 variants: vec![
                     EnumVariant {
                         name: "S".to_string(),
+                        options: vec![],
                         value_type: Some(Type::Ref(TypeRef {
                             abs: false,
                             ns: vec![],
@@ -224,6 +281,7 @@ fn test_parse_enum_with_value() {
                     },
                     EnumVariant {
                         name: "I".to_string(),
+                        options: vec![],
                         value_type: Some(Type::Ref(TypeRef {
                             abs: false,
                             ns: vec![],
@@ -253,6 +311,7 @@ fn test_parse_enum_extends() {
             parse_enum(Span::new(content)),
             Enum {
                 name: "GetError".to_string(),
+                options: vec![],
                 generics: vec![],
                 position: FilePosition { line: 1, column: 1 },
                 extends: Some(TypeRef {
@@ -266,3 +325,58 @@ fn test_parse_enum_extends() {
         )
     }
 }
+
+#[test]
+fn test_parse_enum_with_options() {
+    use crate::idl::r#type::TypeRef;
+    let option = |name: &str, value: Value, column| FieldOption {
+        position: FilePosition { line: 1, column },
+        name: name.to_string(),
+        value,
+    };
+    let string_type = || {
+        Some(Type::Ref(TypeRef {
+            abs: false,
+            ns: vec![],
+            name: "String".to_string(),
+            generics: vec![],
+        }))
+    };
+    assert_parse(
+        parse_enum(Span::new(
+            "enum State (deprecated) { Active, Old (deprecated), Value(String) (deprecated, since=\"1.2\"), S (String) }",
+        )),
+        Enum {
+            name: "State".to_string(),
+            generics: vec![],
+            position: FilePosition { line: 1, column: 1 },
+            extends: None,
+            options: vec![option("deprecated", Value::Boolean(true), 13)],
+            variants: vec![
+                EnumVariant {
+                    name: "Active".to_string(),
+                    value_type: None,
+                    options: vec![],
+                },
+                EnumVariant {
+                    name: "Old".to_string(),
+                    value_type: None,
+                    options: vec![option("deprecated", Value::Boolean(true), 40)],
+                },
+                EnumVariant {
+                    name: "Value".to_string(),
+                    value_type: string_type(),
+                    options: vec![
+                        option("deprecated", Value::Boolean(true), 68),
+                        option("since", Value::String("1.2".to_string()), 80),
+                    ],
+                },
+                EnumVariant {
+                    name: "S".to_string(),
+                    value_type: string_type(),
+                    options: vec![],
+                },
+            ],
+        },
+    )
+}
@@ -1,9 +1,9 @@
 use nom::{
     character::complete::char,
-    combinator::{cut, map},
+    combinator::{cut, map, opt},
     error::context,
     multi::separated_list0,
-    sequence::{preceded, separated_pair, terminated},
+    sequence::{pair, preceded, terminated},
     IResult,
 };
 
@@ -15,7 +15,7 @@ use crate::{
     idl::common::{parse_field_separator, parse_identifier, trailing_comma, ws, Span},
 };
 
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct FieldOption {
     pub position: FilePosition,
     pub name: String,
@@ -35,17 +35,18 @@ pub fn parse_field_options(input: Span) -> IResult<Span, Vec<FieldOption>> {
     )(input)
 }
 
+/// Parses an option given as `name=value`. Options without a value
+/// (e.g. `deprecated`) are flags which are set to `true`.
 fn parse_field_option(input: Span) -> IResult<Span, FieldOption> {
     map(
-        separated_pair(
+        pair(
             parse_identifier,
-            preceded(ws, char('=')),
-            preceded(ws, parse_value),
+            opt(preceded(preceded(ws, char('=')), preceded(ws, parse_value))),
         ),
         |(name, value)| FieldOption {
             position: input.into(),
             name,
-            value,
+            value: value.unwrap_or(Value::Boolean(true)),
         },
     )(input)
 }
@@ -115,3 +116,25 @@ fn test_parse_field_options_2() {
         );
     }
 }
+
+#[test]
+fn test_parse_field_options_flag() {
+    assert_parse(
+        parse_field_options(Span::new("(idempotent, timeout=5s)")),
+        vec![
+            FieldOption {
+                position: FilePosition { line: 1, column: 2 },
+                name: "idempotent".to_owned(),
+                value: Value::Boolean(true),
+            },
+            FieldOption {
+                position: FilePosition {
+                    line: 1,
+                    column: 14,
+                },
+                name: "timeout".to_owned(),
+                value: Value::Duration(std::time::Duration::from_secs(5)),
+            },
+        ],
+    );
+}
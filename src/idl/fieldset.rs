@@ -1,9 +1,10 @@
 use nom::{
+    branch::alt,
     bytes::complete::tag,
     character::complete::char,
     combinator::{cut, map, opt},
     multi::separated_list0,
-    sequence::{pair, preceded, separated_pair, terminated},
+    sequence::{pair, preceded, separated_pair, terminated, tuple},
     IResult,
 };
 
@@ -12,7 +13,8 @@ use crate::idl::common::{
     parse_field_separator, parse_identifier, parse_identifier_with_generics, trailing_comma, ws,
     ws1, Span,
 };
-use crate::idl::r#type::{parse_type_ref, TypeRef};
+use crate::idl::field_option::{parse_field_options, FieldOption};
+use crate::idl::r#type::{parse_type, parse_type_ref, Type, TypeRef};
 
 #[cfg(test)]
 use crate::idl::common::assert_parse;
@@ -21,32 +23,70 @@ use crate::idl::common::assert_parse;
 pub struct Field {
     pub name: String,
     pub optional: bool,
+    /// Type replacing the one of the struct field
+    pub type_: Option<Type>,
+    /// Options replacing the ones of the struct field
+    pub options: Vec<FieldOption>,
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct Fieldset {
     pub name: String,
     pub generics: Vec<String>,
     pub r#struct: TypeRef,
+    /// Whether all fields of the struct are included (`..`)
+    pub include_all: bool,
+    /// Fields which are excluded (`-name`)
+    pub exclude: Vec<String>,
     pub fields: Vec<Field>,
     pub position: FilePosition,
 }
 
+enum Item {
+    All,
+    Exclude(String),
+    Field(Field),
+}
+
 fn parse_field(input: Span) -> IResult<Span, Field> {
     map(
-        pair(preceded(ws, parse_identifier), preceded(ws, opt(char('?')))),
-        |(name, optional)| Field {
+        tuple((
+            preceded(ws, parse_identifier),
+            preceded(ws, opt(char('?'))),
+            opt(preceded(
+                preceded(ws, char(':')),
+                cut(preceded(ws, parse_type)),
+            )),
+            opt(parse_field_options),
+        )),
+        |(name, optional, type_, options)| Field {
             name,
             optional: optional.is_some(),
+            type_,
+            options: options.unwrap_or_default(),
         },
     )(input)
 }
 
-fn parse_fields(input: Span) -> IResult<Span, Vec<Field>> {
+fn parse_item(input: Span) -> IResult<Span, Item> {
+    preceded(
+        ws,
+        alt((
+            map(tag(".."), |_| Item::All),
+            map(
+                preceded(char('-'), cut(preceded(ws, parse_identifier))),
+                Item::Exclude,
+            ),
+            map(parse_field, Item::Field),
+        )),
+    )(input)
+}
+
+fn parse_items(input: Span) -> IResult<Span, Vec<Item>> {
     preceded(
         preceded(ws, char('{')),
         cut(terminated(
-            separated_list0(parse_field_separator, parse_field),
+            separated_list0(parse_field_separator, parse_item),
             preceded(trailing_comma, preceded(ws, char('}'))),
         )),
     )(input)
@@ -62,15 +102,27 @@ pub fn parse_fieldset(input: Span) -> IResult<Span, Fieldset> {
                     preceded(ws, tag("for")),
                     preceded(ws1, parse_type_ref),
                 ),
-                parse_fields,
+                parse_items,
             )),
         ),
-        |(((name, generics), r#struct), fields)| Fieldset {
-            name,
-            generics,
-            r#struct,
-            fields,
-            position: input.into(),
+        |(((name, generics), r#struct), items)| {
+            let mut fieldset = Fieldset {
+                name,
+                generics,
+                r#struct,
+                include_all: false,
+                exclude: Vec::new(),
+                fields: Vec::new(),
+                position: input.into(),
+            };
+            for item in items {
+                match item {
+                    Item::All => fieldset.include_all = true,
+                    Item::Exclude(name) => fieldset.exclude.push(name),
+                    Item::Field(field) => fieldset.fields.push(field),
+                }
+            }
+            fieldset
         },
     )(input)
 }
@@ -98,6 +150,8 @@ fn test_parse_fieldset_0() {
                     name: "Person".to_string(),
                     generics: vec![],
                 },
+                include_all: false,
+                exclude: vec![],
                 fields: vec![],
             },
         )
@@ -127,9 +181,13 @@ fn test_parse_fieldset_1() {
                     name: "Person".to_string(),
                     generics: vec![],
                 },
+                include_all: false,
+                exclude: vec![],
                 fields: vec![Field {
                     name: "name".to_string(),
                     optional: false,
+                    type_: None,
+                    options: vec![],
                 }],
             },
         )
@@ -164,17 +222,78 @@ fn test_parse_fieldset_2() {
                     name: "Person".to_string(),
                     generics: vec![],
                 },
+                include_all: false,
+                exclude: vec![],
                 fields: vec![
                     Field {
                         name: "name".to_string(),
                         optional: false,
+                        type_: None,
+                        options: vec![],
                     },
                     Field {
                         name: "age".to_string(),
                         optional: true,
+                        type_: None,
+                        options: vec![],
                     },
                 ],
             },
         )
     }
 }
+
+#[test]
+fn test_parse_fieldset_include_all() {
+    use crate::idl::value::{RangeBound, Value};
+    let content = "fieldset PersonPublic for Person { .., -password, name: String (length=1..10) }";
+    assert_parse(
+        parse_fieldset(Span::new(content)),
+        Fieldset {
+            name: "PersonPublic".to_string(),
+            generics: vec![],
+            position: FilePosition { line: 1, column: 1 },
+            r#struct: TypeRef {
+                abs: false,
+                ns: vec![],
+                name: "Person".to_string(),
+                generics: vec![],
+            },
+            include_all: true,
+            exclude: vec!["password".to_string()],
+            fields: vec![Field {
+                name: "name".to_string(),
+                optional: false,
+                type_: Some(Type::Ref(TypeRef {
+                    abs: false,
+                    ns: vec![],
+                    name: "String".to_string(),
+                    generics: vec![],
+                })),
+                options: vec![FieldOption {
+                    name: "length".to_string(),
+                    value: Value::Range(
+                        Some(RangeBound::Integer(1)),
+                        Some(RangeBound::Integer(10)),
+                    ),
+                    position: FilePosition {
+                        line: 1,
+                        column: 65,
+                    },
+                }],
+            }],
+        },
+    );
+    let contents = [
+        // minimal whitespace
+        "fieldset PersonPublic for Person{..,-password,name:String(length=1..10)}",
+        // whitespace variants
+        "fieldset PersonPublic for Person { .. , - password , name : String (length=1..10), }",
+    ];
+    for content in contents.iter() {
+        let (_, fieldset) = parse_fieldset(Span::new(content)).unwrap();
+        assert!(fieldset.include_all);
+        assert_eq!(fieldset.exclude, ["password"]);
+        assert_eq!(fieldset.fields.len(), 1);
+    }
+}
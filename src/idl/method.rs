@@ -1,39 +1,149 @@
 use nom::{
+    branch::alt,
     bytes::complete::tag,
     character::complete::char,
-    combinator::map,
-    sequence::{preceded, tuple},
+    combinator::{cut, map, opt, verify},
+    multi::separated_list0,
+    sequence::{preceded, terminated, tuple},
     IResult,
 };
 
-use crate::idl::common::{parse_identifier, ws, Span};
-use crate::idl::r#type::{parse_opt_type, Type};
+use crate::idl::common::{parse_field_separator, parse_identifier, trailing_comma, ws, ws1, Span};
+use crate::idl::field_option::{parse_field_options, FieldOption};
+use crate::idl::r#struct::{parse_field, Field};
+use crate::idl::r#type::{parse_opt_type, parse_type, Type};
 
 #[cfg(test)]
 use crate::idl::common::assert_parse;
 
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct Method {
     pub name: String,
     pub input: Option<Type>,
+    /// Named parameters of the method (`name(param: Type, ...) -> Type`)
+    /// which are passed as a single object
+    pub params: Option<Vec<Field>>,
     pub output: Option<Type>,
+    /// Error type of the method (`name: Type -> Type throws Error`)
+    pub throws: Option<Type>,
+    /// The input is a stream of values (`stream Type`)
+    pub input_stream: bool,
+    /// The output is a stream of values (`stream Type`)
+    pub output_stream: bool,
+    /// The method is a notification which does not return a response
+    /// (`notify name: Type`)
+    pub notify: bool,
+    pub options: Vec<FieldOption>,
 }
 
-pub fn parse_method(input: Span) -> IResult<Span, Method> {
-    map(
-        tuple((
-            parse_identifier,
-            preceded(ws, preceded(char(':'), preceded(ws, parse_opt_type))),
-            preceded(ws, preceded(tag("->"), preceded(ws, parse_opt_type))),
+fn parse_method_type(input: Span) -> IResult<Span, (bool, Option<Type>)> {
+    preceded(
+        ws,
+        alt((
+            map(
+                preceded(
+                    terminated(tag("stream"), ws1),
+                    cut(verify(parse_opt_type, Option::is_some)),
+                ),
+                |type_| (true, type_),
+            ),
+            map(parse_opt_type, |type_| (false, type_)),
+        )),
+    )(input)
+}
+
+fn parse_params(input: Span) -> IResult<Span, Vec<Field>> {
+    preceded(
+        preceded(ws, char('(')),
+        cut(terminated(
+            separated_list0(parse_field_separator, preceded(ws, parse_field)),
+            preceded(trailing_comma, preceded(ws, char(')'))),
         )),
-        |(name, input, output)| Method {
+    )(input)
+}
+
+/// Stream flag, type and parameters of the input of a method
+type MethodInput = (bool, Option<Type>, Option<Vec<Field>>);
+
+/// Parse either a parameter list or `: Type` where the type can be a
+/// `stream` if `allow_stream` is set.
+fn parse_method_input(allow_stream: bool) -> impl Fn(Span) -> IResult<Span, MethodInput> {
+    move |input| {
+        alt((
+            map(parse_params, |params| (false, None, Some(params))),
+            map(
+                preceded(
+                    ws,
+                    preceded(
+                        char(':'),
+                        verify(parse_method_type, |(stream, _)| allow_stream || !stream),
+                    ),
+                ),
+                |(stream, type_)| (stream, type_, None),
+            ),
+        ))(input)
+    }
+}
+
+fn parse_throws(input: Span) -> IResult<Span, Option<Type>> {
+    opt(preceded(
+        terminated(preceded(ws1, tag("throws")), ws1),
+        cut(parse_type),
+    ))(input)
+}
+
+fn parse_notification(input: Span) -> IResult<Span, Method> {
+    map(
+        preceded(
+            terminated(tag("notify"), ws1),
+            tuple((
+                parse_identifier,
+                parse_method_input(false),
+                opt(parse_field_options),
+            )),
+        ),
+        |(name, (_, input, params), options)| Method {
             name,
             input,
-            output,
+            params,
+            output: None,
+            throws: None,
+            input_stream: false,
+            output_stream: false,
+            notify: true,
+            options: options.unwrap_or_default(),
         },
     )(input)
 }
 
+pub fn parse_method(input: Span) -> IResult<Span, Method> {
+    alt((
+        parse_notification,
+        map(
+            tuple((
+                parse_identifier,
+                parse_method_input(true),
+                preceded(ws, preceded(tag("->"), parse_method_type)),
+                parse_throws,
+                opt(parse_field_options),
+            )),
+            |(name, (input_stream, input, params), (output_stream, output), throws, options)| {
+                Method {
+                    name,
+                    input,
+                    params,
+                    output,
+                    throws,
+                    input_stream,
+                    output_stream,
+                    notify: false,
+                    options: options.unwrap_or_default(),
+                }
+            },
+        ),
+    ))(input)
+}
+
 #[test]
 fn test_parse_method_0() {
     let contents = [
@@ -52,7 +162,13 @@ fn test_parse_method_0() {
             Method {
                 name: "ping".to_string(),
                 input: None,
+                params: None,
                 output: None,
+                throws: None,
+                input_stream: false,
+                output_stream: false,
+                notify: false,
+                options: vec![],
             },
         )
     }
@@ -82,7 +198,13 @@ fn test_parse_method_1() {
                     name: "Notification".to_string(),
                     generics: vec![],
                 })),
+                params: None,
                 output: None,
+                throws: None,
+                input_stream: false,
+                output_stream: false,
+                notify: false,
+                options: vec![],
             },
         )
     }
@@ -107,12 +229,18 @@ fn test_parse_method_2() {
             Method {
                 name: "get_time".to_string(),
                 input: None,
+                params: None,
                 output: Some(Type::Ref(TypeRef {
                     abs: false,
                     ns: vec![],
                     name: "Time".to_string(),
                     generics: vec![],
                 })),
+                throws: None,
+                input_stream: false,
+                output_stream: false,
+                notify: false,
+                options: vec![],
             },
         )
     }
@@ -142,6 +270,7 @@ fn test_parse_method_3() {
             Method {
                 name: "no_response".to_string(),
                 input: None,
+                params: None,
                 output: Some(Type::Ref(TypeRef {
                     abs: false,
                     ns: vec![],
@@ -161,6 +290,11 @@ fn test_parse_method_3() {
                         }),
                     ],
                 })),
+                throws: None,
+                input_stream: false,
+                output_stream: false,
+                notify: false,
+                options: vec![],
             },
         )
     }
@@ -195,6 +329,7 @@ fn test_parse_method_4() {
                     name: "HelloRequest".to_string(),
                     generics: vec![],
                 })),
+                params: None,
                 output: Some(Type::Ref(TypeRef {
                     abs: false,
                     ns: vec![],
@@ -214,7 +349,203 @@ fn test_parse_method_4() {
                         }),
                     ],
                 })),
+                throws: None,
+                input_stream: false,
+                output_stream: false,
+                notify: false,
+                options: vec![],
+            },
+        )
+    }
+}
+
+#[test]
+fn test_parse_method_stream() {
+    use crate::idl::r#type::TypeRef;
+    let event = Type::Ref(TypeRef {
+        abs: false,
+        ns: vec![],
+        name: "Event".to_string(),
+        generics: vec![],
+    });
+    let contents = [
+        // normal whitespace
+        "watch: stream Event -> stream Event",
+        // whitespace variants
+        "watch:stream Event->stream Event",
+        "watch: stream  Event -> stream\tEvent",
+    ];
+    for content in contents.iter() {
+        assert_parse(
+            parse_method(Span::new(content)),
+            Method {
+                name: "watch".to_string(),
+                input: Some(event.clone()),
+                params: None,
+                output: Some(event.clone()),
+                throws: None,
+                input_stream: true,
+                output_stream: true,
+                notify: false,
+                options: vec![],
+            },
+        )
+    }
+    // a stream of `None` is not allowed
+    assert!(parse_method(Span::new("watch: None -> stream None")).is_err());
+}
+
+#[test]
+fn test_parse_method_notify() {
+    use crate::idl::r#type::TypeRef;
+    let contents = [
+        // normal whitespace
+        "notify log: LogEntry",
+        // whitespace variants
+        "notify log:LogEntry",
+        "notify  log :LogEntry",
+        "notify\tlog: LogEntry",
+    ];
+    for content in contents.iter() {
+        assert_parse(
+            parse_method(Span::new(content)),
+            Method {
+                name: "log".to_string(),
+                input: Some(Type::Ref(TypeRef {
+                    abs: false,
+                    ns: vec![],
+                    name: "LogEntry".to_string(),
+                    generics: vec![],
+                })),
+                params: None,
+                output: None,
+                throws: None,
+                input_stream: false,
+                output_stream: false,
+                notify: true,
+                options: vec![],
+            },
+        )
+    }
+}
+
+#[test]
+fn test_parse_method_params() {
+    use crate::common::FilePosition;
+    use crate::idl::r#type::TypeRef;
+    let type_ref = |name: &str| {
+        Type::Ref(TypeRef {
+            abs: false,
+            ns: vec![],
+            name: name.to_string(),
+            generics: vec![],
+        })
+    };
+    let contents = [
+        // normal whitespace
+        "user_get(id: UUID, include_deleted?: Boolean) -> User",
+        // whitespace variants
+        "user_get(id:UUID,include_deleted?:Boolean)->User",
+        "user_get (id: UUID, include_deleted?: Boolean,) -> User",
+        "user_get(\n    id: UUID,\n    include_deleted?: Boolean,\n) -> User",
+    ];
+    for content in contents.iter() {
+        let (_, method) = parse_method(Span::new(content)).unwrap();
+        assert_eq!(method.input, None);
+        assert_eq!(method.output, Some(type_ref("User")));
+        let params = method.params.unwrap();
+        assert_eq!(
+            params
+                .iter()
+                .map(|p| (p.name.as_str(), &p.type_, p.optional))
+                .collect::<Vec<_>>(),
+            vec![
+                ("id", &type_ref("UUID"), false),
+                ("include_deleted", &type_ref("Boolean"), true),
+            ]
+        );
+    }
+    assert_parse(
+        parse_method(Span::new("notify log(message: String)")),
+        Method {
+            name: "log".to_string(),
+            input: None,
+            params: Some(vec![Field {
+                name: "message".to_string(),
+                type_: type_ref("String"),
+                optional: false,
+                default: None,
+                options: vec![],
+                position: FilePosition {
+                    line: 1,
+                    column: 12,
+                },
+            }]),
+            output: None,
+            throws: None,
+            input_stream: false,
+            output_stream: false,
+            notify: true,
+            options: vec![],
+        },
+    );
+    assert_parse(
+        parse_method(Span::new("ping() -> None")),
+        Method {
+            name: "ping".to_string(),
+            input: None,
+            params: Some(vec![]),
+            output: None,
+            throws: None,
+            input_stream: false,
+            output_stream: false,
+            notify: false,
+            options: vec![],
+        },
+    );
+}
+
+#[test]
+fn test_parse_method_throws() {
+    use crate::idl::r#type::TypeRef;
+    let type_ref = |name: &str| {
+        Some(Type::Ref(TypeRef {
+            abs: false,
+            ns: vec![],
+            name: name.to_string(),
+            generics: vec![],
+        }))
+    };
+    let contents = [
+        // normal whitespace
+        "user_get: UserRequest -> UserRead throws GetError",
+        // whitespace variants
+        "user_get:UserRequest->UserRead throws GetError",
+        "user_get: UserRequest -> UserRead\n    throws\tGetError",
+    ];
+    for content in contents.iter() {
+        assert_parse(
+            parse_method(Span::new(content)),
+            Method {
+                name: "user_get".to_string(),
+                input: type_ref("UserRequest"),
+                params: None,
+                output: type_ref("UserRead"),
+                throws: type_ref("GetError"),
+                input_stream: false,
+                output_stream: false,
+                notify: false,
+                options: vec![],
             },
         )
     }
+    let (_, method) = parse_method(Span::new(
+        "delete(id: UUID) -> None throws DeleteError (idempotent)",
+    ))
+    .unwrap();
+    assert_eq!(method.output, None);
+    assert_eq!(method.throws, type_ref("DeleteError"));
+    assert_eq!(method.options.len(), 1);
+    // the error type is required
+    assert!(parse_method(Span::new("get: None -> None throws ")).is_err());
 }
@@ -1,4 +1,6 @@
+mod alias;
 mod common;
+mod constant;
 mod document;
 mod r#enum;
 mod errors;
@@ -12,7 +14,9 @@ mod r#struct;
 mod r#type;
 mod value;
 
+pub use alias::{Alias, Newtype};
 pub use common::Span;
+pub use constant::Constant;
 pub use document::{parse_document, Document};
 pub use errors::ParseError;
 pub use field_option::FieldOption;
@@ -23,5 +27,5 @@ pub use namespace::{Namespace, NamespacePart};
 pub use r#enum::{Enum, EnumVariant};
 pub use r#struct::{Field, Struct};
 pub use r#type::{Type, TypeRef};
-pub use service::Service;
-pub use value::Value;
+pub use service::{Service, ServiceSide};
+pub use value::{ConstantRef, RangeBound, Value};
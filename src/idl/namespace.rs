@@ -9,7 +9,9 @@ use nom::{
 };
 
 use crate::common::FilePosition;
+use crate::idl::alias::{parse_alias, parse_newtype, Alias, Newtype};
 use crate::idl::common::{parse_identifier, ws, ws1, Span};
+use crate::idl::constant::{parse_constant, Constant};
 use crate::idl::fieldset::{parse_fieldset, Fieldset};
 use crate::idl::r#enum::{parse_enum, Enum};
 use crate::idl::r#struct::{parse_struct, Struct};
@@ -20,6 +22,9 @@ use crate::idl::common::assert_parse;
 
 #[derive(Debug, PartialEq)]
 pub enum NamespacePart {
+    Alias(Alias),
+    Newtype(Newtype),
+    Constant(Constant),
     Enum(Enum),
     Struct(Struct),
     Fieldset(Fieldset),
@@ -30,6 +35,9 @@ pub enum NamespacePart {
 impl NamespacePart {
     pub fn name(&self) -> &str {
         match self {
+            Self::Alias(part) => &part.name,
+            Self::Newtype(part) => &part.name,
+            Self::Constant(part) => &part.name,
             Self::Enum(part) => &part.name,
             Self::Struct(part) => &part.name,
             Self::Fieldset(part) => &part.name,
@@ -39,6 +47,9 @@ impl NamespacePart {
     }
     pub fn position(&self) -> FilePosition {
         match self {
+            Self::Alias(part) => part.position,
+            Self::Newtype(part) => part.position,
+            Self::Constant(part) => part.position,
             Self::Enum(part) => part.position,
             Self::Struct(part) => part.position,
             Self::Fieldset(part) => part.position,
@@ -57,6 +68,9 @@ pub struct Namespace {
 
 pub fn parse_namespace_part(input: Span) -> IResult<Span, NamespacePart> {
     alt((
+        map(parse_alias, NamespacePart::Alias),
+        map(parse_newtype, NamespacePart::Newtype),
+        map(parse_constant, NamespacePart::Constant),
         map(parse_enum, NamespacePart::Enum),
         map(parse_fieldset, NamespacePart::Fieldset),
         map(parse_struct, NamespacePart::Struct),
@@ -101,7 +115,8 @@ fn test_parse_namespace() {
     use crate::idl::method::Method;
     use crate::idl::r#struct::Field;
     use crate::idl::r#type::{Type, TypeRef};
-    use crate::idl::value::Value;
+    use crate::idl::service::ServiceSide;
+    use crate::idl::value::{RangeBound, Value};
     let content = "
         namespace test {
             struct Person {
@@ -124,11 +139,13 @@ fn test_parse_namespace() {
             parts: vec![
                 NamespacePart::Struct(Struct {
                     name: "Person".to_string(),
+                    options: vec![],
                     position: FilePosition {
                         line: 3,
                         column: 13,
                     },
                     generics: vec![],
+                    extends: None,
                     fields: vec![
                         Field {
                             name: "name".to_string(),
@@ -143,13 +160,17 @@ fn test_parse_namespace() {
                                 generics: vec![],
                             }),
                             optional: false,
+                            default: None,
                             options: vec![FieldOption {
                                 position: FilePosition {
                                     line: 4,
                                     column: 31,
                                 },
                                 name: "length".to_string(),
-                                value: Value::Range(Some(1), Some(50)),
+                                value: Value::Range(
+                                    Some(RangeBound::Integer(1)),
+                                    Some(RangeBound::Integer(50)),
+                                ),
                             }],
                         },
                         Field {
@@ -165,17 +186,20 @@ fn test_parse_namespace() {
                                 generics: vec![],
                             }),
                             optional: false,
+                            default: None,
                             options: vec![],
                         },
                     ],
                 }),
                 NamespacePart::Struct(Struct {
                     name: "Group".to_string(),
+                    options: vec![],
                     position: FilePosition {
                         line: 7,
                         column: 13,
                     },
                     generics: vec![],
+                    extends: None,
                     fields: vec![Field {
                         name: "name".to_string(),
                         position: FilePosition {
@@ -189,11 +213,14 @@ fn test_parse_namespace() {
                             generics: vec![],
                         }),
                         optional: false,
+                        default: None,
                         options: vec![],
                     }],
                 }),
                 NamespacePart::Service(Service {
                     name: "Pinger".to_string(),
+                    side: ServiceSide::Server,
+                    options: vec![],
                     position: FilePosition {
                         line: 10,
                         column: 13,
@@ -202,17 +229,29 @@ fn test_parse_namespace() {
                         Method {
                             name: "ping".to_string(),
                             input: None,
+                            params: None,
                             output: None,
+                            throws: None,
+                            input_stream: false,
+                            output_stream: false,
+                            notify: false,
+                            options: vec![],
                         },
                         Method {
                             name: "get_version".to_string(),
                             input: None,
+                            params: None,
                             output: Some(Type::Ref(TypeRef {
                                 abs: false,
                                 ns: vec![],
                                 name: "String".to_string(),
                                 generics: vec![],
                             })),
+                            throws: None,
+                            input_stream: false,
+                            output_stream: false,
+                            notify: false,
+                            options: vec![],
                         },
                     ],
                 }),
@@ -1,23 +1,35 @@
 use nom::{
+    branch::alt,
     bytes::complete::tag,
     character::complete::char,
-    combinator::{cut, map},
+    combinator::{cut, map, opt, value},
     error::context,
     multi::separated_list0,
-    sequence::{pair, preceded, terminated},
+    sequence::{preceded, terminated, tuple},
     IResult,
 };
 
 use crate::common::FilePosition;
 use crate::idl::common::{parse_field_separator, parse_identifier, trailing_comma, ws, ws1, Span};
+use crate::idl::field_option::{parse_field_options, FieldOption};
 use crate::idl::method::{parse_method, Method};
 
 #[cfg(test)]
 use crate::idl::common::assert_parse;
 
-#[derive(Debug, PartialEq)]
+/// Side of the connection which implements a service
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ServiceSide {
+    #[default]
+    Server,
+    Client,
+}
+
+#[derive(Clone, Debug, PartialEq)]
 pub struct Service {
     pub name: String,
+    pub side: ServiceSide,
+    pub options: Vec<FieldOption>,
     pub methods: Vec<Method>,
     pub position: FilePosition,
 }
@@ -35,16 +47,32 @@ fn parse_methods(input: Span) -> IResult<Span, Vec<Method>> {
     )(input)
 }
 
+fn parse_service_side(input: Span) -> IResult<Span, ServiceSide> {
+    alt((
+        value(ServiceSide::Server, tag("server")),
+        value(ServiceSide::Client, tag("client")),
+    ))(input)
+}
+
 pub fn parse_service(input: Span) -> IResult<Span, Service> {
     context(
         "service",
         map(
-            preceded(
-                terminated(tag("service"), ws1),
-                cut(pair(parse_identifier, parse_methods)),
-            ),
-            |(name, methods)| Service {
+            tuple((
+                opt(terminated(parse_service_side, ws1)),
+                preceded(
+                    terminated(tag("service"), ws1),
+                    cut(tuple((
+                        parse_identifier,
+                        opt(parse_field_options),
+                        parse_methods,
+                    ))),
+                ),
+            )),
+            |(side, (name, options, methods))| Service {
                 name,
+                side: side.unwrap_or_default(),
+                options: options.unwrap_or_default(),
                 methods,
                 position: input.into(),
             },
@@ -66,6 +94,8 @@ fn test_parse_service_no_endpoints() {
             parse_service(Span::new(content)),
             Service {
                 name: "Pinger".to_string(),
+                side: ServiceSide::Server,
+                options: vec![],
                 methods: vec![],
                 position: FilePosition { line: 1, column: 1 },
             },
@@ -100,25 +130,93 @@ fn test_parse_service() {
             parse_service(Span::new(content)),
             Service {
                 name: "Pinger".to_string(),
+                side: ServiceSide::Server,
+                options: vec![],
                 position: FilePosition { line: 1, column: 1 },
                 methods: vec![
                     Method {
                         name: "ping".to_string(),
                         input: None,
+                        params: None,
                         output: None,
+                        throws: None,
+                        input_stream: false,
+                        output_stream: false,
+                        notify: false,
+                        options: vec![],
                     },
                     Method {
                         name: "get_version".to_string(),
                         input: None,
+                        params: None,
                         output: Some(Type::Ref(TypeRef {
                             abs: false,
                             ns: vec![],
                             name: "String".to_string(),
                             generics: vec![],
                         })),
+                        throws: None,
+                        input_stream: false,
+                        output_stream: false,
+                        notify: false,
+                        options: vec![],
                     },
                 ],
             },
         )
     }
 }
+
+#[test]
+fn test_parse_service_side() {
+    let contents = [
+        ("service Pinger {}", ServiceSide::Server),
+        ("server service Pinger {}", ServiceSide::Server),
+        ("client service Pinger {}", ServiceSide::Client),
+        ("client  service Pinger{}", ServiceSide::Client),
+    ];
+    for (content, side) in contents.iter() {
+        assert_parse(
+            parse_service(Span::new(content)),
+            Service {
+                name: "Pinger".to_string(),
+                side: *side,
+                options: vec![],
+                methods: vec![],
+                position: FilePosition { line: 1, column: 1 },
+            },
+        )
+    }
+}
+
+#[test]
+fn test_parse_service_options() {
+    use crate::idl::Value;
+    let parsed = parse_service(Span::new(
+        "service Admin (auth=\"admin\") { reset: None -> None (idempotent) }",
+    ))
+    .unwrap()
+    .1;
+    assert_eq!(
+        parsed.options,
+        vec![FieldOption {
+            position: FilePosition {
+                line: 1,
+                column: 16
+            },
+            name: "auth".to_owned(),
+            value: Value::String("admin".to_owned()),
+        }]
+    );
+    assert_eq!(
+        parsed.methods[0].options,
+        vec![FieldOption {
+            position: FilePosition {
+                line: 1,
+                column: 53
+            },
+            name: "idempotent".to_owned(),
+            value: Value::Boolean(true),
+        }]
+    );
+}
@@ -4,7 +4,7 @@ use nom::{
     combinator::{cut, map, opt},
     error::context,
     multi::separated_list0,
-    sequence::{pair, preceded, separated_pair, terminated},
+    sequence::{pair, preceded, separated_pair, terminated, tuple},
     IResult,
 };
 
@@ -14,46 +14,65 @@ use crate::idl::common::{
     ws1, Span,
 };
 use crate::idl::field_option::{parse_field_options, FieldOption};
-use crate::idl::r#type::{parse_type, Type};
+use crate::idl::r#type::{parse_type, parse_type_ref, Type, TypeRef};
+use crate::idl::value::{parse_value, Value};
 
 #[cfg(test)]
 use crate::idl::common::assert_parse;
 #[cfg(test)]
 use nom::Slice;
 
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct Struct {
     pub name: String,
     pub generics: Vec<String>,
+    pub extends: Option<TypeRef>,
+    pub options: Vec<FieldOption>,
     pub fields: Vec<Field>,
     pub position: FilePosition,
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct Field {
     pub name: String,
     pub type_: Type,
     pub optional: bool,
+    /// Value which is used if the field is missing
+    pub default: Option<Value>,
     pub options: Vec<FieldOption>,
     pub position: FilePosition,
 }
 
 pub fn parse_struct(input: Span) -> IResult<Span, Struct> {
     map(
-        pair(
+        tuple((
             preceded(tag("struct"), preceded(ws1, parse_identifier_with_generics)),
+            parse_struct_extends,
+            opt(parse_field_options),
             parse_fields,
-        ),
-        |((name, generics), fields)| Struct {
+        )),
+        |((name, generics), extends, options, fields)| Struct {
             name,
             generics,
+            extends,
+            options: options.unwrap_or_default(),
             fields,
             position: input.into(),
         },
     )(input)
 }
 
-fn parse_fields(input: Span) -> IResult<Span, Vec<Field>> {
+fn parse_struct_extends(input: Span) -> IResult<Span, Option<TypeRef>> {
+    context(
+        "struct_extends",
+        opt(preceded(
+            terminated(preceded(ws1, tag("extends")), ws1),
+            parse_type_ref,
+        )),
+    )(input)
+}
+
+pub(crate) fn parse_fields(input: Span) -> IResult<Span, Vec<Field>> {
     context(
         "fields",
         preceded(
@@ -66,27 +85,35 @@ fn parse_fields(input: Span) -> IResult<Span, Vec<Field>> {
     )(input)
 }
 
-fn parse_field(input: Span) -> IResult<Span, Field> {
+pub(crate) fn parse_field(input: Span) -> IResult<Span, Field> {
     map(
         separated_pair(
             pair(parse_identifier, opt(preceded(ws, char('?')))),
             preceded(ws, char(':')),
-            pair(parse_type, opt(parse_field_options)),
+            tuple((
+                parse_type,
+                opt(parse_field_options),
+                opt(parse_field_default),
+            )),
         ),
-        |((name, optional), (type_, options))| Field {
+        |((name, optional), (type_, options, default))| Field {
             name,
             position: input.into(),
             optional: optional.is_some(),
+            default,
             type_,
-            options: if let Some(options) = options {
-                options
-            } else {
-                vec![]
-            },
+            options: options.unwrap_or_default(),
         },
     )(input)
 }
 
+fn parse_field_default(input: Span) -> IResult<Span, Value> {
+    context(
+        "default",
+        preceded(preceded(ws, char('=')), cut(preceded(ws, parse_value))),
+    )(input)
+}
+
 #[test]
 fn test_parse_field() {
     use crate::idl::r#type::TypeRef;
@@ -104,6 +131,7 @@ fn test_parse_field() {
                     generics: vec![],
                 }),
                 optional: false,
+                default: None,
                 options: vec![],
             },
         );
@@ -132,6 +160,7 @@ fn test_parse_field_optional() {
                     generics: vec![],
                 }),
                 optional: true,
+                default: None,
                 options: vec![],
             },
         );
@@ -141,7 +170,7 @@ fn test_parse_field_optional() {
 #[test]
 fn test_parse_field_with_options() {
     use crate::idl::r#type::TypeRef;
-    use crate::idl::value::Value;
+    use crate::idl::value::{RangeBound, Value};
     let contents = [
         ("name:String(length=2..50)", 13),
         ("name :String(length=2..50)", 14),
@@ -169,13 +198,17 @@ fn test_parse_field_with_options() {
                     generics: vec![],
                 }),
                 optional: false,
+                default: None,
                 options: vec![FieldOption {
                     position: FilePosition {
                         line: 1,
                         column: *length_column,
                     },
                     name: "length".to_string(),
-                    value: Value::Range(Some(2), Some(50)),
+                    value: Value::Range(
+                        Some(RangeBound::Integer(2)),
+                        Some(RangeBound::Integer(50)),
+                    ),
                 }],
             },
         );
@@ -185,7 +218,7 @@ fn test_parse_field_with_options() {
 #[test]
 fn test_parse_array_field_with_options() {
     use crate::idl::r#type::TypeRef;
-    use crate::idl::value::Value;
+    use crate::idl::value::{RangeBound, Value};
     let contents = [
         ("items:[String](length=0..32)", 16),
         ("items :[String](length=0..32)", 17),
@@ -209,19 +242,66 @@ fn test_parse_array_field_with_options() {
                     generics: vec![],
                 }))),
                 optional: false,
+                default: None,
                 options: vec![FieldOption {
                     position: FilePosition {
                         line: 1,
                         column: *length_column,
                     },
                     name: "length".to_string(),
-                    value: Value::Range(Some(0), Some(32)),
+                    value: Value::Range(
+                        Some(RangeBound::Integer(0)),
+                        Some(RangeBound::Integer(32)),
+                    ),
                 }],
             },
         );
     }
 }
 
+#[test]
+fn test_parse_field_with_default() {
+    use crate::idl::r#type::TypeRef;
+    use crate::idl::value::RangeBound;
+    let contents = [
+        ("limit:Integer=50", Value::Integer(50)),
+        ("limit: Integer = -1", Value::Integer(-1)),
+        ("name: String = \"foo\"", Value::String("foo".to_owned())),
+        ("enabled: Boolean = true", Value::Boolean(true)),
+        (
+            "status: Status = Active",
+            Value::Identifier("Active".to_owned()),
+        ),
+    ];
+    for (content, default) in contents {
+        let (_, field) = parse_field(Span::new(content)).unwrap();
+        assert_eq!(field.default, Some(default));
+    }
+    assert_parse(
+        parse_field(Span::new("limit: Integer (range=1..100) = 50")),
+        Field {
+            name: "limit".to_string(),
+            position: FilePosition { line: 1, column: 1 },
+            type_: Type::Ref(TypeRef {
+                abs: false,
+                ns: vec![],
+                name: "Integer".to_string(),
+                generics: vec![],
+            }),
+            optional: false,
+            default: Some(Value::Integer(50)),
+            options: vec![FieldOption {
+                position: FilePosition {
+                    line: 1,
+                    column: 17,
+                },
+                name: "range".to_string(),
+                value: Value::Range(Some(RangeBound::Integer(1)), Some(RangeBound::Integer(100))),
+            }],
+        },
+    );
+}
+
 #[test]
 fn test_parse_fields_0() {
     let contents = ["{}", "{ }", "{,}", "{ ,}", "{, }"];
@@ -246,6 +326,7 @@ fn test_parse_fields_1() {
                 generics: vec![],
             }),
             optional: false,
+            default: None,
             options: vec![],
         }],
     );
@@ -277,6 +358,7 @@ fn test_parse_fields_2() {
                     generics: vec![],
                 }),
                 optional: false,
+                default: None,
                 options: vec![],
             },
             Field {
@@ -292,6 +374,7 @@ fn test_parse_fields_2() {
                     generics: vec![],
                 }),
                 optional: false,
+                default: None,
                 options: vec![],
             },
         ],
@@ -332,8 +415,10 @@ fn test_parse_struct() {
             parse_struct(Span::new(content)),
             Struct {
                 name: "Pinger".to_string(),
+                options: vec![],
                 position: FilePosition { line: 1, column: 1 },
                 generics: vec![],
+                extends: None,
                 fields: vec![],
             },
         );
@@ -343,15 +428,17 @@ fn test_parse_struct() {
 #[test]
 fn test_parse_struct_field_options() {
     use crate::idl::r#type::TypeRef;
-    use crate::idl::value::Value;
+    use crate::idl::value::{RangeBound, Value};
     let contents = ["struct Person { name: [String] (length=1..50) }"];
     for content in contents.iter() {
         assert_parse(
             parse_struct(Span::new(content)),
             Struct {
                 name: "Person".to_string(),
+                options: vec![],
                 position: FilePosition { line: 1, column: 1 },
                 generics: vec![],
+                extends: None,
                 fields: vec![Field {
                     name: "name".to_string(),
                     position: FilePosition {
@@ -365,13 +452,17 @@ fn test_parse_struct_field_options() {
                         generics: vec![],
                     }))),
                     optional: false,
+                    default: None,
                     options: vec![FieldOption {
                         position: FilePosition {
                             line: 1,
                             column: 33,
                         },
                         name: "length".to_string(),
-                        value: Value::Range(Some(1), Some(50)),
+                        value: Value::Range(
+                            Some(RangeBound::Integer(1)),
+                            Some(RangeBound::Integer(50)),
+                        ),
                     }],
                 }],
             },
@@ -402,8 +493,10 @@ fn test_parse_struct_with_fields() {
             parse_struct(Span::new(content)),
             Struct {
                 name: "Person".to_string(),
+                options: vec![],
                 position: FilePosition { line: 1, column: 1 },
                 generics: vec![],
+                extends: None,
                 fields: vec![
                     Field {
                         name: "name".to_string(),
@@ -418,6 +511,7 @@ fn test_parse_struct_with_fields() {
                             generics: vec![],
                         }),
                         optional: false,
+                        default: None,
                         options: vec![],
                     },
                     Field {
@@ -433,6 +527,7 @@ fn test_parse_struct_with_fields() {
                             generics: vec![],
                         }),
                         optional: false,
+                        default: None,
                         options: vec![],
                     },
                 ],
@@ -470,8 +565,10 @@ fn test_parse_struct_with_generics() {
         parse_struct(Span::new(content)),
         Struct {
             name: "Wrapper".to_string(),
+            options: vec![],
             position: FilePosition { line: 1, column: 1 },
             generics: vec!["T".to_string()],
+            extends: None,
             fields: vec![Field {
                 name: "value".to_string(),
                 position: FilePosition {
@@ -485,6 +582,7 @@ fn test_parse_struct_with_generics() {
                     generics: vec![],
                 }),
                 optional: false,
+                default: None,
                 options: vec![],
             }],
         },
@@ -512,3 +610,60 @@ fn test_parse_struct_with_generics_ws_variants() {
         assert_eq!(s.fields.len(), 1);
     }
 }
+
+#[test]
+fn test_parse_struct_extends() {
+    let contents = [
+        // minimal whitespace
+        "struct User extends Entity<UUID>{}",
+        // normal whitespace
+        "struct User extends Entity<UUID> {}",
+        // whitespace variants
+        "struct User extends Entity<UUID>{ }",
+    ];
+    for content in contents.iter() {
+        assert_parse(
+            parse_struct(Span::new(content)),
+            Struct {
+                name: "User".to_string(),
+                options: vec![],
+                position: FilePosition { line: 1, column: 1 },
+                generics: vec![],
+                extends: Some(TypeRef {
+                    abs: false,
+                    ns: vec![],
+                    name: "Entity".to_string(),
+                    generics: vec![Type::Ref(TypeRef {
+                        abs: false,
+                        ns: vec![],
+                        name: "UUID".to_string(),
+                        generics: vec![],
+                    })],
+                }),
+                fields: vec![],
+            },
+        )
+    }
+}
+
+#[test]
+fn test_parse_struct_with_options() {
+    assert_parse(
+        parse_struct(Span::new("struct User (deprecated=\"Use Account\") {}")),
+        Struct {
+            name: "User".to_string(),
+            options: vec![FieldOption {
+                position: FilePosition {
+                    line: 1,
+                    column: 14,
+                },
+                name: "deprecated".to_string(),
+                value: Value::String("Use Account".to_string()),
+            }],
+            position: FilePosition { line: 1, column: 1 },
+            generics: vec![],
+            extends: None,
+            fields: vec![],
+        },
+    )
+}
@@ -1,25 +1,31 @@
 #[cfg(test)]
 use crate::idl::common::assert_parse;
 use crate::idl::common::{parse_field_separator, parse_identifier, trailing_comma, ws, Span};
+use crate::idl::r#enum::{parse_enum_variants, EnumVariant};
+use crate::idl::r#struct::{parse_fields, Field};
 use nom::{
     branch::alt,
     bytes::complete::tag,
-    character::complete::char,
-    combinator::{cut, map, opt},
+    character::complete::{char, one_of},
+    combinator::{cut, map, opt, peek, value, verify},
     error::context,
     multi::{many0, separated_list0},
-    sequence::{preceded, separated_pair, terminated, tuple},
+    sequence::{pair, preceded, separated_pair, terminated, tuple},
     IResult,
 };
 
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq)]
 pub enum Type {
     Ref(TypeRef),
     Array(Box<Type>),
     Map(Box<Type>, Box<Type>),
+    /// Inline struct (`{ street: String, zip: String }`)
+    Struct(Vec<Field>),
+    /// Inline enum (`enum { Active, Disabled }`)
+    Enum(Vec<EnumVariant>),
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct TypeRef {
     pub abs: bool,
     pub ns: Vec<String>,
@@ -110,6 +116,52 @@ fn parse_type_map(input: Span) -> IResult<Span, Type> {
     )(input)
 }
 
+/// Braces start an inline struct instead of a map if they are empty or
+/// the first identifier is lowercase and followed by `:` or `?`.
+fn parse_type_struct_start(input: Span) -> IResult<Span, ()> {
+    value(
+        (),
+        preceded(
+            char('{'),
+            preceded(
+                ws,
+                alt((
+                    value((), char('}')),
+                    value(
+                        (),
+                        pair(
+                            verify(parse_identifier, |name: &str| {
+                                name.starts_with(|c: char| c.is_ascii_lowercase())
+                            }),
+                            preceded(ws, one_of(":?")),
+                        ),
+                    ),
+                )),
+            ),
+        ),
+    )(input)
+}
+
+fn parse_type_struct(input: Span) -> IResult<Span, Type> {
+    context(
+        "inline_struct",
+        preceded(
+            peek(parse_type_struct_start),
+            map(parse_fields, Type::Struct),
+        ),
+    )(input)
+}
+
+fn parse_type_enum(input: Span) -> IResult<Span, Type> {
+    context(
+        "inline_enum",
+        preceded(
+            terminated(tag("enum"), peek(preceded(ws, char('{')))),
+            map(parse_enum_variants, Type::Enum),
+        ),
+    )(input)
+}
+
 pub fn parse_opt_type(input: Span) -> IResult<Span, Option<Type>> {
     preceded(ws, alt((parse_none, map(parse_type, Some))))(input)
 }
@@ -118,8 +170,10 @@ pub fn parse_type(input: Span) -> IResult<Span, Type> {
     preceded(
         ws,
         alt((
+            parse_type_enum,
             map(parse_type_ref, Type::Ref),
             parse_type_array,
+            parse_type_struct,
             parse_type_map,
         )),
     )(input)
@@ -316,3 +370,60 @@ fn test_parse_type_map() {
         );
     }
 }
+
+#[test]
+fn test_parse_type_inline_struct() {
+    let contents = [
+        "{street:String,zip?:String}",
+        "{ street: String, zip?: String }",
+        "{\n    street: String,\n    zip?: String,\n}",
+    ];
+    for content in contents.iter() {
+        let (_, type_) = parse_type(Span::new(content)).unwrap();
+        let Type::Struct(fields) = type_ else {
+            panic!("expected inline struct: {:?}", type_);
+        };
+        assert_eq!(
+            fields
+                .iter()
+                .map(|f| (f.name.as_str(), f.optional))
+                .collect::<Vec<_>>(),
+            vec![("street", false), ("zip", true)]
+        );
+    }
+    assert_parse(parse_type(Span::new("{}")), Type::Struct(vec![]));
+    // a lowercase first identifier starts an inline struct, anything else a map
+    assert!(matches!(
+        parse_type(Span::new("{key: String}")).unwrap().1,
+        Type::Struct(_)
+    ));
+    assert!(matches!(
+        parse_type(Span::new("{Key: String}")).unwrap().1,
+        Type::Map(_, _)
+    ));
+}
+
+#[test]
+fn test_parse_type_inline_enum() {
+    let contents = ["enum{Active,Disabled}", "enum { Active, Disabled, }"];
+    for content in contents.iter() {
+        let (_, type_) = parse_type(Span::new(content)).unwrap();
+        let Type::Enum(variants) = type_ else {
+            panic!("expected inline enum: {:?}", type_);
+        };
+        assert_eq!(
+            variants.iter().map(|v| v.name.as_str()).collect::<Vec<_>>(),
+            vec!["Active", "Disabled"]
+        );
+    }
+    // types whose name starts with `enum` are references
+    assert_parse(
+        parse_type(Span::new("enumeration")),
+        Type::Ref(TypeRef {
+            abs: false,
+            ns: vec![],
+            name: "enumeration".to_string(),
+            generics: vec![],
+        }),
+    );
+}
@@ -1,4 +1,5 @@
 use std::str::FromStr;
+use std::time::Duration;
 
 #[cfg(test)]
 use crate::idl::common::assert_parse;
@@ -7,20 +8,115 @@ use nom::{
     branch::alt,
     bytes::complete::{escaped_transform, is_a, is_not, tag},
     character::complete::{char, digit1, one_of},
-    combinator::{cut, map, map_res, opt},
+    combinator::{cut, map, map_res, opt, verify},
     error::context,
-    sequence::{pair, preceded, separated_pair, terminated},
+    multi::many0,
+    sequence::{pair, preceded, separated_pair, terminated, tuple},
     IResult,
 };
 
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq)]
 pub enum Value {
     Boolean(bool),
     Integer(i64),
     Float(f64),
-    Range(Option<i64>, Option<i64>),
+    Range(Option<RangeBound>, Option<RangeBound>),
     String(String),
     Identifier(String),
+    /// Reference to a constant of another namespace, e.g. `limits::MAX`
+    /// or `::MAX`. Plain names are parsed as identifiers.
+    Constant(ConstantRef),
+    /// Duration given as integer with a unit (`ms`, `s`, `m` or `h`),
+    /// e.g. `5s`
+    Duration(Duration),
+    /// Number of events per duration, e.g. `10/s` or `100/5m`
+    Rate(u64, Duration),
+}
+
+impl std::fmt::Display for Value {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Boolean(b) => write!(f, "{}", b),
+            Self::Integer(i) => write!(f, "{}", i),
+            Self::Float(v) => write!(f, "{:?}", v),
+            Self::Range(min, max) => {
+                if let Some(min) = min {
+                    write!(f, "{}", min)?;
+                }
+                write!(f, "..")?;
+                if let Some(max) = max {
+                    write!(f, "{}", max)?;
+                }
+                Ok(())
+            }
+            Self::String(s) => write!(
+                f,
+                "\"{}\"",
+                s.replace('\\', "\\\\")
+                    .replace('"', "\\\"")
+                    .replace('\n', "\\n")
+            ),
+            Self::Identifier(name) => write!(f, "{}", name),
+            Self::Constant(constant) => write!(f, "{}", constant),
+            Self::Duration(duration) => {
+                let (count, unit) = duration_parts(*duration);
+                write!(f, "{}{}", count, unit)
+            }
+            Self::Rate(count, per) => match duration_parts(*per) {
+                (1, unit) => write!(f, "{}/{}", count, unit),
+                (n, unit) => write!(f, "{}/{}{}", count, n, unit),
+            },
+        }
+    }
+}
+
+/// Splits a duration into a count and the largest unit which represents
+/// it exactly
+fn duration_parts(duration: Duration) -> (u128, &'static str) {
+    match duration.as_millis() {
+        ms if ms > 0 && ms % 3_600_000 == 0 => (ms / 3_600_000, "h"),
+        ms if ms > 0 && ms % 60_000 == 0 => (ms / 60_000, "m"),
+        ms if ms % 1000 == 0 => (ms / 1000, "s"),
+        ms => (ms, "ms"),
+    }
+}
+
+/// Bound of a range which is either an integer literal or a reference
+/// to an integer constant
+#[derive(Clone, Debug, PartialEq)]
+pub enum RangeBound {
+    Integer(i64),
+    Constant(ConstantRef),
+}
+
+impl std::fmt::Display for RangeBound {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Integer(i) => write!(f, "{}", i),
+            Self::Constant(constant) => write!(f, "{}", constant),
+        }
+    }
+}
+
+/// Reference to a constant which is written like a type reference
+/// without generics (`MAX`, `limits::MAX` or `::MAX`)
+#[derive(Clone, Debug, PartialEq)]
+pub struct ConstantRef {
+    pub abs: bool,
+    pub ns: Vec<String>,
+    pub name: String,
+}
+
+impl std::fmt::Display for ConstantRef {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.abs {
+            write!(f, "::")?;
+        }
+        for part in self.ns.iter() {
+            write!(f, "{}::", part)?;
+        }
+        write!(f, "{}", self.name)
+    }
 }
 
 pub fn parse_boolean(input: Span) -> IResult<Span, bool> {
@@ -107,20 +203,85 @@ pub fn parse_float(input: Span) -> IResult<Span, f64> {
     )(input)
 }
 
-pub fn parse_range(input: Span) -> IResult<Span, (Option<i64>, Option<i64>)> {
+pub fn parse_constant_ref(input: Span) -> IResult<Span, ConstantRef> {
+    map(
+        tuple((
+            map(opt(tag("::")), |r| r.is_some()),
+            parse_identifier,
+            many0(preceded(tag("::"), parse_identifier)),
+        )),
+        |(abs, first, mut path)| {
+            path.insert(0, first);
+            let name = path.pop().unwrap();
+            ConstantRef {
+                abs,
+                ns: path,
+                name,
+            }
+        },
+    )(input)
+}
+
+fn parse_range_bound(input: Span) -> IResult<Span, RangeBound> {
+    alt((
+        map(parse_integer, RangeBound::Integer),
+        map(parse_constant_ref, RangeBound::Constant),
+    ))(input)
+}
+
+pub fn parse_range(input: Span) -> IResult<Span, (Option<RangeBound>, Option<RangeBound>)> {
     context(
         "range",
-        separated_pair(opt(parse_integer), tag(".."), opt(parse_integer)),
+        separated_pair(opt(parse_range_bound), tag(".."), opt(parse_range_bound)),
+    )(input)
+}
+
+fn parse_duration_unit(input: Span) -> IResult<Span, Duration> {
+    alt((
+        map(tag("ms"), |_| Duration::from_millis(1)),
+        map(tag("s"), |_| Duration::from_secs(1)),
+        map(tag("m"), |_| Duration::from_secs(60)),
+        map(tag("h"), |_| Duration::from_secs(3600)),
+    ))(input)
+}
+
+fn parse_count(input: Span) -> IResult<Span, u32> {
+    map_res(digit1, |digits: Span| digits.parse::<u32>())(input)
+}
+
+pub fn parse_duration(input: Span) -> IResult<Span, Duration> {
+    context(
+        "duration",
+        map(pair(parse_count, parse_duration_unit), |(count, unit)| {
+            unit * count
+        }),
+    )(input)
+}
+
+pub fn parse_rate(input: Span) -> IResult<Span, (u64, Duration)> {
+    context(
+        "rate",
+        separated_pair(
+            map(parse_count, u64::from),
+            char('/'),
+            alt((parse_duration, parse_duration_unit)),
+        ),
     )(input)
 }
 
 pub fn parse_value(input: Span) -> IResult<Span, Value> {
     alt((
         map(parse_boolean, Value::Boolean),
+        map(parse_rate, |(count, per)| Value::Rate(count, per)),
+        map(parse_duration, Value::Duration),
         map(parse_range, |(min, max)| Value::Range(min, max)),
         map(parse_float, Value::Float),
         map(parse_integer, Value::Integer),
         map(parse_string, Value::String),
+        map(
+            verify(parse_constant_ref, |c| c.abs || !c.ns.is_empty()),
+            Value::Constant,
+        ),
         map(parse_identifier, Value::Identifier),
     ))(input)
 }
@@ -178,10 +339,102 @@ fn test_parse_value_float() {
 fn test_parse_value_range() {
     assert_parse(
         parse_value(Span::new("0..1337")),
-        Value::Range(Some(0), Some(1337)),
+        Value::Range(
+            Some(RangeBound::Integer(0)),
+            Some(RangeBound::Integer(1337)),
+        ),
     );
     assert_parse(
         parse_value(Span::new("0..0xFF")),
-        Value::Range(Some(0), Some(0xFF)),
+        Value::Range(
+            Some(RangeBound::Integer(0)),
+            Some(RangeBound::Integer(0xFF)),
+        ),
+    );
+}
+
+#[test]
+fn test_display_value() {
+    let values = [
+        "true",
+        "-42",
+        "13.37",
+        "1.0",
+        "0..1337",
+        "..50",
+        "\"hello \\\"world\\\"\\n\"",
+        "Active",
+        "limits::MAX",
+        "::MIN..limits::MAX",
+    ];
+    for value in values {
+        let (_, parsed) = parse_value(Span::new(value)).unwrap();
+        assert_eq!(parsed.to_string(), value);
+    }
+}
+
+#[test]
+fn test_parse_value_duration() {
+    assert_parse(
+        parse_value(Span::new("250ms")),
+        Value::Duration(Duration::from_millis(250)),
+    );
+    assert_parse(
+        parse_value(Span::new("5s")),
+        Value::Duration(Duration::from_secs(5)),
+    );
+    assert_parse(
+        parse_value(Span::new("2m")),
+        Value::Duration(Duration::from_secs(120)),
+    );
+    assert_parse(
+        parse_value(Span::new("1h")),
+        Value::Duration(Duration::from_secs(3600)),
+    );
+    assert_eq!(Value::Duration(Duration::from_secs(120)).to_string(), "2m");
+}
+
+#[test]
+fn test_parse_value_rate() {
+    assert_parse(
+        parse_value(Span::new("10/s")),
+        Value::Rate(10, Duration::from_secs(1)),
+    );
+    assert_parse(
+        parse_value(Span::new("100/5m")),
+        Value::Rate(100, Duration::from_secs(300)),
+    );
+    assert_eq!(Value::Rate(10, Duration::from_secs(1)).to_string(), "10/s");
+    assert_eq!(
+        Value::Rate(100, Duration::from_secs(300)).to_string(),
+        "100/5m"
+    );
+}
+
+#[test]
+fn test_parse_value_constant() {
+    let max = |abs: bool, ns: &[&str]| ConstantRef {
+        abs,
+        ns: ns.iter().map(|s| s.to_string()).collect(),
+        name: "MAX".to_string(),
+    };
+    assert_parse(
+        parse_value(Span::new("MAX")),
+        Value::Identifier("MAX".to_string()),
+    );
+    assert_parse(
+        parse_value(Span::new("n::MAX")),
+        Value::Constant(max(false, &["n"])),
+    );
+    assert_parse(
+        parse_value(Span::new("::MAX")),
+        Value::Constant(max(true, &[])),
+    );
+    assert_parse(
+        parse_value(Span::new("0..a::b::MAX")),
+        Value::Range(
+            Some(RangeBound::Integer(0)),
+            Some(RangeBound::Constant(max(false, &["a", "b"]))),
+        ),
     );
 }
@@ -0,0 +1,706 @@
+//! Serializable intermediate representation of a resolved schema
+//!
+//! The types in this module mirror the ones in [`crate::schema`] but
+//! contain no shared or weak references. All user defined types and
+//! services are indexed by their fully qualified name (e.g. `user.Name`)
+//! which makes this representation suitable for passing it to external
+//! code generators as JSON.
+//!
+//! Whenever the structure of this representation changes in an
+//! incompatible way [`VERSION`] must be incremented.
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{idl, schema};
+
+/// Version of the intermediate representation
+pub const VERSION: u32 = 1;
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Document {
+    pub version: u32,
+    pub namespaces: Vec<Vec<String>>,
+    #[serde(default)]
+    pub constants: BTreeMap<String, Constant>,
+    pub types: BTreeMap<String, UserDefinedType>,
+    pub services: BTreeMap<String, Service>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Fqtn {
+    pub ns: Vec<String>,
+    pub name: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Constant {
+    pub fqtn: Fqtn,
+    pub r#type: Type,
+    /// JSON representation of the value
+    pub value: serde_json::Value,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum UserDefinedType {
+    Alias(Alias),
+    Newtype(Newtype),
+    Enum(Enum),
+    Struct(Struct),
+    Fieldset(Fieldset),
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Alias {
+    pub fqtn: Fqtn,
+    pub generics: Vec<String>,
+    pub r#type: Type,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Newtype {
+    pub fqtn: Fqtn,
+    pub generics: Vec<String>,
+    pub r#type: Type,
+    pub options: FieldOptions,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Enum {
+    pub fqtn: Fqtn,
+    pub generics: Vec<String>,
+    pub extends: Option<TypeRef>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deprecated: Option<Deprecation>,
+    pub variants: Vec<EnumVariant>,
+    pub all_variants: Vec<EnumVariant>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct EnumVariant {
+    pub name: String,
+    pub value_type: Option<Type>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deprecated: Option<Deprecation>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Struct {
+    pub fqtn: Fqtn,
+    pub generics: Vec<String>,
+    #[serde(default)]
+    pub extends: Option<TypeRef>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deprecated: Option<Deprecation>,
+    /// Fields declared by the struct itself
+    pub fields: Vec<Field>,
+    /// Inherited fields followed by the fields of the struct itself
+    #[serde(default)]
+    pub all_fields: Vec<Field>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Fieldset {
+    pub fqtn: Fqtn,
+    pub generics: Vec<String>,
+    pub r#struct: TypeRef,
+    /// Fields of the struct as selected by the fieldset. The `optional`
+    /// flag of the fields is the one of the fieldset.
+    pub fields: Vec<Field>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Field {
+    pub name: String,
+    pub r#type: Type,
+    pub optional: bool,
+    /// Value which is used if the field is missing, given in its JSON
+    /// representation (e.g. the name of an enum variant as string).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default: Option<serde_json::Value>,
+    pub options: FieldOptions,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct FieldOptions {
+    pub length: Range,
+    pub range: Range,
+    pub format: Option<String>,
+    pub help: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deprecated: Option<Deprecation>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Range {
+    pub min: Option<i64>,
+    pub max: Option<i64>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Service {
+    pub ns: Vec<String>,
+    pub name: String,
+    #[serde(default)]
+    pub side: ServiceSide,
+    #[serde(default)]
+    pub annotations: Annotations,
+    pub methods: Vec<Method>,
+}
+
+/// Side of the connection implementing a service
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ServiceSide {
+    #[default]
+    Server,
+    Client,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Method {
+    pub name: String,
+    pub input: Option<Type>,
+    /// Parameter names of methods declared with a parameter list. The
+    /// input refers to the struct holding the parameters.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub params: Option<Vec<String>>,
+    pub output: Option<Type>,
+    /// Error enum of the method. The response is a `Result` of the output
+    /// and this type.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub throws: Option<Type>,
+    #[serde(default)]
+    pub input_stream: bool,
+    #[serde(default)]
+    pub output_stream: bool,
+    #[serde(default)]
+    pub notify: bool,
+    #[serde(default)]
+    pub annotations: Annotations,
+}
+
+/// Annotations of a service or method
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Annotations {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deprecated: Option<Deprecation>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auth: Option<String>,
+    /// Timeout in milliseconds
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timeout_ms: Option<u64>,
+    #[serde(default)]
+    pub idempotent: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rate_limit: Option<RateLimit>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Deprecation {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+    /// Version which deprecated the item
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub since: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct RateLimit {
+    pub requests: u64,
+    /// Duration in milliseconds
+    pub per_ms: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum Type {
+    None,
+    Boolean,
+    Integer,
+    Float,
+    String,
+    #[serde(rename = "uuid")]
+    UUID,
+    Date,
+    Time,
+    DateTime,
+    Decimal,
+    Bytes,
+    Duration,
+    Int8,
+    Int16,
+    Int32,
+    Int64,
+    #[serde(rename = "uint8")]
+    UInt8,
+    #[serde(rename = "uint16")]
+    UInt16,
+    #[serde(rename = "uint32")]
+    UInt32,
+    #[serde(rename = "uint64")]
+    UInt64,
+    Option {
+        some: Box<Type>,
+    },
+    Result {
+        ok: Box<Type>,
+        err: Box<Type>,
+    },
+    Array {
+        item_type: Box<Type>,
+        length: Range,
+    },
+    Map {
+        key_type: Box<Type>,
+        value_type: Box<Type>,
+        length: Range,
+    },
+    Ref(TypeRef),
+    Generic {
+        name: String,
+    },
+    Builtin {
+        name: String,
+    },
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TypeRefKind {
+    Alias,
+    Newtype,
+    Enum,
+    Struct,
+    Fieldset,
+}
+
+/// Reference to a user defined type
+///
+/// The referenced type can be looked up in [`Document::types`] using
+/// the `key`.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct TypeRef {
+    pub key: String,
+    pub fqtn: Fqtn,
+    pub ref_kind: TypeRefKind,
+    pub generics: Vec<Type>,
+}
+
+impl From<&schema::FQTN> for Fqtn {
+    fn from(fqtn: &schema::FQTN) -> Self {
+        Self {
+            ns: fqtn.ns.clone(),
+            name: fqtn.name.clone(),
+        }
+    }
+}
+
+impl Fqtn {
+    /// Key used for indexing types and services in the [`Document`]
+    pub fn key(&self) -> String {
+        if self.ns.is_empty() {
+            self.name.clone()
+        } else {
+            format!("{}.{}", self.ns.join("."), self.name)
+        }
+    }
+}
+
+impl From<&schema::Document> for Document {
+    fn from(doc: &schema::Document) -> Self {
+        let mut ir = Self {
+            version: VERSION,
+            namespaces: Vec::new(),
+            constants: BTreeMap::new(),
+            types: BTreeMap::new(),
+            services: BTreeMap::new(),
+        };
+        ir.add_namespace(&doc.ns);
+        ir
+    }
+}
+
+impl Document {
+    fn add_namespace(&mut self, ns: &schema::Namespace) {
+        self.namespaces.push(ns.path.clone());
+        for constant in ns.constants.values() {
+            let constant = Constant {
+                fqtn: Fqtn::from(&constant.fqtn),
+                r#type: Type::from(&constant.type_),
+                value: json_value(&constant.value),
+            };
+            self.constants.insert(constant.fqtn.key(), constant);
+        }
+        for type_ in ns.types.values() {
+            let type_ = UserDefinedType::from(type_);
+            self.types.insert(type_.fqtn().key(), type_);
+        }
+        for service in ns.services.values() {
+            let service = Service {
+                ns: ns.path.clone(),
+                name: service.name.clone(),
+                side: match service.side {
+                    idl::ServiceSide::Server => ServiceSide::Server,
+                    idl::ServiceSide::Client => ServiceSide::Client,
+                },
+                annotations: Annotations::from(&service.annotations),
+                methods: service.methods.iter().map(Method::from).collect(),
+            };
+            let key = Fqtn {
+                ns: service.ns.clone(),
+                name: service.name.clone(),
+            }
+            .key();
+            self.services.insert(key, service);
+        }
+        for child_ns in ns.namespaces.values() {
+            self.add_namespace(child_ns);
+        }
+    }
+}
+
+impl UserDefinedType {
+    pub fn fqtn(&self) -> &Fqtn {
+        match self {
+            Self::Alias(t) => &t.fqtn,
+            Self::Newtype(t) => &t.fqtn,
+            Self::Enum(t) => &t.fqtn,
+            Self::Struct(t) => &t.fqtn,
+            Self::Fieldset(t) => &t.fqtn,
+        }
+    }
+}
+
+impl From<&schema::UserDefinedType> for UserDefinedType {
+    fn from(type_: &schema::UserDefinedType) -> Self {
+        match type_ {
+            schema::UserDefinedType::Alias(alias) => Self::Alias(Alias::from(&*alias.borrow())),
+            schema::UserDefinedType::Newtype(newtype) => {
+                Self::Newtype(Newtype::from(&*newtype.borrow()))
+            }
+            schema::UserDefinedType::Enum(enum_) => Self::Enum(Enum::from(&*enum_.borrow())),
+            schema::UserDefinedType::Struct(struct_) => {
+                Self::Struct(Struct::from(&*struct_.borrow()))
+            }
+            schema::UserDefinedType::Fieldset(fieldset) => {
+                Self::Fieldset(Fieldset::from(&*fieldset.borrow()))
+            }
+        }
+    }
+}
+
+impl From<&schema::Alias> for Alias {
+    fn from(alias: &schema::Alias) -> Self {
+        Self {
+            fqtn: Fqtn::from(&alias.fqtn),
+            generics: alias.generics.clone(),
+            r#type: Type::from(&alias.type_),
+        }
+    }
+}
+
+impl From<&schema::Newtype> for Newtype {
+    fn from(newtype: &schema::Newtype) -> Self {
+        let field = Field::from(&newtype.field);
+        Self {
+            fqtn: Fqtn::from(&newtype.fqtn),
+            generics: newtype.generics.clone(),
+            r#type: field.r#type,
+            options: field.options,
+        }
+    }
+}
+
+impl From<&schema::Enum> for Enum {
+    fn from(enum_: &schema::Enum) -> Self {
+        Self {
+            fqtn: Fqtn::from(&enum_.fqtn),
+            generics: enum_.generics.clone(),
+            extends: enum_.extends.as_ref().map(TypeRef::from),
+            deprecated: enum_.deprecated.as_ref().map(Deprecation::from),
+            variants: enum_.variants.iter().map(EnumVariant::from).collect(),
+            all_variants: enum_.all_variants.iter().map(EnumVariant::from).collect(),
+        }
+    }
+}
+
+impl From<&schema::EnumVariant> for EnumVariant {
+    fn from(variant: &schema::EnumVariant) -> Self {
+        Self {
+            name: variant.name.clone(),
+            value_type: variant.value_type.as_ref().map(Type::from),
+            deprecated: variant.deprecated.as_ref().map(Deprecation::from),
+        }
+    }
+}
+
+impl From<&schema::Struct> for Struct {
+    fn from(struct_: &schema::Struct) -> Self {
+        Self {
+            fqtn: Fqtn::from(&struct_.fqtn),
+            generics: struct_.generics.clone(),
+            extends: struct_.extends.as_ref().map(TypeRef::from),
+            deprecated: struct_.deprecated.as_ref().map(Deprecation::from),
+            fields: struct_.fields.iter().map(Field::from).collect(),
+            all_fields: struct_.all_fields.iter().map(Field::from).collect(),
+        }
+    }
+}
+
+impl From<&schema::Fieldset> for Fieldset {
+    fn from(fieldset: &schema::Fieldset) -> Self {
+        Self {
+            fqtn: Fqtn::from(&fieldset.fqtn),
+            generics: fieldset.generics.clone(),
+            r#struct: TypeRef::from(&fieldset.r#struct),
+            fields: fieldset
+                .fields
+                .iter()
+                .map(|f| Field {
+                    optional: f.optional,
+                    ..Field::from(f.field.as_ref().unwrap())
+                })
+                .collect(),
+        }
+    }
+}
+
+impl From<&schema::Field> for Field {
+    fn from(field: &schema::Field) -> Self {
+        Self {
+            name: field.name.clone(),
+            r#type: Type::from(&field.type_),
+            optional: field.optional,
+            default: field.default.as_ref().map(json_value),
+            options: FieldOptions {
+                length: Range {
+                    min: field.length.0,
+                    max: field.length.1,
+                },
+                range: Range {
+                    min: field.range.0,
+                    max: field.range.1,
+                },
+                format: field.format.clone(),
+                help: field.help.clone(),
+                deprecated: field.deprecated.as_ref().map(Deprecation::from),
+            },
+        }
+    }
+}
+
+fn json_value(value: &idl::Value) -> serde_json::Value {
+    match value {
+        idl::Value::Boolean(b) => serde_json::Value::from(*b),
+        idl::Value::Integer(i) => serde_json::Value::from(*i),
+        idl::Value::Float(f) => serde_json::Value::from(*f),
+        idl::Value::String(s) | idl::Value::Identifier(s) => serde_json::Value::from(s.clone()),
+        idl::Value::Range(_, _) | idl::Value::Duration(_) | idl::Value::Rate(_, _) => {
+            unreachable!("ranges, durations and rates are rejected by the validation")
+        }
+        idl::Value::Constant(_) => unreachable!("constants are resolved by the schema"),
+    }
+}
+
+impl From<&schema::Range> for Range {
+    fn from(range: &schema::Range) -> Self {
+        Self {
+            min: range.start.map(i64::from),
+            max: range.end.map(i64::from),
+        }
+    }
+}
+
+impl From<&schema::Method> for Method {
+    fn from(method: &schema::Method) -> Self {
+        Self {
+            name: method.name.clone(),
+            input: method.input.as_ref().map(Type::from),
+            params: method
+                .params
+                .as_ref()
+                .map(|params| params.iter().map(|p| p.name.clone()).collect()),
+            output: method.output.as_ref().map(Type::from),
+            throws: method.throws.as_ref().map(Type::from),
+            input_stream: method.input_stream,
+            output_stream: method.output_stream,
+            notify: method.notify,
+            annotations: Annotations::from(&method.annotations),
+        }
+    }
+}
+
+impl From<&schema::Annotations> for Annotations {
+    fn from(annotations: &schema::Annotations) -> Self {
+        Self {
+            deprecated: annotations.deprecated.as_ref().map(Deprecation::from),
+            auth: annotations.auth.clone(),
+            timeout_ms: annotations.timeout.map(|t| t.as_millis() as u64),
+            idempotent: annotations.idempotent,
+            rate_limit: annotations.rate_limit.map(|rate_limit| RateLimit {
+                requests: rate_limit.requests,
+                per_ms: rate_limit.per.as_millis() as u64,
+            }),
+        }
+    }
+}
+
+impl From<&schema::Deprecation> for Deprecation {
+    fn from(deprecation: &schema::Deprecation) -> Self {
+        Self {
+            note: deprecation.note.clone(),
+            since: deprecation.since.clone(),
+        }
+    }
+}
+
+impl From<&schema::Type> for Type {
+    fn from(type_: &schema::Type) -> Self {
+        match type_ {
+            schema::Type::None => Self::None,
+            schema::Type::Boolean => Self::Boolean,
+            schema::Type::Integer => Self::Integer,
+            schema::Type::Float => Self::Float,
+            schema::Type::String => Self::String,
+            schema::Type::UUID => Self::UUID,
+            schema::Type::Date => Self::Date,
+            schema::Type::Time => Self::Time,
+            schema::Type::DateTime => Self::DateTime,
+            schema::Type::Decimal => Self::Decimal,
+            schema::Type::Bytes => Self::Bytes,
+            schema::Type::Duration => Self::Duration,
+            schema::Type::Int8 => Self::Int8,
+            schema::Type::Int16 => Self::Int16,
+            schema::Type::Int32 => Self::Int32,
+            schema::Type::Int64 => Self::Int64,
+            schema::Type::UInt8 => Self::UInt8,
+            schema::Type::UInt16 => Self::UInt16,
+            schema::Type::UInt32 => Self::UInt32,
+            schema::Type::UInt64 => Self::UInt64,
+            schema::Type::Option(some) => Self::Option {
+                some: Box::new(Self::from(&**some)),
+            },
+            schema::Type::Result(ok, err) => Self::Result {
+                ok: Box::new(Self::from(&**ok)),
+                err: Box::new(Self::from(&**err)),
+            },
+            schema::Type::Array(array) => Self::Array {
+                item_type: Box::new(Self::from(&array.item_type)),
+                length: Range::from(&array.length),
+            },
+            schema::Type::Map(map) => Self::Map {
+                key_type: Box::new(Self::from(&map.key_type)),
+                value_type: Box::new(Self::from(&map.value_type)),
+                length: Range::from(&map.length),
+            },
+            schema::Type::Ref(typeref) => Self::Ref(TypeRef::from(typeref)),
+            schema::Type::Generic(name) => Self::Generic { name: name.clone() },
+            schema::Type::Builtin(name) => Self::Builtin { name: name.clone() },
+        }
+    }
+}
+
+impl From<&schema::TypeRef> for TypeRef {
+    fn from(typeref: &schema::TypeRef) -> Self {
+        let fqtn = Fqtn::from(&typeref.fqtn());
+        let ref_kind = match typeref {
+            schema::TypeRef::Alias(_) => TypeRefKind::Alias,
+            schema::TypeRef::Newtype(_) => TypeRefKind::Newtype,
+            schema::TypeRef::Enum(_) => TypeRefKind::Enum,
+            schema::TypeRef::Struct(_) => TypeRefKind::Struct,
+            schema::TypeRef::Fieldset(_) => TypeRefKind::Fieldset,
+            schema::TypeRef::Unresolved { .. } => {
+                unreachable!("The IR can only be created from a resolved schema")
+            }
+        };
+        Self {
+            key: fqtn.key(),
+            fqtn,
+            ref_kind,
+            generics: typeref.generics().iter().map(Type::from).collect(),
+        }
+    }
+}
+
+#[test]
+fn test_ir_document() {
+    let idl = r#"
+        const VERSION: String = "1.0";
+        namespace user {
+            const MAX_NAME_LENGTH: Integer = 50;
+            struct User {
+                id: UUID,
+                name: String (length=1..MAX_NAME_LENGTH),
+                role: Role = Guest,
+            }
+            enum Role { Admin, Guest }
+            fieldset UserName for User { name? }
+            enum GetError { DoesNotExist }
+            service Users {
+                get: UUID -> Result<UserName, GetError>,
+            }
+        }
+    "#;
+    let idocs = [crate::idl::parse_document(idl).unwrap()];
+    let builtin_types = std::collections::HashSet::default();
+    let doc = schema::Document::from_idl(idocs.iter(), &builtin_types).unwrap();
+    let ir = Document::from(&doc);
+    assert_eq!(ir.version, VERSION);
+    assert_eq!(ir.namespaces, vec![vec![], vec!["user".to_string()]]);
+    assert_eq!(
+        ir.constants.keys().collect::<Vec<_>>(),
+        vec!["VERSION", "user.MAX_NAME_LENGTH"]
+    );
+    assert_eq!(ir.constants["user.MAX_NAME_LENGTH"].value, 50);
+    assert_eq!(ir.constants["VERSION"].r#type, Type::String);
+    assert_eq!(
+        ir.types.keys().collect::<Vec<_>>(),
+        vec!["user.GetError", "user.Role", "user.User", "user.UserName"]
+    );
+    match ir.types.get("user.User").unwrap() {
+        UserDefinedType::Struct(struct_) => {
+            assert_eq!(struct_.fields[1].default, None);
+            assert_eq!(struct_.fields[2].default, Some("Guest".into()));
+        }
+        _ => unreachable!(),
+    }
+    match ir.types.get("user.UserName").unwrap() {
+        UserDefinedType::Fieldset(fieldset) => {
+            assert_eq!(fieldset.r#struct.key, "user.User");
+            assert_eq!(fieldset.fields.len(), 1);
+            assert!(fieldset.fields[0].optional);
+            assert_eq!(fieldset.fields[0].options.length.max, Some(50));
+        }
+        _ => unreachable!(),
+    }
+    let service = ir.services.get("user.Users").unwrap();
+    assert_eq!(
+        service.methods[0].output,
+        Some(Type::Result {
+            ok: Box::new(Type::Ref(TypeRef {
+                key: "user.UserName".to_string(),
+                fqtn: Fqtn {
+                    ns: vec!["user".to_string()],
+                    name: "UserName".to_string(),
+                },
+                ref_kind: TypeRefKind::Fieldset,
+                generics: vec![],
+            })),
+            err: Box::new(Type::Ref(TypeRef {
+                key: "user.GetError".to_string(),
+                fqtn: Fqtn {
+                    ns: vec!["user".to_string()],
+                    name: "GetError".to_string(),
+                },
+                ref_kind: TypeRefKind::Enum,
+                generics: vec![],
+            })),
+        })
+    );
+    // The IR must survive a JSON round trip
+    let json = serde_json::to_string(&ir).unwrap();
+    assert_eq!(serde_json::from_str::<Document>(&json).unwrap(), ir);
+}
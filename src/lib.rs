@@ -42,10 +42,344 @@
 //! The server and client files can be generated using the code generator:
 //!
 //! ```bash
-//! $ webwire gen rust < api/chat.ww > server/src/api.rs
+//! $ webwire gen rs < api/chat.ww > server/src/api.rs
 //! $ webwire gen ts < api/chat.ww > client/src/api.ts
 //! ```
 //!
+//! Large APIs can be split into one file per namespace. The Rust generator
+//! creates a module tree with a `mod.rs` file per namespace and the
+//! TypeScript generator creates one ES module per namespace. The modules
+//! import the `webwire` runtime module next to `index.ts`, so TypeScript
+//! code can not be generated for a top-level namespace named `webwire`.
+//! Files whose contents did not change are not rewritten:
+//!
+//! ```bash
+//! $ webwire gen rs --out-dir server/src/api api/chat.ww
+//! $ webwire gen ts --out-dir client/src/api api/chat.ww
+//! ```
+//!
+//! The Rust code uses `chrono` for the `Date`, `Time` and `DateTime` types.
+//! The `--time-crate` option switches to the
+//! [`time`](https://docs.rs/time) or [`jiff`](https://docs.rs/jiff) crate
+//! instead. All of them are serialized as ISO 8601 strings:
+//!
+//! ```bash
+//! $ webwire gen rs --time-crate jiff < api/chat.ww > server/src/api.rs
+//! ```
+//!
+//! Besides the date and time types the following types are builtin:
+//!
+//! | Type                   | JSON                                 | Rust                        | TypeScript         |
+//! | ---------------------- | ------------------------------------ | --------------------------- | ------------------ |
+//! | `Decimal`              | string (e.g. `"12.50"`)              | `rust_decimal::Decimal`     | `webwire.Decimal`  |
+//! | `Bytes`                | base64 encoded string                | `Vec<u8>`                   | `webwire.Bytes`    |
+//! | `Duration`             | ISO 8601 string (e.g. `"PT1H30M"`)   | `jiff::SignedDuration`      | `webwire.Duration` |
+//! | `Int8` … `Int64`       | number                               | `i8` … `i64`                | `number`           |
+//! | `UInt8` … `UInt64`     | number                               | `u8` … `u64`                | `number`           |
+//!
+//! The Rust code uses [`serde_with`](https://docs.rs/serde_with) (with the
+//! `base64` feature) to (de)serialize `Bytes` as base64 via
+//! `#[serde_as(as = "...")]`. `Duration` is always mapped to
+//! `jiff::SignedDuration` as neither `chrono` nor `time` (de)serialize
+//! durations as ISO 8601. With `--time-crate time` the `serde-human-readable`
+//! feature of `time` is needed for `Date` and `Time`, and the `time_0_3`
+//! feature of `serde_with` for `DateTime` which is (de)serialized as
+//! RFC 3339. Note that `time` requires the fraction of a second when
+//! parsing a `Time`. Options, arrays, maps and results containing these
+//! types are supported as well, type arguments of generic structs are not.
+//!
+//! `Integer`, `Int64` and `UInt64` are mapped to `number` in TypeScript.
+//! JavaScript numbers can not represent all 64 bit integers exactly, so
+//! values beyond ±2^53 (`Number.MAX_SAFE_INTEGER`) lose precision in
+//! TypeScript clients. Use a `String` or `Decimal` for identifiers and
+//! amounts which may exceed that range.
+//!
+//! Fields can have a default value which is used if the field is missing.
+//! Booleans, numbers, strings and variants of enums without a value are
+//! supported:
+//!
+//! ```webwire
+//! struct Query {
+//!     search: String,
+//!     limit: Integer (range=1..100) = 50,
+//!     order: Order = Descending,
+//! }
+//! ```
+//!
+//! Default values must satisfy the `range` and `length` options of the
+//! field. The Rust code fills in missing fields via
+//! `#[serde(default = "Query::default_limit")]` and implements `Default` if all other fields are optional or have a type
+//! implementing `Default`. Generic structs implement it if their type
+//! parameters do. The TypeScript code contains a `newQuery()` function
+//! which accepts an object without the fields having default values or
+//! with these fields set to `undefined`.
+//!
+//! Constants of the types `Boolean`, `Integer`, `Float`, `String` and the
+//! sized integer types are generated as `pub const` in Rust and `export const`
+//! in TypeScript. Integer constants can be used as bounds of the `length` and
+//! `range` options and constants of any type as default values of fields.
+//! Constants are looked up in the current namespace and then in its parent
+//! namespaces. Constants of other namespaces are referenced like types
+//! (`limits::MAX` or `::MAX`):
+//!
+//! ```webwire
+//! const API_VERSION: String = "1.2.0";
+//! const MAX_NAME_LENGTH: Integer = 64;
+//!
+//! namespace user {
+//!     struct User {
+//!         name: String (length=1..MAX_NAME_LENGTH),
+//!         client_version: String = ::API_VERSION,
+//!     }
+//! }
+//! ```
+//!
+//! A plain name used as default value of an enum field refers to a
+//! constant if one of that name exists and to a variant otherwise.
+//!
+//! Structs can extend another struct and inherit all of its fields.
+//! Inherited fields must not be redeclared:
+//!
+//! ```webwire
+//! struct Entity<ID> {
+//!     id: ID,
+//!     created_at: DateTime,
+//! }
+//!
+//! struct User extends Entity<UUID> {
+//!     name: String,
+//! }
+//! ```
+//!
+//! The Rust, Kotlin and Swift code contains the inherited fields directly
+//! while the TypeScript interfaces use `extends`.
+//!
+//! Fieldsets select fields of a struct. `..` selects all fields and
+//! `-name` removes a field from that selection. The type and options of a
+//! selected field can be overridden:
+//!
+//! ```webwire
+//! fieldset UserPublic for User { .., -password }
+//! fieldset UserUpdate for User { name?, password? (length=12..) }
+//! ```
+//!
+//! Small structs and enums can be declared inline where a field, enum
+//! variant or method uses them:
+//!
+//! ```webwire
+//! struct User {
+//!     address: { street: String, zip: String },
+//!     status: enum { Active, Banned({ reason: String }) },
+//! }
+//!
+//! service Users {
+//!     stats: None -> { total: UInt64, active: UInt64 },
+//! }
+//! ```
+//!
+//! Inline types are turned into named types whose names are made of the
+//! name of the containing item and the field, e.g. `UserAddress`,
+//! `UserStatusBanned` and `UsersStatsOutput`. Method inputs get the suffix
+//! `Input` and errors after `throws` the suffix `Error`. The generated code
+//! uses these names like for any other type, so they must not clash with
+//! other types of the namespace. Inline types inside generic types get the
+//! type parameters they use, e.g. `struct Page<T> { cursor: { first: T } }`
+//! refers to `PageCursor<T>`. Aliases and newtypes can not contain inline
+//! types.
+//!
+//! Type aliases give a type another name and are interchangeable with it.
+//! Newtypes wrap a type into a distinct type which can carry field options
+//! like `length`, `range` and `format`:
+//!
+//! ```webwire
+//! type Page<T> = [T];
+//! newtype UserId = UUID;
+//! newtype Email = String (format="email");
+//! ```
+//!
+//! Newtypes are generated as `#[serde(transparent)]` structs which are
+//! validated like structs in Rust and as branded types in TypeScript, so an
+//! `OrderId` can not be passed where a `UserId` is expected. Both are
+//! serialized as the wrapped type. Rust fields containing structs, fieldsets
+//! or newtypes are validated recursively using `#[validate]`. The
+//! `validator` crate used by `webwire` only supports this for the type
+//! itself, an `Option` or an array of it, so the values of maps, nested
+//! arrays and aliases of arrays are not validated recursively.
+//!
+//! Services are implemented by the server unless they are marked as
+//! `client service`. Those are implemented by the client and called by the
+//! server, e.g. to push events over a websocket:
+//!
+//! ```webwire
+//! service Chat {
+//!     send: Message -> None,
+//! }
+//!
+//! client service ChatEvents {
+//!     on_message: Message -> None,
+//! }
+//! ```
+//!
+//! For client services the Rust code only contains a consumer while the
+//! TypeScript, Kotlin and Swift code contains a `ChatEventsProvider` which
+//! dispatches incoming requests to an implementation of the service.
+//!
+//! The input and output of a method can be a stream of values:
+//!
+//! ```webwire
+//! service Events {
+//!     watch: Filter -> stream Event,
+//!     upload: stream Chunk -> Receipt,
+//! }
+//! ```
+//!
+//! Streaming methods use `BoxStream` in Rust, `AsyncIterable` in
+//! TypeScript, `Flow` in Kotlin and `AsyncThrowingStream` in Swift. They
+//! are called via the regular `request` and `call` methods of the runtime.
+//! A stream is transferred as a JSON array of all its values in a single
+//! request or response, i.e. the consumer sends a streamed input once the
+//! stream has ended and the provider responds once the streamed output has
+//! ended. Items of a streamed output are thus only available after all of
+//! them have been produced which makes streaming methods unsuitable for
+//! infinite streams like live updates.
+//!
+//! Notifications are methods which do not return a response. The consumer
+//! sends them via `Consumer::notify` without waiting for a reply which saves
+//! a round-trip, e.g. for telemetry and chat events:
+//!
+//! ```webwire
+//! service Telemetry {
+//!     notify log: LogEntry,
+//! }
+//! ```
+//!
+//! The provider handles notifications like other methods but does not
+//! return a payload. As the runtime does not report whether a notification
+//! was delivered, the Rust consumer method only fails if the input can not
+//! be serialized.
+//!
+//! Instead of a single input type methods can declare a list of named
+//! parameters which support the same syntax and options as struct fields:
+//!
+//! ```webwire
+//! service Users {
+//!     user_get(id: UUID, include_deleted?: Boolean) -> Result<User, GetError>,
+//!     notify user_seen(id: UUID),
+//! }
+//! ```
+//!
+//! The parameters are sent as a single JSON object. Its type is a struct
+//! named after the service and the method (`UsersUserGetInput`) which is
+//! added to the namespace and must not clash with other types. The Rust
+//! and TypeScript code takes the parameters as separate arguments, e.g.
+//! `user_get(&self, id: Uuid, include_deleted: Option<bool>)`, while the
+//! other languages pass the struct.
+//!
+//! The errors of a method are declared as an enum after `throws`:
+//!
+//! ```webwire
+//! service Users {
+//!     get: UUID -> User throws GetError,
+//! }
+//! ```
+//!
+//! On the wire the response is a `Result<User, GetError>`. Rust consumers
+//! return a `UsersGetConsumerError` which is either a `Consumer` error of
+//! the transport or a `Service` error containing the `GetError`. It
+//! implements `std::error::Error` with the transport error as `source`.
+//!
+//! TypeScript differs on purpose as its promises can only be rejected
+//! with an untyped value: the consumer throws a `UsersGetException` whose
+//! `error` field holds the `GetError` union, so callers check
+//! `e instanceof UsersGetException` before using it. Implementations of
+//! the service throw the same exception to return an error. Kotlin and
+//! Swift return the `Result`.
+//! Streaming methods and notifications can not declare errors.
+//!
+//! Services and methods can be annotated with options:
+//!
+//! ```webwire
+//! service Users (auth="user", timeout=5s) {
+//!     get: UUID -> User (idempotent),
+//!     get_by_name: String -> User (deprecated="Use `search` instead"),
+//!     search: String -> [User] (idempotent, timeout=30s, rate_limit=10/s),
+//! }
+//! ```
+//!
+//! The following options are supported:
+//!
+//! - `deprecated` or `deprecated="note"` marks the service or method as
+//!   deprecated. The generated code uses the native deprecation markers.
+//! - `auth="role"` names the role or scope required to call it.
+//! - `timeout=5s` limits how long a call may take. Durations use the units
+//!   `ms`, `s`, `m` and `h`.
+//! - `idempotent` marks methods which can safely be retried.
+//! - `rate_limit=10/s` or `rate_limit=100/5m` limits the number of requests.
+//!
+//! The generated Rust providers expose them as `ANNOTATIONS` and
+//! `METHOD_ANNOTATIONS` constants so middleware can enforce them. Their
+//! `Annotations` and `RateLimit` types are generated into a
+//! `webwire_annotations` module at the root of the generated code. The
+//! documentation lists them next to each service and method.
+//!
+//! Structs, enums, newtypes, fields and enum variants can be deprecated
+//! via the `deprecated` option, too. It accepts an optional note and the
+//! version which deprecated the item can be given via `since`:
+//!
+//! ```webwire
+//! struct Login (deprecated="Use `Account` instead", since="1.2") {
+//!     name: String,
+//! }
+//!
+//! struct Account {
+//!     email: String (deprecated, since="1.4"),
+//!     emails: [String],
+//! }
+//!
+//! enum AccountState {
+//!     Active,
+//!     Disabled (deprecated="Use `Suspended`"),
+//!     Suspended,
+//! }
+//! ```
+//!
+//! Deprecated items are marked with `#[deprecated]` in Rust, `@deprecated`
+//! in TypeScript, `@Deprecated` in Kotlin and `@available(*, deprecated)`
+//! in Swift. `webwire check` validates the IDL file and warns about items
+//! which are not deprecated themselves but use a deprecated type:
+//!
+//! ```bash
+//! $ webwire check api/accounts.ww
+//! warning: Accounts.rename uses deprecated type Username
+//! ```
+//!
+//! Types which are not defined in the IDL can be declared as builtin types
+//! and mapped to a type of the target language using `--type NAME=TYPE`.
+//! Using a builtin type without a mapping for the target language is an
+//! error:
+//!
+//! ```bash
+//! $ webwire gen rs -t Url=::url::Url < api/shop.ww > server/src/api.rs
+//! $ webwire gen ts -t Url=string < api/shop.ww > client/src/api.ts
+//! ```
+//!
+//! Rust mappings must be valid Rust types. Rust types containing `f32` or
+//! `f64` do not derive `Eq`, all other mapped types must implement it.
+//!
+//! Mobile clients can be generated for Kotlin (`kt`) and Swift (`swift`), too.
+//! Both generators produce the same JSON representation as the Rust code:
+//!
+//! ```bash
+//! $ webwire gen kt --package com.example.chat < api/chat.ww > android/src/main/kotlin/Api.kt
+//! $ webwire gen swift < api/chat.ww > ios/Sources/Api.swift
+//! ```
+//!
+//! The Kotlin code is placed in the package given via `--package`, which
+//! defaults to `api`. Swift's `JSONEncoder` encodes dictionaries with keys
+//! other than `String` or `Int` as arrays, so maps with non-string keys are
+//! represented as `[String: V]` in Swift with the keys in their JSON
+//! representation.
+//!
 //! A Rust server implementation for the given code would look like this:
 //!
 //! ```rust,ignore
@@ -151,6 +485,108 @@
 //! assert(response.Ok === null)
 //! ```
 //!
+//! ## Configuration file
+//!
+//! Projects with several generated artifacts can list them in a
+//! `webwire.toml` file. Running `webwire gen` without any arguments then
+//! regenerates all of them. All paths are relative to the configuration
+//! file:
+//!
+//! ```toml
+//! sources = ["api/chat.ww"]
+//! # Searched for included files which are not found next to the including file
+//! include_paths = ["api/common"]
+//!
+//! # Builtin types per language (same as `--type NAME=TYPE`)
+//! [builtin_types.rs]
+//! Url = "::url::Url"
+//!
+//! [builtin_types.ts]
+//! Url = "string"
+//!
+//! [[target]]
+//! language = "rs"
+//! output = "server/src/api.rs"
+//! time_crate = "jiff"
+//!
+//! [[target]]
+//! language = "ts"
+//! out_dir = "client/src/api"
+//!
+//! [[target]]
+//! language = "kt"
+//! output = "android/src/main/kotlin/Api.kt"
+//! package = "com.example.chat"
+//!
+//! [[target]]
+//! plugin = "./tools/gen-python"
+//! output = "python/api"
+//! builtin_types = { Url = "str" }
+//! options = { package = "api" }
+//! ```
+//!
+//! A different configuration file can be given via `--config`. The
+//! `--type` option can only be used together with a configuration file
+//! whose targets all use the same language.
+//!
+//! ## Documentation
+//!
+//! A Markdown API reference containing all namespaces, types and services
+//! can be generated from the same IDL file:
+//!
+//! ```bash
+//! $ webwire doc api/chat.ww docs/api.md
+//! ```
+//!
+//! ## Templates
+//!
+//! Small one-off outputs like SQL scripts can be generated using
+//! [minijinja](https://docs.rs/minijinja) templates. Every file of the
+//! template directory is rendered into the target directory. Files starting
+//! with an underscore can be used for includes and macros:
+//!
+//! ```bash
+//! $ webwire gen template --template templates/ api/chat.ww out/
+//! ```
+//!
+//! The templates have access to `types`, `services`, `namespaces` and the
+//! whole `document`. The filters `fqtn`, `map_type`, `snake_case`,
+//! `camel_case`, `pascal_case`, `kebab_case` and `shouty_snake_case` help
+//! with formatting names and types:
+//!
+//! ```jinja
+//! {% set sql = {"integer": "BIGINT", "string": "TEXT", "option": "{} NULL"} %}
+//! {% for type in types %}
+//! {{ file("tables/" ~ (type | fqtn("_") | snake_case) ~ ".sql") }}
+//! CREATE TABLE {{ type | fqtn("_") | snake_case }} (
+//! {% for field in type.all_fields %}
+//!     {{ field.name }} {{ field.type | map_type(sql) }}{{ "," if not loop.last }}
+//! {% endfor %}
+//! );
+//! {% endfor %}
+//! ```
+//!
+//! The `file` function writes the remaining output of the template to the
+//! given file which makes it possible to generate one file per type.
+//!
+//! ## Plugins
+//!
+//! Code generators for other languages can be implemented as external
+//! executables. The plugin receives the resolved schema as versioned JSON
+//! document via stdin and responds with the files that should be written
+//! to the target directory:
+//!
+//! ```bash
+//! $ webwire gen plugin --plugin ./my-generator --plugin-option package=api api/chat.ww out/
+//! ```
+//!
+//! The request has the form
+//! `{"document": {"version": 1, ...}, "parameters": {...}, "builtin_types": {...}}`
+//! where `document` is the intermediate representation defined in the
+//! `webwire_cli::ir` module and `builtin_types` contains the mappings given
+//! via `--type NAME=TYPE`. The plugin must respond with
+//! `{"files": [{"name": "chat.py", "content": "..."}]}` or `{"error": "..."}`.
+//!
 //! ## License
 //!
 //! Licensed under either of
@@ -161,5 +597,7 @@
 //! at your option.
 pub mod codegen;
 pub mod common;
+pub mod config;
 pub mod idl;
+pub mod ir;
 pub mod schema;
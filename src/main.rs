@@ -1,4 +1,4 @@
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
 use std::fs::File;
 use std::io::{stdin, stdout, Read, Write};
 use std::path::{Path, PathBuf};
@@ -6,15 +6,44 @@ use std::path::{Path, PathBuf};
 use clap::{Parser, Subcommand, ValueEnum};
 
 use webwire_cli::codegen;
+use webwire_cli::config;
 use webwire_cli::idl;
 use webwire_cli::schema;
 
-#[derive(ValueEnum, Copy, Clone, Debug, PartialEq, Eq)]
+#[derive(ValueEnum, Copy, Clone, Debug, PartialEq, Eq, Hash)]
 enum Language {
     #[value(name = "rs", help = "Rust")]
     Rust,
     #[value(name = "ts", help = "TypeScript")]
     TypeScript,
+    #[value(name = "kt", help = "Kotlin")]
+    Kotlin,
+    #[value(name = "swift", help = "Swift")]
+    Swift,
+    #[value(name = "template", help = "Templates given via --template")]
+    Template,
+    #[value(name = "plugin", help = "External generator given via --plugin")]
+    Plugin,
+}
+
+#[derive(ValueEnum, Copy, Clone, Debug, PartialEq, Eq)]
+enum TimeCrate {
+    #[value(help = "chrono::NaiveDate, chrono::NaiveTime and chrono::DateTime<Utc>")]
+    Chrono,
+    #[value(help = "time::Date, time::Time and time::OffsetDateTime")]
+    Time,
+    #[value(help = "jiff::civil::Date, jiff::civil::Time and jiff::Timestamp")]
+    Jiff,
+}
+
+impl From<TimeCrate> for codegen::rust::TimeCrate {
+    fn from(time_crate: TimeCrate) -> Self {
+        match time_crate {
+            TimeCrate::Chrono => Self::Chrono,
+            TimeCrate::Time => Self::Time,
+            TimeCrate::Jiff => Self::Jiff,
+        }
+    }
 }
 
 #[derive(Debug, Parser)]
@@ -36,13 +65,92 @@ struct Cli {
 enum Command {
     #[command(about = "Generate source")]
     Gen(Gen),
+    #[command(about = "Generate documentation")]
+    Doc(Doc),
+    #[command(about = "Validate the source and report warnings")]
+    Check(Check),
 }
 
 #[derive(Debug, Parser)]
+#[command(
+    override_usage = "webwire gen [OPTIONS] <LANGUAGE> [SOURCE] [TARGET]\n       \
+    webwire gen [OPTIONS] --out-dir <DIR> <LANGUAGE> [SOURCE]\n       \
+    webwire gen [OPTIONS] --plugin <PLUGIN> plugin [SOURCE] [TARGET_DIR]\n       \
+    webwire gen [--config <FILE>]"
+)]
 struct Gen {
-    language: Language,
+    #[arg(help = "Target language. Omit it to run the targets of the configuration file.")]
+    language: Option<Language>,
+    #[arg(help = "Source file [default: stdin]")]
     source: Option<String>,
+    #[arg(help = "Target file or directory [default: stdout or the current directory]")]
     target: Option<String>,
+    #[arg(
+        long,
+        value_name = "DIR",
+        help = "Write one file per namespace to the given directory"
+    )]
+    out_dir: Option<PathBuf>,
+    #[arg(long, value_name = "DIR", help = "Template directory")]
+    template: Option<PathBuf>,
+    #[arg(
+        long,
+        help = "External code generator executable",
+        required_if_eq("language", "plugin")
+    )]
+    plugin: Option<PathBuf>,
+    #[arg(
+        long = "plugin-option",
+        value_name = "KEY=VALUE",
+        help = "Parameter which is passed to the plugin",
+        requires = "plugin"
+    )]
+    plugin_options: Vec<String>,
+    #[arg(
+        long,
+        value_name = "CRATE",
+        default_value = "chrono",
+        help = "Crate used for date and time types in generated Rust code"
+    )]
+    time_crate: TimeCrate,
+    #[arg(
+        long,
+        value_name = "PACKAGE",
+        default_value = codegen::kotlin::DEFAULT_PACKAGE,
+        help = "Package of the generated Kotlin code"
+    )]
+    package: String,
+    #[arg(
+        short,
+        long,
+        help = "Type name that should be treated as a built-in type"
+    )]
+    r#type: Option<Vec<String>>,
+    #[arg(
+        long,
+        value_name = "FILE",
+        help = "Configuration file which is used if no language or plugin is given \
+            [default: webwire.toml]",
+        conflicts_with_all = ["language", "plugin", "out_dir", "template"]
+    )]
+    config: Option<PathBuf>,
+}
+
+#[derive(Debug, Parser)]
+struct Doc {
+    source: Option<String>,
+    target: Option<String>,
+    #[arg(
+        short,
+        long,
+        help = "Type name that should be treated as a built-in type"
+    )]
+    r#type: Option<Vec<String>>,
+}
+
+#[derive(Debug, Parser)]
+struct Check {
+    source: Option<String>,
     #[arg(
         short,
         long,
@@ -55,6 +163,8 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     let args = Cli::parse();
     match args.command {
         Command::Gen(gen_args) => cmd_gen(&gen_args),
+        Command::Doc(doc_args) => cmd_doc(&doc_args),
+        Command::Check(check_args) => cmd_check(&check_args),
     }
 }
 
@@ -103,80 +213,351 @@ impl std::fmt::Display for GenError {
 
 impl std::error::Error for GenError {}
 
+/// Generator invocation given either via the command line or as target
+/// of the configuration file
+struct GenTarget {
+    language: Language,
+    plugin: Option<PathBuf>,
+    plugin_options: BTreeMap<String, String>,
+    template: Option<PathBuf>,
+    /// Builtin types mapped to the types of the target language
+    builtin_types: HashMap<String, String>,
+    /// Generate one file per namespace
+    out_dir: bool,
+    /// Target file or directory. Single files are written to stdout and
+    /// multiple files to the current directory if no target is given.
+    target: Option<PathBuf>,
+    time_crate: TimeCrate,
+    /// Package of the generated Kotlin code
+    package: String,
+}
+
+impl GenTarget {
+    fn from_config(
+        target: &config::Target,
+        builtin_types: HashMap<String, String>,
+    ) -> Result<Self, GenError> {
+        let language = match target.language.as_deref() {
+            // The configuration requires either a language or a plugin.
+            None => Language::Plugin,
+            Some(language) => Language::from_str(language, true).map_err(|_| GenError {
+                message: format!("Unsupported language: {}", language),
+            })?,
+        };
+        let time_crate = match target.time_crate.as_deref() {
+            None => TimeCrate::Chrono,
+            Some(time_crate) => TimeCrate::from_str(time_crate, true).map_err(|_| GenError {
+                message: format!("Unsupported time crate: {}", time_crate),
+            })?,
+        };
+        Ok(Self {
+            language,
+            plugin: target.plugin.clone(),
+            plugin_options: target.options.clone(),
+            template: target.template.clone(),
+            builtin_types,
+            out_dir: target.out_dir.is_some(),
+            target: target.out_dir.clone().or_else(|| target.output.clone()),
+            time_crate,
+            package: target
+                .package
+                .clone()
+                .unwrap_or_else(|| codegen::kotlin::DEFAULT_PACKAGE.to_owned()),
+        })
+    }
+    /// Check the builtin type mappings before the document is loaded
+    fn check_builtin_types(&self) -> Result<(), Box<dyn std::error::Error>> {
+        if self.language == Language::Rust {
+            codegen::rust::check_builtin_types(&self.builtin_types)?;
+        }
+        Ok(())
+    }
+    fn run(
+        &self,
+        doc: &schema::Document,
+        sources: Vec<String>,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let target_dir = self.target.clone().unwrap_or_else(|| PathBuf::from("."));
+        let language = self.language;
+        if language == Language::Plugin {
+            let plugin = self.plugin.as_ref().ok_or_else(|| GenError {
+                message: "The plugin generator requires the --plugin option".to_owned(),
+            })?;
+            let builtin_types = self.builtin_types.clone().into_iter().collect();
+            let output = codegen::plugin::gen(doc, plugin, &self.plugin_options, &builtin_types)?;
+            return write_output(&output, &target_dir);
+        }
+        if language == Language::Template {
+            let template_dir = self.template.as_ref().ok_or_else(|| GenError {
+                message: "The template generator requires the --template option".to_owned(),
+            })?;
+            let output = codegen::template::gen(doc, template_dir)?;
+            return write_output(&output, &target_dir);
+        }
+
+        codegen::check_builtin_types(doc, &self.builtin_types)?;
+        if language == Language::TypeScript {
+            codegen::ts::check_namespaces(doc)?;
+        }
+        let rust_options = codegen::rust::Options {
+            sources,
+            time_crate: self.time_crate.into(),
+            builtin_types: self.builtin_types.clone(),
+        };
+        let kotlin_options = codegen::kotlin::Options {
+            package: self.package.clone(),
+            builtin_types: self.builtin_types.clone(),
+        };
+
+        if self.out_dir {
+            let output = match language {
+                Language::Rust => codegen::rust::gen_files(doc, &rust_options),
+                Language::TypeScript => codegen::ts::gen_files(doc, &self.builtin_types),
+                _ => {
+                    return Err(Box::new(GenError {
+                        message: format!(
+                            "The --out-dir option is not supported for {:?}",
+                            language
+                        ),
+                    }))
+                }
+            };
+            return write_output(&output, &target_dir);
+        }
+
+        // Call code generator function
+        let target_code = match language {
+            Language::Rust => codegen::rust::gen(doc, &rust_options),
+            Language::TypeScript => codegen::ts::gen(doc, &self.builtin_types),
+            Language::Kotlin => codegen::kotlin::gen(doc, &kotlin_options),
+            Language::Swift => codegen::swift::gen(doc, &self.builtin_types),
+            Language::Template | Language::Plugin => unreachable!(),
+        };
+
+        write_target(self.target.as_deref(), target_code)
+    }
+}
+
 fn cmd_gen(args: &Gen) -> Result<(), Box<dyn std::error::Error>> {
-    let path = match args.source.as_deref() {
-        None | Some("--") => None,
-        Some(path) => Some(Path::new(path)),
+    let Some(language) = args.language else {
+        return cmd_gen_config(args);
     };
-    let source = path.map_or(Source::Stdin, |p| Source::File(p.to_owned()));
-
-    // Parse IDL file
-    let mut idocs: Vec<idl::Document> = Vec::new();
-    let idoc = idl::parse_document(&source.read()?).map_err(|e| format!("{}", e))?;
-    idocs.push(idoc);
+    if args.plugin.is_some() && language != Language::Plugin {
+        return Err(Box::new(GenError {
+            message: format!("The --plugin option can not be used with {:?}", language),
+        }));
+    }
+    let builtin_types = parse_builtin_types(args.r#type.as_deref());
+    let builtin_type_names = builtin_types.keys().cloned().collect();
+    let gen_target = GenTarget {
+        language,
+        plugin: args.plugin.clone(),
+        plugin_options: args
+            .plugin_options
+            .iter()
+            .map(|v| match v.split_once('=') {
+                Some((key, value)) => (key.to_owned(), value.to_owned()),
+                None => (v.to_owned(), String::new()),
+            })
+            .collect(),
+        template: args.template.clone(),
+        builtin_types,
+        out_dir: args.out_dir.is_some(),
+        target: target_path(args)?,
+        time_crate: args.time_crate,
+        package: args.package.clone(),
+    };
+    gen_target.check_builtin_types()?;
+    let (doc, sources) = load_document(args.source.as_deref(), &builtin_type_names)?;
+    gen_target.run(&doc, sources)
+}
 
-    // Parse all included files (recursively)
-    if !idocs[0].includes.is_empty() {
-        if matches!(source, Source::Stdin) {
+/// Run all targets of the configuration file
+fn cmd_gen_config(args: &Gen) -> Result<(), Box<dyn std::error::Error>> {
+    let path = args
+        .config
+        .clone()
+        .unwrap_or_else(|| PathBuf::from(config::FILENAME));
+    if args.config.is_none() && !path.exists() {
+        return Err(Box::new(GenError {
+            message: format!(
+                "Either a language, a plugin or a {} file must be given",
+                config::FILENAME
+            ),
+        }));
+    }
+    let config = config::Config::load(&path)?;
+    let mut targets = config
+        .targets
+        .iter()
+        .map(|target| GenTarget::from_config(target, config.builtin_types(target)))
+        .collect::<Result<Vec<_>, _>>()?;
+    // Builtin types given on the command line are mapped to the same type
+    // for all targets which only makes sense for a single language.
+    let cli_types = parse_builtin_types(args.r#type.as_deref());
+    if !cli_types.is_empty() {
+        let generators = targets
+            .iter()
+            .map(|target| (target.language, target.plugin.as_ref()))
+            .collect::<HashSet<_>>();
+        if generators.len() > 1 {
             return Err(Box::new(GenError {
-                message: "Source must not contain any includes if reading from stdin".to_owned(),
+                message: format!(
+                    "The --type option can not be used if the targets of {:?} use different \
+                    languages. Map the types per language or target in the configuration file \
+                    instead.",
+                    path
+                ),
             }));
         }
-        let base_dir = path
-            .and_then(|p| p.parent())
-            .ok_or("base_dir could not be determined from source")?;
-        let mut included_files: HashSet<PathBuf> = HashSet::new();
-        let mut includes = idocs[0]
-            .includes
-            .iter()
-            .map(|p| (base_dir.join(&p.filename)))
-            .collect::<Vec<_>>();
-        while !includes.is_empty() {
-            let include = includes.remove(0);
-            if included_files.contains(&include) {
-                continue;
-            }
-            included_files.insert(include.clone());
-            let source = Source::File(include.clone());
-            let idoc = idl::parse_document(&source.read()?).map_err(|e| format!("{}", e))?;
-            let dir = include.parent().unwrap();
-            includes.extend(idoc.includes.iter().map(|inc| dir.join(&inc.filename)));
-            idocs.push(idoc);
+        for target in targets.iter_mut() {
+            target.builtin_types.extend(cli_types.clone());
         }
     }
+    for target in targets.iter() {
+        target.check_builtin_types()?;
+    }
+    let mut builtin_type_names = config.builtin_type_names();
+    builtin_type_names.extend(cli_types.keys().cloned());
+    let (doc, sources) = load_files(&config.sources, &config.include_paths, &builtin_type_names)?;
+    for target in targets.iter() {
+        target.run(&doc, sources.clone())?;
+    }
+    Ok(())
+}
+
+/// Target file or directory given either via `--out-dir` or as
+/// positional argument
+fn target_path(args: &Gen) -> Result<Option<PathBuf>, GenError> {
+    match (&args.out_dir, args.target.as_deref()) {
+        (Some(_), Some(target)) => Err(GenError {
+            message: format!("Unexpected argument: {}", target),
+        }),
+        (Some(out_dir), None) => Ok(Some(out_dir.clone())),
+        (None, Some("--")) | (None, None) => Ok(None),
+        (None, Some(target)) => Ok(Some(PathBuf::from(target))),
+    }
+}
+
+fn cmd_doc(args: &Doc) -> Result<(), Box<dyn std::error::Error>> {
+    let builtin_types = parse_builtin_types(args.r#type.as_deref());
+    let (doc, _) = load_document(
+        args.source.as_deref(),
+        &builtin_types.keys().cloned().collect(),
+    )?;
+    let target = args.target.as_deref().filter(|t| *t != "--").map(Path::new);
+    write_target(target, codegen::doc::gen(&doc))
+}
 
-    let types = args
-        .r#type
-        .as_deref()
+/// Validate the source and print the warnings to stderr. Warnings do
+/// not cause a failure.
+fn cmd_check(args: &Check) -> Result<(), Box<dyn std::error::Error>> {
+    let builtin_types = parse_builtin_types(args.r#type.as_deref());
+    let (doc, _) = load_document(
+        args.source.as_deref(),
+        &builtin_types.keys().cloned().collect(),
+    )?;
+    for warning in doc.warnings() {
+        eprintln!("warning: {}", warning);
+    }
+    Ok(())
+}
+
+/// Parse the builtin types given via `--type NAME[=TYPE]`. Types
+/// without explicit mapping are mapped to their name.
+fn parse_builtin_types(types: Option<&[String]>) -> HashMap<String, String> {
+    types
         .unwrap_or_default()
         .iter()
-        .map(|v| {
-            let parts = v.splitn(2, '=').collect::<Vec<_>>();
-            if parts.len() == 1 {
-                (parts[0].to_owned(), parts[0].to_owned())
-            } else if parts.len() == 2 {
-                (parts[0].to_owned(), parts[1].to_owned())
-            } else {
-                unreachable!();
-            }
+        .map(|v| match v.split_once('=') {
+            Some((name, type_)) => (name.to_owned(), type_.to_owned()),
+            None => (v.to_owned(), v.to_owned()),
         })
-        .collect::<HashMap<_, _>>();
+        .collect()
+}
+
+/// Load the document and all included files. Returns the document
+/// and the names of all files it was loaded from.
+fn load_document(
+    source: Option<&str>,
+    builtin_types: &HashSet<String>,
+) -> Result<(schema::Document, Vec<String>), Box<dyn std::error::Error>> {
+    match source {
+        None | Some("--") => {
+            let idoc = idl::parse_document(&Source::Stdin.read()?).map_err(|e| format!("{}", e))?;
+            if !idoc.includes.is_empty() {
+                return Err(Box::new(GenError {
+                    message: "Source must not contain any includes if reading from stdin"
+                        .to_owned(),
+                }));
+            }
+            let doc = schema::Document::from_idl([idoc].iter(), builtin_types)?;
+            Ok((doc, Vec::new()))
+        }
+        Some(path) => load_files(&[PathBuf::from(path)], &[], builtin_types),
+    }
+}
+
+/// Load the given files and all included files (recursively). Includes
+/// are resolved relative to the including file first and then relative
+/// to the include paths.
+fn load_files(
+    paths: &[PathBuf],
+    include_paths: &[PathBuf],
+    builtin_types: &HashSet<String>,
+) -> Result<(schema::Document, Vec<String>), Box<dyn std::error::Error>> {
+    let mut idocs: Vec<idl::Document> = Vec::new();
+    let mut sources: Vec<String> = Vec::new();
+    let mut loaded_files: HashSet<PathBuf> = HashSet::new();
+    let mut queue = paths.iter().cloned().collect::<VecDeque<_>>();
+    while let Some(path) = queue.pop_front() {
+        if !loaded_files.insert(path.clone()) {
+            continue;
+        }
+        let source = Source::File(path.clone());
+        let idoc = idl::parse_document(&source.read()?).map_err(|e| format!("{}", e))?;
+        let dir = path.parent().unwrap_or(Path::new(""));
+        queue.extend(
+            idoc.includes
+                .iter()
+                .map(|include| resolve_include(dir, &include.filename, include_paths)),
+        );
+        idocs.push(idoc);
+        sources.push(path.display().to_string());
+    }
 
     // Convert IDL to Schema
-    let doc = schema::Document::from_idl(idocs.iter(), &types)?;
+    let doc = schema::Document::from_idl(idocs.iter(), builtin_types)?;
+    Ok((doc, sources))
+}
 
-    // Call code generator function
-    let target_code = match args.language {
-        Language::Rust => codegen::rust::gen(&doc),
-        Language::TypeScript => codegen::ts::gen(&doc),
-    };
+fn resolve_include(dir: &Path, filename: &str, include_paths: &[PathBuf]) -> PathBuf {
+    let path = dir.join(filename);
+    if path.exists() {
+        return path;
+    }
+    include_paths
+        .iter()
+        .map(|include_path| include_path.join(filename))
+        .find(|path| path.exists())
+        .unwrap_or(path)
+}
 
-    // Write target file
-    let mut target: Box<dyn Write> = match args.target.as_deref() {
-        None | Some("--") => Box::new(stdout()),
+fn write_output(
+    output: &codegen::Output,
+    target_dir: &Path,
+) -> Result<(), Box<dyn std::error::Error>> {
+    output.write(target_dir).map_err(|e| GenError {
+        message: format!("Could not write to {:?}: {}", target_dir, e),
+    })?;
+    Ok(())
+}
+
+fn write_target(target: Option<&Path>, content: String) -> Result<(), Box<dyn std::error::Error>> {
+    let mut target: Box<dyn Write> = match target {
+        None => Box::new(stdout()),
         Some(filename) => Box::new(File::create(filename)?),
     };
-    target.write_all(&target_code.into_bytes())?;
-
+    target.write_all(&content.into_bytes())?;
     Ok(())
 }
@@ -0,0 +1,165 @@
+use std::collections::HashSet;
+
+use crate::common::FilePosition;
+use crate::idl;
+
+use super::constant::ConstantMap;
+use super::errors::{ValidationError, ValidationErrorCause};
+use super::fqtn::FQTN;
+use super::namespace::Namespace;
+use super::r#struct::Field;
+use super::r#type::{Type, TypeRef};
+use super::typemap::TypeMap;
+
+/// Alternative name of a type which is interchangeable with the type
+pub struct Alias {
+    pub fqtn: FQTN,
+    pub generics: Vec<String>,
+    pub type_: Type,
+    pub position: FilePosition,
+}
+
+/// Distinct type wrapping another type
+pub struct Newtype {
+    pub fqtn: FQTN,
+    pub generics: Vec<String>,
+    /// Type and options of the wrapped value. The name of the field is
+    /// the name of the newtype.
+    pub field: Field,
+    pub position: FilePosition,
+}
+
+impl Alias {
+    pub(crate) fn from_idl(
+        ialias: &idl::Alias,
+        ns: &Namespace,
+        builtin_types: &HashSet<String>,
+    ) -> Self {
+        Self {
+            fqtn: FQTN::new(&ialias.name, ns),
+            generics: ialias.generics.clone(),
+            type_: Type::from_idl(&ialias.type_, ns, &ialias.generics, builtin_types),
+            position: ialias.position,
+        }
+    }
+    pub(crate) fn resolve(&mut self, type_map: &TypeMap) -> Result<(), ValidationError> {
+        self.type_.resolve(type_map)
+    }
+    /// Aliases which refer to themselves can not be expanded and are
+    /// therefore rejected.
+    pub(crate) fn validate(&self) -> Result<(), ValidationError> {
+        if is_recursive(&self.type_, &mut vec![self.fqtn.clone()]) {
+            return Err(ValidationError {
+                position: self.position,
+                cause: Box::new(ValidationErrorCause::RecursiveAlias {
+                    fqtn: self.fqtn.clone(),
+                }),
+            });
+        }
+        Ok(())
+    }
+}
+
+fn is_recursive(type_: &Type, stack: &mut Vec<FQTN>) -> bool {
+    match type_ {
+        Type::Option(some) => is_recursive(some, stack),
+        Type::Result(ok, err) => is_recursive(ok, stack) || is_recursive(err, stack),
+        Type::Array(array) => is_recursive(&array.item_type, stack),
+        Type::Map(map) => {
+            is_recursive(&map.key_type, stack) || is_recursive(&map.value_type, stack)
+        }
+        Type::Ref(typeref) => {
+            if typeref.generics().iter().any(|t| is_recursive(t, stack)) {
+                return true;
+            }
+            let TypeRef::Alias(aliasref) = typeref else {
+                return false;
+            };
+            let alias = aliasref.alias.upgrade().unwrap();
+            let alias = alias.borrow();
+            if stack.contains(&alias.fqtn) {
+                return true;
+            }
+            stack.push(alias.fqtn.clone());
+            let recursive = is_recursive(&alias.type_, stack);
+            stack.pop();
+            recursive
+        }
+        _ => false,
+    }
+}
+
+impl Newtype {
+    pub(crate) fn from_idl(
+        inewtype: &idl::Newtype,
+        ns: &Namespace,
+        constants: &ConstantMap,
+        builtin_types: &HashSet<String>,
+    ) -> Result<Self, ValidationError> {
+        let ifield = idl::Field {
+            name: inewtype.name.clone(),
+            type_: inewtype.type_.clone(),
+            optional: false,
+            default: None,
+            options: inewtype.options.clone(),
+            position: inewtype.position,
+        };
+        Ok(Self {
+            fqtn: FQTN::new(&inewtype.name, ns),
+            generics: inewtype.generics.clone(),
+            field: Field::from_idl(&ifield, ns, &inewtype.generics, constants, builtin_types)?,
+            position: inewtype.position,
+        })
+    }
+    pub(crate) fn resolve(&mut self, type_map: &TypeMap) -> Result<(), ValidationError> {
+        self.field.type_.resolve(type_map)
+    }
+}
+
+#[test]
+fn test_schema_alias_and_newtype() {
+    let parse = |idl: &str| {
+        let idocs = [crate::idl::parse_document(idl).unwrap()];
+        super::Document::from_idl(idocs.iter(), &HashSet::new())
+    };
+    let doc = parse(
+        r#"
+        type Page<T> = [T];
+        type UserIds = Page<UUID>;
+        type Name = String;
+        newtype Email = String (format="email");
+        struct User {
+            name: Name = "anonymous",
+            email: Email,
+        }
+        "#,
+    )
+    .unwrap();
+    let super::UserDefinedType::Alias(user_ids) = doc.ns.types.get("UserIds").unwrap() else {
+        unreachable!()
+    };
+    let Type::Ref(TypeRef::Alias(page)) = &user_ids.borrow().type_ else {
+        unreachable!()
+    };
+    assert!(matches!(
+        page.target(),
+        Type::Array(array) if matches!(array.item_type, Type::UUID)
+    ));
+    let super::UserDefinedType::Newtype(email) = doc.ns.types.get("Email").unwrap() else {
+        unreachable!()
+    };
+    assert_eq!(email.borrow().field.format.as_deref(), Some("email"));
+    let invalid = [
+        "type A = A;",
+        "type A = [A];",
+        "type A = B; type B = Option<A>;",
+    ];
+    for idl in invalid {
+        let error = parse(idl).err().unwrap_or_else(|| panic!("{}", idl));
+        assert!(
+            matches!(*error.cause, ValidationErrorCause::RecursiveAlias { .. }),
+            "{}",
+            idl
+        );
+    }
+}
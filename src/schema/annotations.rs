@@ -0,0 +1,214 @@
+use std::time::Duration;
+
+use crate::idl;
+
+use super::errors::{ValidationError, ValidationErrorCause};
+
+/// Annotations of a service or method given as options in the IDL, e.g.
+/// `(auth="admin", timeout=5s, idempotent, rate_limit=10/s)`
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Annotations {
+    pub deprecated: Option<Deprecation>,
+    /// Role or scope required for calling the service or method
+    pub auth: Option<String>,
+    pub timeout: Option<Duration>,
+    /// Calling the method multiple times has the same effect as calling
+    /// it once
+    pub idempotent: bool,
+    pub rate_limit: Option<RateLimit>,
+}
+
+/// Deprecation of a type, field, variant, service or method given via
+/// the `deprecated` and `since` options
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Deprecation {
+    pub note: Option<String>,
+    /// Version which deprecated the item
+    pub since: Option<String>,
+}
+
+/// Maximum number of requests per duration
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RateLimit {
+    pub requests: u64,
+    pub per: Duration,
+}
+
+impl Annotations {
+    pub(crate) fn from_idl(ioptions: &[idl::FieldOption]) -> Result<Self, ValidationError> {
+        let (deprecated, ioptions) = Deprecation::from_idl(ioptions)?;
+        let mut annotations = Self {
+            deprecated,
+            ..Self::default()
+        };
+        for option in ioptions {
+            match (option.name.as_str(), &option.value) {
+                ("auth", idl::Value::String(auth)) => annotations.auth = Some(auth.clone()),
+                ("timeout", idl::Value::Duration(timeout)) => annotations.timeout = Some(*timeout),
+                ("idempotent", idl::Value::Boolean(idempotent)) => {
+                    annotations.idempotent = *idempotent
+                }
+                ("rate_limit", idl::Value::Rate(requests, per)) => {
+                    annotations.rate_limit = Some(RateLimit {
+                        requests: *requests,
+                        per: *per,
+                    })
+                }
+                (name @ ("auth" | "timeout" | "idempotent" | "rate_limit"), _) => {
+                    return Err(invalid_option_value(option, name))
+                }
+                (name, _) => {
+                    return Err(ValidationError {
+                        position: option.position,
+                        cause: Box::new(ValidationErrorCause::UnknownOption {
+                            name: name.to_owned(),
+                        }),
+                    })
+                }
+            }
+        }
+        Ok(annotations)
+    }
+    /// Returns `true` if no annotation is set
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+impl Deprecation {
+    /// Message combining the note and the version, e.g. for target
+    /// languages which do not support a version
+    pub fn message(&self) -> Option<String> {
+        match (&self.note, &self.since) {
+            (Some(note), Some(since)) => Some(format!("{} (since {})", note, since)),
+            (Some(note), None) => Some(note.clone()),
+            (None, Some(since)) => Some(format!("Deprecated since {}", since)),
+            (None, None) => None,
+        }
+    }
+    /// Get the deprecation given via the `deprecated` and `since` options.
+    /// All other options are returned for further processing.
+    pub(crate) fn from_idl(
+        ioptions: &[idl::FieldOption],
+    ) -> Result<(Option<Self>, Vec<&idl::FieldOption>), ValidationError> {
+        let mut deprecated = false;
+        let mut deprecation = Self::default();
+        let mut since_option = None;
+        let mut other = Vec::new();
+        for option in ioptions {
+            match (option.name.as_str(), &option.value) {
+                ("deprecated", idl::Value::Boolean(value)) => deprecated = *value,
+                ("deprecated", idl::Value::String(note)) => {
+                    deprecated = true;
+                    deprecation.note = Some(note.clone());
+                }
+                ("since", idl::Value::String(since)) => {
+                    deprecation.since = Some(since.clone());
+                    since_option = Some(option);
+                }
+                (name @ ("deprecated" | "since"), _) => {
+                    return Err(invalid_option_value(option, name))
+                }
+                _ => other.push(option),
+            }
+        }
+        // A version without a deprecation is most likely a mistake.
+        if let (false, Some(option)) = (deprecated, since_option) {
+            return Err(invalid_option_value(option, "since"));
+        }
+        Ok((deprecated.then_some(deprecation), other))
+    }
+    /// Get the deprecation of an item which does not support any other
+    /// options, e.g. structs and enum variants.
+    pub(crate) fn from_idl_exclusive(
+        ioptions: &[idl::FieldOption],
+    ) -> Result<Option<Self>, ValidationError> {
+        let (deprecation, other) = Self::from_idl(ioptions)?;
+        if let Some(option) = other.first() {
+            return Err(ValidationError {
+                position: option.position,
+                cause: Box::new(ValidationErrorCause::UnknownOption {
+                    name: option.name.clone(),
+                }),
+            });
+        }
+        Ok(deprecation)
+    }
+}
+
+fn invalid_option_value(option: &idl::FieldOption, name: &str) -> ValidationError {
+    ValidationError {
+        position: option.position,
+        cause: Box::new(ValidationErrorCause::InvalidOptionValue {
+            name: name.to_owned(),
+        }),
+    }
+}
+
+#[test]
+fn test_annotations_from_idl() {
+    use crate::common::FilePosition;
+    let option = |name: &str, value: idl::Value| idl::FieldOption {
+        position: FilePosition { line: 1, column: 1 },
+        name: name.to_owned(),
+        value,
+    };
+    let annotations = Annotations::from_idl(&[
+        option("deprecated", idl::Value::String("Use v2".to_owned())),
+        option("auth", idl::Value::String("admin".to_owned())),
+        option("timeout", idl::Value::Duration(Duration::from_secs(5))),
+        option("idempotent", idl::Value::Boolean(true)),
+        option("rate_limit", idl::Value::Rate(10, Duration::from_secs(1))),
+    ])
+    .unwrap();
+    assert_eq!(
+        annotations,
+        Annotations {
+            deprecated: Some(Deprecation {
+                note: Some("Use v2".to_owned()),
+                since: None,
+            }),
+            auth: Some("admin".to_owned()),
+            timeout: Some(Duration::from_secs(5)),
+            idempotent: true,
+            rate_limit: Some(RateLimit {
+                requests: 10,
+                per: Duration::from_secs(1),
+            }),
+        }
+    );
+    assert!(Annotations::from_idl(&[option("timeout", idl::Value::Integer(5))]).is_err());
+    assert!(Annotations::from_idl(&[option("retries", idl::Value::Integer(5))]).is_err());
+}
+
+#[test]
+fn test_deprecation_from_idl() {
+    use crate::common::FilePosition;
+    let option = |name: &str, value: idl::Value| idl::FieldOption {
+        position: FilePosition { line: 1, column: 1 },
+        name: name.to_owned(),
+        value,
+    };
+    assert_eq!(
+        Deprecation::from_idl_exclusive(&[
+            option("since", idl::Value::String("1.2".to_owned())),
+            option("deprecated", idl::Value::Boolean(true)),
+        ])
+        .unwrap(),
+        Some(Deprecation {
+            note: None,
+            since: Some("1.2".to_owned()),
+        })
+    );
+    assert_eq!(
+        Deprecation::from_idl_exclusive(&[option("deprecated", idl::Value::Boolean(false))])
+            .unwrap(),
+        None
+    );
+    assert!(Deprecation::from_idl_exclusive(&[option(
+        "since",
+        idl::Value::String("1.2".to_owned())
+    )])
+    .is_err());
+    assert!(Deprecation::from_idl_exclusive(&[option("help", idl::Value::Boolean(true))]).is_err());
+}
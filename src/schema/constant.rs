@@ -0,0 +1,179 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::common::FilePosition;
+use crate::idl;
+
+use super::errors::{ValidationError, ValidationErrorCause};
+use super::fqtn::FQTN;
+use super::namespace::Namespace;
+use super::r#type::Type;
+
+pub struct Constant {
+    pub fqtn: FQTN,
+    pub type_: Type,
+    pub value: idl::Value,
+    pub position: FilePosition,
+}
+
+impl Constant {
+    /// Create a constant from its IDL representation. Constants are
+    /// converted before all other types and can therefore only be of a
+    /// builtin type like `Integer` or `String`.
+    pub(crate) fn from_idl(
+        iconst: &idl::Constant,
+        ns: &Namespace,
+        builtin_types: &HashSet<String>,
+    ) -> Result<Self, ValidationError> {
+        let type_ = Type::from_idl(&iconst.type_, ns, &[], builtin_types);
+        if !type_.accepts_value(&iconst.value) {
+            return Err(ValidationError {
+                position: iconst.position,
+                cause: Box::new(ValidationErrorCause::InvalidConstant {
+                    name: iconst.name.clone(),
+                }),
+            });
+        }
+        Ok(Self {
+            fqtn: FQTN::new(&iconst.name, ns),
+            type_,
+            value: iconst.value.clone(),
+            position: iconst.position,
+        })
+    }
+}
+
+/// Values of all constants of the document. Constants are added before
+/// the types are converted so they can be used in field options and
+/// default values regardless of the order of their declaration.
+#[derive(Default)]
+pub(crate) struct ConstantMap {
+    map: HashMap<FQTN, idl::Value>,
+}
+
+impl ConstantMap {
+    pub(crate) fn insert(&mut self, constant: &Constant) {
+        self.map
+            .insert(constant.fqtn.clone(), constant.value.clone());
+    }
+    /// Look up a constant referenced from the namespace `ns`. Absolute
+    /// references start at the root namespace. Relative references are
+    /// looked up in `ns` first and then in its parent namespaces.
+    pub(crate) fn get(&self, constant: &idl::ConstantRef, ns: &Namespace) -> Option<&idl::Value> {
+        let lookup = |path: &[String]| {
+            self.map.get(&FQTN {
+                ns: path.iter().chain(constant.ns.iter()).cloned().collect(),
+                name: constant.name.clone(),
+            })
+        };
+        if constant.abs {
+            return lookup(&[]);
+        }
+        (0..=ns.path.len())
+            .rev()
+            .find_map(|depth| lookup(&ns.path[..depth]))
+    }
+}
+
+#[test]
+fn test_schema_constant() {
+    let parse = |idl: &str| {
+        let idocs = [crate::idl::parse_document(idl).unwrap()];
+        super::Document::from_idl(idocs.iter(), &HashSet::new())
+    };
+    let doc = parse(
+        r#"
+        struct User { name: String (length=MIN..MAX) }
+        const MIN: Integer = 1;
+        const MAX: UInt8 = 50;
+        const NAME: String = "webwire";
+        "#,
+    )
+    .unwrap();
+    assert_eq!(
+        doc.ns.constants.keys().collect::<Vec<_>>(),
+        vec!["MAX", "MIN", "NAME"]
+    );
+    let super::UserDefinedType::Struct(user) = &doc.ns.types["User"] else {
+        unreachable!()
+    };
+    assert_eq!(user.borrow().fields[0].length, (Some(1), Some(50)));
+    let error = |idl: &str| *parse(idl).err().unwrap().cause;
+    assert!(matches!(
+        error("const A: UInt8 = 256;"),
+        ValidationErrorCause::InvalidConstant { name } if name == "A"
+    ));
+    assert!(matches!(
+        error("enum E { A } const A: E = A;"),
+        ValidationErrorCause::InvalidConstant { name } if name == "A"
+    ));
+    assert!(matches!(
+        error("struct A { a: String (length=0..MAX) }"),
+        ValidationErrorCause::NoSuchConstant { name } if name == "MAX"
+    ));
+    assert!(matches!(
+        error("const MAX: String = \"50\"; struct A { a: String (length=0..MAX) }"),
+        ValidationErrorCause::InvalidOptionValue { name } if name == "length"
+    ));
+    assert!(matches!(
+        error("const A: Integer = 1; const A: Integer = 2;"),
+        ValidationErrorCause::DuplicateIdentifier { identifier } if identifier == "A"
+    ));
+}
+
+#[test]
+fn test_schema_constant_lookup() {
+    let parse = |idl: &str| {
+        let idocs = [crate::idl::parse_document(idl).unwrap()];
+        super::Document::from_idl(idocs.iter(), &HashSet::new())
+    };
+    let doc = parse(
+        r#"
+        const MAX: Integer = 10;
+        const LIMIT: Integer = 100;
+        namespace n {
+            const LIMIT: Integer = 20;
+            struct A {
+                root: String (length=0..MAX),
+                shadowed: String (length=0..LIMIT),
+                absolute: String (length=0..::LIMIT),
+                default: Integer = MAX,
+            }
+            namespace m {
+                const MIN: Integer = 1;
+            }
+        }
+        struct B {
+            nested: String (length=n::m::MIN..n::LIMIT),
+            default: Integer (range=0..LIMIT) = n::LIMIT,
+        }
+        "#,
+    )
+    .unwrap();
+    let super::UserDefinedType::Struct(a) = &doc.ns.namespaces["n"].types["A"] else {
+        unreachable!()
+    };
+    let a = a.borrow();
+    assert_eq!(a.fields[0].length, (Some(0), Some(10)));
+    assert_eq!(a.fields[1].length, (Some(0), Some(20)));
+    assert_eq!(a.fields[2].length, (Some(0), Some(100)));
+    assert_eq!(a.fields[3].default, Some(idl::Value::Integer(10)));
+    let super::UserDefinedType::Struct(b) = &doc.ns.types["B"] else {
+        unreachable!()
+    };
+    let b = b.borrow();
+    assert_eq!(b.fields[0].length, (Some(1), Some(20)));
+    assert_eq!(b.fields[1].default, Some(idl::Value::Integer(20)));
+    let error = |idl: &str| *parse(idl).err().unwrap().cause;
+    assert!(matches!(
+        error("namespace n { const MAX: Integer = 1; } struct A { a: String (length=0..MAX) }"),
+        ValidationErrorCause::NoSuchConstant { name } if name == "MAX"
+    ));
+    assert!(matches!(
+        error("struct A { a: Integer = n::MAX }"),
+        ValidationErrorCause::NoSuchConstant { name } if name == "n::MAX"
+    ));
+    assert!(matches!(
+        error("const NAME: String = \"x\"; struct A { a: Integer = NAME }"),
+        ValidationErrorCause::InvalidDefaultValue { field } if field == "a"
+    ));
+}
@@ -1,7 +1,8 @@
-use std::collections::HashMap;
+use std::collections::{BTreeSet, HashSet};
 
 use super::errors::ValidationError;
 use super::namespace::Namespace;
+use super::warnings::{check_namespace, Warning};
 
 #[derive(Default)]
 pub struct Document {
@@ -11,10 +12,23 @@ pub struct Document {
 impl Document {
     pub fn from_idl<'a>(
         idocs: impl Iterator<Item = &'a crate::idl::Document>,
-        builtin_types: &HashMap<String, String>,
+        builtin_types: &HashSet<String>,
     ) -> Result<Self, ValidationError> {
         Ok(Self {
             ns: Namespace::from_idl(idocs.map(|idoc| &idoc.ns), builtin_types)?,
         })
     }
+    /// Names of the builtin types which are used by the document
+    pub fn builtin_types(&self) -> BTreeSet<String> {
+        let mut names = BTreeSet::new();
+        self.ns.collect_builtin_types(&mut names);
+        names
+    }
+    /// Issues which do not prevent code generation like the use of
+    /// deprecated types
+    pub fn warnings(&self) -> Vec<Warning> {
+        let mut warnings = Vec::new();
+        check_namespace(&self.ns, &mut warnings);
+        warnings
+    }
 }
@@ -1,10 +1,11 @@
 use std::cell::RefCell;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::rc::Rc;
 
 use crate::common::FilePosition;
 use crate::idl;
 
+use super::annotations::Deprecation;
 use super::errors::{ValidationError, ValidationErrorCause};
 use super::fqtn::FQTN;
 use super::namespace::Namespace;
@@ -17,6 +18,7 @@ pub struct Enum {
     pub fqtn: FQTN,
     pub generics: Vec<String>,
     pub extends: Option<TypeRef>,
+    pub deprecated: Option<Deprecation>,
     pub variants: Vec<EnumVariant>,
     pub all_variants: Vec<EnumVariant>,
 }
@@ -25,36 +27,41 @@ pub struct Enum {
 pub struct EnumVariant {
     pub name: String,
     pub value_type: Option<Type>,
+    pub deprecated: Option<Deprecation>,
 }
 
 impl Enum {
     pub(crate) fn from_idl(
         ienum: &idl::Enum,
         ns: &Namespace,
-        builtin_types: &HashMap<String, String>,
-    ) -> Self {
+        builtin_types: &HashSet<String>,
+    ) -> Result<Self, ValidationError> {
         let variants = ienum
             .variants
             .iter()
-            .map(|ivariant| EnumVariant {
-                name: ivariant.name.clone(),
-                value_type: ivariant
-                    .value_type
-                    .as_ref()
-                    .map(|itype| Type::from_idl(itype, ns, builtin_types)),
+            .map(|ivariant| {
+                Ok(EnumVariant {
+                    name: ivariant.name.clone(),
+                    value_type: ivariant
+                        .value_type
+                        .as_ref()
+                        .map(|itype| Type::from_idl(itype, ns, &ienum.generics, builtin_types)),
+                    deprecated: Deprecation::from_idl_exclusive(&ivariant.options)?,
+                })
             })
-            .collect();
+            .collect::<Result<_, _>>()?;
         let extends = ienum
             .extends
             .as_ref()
-            .map(|itype| TypeRef::from_idl(itype, ns, builtin_types));
-        Self {
+            .map(|itype| TypeRef::from_idl(itype, ns, &ienum.generics, builtin_types));
+        Ok(Self {
             fqtn: FQTN::new(&ienum.name, ns),
             generics: ienum.generics.clone(),
             extends,
+            deprecated: Deprecation::from_idl_exclusive(&ienum.options)?,
             variants,
             all_variants: Vec::new(),
-        }
+        })
     }
     pub(crate) fn resolve(&mut self, type_map: &TypeMap) -> Result<(), ValidationError> {
         for variant in self.variants.iter_mut() {
@@ -72,14 +79,22 @@ impl Enum {
         let mut variants = self.variants.clone();
         if let Some(extends) = &self.extends {
             if let TypeRef::Enum(extends_enum) = extends {
-                variants.extend(
-                    extends_enum
-                        .enum_
-                        .upgrade()
-                        .unwrap()
-                        .borrow()
-                        .resolve_extends()?,
-                );
+                let extends_enum = extends_enum.enum_.upgrade().unwrap();
+                let extends_enum = extends_enum.borrow();
+                // Inherited variants refer to the generics of the extended
+                // enum which need to be replaced by the actual types.
+                let generics = extends_enum
+                    .generics
+                    .iter()
+                    .cloned()
+                    .zip(extends.generics().iter().cloned())
+                    .collect::<HashMap<_, _>>();
+                variants.extend(extends_enum.resolve_extends()?.into_iter().map(|variant| {
+                    EnumVariant {
+                        value_type: variant.value_type.as_ref().map(|t| t.substitute(&generics)),
+                        ..variant
+                    }
+                }));
             } else {
                 return Err(ValidationError {
                     position: FilePosition { line: 0, column: 0 },
@@ -109,8 +124,8 @@ fn test_schema_enum_extends() {
         enum Bar extends Foo { Bar }
     ";
     let idoc = crate::idl::parse_document(idl).unwrap();
-    let idocs = vec![idoc];
-    let builtin_types = HashMap::default();
+    let idocs = [idoc];
+    let builtin_types = HashSet::default();
     let doc = crate::schema::Document::from_idl(idocs.iter(), &builtin_types).unwrap();
     let foo = doc.ns.types.get("Bar").unwrap();
     match foo {
@@ -133,8 +148,8 @@ fn test_schema_enum_extends_different_namespace() {
         }
     ";
     let idoc = crate::idl::parse_document(idl).unwrap();
-    let idocs = vec![idoc];
-    let builtin_types = HashMap::default();
+    let idocs = [idoc];
+    let builtin_types = HashSet::default();
     let doc = crate::schema::Document::from_idl(idocs.iter(), &builtin_types).unwrap();
     let bar_ns = doc.ns.namespaces.get("bar").unwrap();
     let bar_type = bar_ns.types.get("Bar").unwrap();
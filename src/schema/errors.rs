@@ -31,13 +31,53 @@ pub enum ValidationErrorCause {
         r#struct: FQTN,
         field: String,
     },
+    /// Fields can only be excluded from fieldsets selecting all fields
+    /// using `..`
+    ExcludeWithoutIncludeAll {
+        fieldset: FQTN,
+    },
     EnumExtendsNonEnum {
         r#enum: FQTN,
         extends: FQTN,
     },
+    StructExtendsNonStruct {
+        r#struct: FQTN,
+        extends: FQTN,
+    },
+    RecursiveExtends {
+        fqtn: FQTN,
+    },
     UnknownOption {
         name: String,
     },
+    InvalidOptionValue {
+        name: String,
+    },
+    InvalidDefaultValue {
+        field: String,
+    },
+    InvalidConstant {
+        name: String,
+    },
+    NoSuchConstant {
+        name: String,
+    },
+    RecursiveAlias {
+        fqtn: FQTN,
+    },
+    /// Inline structs and enums are only allowed in fields, enum variants
+    /// and methods.
+    InlineTypeNotAllowed {
+        name: String,
+    },
+    /// The error type of a method is not an enum
+    ThrowsNonEnum {
+        method: String,
+    },
+    /// Streaming methods can not declare an error type
+    StreamingMethodThrows {
+        method: String,
+    },
 }
 
 impl fmt::Display for ValidationError {
@@ -1,13 +1,14 @@
-use std::collections::HashMap;
+use std::collections::HashSet;
 
 use crate::common::FilePosition;
 use crate::idl;
 
+use super::constant::ConstantMap;
 use super::errors::{ValidationError, ValidationErrorCause};
 use super::fqtn::FQTN;
 use super::namespace::Namespace;
-use super::r#struct::Field;
-use super::r#type::TypeRef;
+use super::r#struct::{Field, FieldOptions};
+use super::r#type::{Type, TypeRef};
 use super::typemap::TypeMap;
 
 pub struct Fieldset {
@@ -15,59 +16,143 @@ pub struct Fieldset {
     pub generics: Vec<String>,
     pub r#struct: TypeRef,
     pub fields: Vec<FieldsetField>,
+    /// Whether all fields of the struct are selected
+    include_all: bool,
+    /// Fields of the struct which are not selected
+    exclude: Vec<String>,
 }
 
 pub struct FieldsetField {
     pub name: String,
     pub optional: bool,
     pub field: Option<Field>,
+    /// Type replacing the one of the struct field
+    type_: Option<Type>,
+    /// Options replacing the ones of the struct field
+    options: FieldOptions,
 }
 
 impl Fieldset {
     pub(crate) fn from_idl(
         ifieldset: &idl::Fieldset,
         ns: &Namespace,
-        builtin_types: &HashMap<String, String>,
-    ) -> Self {
-        Self {
-            fqtn: FQTN::new(&ifieldset.name, ns),
-            generics: ifieldset.generics.clone(),
-            r#struct: TypeRef::from_idl(&ifieldset.r#struct, ns, builtin_types),
-            fields: ifieldset
-                .fields
-                .iter()
-                .map(|ifield| FieldsetField {
-                    name: ifield.name.clone(),
-                    optional: ifield.optional,
-                    field: None,
-                })
-                .collect(),
+        constants: &ConstantMap,
+        builtin_types: &HashSet<String>,
+    ) -> Result<Self, ValidationError> {
+        let mut fields = Vec::new();
+        for ifield in ifieldset.fields.iter() {
+            fields.push(FieldsetField {
+                name: ifield.name.clone(),
+                optional: ifield.optional,
+                field: None,
+                type_: ifield
+                    .type_
+                    .as_ref()
+                    .map(|t| Type::from_idl(t, ns, &ifieldset.generics, builtin_types)),
+                options: FieldOptions::from_idl(&ifield.options, ns, constants)?,
+            });
         }
+        let fqtn = FQTN::new(&ifieldset.name, ns);
+        if !ifieldset.include_all && !ifieldset.exclude.is_empty() {
+            return Err(ValidationError {
+                position: ifieldset.position,
+                cause: Box::new(ValidationErrorCause::ExcludeWithoutIncludeAll { fieldset: fqtn }),
+            });
+        }
+        let mut names = HashSet::new();
+        for name in ifieldset
+            .fields
+            .iter()
+            .map(|f| &f.name)
+            .chain(ifieldset.exclude.iter())
+        {
+            if !names.insert(name) {
+                return Err(ValidationError {
+                    position: ifieldset.position,
+                    cause: Box::new(ValidationErrorCause::DuplicateIdentifier {
+                        identifier: name.clone(),
+                    }),
+                });
+            }
+        }
+        Ok(Self {
+            fqtn,
+            generics: ifieldset.generics.clone(),
+            r#struct: TypeRef::from_idl(
+                &ifieldset.r#struct,
+                ns,
+                &ifieldset.generics,
+                builtin_types,
+            ),
+            include_all: ifieldset.include_all,
+            exclude: ifieldset.exclude.clone(),
+            fields,
+        })
     }
     pub(crate) fn resolve(&mut self, type_map: &TypeMap) -> Result<(), ValidationError> {
         self.r#struct.resolve(type_map)?;
         if let TypeRef::Struct(struct_) = &self.r#struct {
             let struct_rc = struct_.struct_.upgrade().unwrap();
             let struct_borrow = struct_rc.borrow();
-            let field_map = struct_borrow
+            // The struct might not be resolved yet so its fields are
+            // collected and resolved here.
+            let struct_fields =
+                struct_borrow.collect_fields(type_map, &mut vec![struct_borrow.fqtn.clone()])?;
+            let no_such_field = |name: &str| ValidationError {
+                position: FilePosition { line: 0, column: 0 },
+                cause: Box::new(ValidationErrorCause::NoSuchField {
+                    fieldset: self.fqtn.clone(),
+                    r#struct: struct_borrow.fqtn.clone(),
+                    field: name.to_owned(),
+                }),
+            };
+            for name in self
                 .fields
                 .iter()
-                .map(|f| (f.name.clone(), f))
-                .collect::<HashMap<_, _>>();
-            for field in self.fields.iter_mut() {
-                if let Some(&struct_field) = field_map.get(&field.name) {
-                    field.field.replace(struct_field.clone());
-                } else {
-                    return Err(ValidationError {
-                        position: FilePosition { line: 0, column: 0 },
-                        cause: Box::new(ValidationErrorCause::NoSuchField {
-                            fieldset: self.fqtn.clone(),
-                            r#struct: struct_borrow.fqtn.clone(),
-                            field: field.name.clone(),
-                        }),
-                    });
+                .map(|f| &f.name)
+                .chain(self.exclude.iter())
+            {
+                if !struct_fields.iter().any(|f| &f.name == name) {
+                    return Err(no_such_field(name));
                 }
             }
+            if self.include_all {
+                // All fields of the struct are selected in the order of
+                // the struct. Fields listed explicitly override them.
+                let mut listed = std::mem::take(&mut self.fields);
+                for struct_field in struct_fields.iter() {
+                    if self.exclude.contains(&struct_field.name) {
+                        continue;
+                    }
+                    let field = match listed.iter().position(|f| f.name == struct_field.name) {
+                        Some(index) => listed.remove(index),
+                        None => FieldsetField {
+                            name: struct_field.name.clone(),
+                            optional: struct_field.optional,
+                            field: None,
+                            type_: None,
+                            options: FieldOptions::default(),
+                        },
+                    };
+                    self.fields.push(field);
+                }
+            }
+            for field in self.fields.iter_mut() {
+                let struct_field = struct_fields.iter().find(|f| f.name == field.name).unwrap();
+                let mut type_ = field
+                    .type_
+                    .clone()
+                    .unwrap_or_else(|| struct_field.type_.clone());
+                type_.resolve(type_map)?;
+                // Default values are only applied to structs.
+                let mut resolved = Field {
+                    type_,
+                    default: None,
+                    ..struct_field.clone()
+                };
+                field.options.apply(&mut resolved);
+                field.field.replace(resolved);
+            }
         } else {
             return Err(ValidationError {
                 position: FilePosition { line: 0, column: 0 },
@@ -77,7 +162,52 @@ impl Fieldset {
                 }),
             });
         }
-        // FIXME fields need to be resolved, too.
         Ok(())
     }
 }
+
+#[test]
+fn test_schema_fieldset_include_all() {
+    let parse = |idl: &str| {
+        let idocs = [crate::idl::parse_document(idl).unwrap()];
+        super::Document::from_idl(idocs.iter(), &HashSet::new())
+    };
+    let doc = parse(
+        r#"
+        struct User {
+            id: UUID,
+            name: String (length=1..64),
+            email?: String,
+            password: String,
+        }
+        fieldset UserPublic for User { .., -password, name (length=1..10), email: UUID }
+        "#,
+    )
+    .unwrap();
+    let super::UserDefinedType::Fieldset(fieldset) = doc.ns.types.get("UserPublic").unwrap() else {
+        unreachable!()
+    };
+    let fieldset = fieldset.borrow();
+    assert_eq!(
+        fieldset
+            .fields
+            .iter()
+            .map(|f| (f.name.as_str(), f.optional))
+            .collect::<Vec<_>>(),
+        [("id", false), ("name", false), ("email", false)]
+    );
+    let name = fieldset.fields[1].field.as_ref().unwrap();
+    assert_eq!(name.length, (Some(1), Some(10)));
+    let email = fieldset.fields[2].field.as_ref().unwrap();
+    assert!(matches!(email.type_, Type::UUID));
+    let invalid = [
+        "struct A { a: String } fieldset B for A { .., -b }",
+        "struct A { a: String } fieldset B for A { b (length=1..10) }",
+        "struct A { a: String } fieldset B for A { .., a, -a }",
+        "struct A { a: String } fieldset B for A { a (foo=1) }",
+        "struct A { a: String, b: String } fieldset B for A { a, -b }",
+    ];
+    for idl in invalid {
+        assert!(parse(idl).is_err(), "{}", idl);
+    }
+}
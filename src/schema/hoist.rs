@@ -0,0 +1,342 @@
+//! Inline structs and enums (`address: { street: String }`) are hoisted
+//! into named types before the parts of a namespace are converted. The
+//! names are derived from the item containing the inline type, e.g.
+//! `UserAddress` for the field `address` of the struct `User`. Hoisted
+//! types get the type parameters of the containing item which they use,
+//! so `struct Page<T> { meta: { item: T } }` refers to `PageMeta<T>`.
+
+use heck::ToUpperCamelCase;
+
+use crate::common::FilePosition;
+use crate::idl;
+
+use super::errors::{ValidationError, ValidationErrorCause};
+use super::service::input_struct_name;
+
+/// Replace the inline types of a namespace part by references to named
+/// types. Returns the rewritten part or `None` if it does not contain
+/// inline types followed by the hoisted types.
+pub(crate) fn hoist_inline_types(
+    ipart: &idl::NamespacePart,
+) -> Result<(Option<idl::NamespacePart>, Vec<idl::NamespacePart>), ValidationError> {
+    let mut hoisted = Vec::new();
+    let part = match ipart {
+        idl::NamespacePart::Struct(istruct) => {
+            let mut istruct = istruct.clone();
+            hoist_fields(
+                &istruct.name,
+                &istruct.generics,
+                &mut istruct.fields,
+                &mut hoisted,
+            );
+            idl::NamespacePart::Struct(istruct)
+        }
+        idl::NamespacePart::Enum(ienum) => {
+            let mut ienum = ienum.clone();
+            hoist_variants(
+                &ienum.name,
+                &ienum.generics,
+                &mut ienum.variants,
+                ienum.position,
+                &mut hoisted,
+            );
+            idl::NamespacePart::Enum(ienum)
+        }
+        idl::NamespacePart::Fieldset(ifieldset) => {
+            let mut ifieldset = ifieldset.clone();
+            for field in ifieldset.fields.iter_mut() {
+                if let Some(type_) = &mut field.type_ {
+                    hoist_type(
+                        type_,
+                        &child_name(&ifieldset.name, &field.name),
+                        &ifieldset.generics,
+                        ifieldset.position,
+                        &mut hoisted,
+                    );
+                }
+            }
+            idl::NamespacePart::Fieldset(ifieldset)
+        }
+        idl::NamespacePart::Service(iservice) => {
+            let mut iservice = iservice.clone();
+            for method in iservice.methods.iter_mut() {
+                let name = child_name(&iservice.name, &method.name);
+                if let Some(params) = &mut method.params {
+                    hoist_fields(&name, &[], params, &mut hoisted);
+                }
+                if let Some(input) = &mut method.input {
+                    let name = input_struct_name(&iservice.name, &method.name);
+                    hoist_type(input, &name, &[], iservice.position, &mut hoisted);
+                }
+                if let Some(output) = &mut method.output {
+                    let name = format!("{}Output", name);
+                    hoist_type(output, &name, &[], iservice.position, &mut hoisted);
+                }
+                if let Some(throws) = &mut method.throws {
+                    let name = format!("{}Error", name);
+                    hoist_type(throws, &name, &[], iservice.position, &mut hoisted);
+                }
+            }
+            idl::NamespacePart::Service(iservice)
+        }
+        idl::NamespacePart::Alias(idl::Alias {
+            name,
+            type_,
+            position,
+            ..
+        })
+        | idl::NamespacePart::Newtype(idl::Newtype {
+            name,
+            type_,
+            position,
+            ..
+        }) => {
+            if has_inline_type(type_) {
+                return Err(ValidationError {
+                    position: *position,
+                    cause: Box::new(ValidationErrorCause::InlineTypeNotAllowed {
+                        name: name.clone(),
+                    }),
+                });
+            }
+            return Ok((None, hoisted));
+        }
+        idl::NamespacePart::Constant(_) | idl::NamespacePart::Namespace(_) => {
+            return Ok((None, hoisted));
+        }
+    };
+    if hoisted.is_empty() {
+        Ok((None, hoisted))
+    } else {
+        Ok((Some(part), hoisted))
+    }
+}
+
+fn child_name(parent: &str, child: &str) -> String {
+    format!("{}{}", parent, child.to_upper_camel_case())
+}
+
+fn has_inline_type(type_: &idl::Type) -> bool {
+    match type_ {
+        idl::Type::Ref(typeref) => typeref.generics.iter().any(has_inline_type),
+        idl::Type::Array(item_type) => has_inline_type(item_type),
+        idl::Type::Map(key_type, value_type) => {
+            has_inline_type(key_type) || has_inline_type(value_type)
+        }
+        idl::Type::Struct(_) | idl::Type::Enum(_) => true,
+    }
+}
+
+/// Whether the type refers to the type parameter `generic`
+fn uses_generic(type_: &idl::Type, generic: &str) -> bool {
+    match type_ {
+        idl::Type::Ref(typeref) => {
+            (!typeref.abs
+                && typeref.ns.is_empty()
+                && typeref.generics.is_empty()
+                && typeref.name == generic)
+                || typeref.generics.iter().any(|t| uses_generic(t, generic))
+        }
+        idl::Type::Array(item_type) => uses_generic(item_type, generic),
+        idl::Type::Map(key_type, value_type) => {
+            uses_generic(key_type, generic) || uses_generic(value_type, generic)
+        }
+        idl::Type::Struct(fields) => fields.iter().any(|f| uses_generic(&f.type_, generic)),
+        idl::Type::Enum(variants) => variants
+            .iter()
+            .filter_map(|v| v.value_type.as_ref())
+            .any(|t| uses_generic(t, generic)),
+    }
+}
+
+fn hoist_fields(
+    parent: &str,
+    generics: &[String],
+    fields: &mut [idl::Field],
+    hoisted: &mut Vec<idl::NamespacePart>,
+) {
+    for field in fields.iter_mut() {
+        let name = child_name(parent, &field.name);
+        hoist_type(&mut field.type_, &name, generics, field.position, hoisted);
+    }
+}
+
+fn hoist_variants(
+    parent: &str,
+    generics: &[String],
+    variants: &mut [idl::EnumVariant],
+    position: FilePosition,
+    hoisted: &mut Vec<idl::NamespacePart>,
+) {
+    for variant in variants.iter_mut() {
+        if let Some(value_type) = &mut variant.value_type {
+            let name = child_name(parent, &variant.name);
+            hoist_type(value_type, &name, generics, position, hoisted);
+        }
+    }
+}
+
+/// Replace the inline types of `type_` by a reference to a type called
+/// `name`. Inline types within generic arguments, arrays and maps share
+/// the name so using more than one of them causes a duplicate identifier.
+/// `generics` are the type parameters of the containing item.
+fn hoist_type(
+    type_: &mut idl::Type,
+    name: &str,
+    generics: &[String],
+    position: FilePosition,
+    hoisted: &mut Vec<idl::NamespacePart>,
+) {
+    let generics = generics
+        .iter()
+        .filter(|generic| uses_generic(type_, generic))
+        .cloned()
+        .collect::<Vec<_>>();
+    match type_ {
+        idl::Type::Ref(typeref) => {
+            for type_ in typeref.generics.iter_mut() {
+                hoist_type(type_, name, &generics, position, hoisted);
+            }
+            return;
+        }
+        idl::Type::Array(item_type) => {
+            hoist_type(item_type, name, &generics, position, hoisted);
+            return;
+        }
+        idl::Type::Map(key_type, value_type) => {
+            hoist_type(key_type, name, &generics, position, hoisted);
+            hoist_type(value_type, name, &generics, position, hoisted);
+            return;
+        }
+        idl::Type::Struct(fields) => {
+            let mut fields = std::mem::take(fields);
+            hoist_fields(name, &generics, &mut fields, hoisted);
+            hoisted.push(idl::NamespacePart::Struct(idl::Struct {
+                name: name.to_owned(),
+                generics: generics.clone(),
+                extends: None,
+                options: vec![],
+                fields,
+                position,
+            }));
+        }
+        idl::Type::Enum(variants) => {
+            let mut variants = std::mem::take(variants);
+            hoist_variants(name, &generics, &mut variants, position, hoisted);
+            hoisted.push(idl::NamespacePart::Enum(idl::Enum {
+                name: name.to_owned(),
+                generics: generics.clone(),
+                extends: None,
+                options: vec![],
+                variants,
+                position,
+            }));
+        }
+    }
+    *type_ = idl::Type::Ref(idl::TypeRef {
+        abs: false,
+        ns: vec![],
+        name: name.to_owned(),
+        generics: generics
+            .into_iter()
+            .map(|generic| {
+                idl::Type::Ref(idl::TypeRef {
+                    abs: false,
+                    ns: vec![],
+                    name: generic,
+                    generics: vec![],
+                })
+            })
+            .collect(),
+    });
+}
+
+#[test]
+fn test_hoist_inline_types() {
+    use std::collections::HashSet;
+
+    use super::r#type::{Type, UserDefinedType};
+    let parse = |idl: &str| {
+        let idocs = [crate::idl::parse_document(idl).unwrap()];
+        super::Document::from_idl(idocs.iter(), &HashSet::new())
+    };
+    let doc = parse(
+        r#"
+        struct User {
+            address: {
+                street: String,
+                geo?: { lat: Float, lng: Float },
+            },
+            status: enum { Active, Banned({ reason: String }) },
+            tags: [{ name: String }],
+        }
+        service Users {
+            search(filter: { name?: String }) -> [{ id: UUID }],
+        }
+        "#,
+    )
+    .unwrap();
+    assert_eq!(
+        doc.ns.types.keys().collect::<Vec<_>>(),
+        vec![
+            "User",
+            "UserAddress",
+            "UserAddressGeo",
+            "UserStatus",
+            "UserStatusBanned",
+            "UserTags",
+            "UsersSearchFilter",
+            "UsersSearchInput",
+            "UsersSearchOutput",
+        ]
+    );
+    let UserDefinedType::Struct(user) = &doc.ns.types["User"] else {
+        unreachable!()
+    };
+    assert!(matches!(
+        &user.borrow().fields[2].type_,
+        Type::Array(array) if matches!(&array.item_type, Type::Ref(typeref) if typeref.fqtn().name == "UserTags")
+    ));
+    // Hoisted types get the type parameters they use.
+    let doc = parse(
+        r#"
+        struct Page<T, M> {
+            meta: { item: T, tags: [{ value: T }], total: Integer },
+            info: { total: Integer },
+            state: enum { Done({ meta: M }), Failed },
+        }
+        "#,
+    )
+    .unwrap();
+    let generics = |name: &str| match &doc.ns.types[name] {
+        UserDefinedType::Struct(struct_) => struct_.borrow().generics.clone(),
+        UserDefinedType::Enum(enum_) => enum_.borrow().generics.clone(),
+        _ => unreachable!(),
+    };
+    assert_eq!(generics("PageMeta"), vec!["T"]);
+    assert_eq!(generics("PageMetaTags"), vec!["T"]);
+    assert!(generics("PageInfo").is_empty());
+    assert_eq!(generics("PageState"), vec!["M"]);
+    assert_eq!(generics("PageStateDone"), vec!["M"]);
+    let UserDefinedType::Struct(page) = &doc.ns.types["Page"] else {
+        unreachable!()
+    };
+    assert!(matches!(
+        &page.borrow().fields[0].type_,
+        Type::Ref(typeref) if typeref.fqtn().name == "PageMeta"
+            && matches!(typeref.generics()[..], [Type::Generic(ref name)] if name == "T")
+    ));
+    let error = |idl: &str| *parse(idl).err().unwrap().cause;
+    assert!(matches!(
+        error("struct User { address: { street: String } } struct UserAddress {}"),
+        ValidationErrorCause::DuplicateIdentifier { identifier } if identifier == "UserAddress"
+    ));
+    assert!(matches!(
+        error("struct User { names: { { first: String }: { last: String } } }"),
+        ValidationErrorCause::DuplicateIdentifier { identifier } if identifier == "UserNames"
+    ));
+    assert!(matches!(
+        error("type Address = { street: String };"),
+        ValidationErrorCause::InlineTypeNotAllowed { name } if name == "Address"
+    ));
+}
@@ -1,21 +1,31 @@
+mod alias;
+mod annotations;
+mod constant;
 mod document;
 mod r#enum;
 mod errors;
 mod fieldset;
 mod fqtn;
+mod hoist;
 mod namespace;
 mod options;
 mod service;
 mod r#struct;
 mod r#type;
 mod typemap;
+mod warnings;
 
+pub use alias::{Alias, Newtype};
+pub use annotations::{Annotations, Deprecation, RateLimit};
+pub use constant::Constant;
 pub use document::Document;
 pub use errors::ValidationError;
 pub use fieldset::{Fieldset, FieldsetField};
 pub use fqtn::FQTN;
 pub use namespace::Namespace;
+pub use options::Range;
 pub use r#enum::{Enum, EnumVariant};
 pub use r#struct::{Field, Struct};
 pub use r#type::{Type, TypeRef, UserDefinedType};
 pub use service::{Method, Service};
+pub use warnings::{Warning, WarningCause};
@@ -1,116 +1,286 @@
 use std::cell::RefCell;
-use std::collections::HashMap;
 use std::collections::{btree_map::Entry as BTreeMapEntry, BTreeMap};
+use std::collections::{BTreeSet, HashSet};
 use std::rc::Rc;
 
 use crate::common::FilePosition;
 use crate::idl;
 
+use super::alias::{Alias, Newtype};
+use super::constant::{Constant, ConstantMap};
 use super::errors::{ValidationError, ValidationErrorCause};
 use super::fieldset::Fieldset;
+use super::hoist::hoist_inline_types;
 use super::r#enum::Enum;
 use super::r#struct::Struct;
-use super::r#type::UserDefinedType;
+use super::r#type::{Type, UserDefinedType};
 use super::service::Service;
 use super::typemap::TypeMap;
 
 #[derive(Default)]
 pub struct Namespace {
     pub path: Vec<String>,
+    pub constants: BTreeMap<String, Constant>,
     pub types: BTreeMap<String, UserDefinedType>,
     pub services: BTreeMap<String, Service>,
     pub namespaces: BTreeMap<String, Namespace>,
 }
 
 impl Namespace {
+    pub(crate) fn collect_builtin_types(&self, names: &mut BTreeSet<String>) {
+        self.visit_types(&mut |type_| {
+            if let Type::Builtin(name) = type_ {
+                names.insert(name.clone());
+            }
+        });
+    }
+    /// Calls `visit` for every type used by the types and services of
+    /// this namespace and its child namespaces including the types
+    /// nested in other types.
+    pub(crate) fn visit_types<F: FnMut(&Type)>(&self, visit: &mut F) {
+        let mut types = Vec::new();
+        for type_ in self.types.values() {
+            match type_ {
+                UserDefinedType::Enum(enum_) => {
+                    let enum_ = enum_.borrow();
+                    types.extend(enum_.variants.iter().filter_map(|v| v.value_type.clone()));
+                    types.extend(enum_.extends.iter().flat_map(|e| e.generics().clone()));
+                }
+                UserDefinedType::Struct(struct_) => {
+                    let struct_ = struct_.borrow();
+                    types.extend(struct_.fields.iter().map(|f| f.type_.clone()));
+                    types.extend(struct_.extends.iter().flat_map(|e| e.generics().clone()));
+                }
+                UserDefinedType::Alias(alias) => {
+                    types.push(alias.borrow().type_.clone());
+                }
+                UserDefinedType::Newtype(newtype) => {
+                    types.push(newtype.borrow().field.type_.clone());
+                }
+                // Fieldsets contain fields of structs whose type might be
+                // overridden.
+                UserDefinedType::Fieldset(fieldset) => {
+                    types.extend(
+                        fieldset
+                            .borrow()
+                            .fields
+                            .iter()
+                            .filter_map(|f| f.field.as_ref().map(|f| f.type_.clone())),
+                    );
+                }
+            }
+        }
+        for service in self.services.values() {
+            for method in service.methods.iter() {
+                types.extend(method.input.iter().chain(method.output.iter()).cloned());
+            }
+        }
+        for type_ in types.iter() {
+            type_.visit(visit);
+        }
+        for child_ns in self.namespaces.values() {
+            child_ns.visit_types(visit);
+        }
+    }
     pub(crate) fn from_idl<'a>(
         inss: impl Iterator<Item = &'a crate::idl::Namespace>,
-        builtin_types: &HashMap<String, String>,
+        builtin_types: &HashSet<String>,
     ) -> Result<Self, ValidationError> {
         let mut ns = Self::default();
         let mut type_map = TypeMap::new();
+        let mut constants = ConstantMap::default();
+        // Constants are added first as they can be used in the options of
+        // fields regardless of the order of their declaration.
+        let inss = inss.collect::<Vec<_>>();
+        for ins in inss.iter() {
+            ns.add_constants(ins, &mut constants, builtin_types)?;
+        }
         for ins in inss {
-            ns.idl_convert(ins, &mut type_map, builtin_types)?;
+            ns.idl_convert(ins, &mut type_map, &constants, builtin_types)?;
         }
         ns.resolve(&type_map)?;
+        // Aliases are checked first as the validation of the other types
+        // needs to expand them.
+        ns.validate_aliases()?;
+        ns.validate()?;
         Ok(ns)
     }
     fn add_type(&mut self, type_: UserDefinedType, type_map: &mut TypeMap) {
         type_map.insert(&type_);
         self.types.insert(type_.fqtn().name.to_owned(), type_);
     }
+    fn add_constants(
+        &mut self,
+        ins: &crate::idl::Namespace,
+        constants: &mut ConstantMap,
+        builtin_types: &HashSet<String>,
+    ) -> Result<(), ValidationError> {
+        for ipart in ins.parts.iter() {
+            match ipart {
+                idl::NamespacePart::Constant(iconst) => {
+                    if self.constants.contains_key(&iconst.name) {
+                        return Err(ValidationError {
+                            position: iconst.position,
+                            cause: Box::new(ValidationErrorCause::DuplicateIdentifier {
+                                identifier: iconst.name.clone(),
+                            }),
+                        });
+                    }
+                    let constant = Constant::from_idl(iconst, self, builtin_types)?;
+                    constants.insert(&constant);
+                    self.constants.insert(iconst.name.clone(), constant);
+                }
+                idl::NamespacePart::Namespace(inamespace) => {
+                    self.child_namespace(&inamespace.name).add_constants(
+                        inamespace,
+                        constants,
+                        builtin_types,
+                    )?;
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+    fn child_namespace(&mut self, name: &str) -> &mut Self {
+        let path = self.path.clone();
+        self.namespaces
+            .entry(name.to_owned())
+            .or_insert_with(|| Self {
+                path: path.into_iter().chain([name.to_owned()]).collect(),
+                ..Default::default()
+            })
+    }
     fn idl_convert(
         &mut self,
         ins: &crate::idl::Namespace,
         type_map: &mut TypeMap,
-        builtin_types: &HashMap<String, String>,
+        constants: &ConstantMap,
+        builtin_types: &HashSet<String>,
     ) -> Result<(), ValidationError> {
         let mut names: BTreeMap<String, FilePosition> = BTreeMap::new();
         for ipart in ins.parts.iter() {
-            match names.entry(ipart.name().to_owned()) {
-                BTreeMapEntry::Occupied(entry) => {
-                    return Err(ValidationError {
-                        position: *entry.get(),
-                        cause: Box::new(ValidationErrorCause::DuplicateIdentifier {
-                            identifier: ipart.name().to_owned(),
-                        }),
-                    });
-                }
-                BTreeMapEntry::Vacant(entry) => {
-                    entry.insert(ipart.position());
-                }
+            let (hoisted_part, hoisted) = hoist_inline_types(ipart)?;
+            let ipart = hoisted_part.as_ref().unwrap_or(ipart);
+            for ipart in std::iter::once(ipart).chain(hoisted.iter()) {
+                self.convert_part(ipart, &mut names, type_map, constants, builtin_types)?;
             }
-            match ipart {
-                idl::NamespacePart::Enum(ienum) => {
-                    self.add_type(
-                        UserDefinedType::Enum(Rc::new(RefCell::new(Enum::from_idl(
-                            ienum,
-                            self,
-                            builtin_types,
-                        )))),
-                        type_map,
-                    );
-                }
-                idl::NamespacePart::Struct(istruct) => {
-                    self.add_type(
-                        UserDefinedType::Struct(Rc::new(RefCell::new(Struct::from_idl(
-                            istruct,
-                            self,
-                            builtin_types,
-                        )?))),
-                        type_map,
-                    );
-                }
-                idl::NamespacePart::Fieldset(ifieldset) => {
+        }
+        Ok(())
+    }
+    fn convert_part(
+        &mut self,
+        ipart: &idl::NamespacePart,
+        names: &mut BTreeMap<String, FilePosition>,
+        type_map: &mut TypeMap,
+        constants: &ConstantMap,
+        builtin_types: &HashSet<String>,
+    ) -> Result<(), ValidationError> {
+        match names.entry(ipart.name().to_owned()) {
+            BTreeMapEntry::Occupied(entry) => {
+                return Err(ValidationError {
+                    position: *entry.get(),
+                    cause: Box::new(ValidationErrorCause::DuplicateIdentifier {
+                        identifier: ipart.name().to_owned(),
+                    }),
+                });
+            }
+            BTreeMapEntry::Vacant(entry) => {
+                entry.insert(ipart.position());
+            }
+        }
+        match ipart {
+            idl::NamespacePart::Alias(ialias) => {
+                self.add_type(
+                    UserDefinedType::Alias(Rc::new(RefCell::new(Alias::from_idl(
+                        ialias,
+                        self,
+                        builtin_types,
+                    )))),
+                    type_map,
+                );
+            }
+            idl::NamespacePart::Newtype(inewtype) => {
+                self.add_type(
+                    UserDefinedType::Newtype(Rc::new(RefCell::new(Newtype::from_idl(
+                        inewtype,
+                        self,
+                        constants,
+                        builtin_types,
+                    )?))),
+                    type_map,
+                );
+            }
+            idl::NamespacePart::Enum(ienum) => {
+                self.add_type(
+                    UserDefinedType::Enum(Rc::new(RefCell::new(Enum::from_idl(
+                        ienum,
+                        self,
+                        builtin_types,
+                    )?))),
+                    type_map,
+                );
+            }
+            idl::NamespacePart::Struct(istruct) => {
+                self.add_type(
+                    UserDefinedType::Struct(Rc::new(RefCell::new(Struct::from_idl(
+                        istruct,
+                        self,
+                        constants,
+                        builtin_types,
+                    )?))),
+                    type_map,
+                );
+            }
+            idl::NamespacePart::Fieldset(ifieldset) => {
+                self.add_type(
+                    UserDefinedType::Fieldset(Rc::new(RefCell::new(Fieldset::from_idl(
+                        ifieldset,
+                        self,
+                        constants,
+                        builtin_types,
+                    )?))),
+                    type_map,
+                );
+            }
+            idl::NamespacePart::Service(iservice) => {
+                let service = Service::from_idl(iservice, self, constants, builtin_types)?;
+                // Methods with a parameter list define a struct for
+                // their input which shares the names of the namespace.
+                for struct_ in service.input_structs(self) {
+                    match names.entry(struct_.fqtn.name.clone()) {
+                        BTreeMapEntry::Occupied(entry) => {
+                            return Err(ValidationError {
+                                position: struct_.position,
+                                cause: Box::new(ValidationErrorCause::DuplicateIdentifier {
+                                    identifier: entry.key().clone(),
+                                }),
+                            });
+                        }
+                        BTreeMapEntry::Vacant(entry) => {
+                            entry.insert(struct_.position);
+                        }
+                    }
                     self.add_type(
-                        UserDefinedType::Fieldset(Rc::new(RefCell::new(Fieldset::from_idl(
-                            ifieldset,
-                            self,
-                            builtin_types,
-                        )))),
+                        UserDefinedType::Struct(Rc::new(RefCell::new(struct_))),
                         type_map,
                     );
                 }
-                idl::NamespacePart::Service(iservice) => {
-                    self.services.insert(
-                        iservice.name.clone(),
-                        Service::from_idl(iservice, self, builtin_types),
-                    );
-                    // This is done in the next step. Since services do not
-                    // define any types we can ignore the merging and just
-                    // delay processing of the service to the resolve step.
-                }
-                idl::NamespacePart::Namespace(inamespace) => {
-                    let mut child_ns = Self {
-                        path: self.path.clone(),
-                        ..Default::default()
-                    };
-                    child_ns.path.push(ipart.name().to_owned());
-                    child_ns.idl_convert(inamespace, type_map, builtin_types)?;
-                    self.namespaces.insert(inamespace.name.to_owned(), child_ns);
-                }
-            };
-        }
+                self.services.insert(iservice.name.clone(), service);
+                // Resolving the types of the methods is done in the
+                // next step.
+            }
+            idl::NamespacePart::Namespace(inamespace) => {
+                self.child_namespace(&inamespace.name).idl_convert(
+                    inamespace,
+                    type_map,
+                    constants,
+                    builtin_types,
+                )?;
+            }
+            // Constants are added by `add_constants` before.
+            idl::NamespacePart::Constant(_) => {}
+        };
         Ok(())
     }
     fn resolve(&mut self, type_map: &TypeMap) -> Result<(), ValidationError> {
@@ -125,6 +295,28 @@ impl Namespace {
         }
         Ok(())
     }
+    fn validate_aliases(&self) -> Result<(), ValidationError> {
+        for ud_type in self.types.values() {
+            if let UserDefinedType::Alias(alias) = ud_type {
+                alias.borrow().validate()?;
+            }
+        }
+        for child_ns in self.namespaces.values() {
+            child_ns.validate_aliases()?;
+        }
+        Ok(())
+    }
+    fn validate(&self) -> Result<(), ValidationError> {
+        for ud_type in self.types.values() {
+            if let UserDefinedType::Struct(struct_) = ud_type {
+                struct_.borrow().validate()?;
+            }
+        }
+        for child_ns in self.namespaces.values() {
+            child_ns.validate()?;
+        }
+        Ok(())
+    }
     pub fn name(&self) -> &str {
         self.path.last().unwrap()
     }
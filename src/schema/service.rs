@@ -1,53 +1,199 @@
-use std::collections::HashMap;
+use std::collections::HashSet;
 
+use heck::ToUpperCamelCase;
+use itertools::Itertools;
+
+use crate::common::FilePosition;
 use crate::idl;
 
-use super::errors::ValidationError;
+use super::annotations::Annotations;
+use super::constant::ConstantMap;
+use super::errors::{ValidationError, ValidationErrorCause};
+use super::fqtn::FQTN;
 use super::namespace::Namespace;
-use super::r#type::Type;
+use super::options::Range;
+use super::r#struct::{Field, Struct};
+use super::r#type::{Array, Type, TypeRef};
 use super::typemap::TypeMap;
 
 pub struct Service {
     pub name: String,
+    /// Side of the connection implementing the service
+    pub side: idl::ServiceSide,
+    pub annotations: Annotations,
     pub methods: Vec<Method>,
+    pub position: FilePosition,
 }
 
 pub struct Method {
     pub name: String,
     pub input: Option<Type>,
+    /// Parameters of methods declared with a parameter list. The input
+    /// refers to the struct which is synthesized from them.
+    pub params: Option<Vec<Field>>,
     pub output: Option<Type>,
+    /// Enum of the errors returned by the method. On the wire the output
+    /// is a `Result` of the output and this type.
+    pub throws: Option<Type>,
+    /// The input is a stream of values
+    pub input_stream: bool,
+    /// The output is a stream of values
+    pub output_stream: bool,
+    /// The method is a notification which does not return a response
+    pub notify: bool,
+    pub annotations: Annotations,
+}
+
+impl Method {
+    /// Returns `true` if the input or the output is a stream
+    pub fn is_streaming(&self) -> bool {
+        self.input_stream || self.output_stream
+    }
+    /// Type which is sent as request. A streamed input is sent as an
+    /// array of all its values.
+    pub fn wire_input(&self) -> Option<Type> {
+        match &self.input {
+            Some(input) if self.input_stream => Some(stream_array(input)),
+            input => input.clone(),
+        }
+    }
+    /// Type which is sent as response. This is a `Result` if the method
+    /// throws errors and an array of all values if the output is a
+    /// stream.
+    pub fn wire_output(&self) -> Option<Type> {
+        match (&self.output, &self.throws) {
+            (_, Some(throws)) => Some(Type::Result(
+                Box::new(self.output.clone().unwrap_or(Type::None)),
+                Box::new(throws.clone()),
+            )),
+            (Some(output), None) if self.output_stream => Some(stream_array(output)),
+            (output, None) => output.clone(),
+        }
+    }
+}
+
+/// Array which carries the values of a stream on the wire
+fn stream_array(item_type: &Type) -> Type {
+    Type::Array(Box::new(Array {
+        length: Range {
+            start: None,
+            end: None,
+        },
+        item_type: item_type.clone(),
+    }))
+}
+
+/// Name of the struct which is synthesized from the parameters of a
+/// method, e.g. `UsersUserGetInput` for the method `user_get` of the
+/// service `Users`.
+pub(crate) fn input_struct_name(service: &str, method: &str) -> String {
+    format!("{}{}Input", service, method.to_upper_camel_case())
 }
 
 impl Service {
     pub(crate) fn from_idl(
         iservice: &idl::Service,
         ns: &Namespace,
-        builtin_types: &HashMap<String, String>,
-    ) -> Self {
-        Self {
+        constants: &ConstantMap,
+        builtin_types: &HashSet<String>,
+    ) -> Result<Self, ValidationError> {
+        Ok(Self {
             name: iservice.name.clone(),
+            side: iservice.side,
+            annotations: Annotations::from_idl(&iservice.options)?,
             methods: iservice
                 .methods
                 .iter()
-                .map(|imethod| Method {
-                    name: imethod.name.clone(),
-                    input: imethod
-                        .input
-                        .as_ref()
-                        .map(|x| Type::from_idl(x, ns, builtin_types)),
-                    output: imethod
-                        .output
-                        .as_ref()
-                        .map(|x| Type::from_idl(x, ns, builtin_types)),
+                .map(|imethod| {
+                    // An empty parameter list is the same as no input.
+                    let params: Option<Vec<Field>> = match &imethod.params {
+                        Some(iparams) if !iparams.is_empty() => Some(
+                            iparams
+                                .iter()
+                                .map(|ifield| {
+                                    Field::from_idl(ifield, ns, &[], constants, builtin_types)
+                                })
+                                .try_collect()?,
+                        ),
+                        _ => None,
+                    };
+                    let input = match &params {
+                        Some(_) => Some(Type::Ref(TypeRef::Unresolved {
+                            fqtn: FQTN::new(&input_struct_name(&iservice.name, &imethod.name), ns),
+                            generics: vec![],
+                        })),
+                        None => imethod
+                            .input
+                            .as_ref()
+                            .map(|x| Type::from_idl(x, ns, &[], builtin_types)),
+                    };
+                    Ok(Method {
+                        name: imethod.name.clone(),
+                        input,
+                        params,
+                        output: imethod
+                            .output
+                            .as_ref()
+                            .map(|x| Type::from_idl(x, ns, &[], builtin_types)),
+                        throws: imethod
+                            .throws
+                            .as_ref()
+                            .map(|x| Type::from_idl(x, ns, &[], builtin_types)),
+                        input_stream: imethod.input_stream,
+                        output_stream: imethod.output_stream,
+                        notify: imethod.notify,
+                        annotations: Annotations::from_idl(&imethod.options)?,
+                    })
                 })
-                .collect(),
-        }
+                .collect::<Result<_, _>>()?,
+            position: iservice.position,
+        })
+    }
+    /// Structs which are synthesized from the parameter lists of the
+    /// methods. They are added to the namespace like any other struct.
+    pub(crate) fn input_structs(&self, ns: &Namespace) -> Vec<Struct> {
+        self.methods
+            .iter()
+            .filter_map(|method| {
+                let params = method.params.as_ref()?;
+                Some(Struct {
+                    fqtn: FQTN::new(&input_struct_name(&self.name, &method.name), ns),
+                    generics: vec![],
+                    extends: None,
+                    deprecated: None,
+                    fields: params.clone(),
+                    all_fields: Vec::new(),
+                    position: params[0].position,
+                })
+            })
+            .collect()
     }
     pub(crate) fn resolve(&mut self, type_map: &TypeMap) -> Result<(), ValidationError> {
         for method in self.methods.iter_mut() {
             if let Some(input) = &mut method.input {
                 input.resolve(type_map)?;
             }
+            for param in method.params.iter_mut().flatten() {
+                param.type_.resolve(type_map)?;
+            }
+            if let Some(throws) = &mut method.throws {
+                throws.resolve(type_map)?;
+                let error = |cause| ValidationError {
+                    position: self.position,
+                    cause: Box::new(cause),
+                };
+                let method_name = format!("{}.{}", self.name, method.name);
+                if !matches!(throws, Type::Ref(TypeRef::Enum(_))) {
+                    return Err(error(ValidationErrorCause::ThrowsNonEnum {
+                        method: method_name,
+                    }));
+                }
+                if method.is_streaming() {
+                    return Err(error(ValidationErrorCause::StreamingMethodThrows {
+                        method: method_name,
+                    }));
+                }
+            }
             if let Some(output) = &mut method.output {
                 output.resolve(type_map)?;
             }
@@ -55,3 +201,123 @@ impl Service {
         Ok(())
     }
 }
+
+#[test]
+fn test_method_params() {
+    use super::errors::ValidationErrorCause;
+    use super::r#type::UserDefinedType;
+    let parse = |idl: &str| {
+        let idocs = [crate::idl::parse_document(idl).unwrap()];
+        super::Document::from_idl(idocs.iter(), &HashSet::new())
+    };
+    let doc = parse(
+        r#"
+        struct User { id: UUID }
+        service Users {
+            user_get(id: UUID, include_deleted?: Boolean) -> User,
+            ping() -> None,
+        }
+        "#,
+    )
+    .unwrap();
+    let UserDefinedType::Struct(input) = &doc.ns.types["UsersUserGetInput"] else {
+        unreachable!()
+    };
+    let input = input.borrow();
+    assert_eq!(
+        input
+            .fields
+            .iter()
+            .map(|f| (f.name.as_str(), f.optional))
+            .collect::<Vec<_>>(),
+        vec![("id", false), ("include_deleted", true)]
+    );
+    let methods = &doc.ns.services["Users"].methods;
+    assert!(matches!(
+        &methods[0].input,
+        Some(Type::Ref(typeref)) if typeref.fqtn().name == "UsersUserGetInput"
+    ));
+    assert_eq!(methods[0].params.as_ref().map(Vec::len), Some(2));
+    assert!(methods[1].input.is_none() && methods[1].params.is_none());
+    assert!(matches!(
+        *parse(
+            r#"
+            service Users { user_get(id: UUID) -> None }
+            struct UsersUserGetInput { id: UUID }
+            "#
+        )
+        .err()
+        .unwrap()
+        .cause,
+        ValidationErrorCause::DuplicateIdentifier { identifier } if identifier == "UsersUserGetInput"
+    ));
+}
+
+#[test]
+fn test_method_throws() {
+    use super::errors::ValidationErrorCause;
+    let parse = |idl: &str| {
+        let idocs = [crate::idl::parse_document(idl).unwrap()];
+        super::Document::from_idl(idocs.iter(), &HashSet::new())
+    };
+    let doc = parse(
+        r#"
+        enum GetError { NotFound, PermissionDenied }
+        service Users {
+            get: UUID -> String throws GetError,
+            delete: UUID -> None throws enum { NotFound },
+        }
+        "#,
+    )
+    .unwrap();
+    let methods = &doc.ns.services["Users"].methods;
+    assert!(matches!(
+        methods[0].wire_output(),
+        Some(Type::Result(ok, err))
+            if matches!(*ok, Type::String)
+            && matches!(&*err, Type::Ref(typeref) if typeref.fqtn().name == "GetError")
+    ));
+    assert!(matches!(
+        &methods[1].throws,
+        Some(Type::Ref(typeref)) if typeref.fqtn().name == "UsersDeleteError"
+    ));
+    let error = |idl: &str| *parse(idl).err().unwrap().cause;
+    assert!(matches!(
+        error("struct GetError {} service Users { get: UUID -> String throws GetError }"),
+        ValidationErrorCause::ThrowsNonEnum { method } if method == "Users.get"
+    ));
+    assert!(matches!(
+        error("service Users { get: UUID -> String throws [String] }"),
+        ValidationErrorCause::ThrowsNonEnum { method } if method == "Users.get"
+    ));
+    assert!(matches!(
+        error("enum E { A } service Users { watch: None -> stream String throws E }"),
+        ValidationErrorCause::StreamingMethodThrows { method } if method == "Users.watch"
+    ));
+}
+
+#[test]
+fn test_method_wire_types_of_streams() {
+    let idocs = [crate::idl::parse_document(
+        r#"
+        service Events {
+            watch: String -> stream Integer,
+            upload: stream String -> Integer,
+        }
+        "#,
+    )
+    .unwrap()];
+    let doc = super::Document::from_idl(idocs.iter(), &HashSet::new()).unwrap();
+    let methods = &doc.ns.services["Events"].methods;
+    let is_array_of = |type_: Option<Type>, item: fn(&Type) -> bool| matches!(type_, Some(Type::Array(array)) if item(&array.item_type));
+    assert!(matches!(methods[0].wire_input(), Some(Type::String)));
+    assert!(is_array_of(methods[0].wire_output(), |t| matches!(
+        t,
+        Type::Integer
+    )));
+    assert!(is_array_of(methods[1].wire_input(), |t| matches!(
+        t,
+        Type::String
+    )));
+    assert!(matches!(methods[1].wire_output(), Some(Type::Integer)));
+}
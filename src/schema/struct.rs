@@ -1,20 +1,28 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use itertools::Itertools;
 
 use crate::common::FilePosition;
 use crate::idl;
 
+use super::annotations::Deprecation;
+use super::constant::ConstantMap;
 use super::errors::{ValidationError, ValidationErrorCause};
 use super::fqtn::FQTN;
 use super::namespace::Namespace;
-use super::r#type::Type;
+use super::r#type::{Type, TypeRef};
 use super::typemap::TypeMap;
 
 pub struct Struct {
     pub fqtn: FQTN,
     pub generics: Vec<String>,
+    pub extends: Option<TypeRef>,
+    pub deprecated: Option<Deprecation>,
+    /// Fields declared by the struct itself
     pub fields: Vec<Field>,
+    /// Fields of the extended structs followed by the fields of the
+    /// struct itself
+    pub all_fields: Vec<Field>,
     pub position: FilePosition,
 }
 
@@ -23,9 +31,16 @@ pub struct Field {
     pub name: String,
     pub type_: Type,
     pub optional: bool,
+    /// Value which is used if the field is missing
+    pub default: Option<idl::Value>,
     // FIXME add options
     pub length: (Option<i64>, Option<i64>),
+    pub range: (Option<i64>, Option<i64>),
     pub format: Option<String>,
+    pub help: Option<String>,
+    /// Size of integer fields in bits
+    pub size: Option<i64>,
+    pub deprecated: Option<Deprecation>,
     pub position: FilePosition,
 }
 
@@ -33,17 +48,25 @@ impl Struct {
     pub(crate) fn from_idl(
         istruct: &idl::Struct,
         ns: &Namespace,
-        builtin_types: &HashMap<String, String>,
+        constants: &ConstantMap,
+        builtin_types: &HashSet<String>,
     ) -> Result<Self, ValidationError> {
         let fields = istruct
             .fields
             .iter()
-            .map(|ifield| Field::from_idl(ifield, ns, builtin_types))
+            .map(|ifield| Field::from_idl(ifield, ns, &istruct.generics, constants, builtin_types))
             .try_collect()?;
+        let extends = istruct
+            .extends
+            .as_ref()
+            .map(|itype| TypeRef::from_idl(itype, ns, &istruct.generics, builtin_types));
         Ok(Self {
             fqtn: FQTN::new(&istruct.name, ns),
             generics: istruct.generics.clone(),
+            extends,
+            deprecated: Deprecation::from_idl_exclusive(&istruct.options)?,
             fields,
+            all_fields: Vec::new(),
             position: istruct.position,
         })
     }
@@ -51,23 +74,202 @@ impl Struct {
         for field in self.fields.iter_mut() {
             field.type_.resolve(type_map)?;
         }
+        if let Some(extends) = &mut self.extends {
+            extends.resolve(type_map)?;
+        }
+        self.all_fields = self.collect_fields(type_map, &mut vec![self.fqtn.clone()])?;
+        Ok(())
+    }
+    /// Collect the inherited fields and the fields of the struct. As the
+    /// extended structs might not be resolved yet the types of the
+    /// inherited fields are resolved after replacing the generics.
+    pub(crate) fn collect_fields(
+        &self,
+        type_map: &TypeMap,
+        stack: &mut Vec<FQTN>,
+    ) -> Result<Vec<Field>, ValidationError> {
+        let mut fields = Vec::new();
+        if let Some(extends) = &self.extends {
+            let mut extends = extends.clone();
+            extends.resolve(type_map)?;
+            let TypeRef::Struct(extends_struct) = &extends else {
+                return Err(ValidationError {
+                    position: self.position,
+                    cause: Box::new(ValidationErrorCause::StructExtendsNonStruct {
+                        r#struct: self.fqtn.clone(),
+                        extends: extends.fqtn(),
+                    }),
+                });
+            };
+            let recursive_error = || ValidationError {
+                position: self.position,
+                cause: Box::new(ValidationErrorCause::RecursiveExtends {
+                    fqtn: self.fqtn.clone(),
+                }),
+            };
+            let extends_struct = extends_struct.struct_.upgrade().unwrap();
+            // The struct which is currently being resolved is mutably
+            // borrowed and can only be borrowed again if it extends itself.
+            let Ok(extends_struct) = extends_struct.try_borrow() else {
+                return Err(recursive_error());
+            };
+            if stack.contains(&extends_struct.fqtn) {
+                return Err(recursive_error());
+            }
+            let generics = extends_struct
+                .generics
+                .iter()
+                .cloned()
+                .zip(extends.generics().iter().cloned())
+                .collect::<HashMap<_, _>>();
+            stack.push(extends_struct.fqtn.clone());
+            for field in extends_struct.collect_fields(type_map, stack)? {
+                let mut type_ = field.type_.substitute(&generics);
+                type_.resolve(type_map)?;
+                fields.push(Field { type_, ..field });
+            }
+            stack.pop();
+        }
+        for field in self.fields.iter() {
+            if fields.iter().any(|f: &Field| f.name == field.name) {
+                return Err(ValidationError {
+                    position: field.position,
+                    cause: Box::new(ValidationErrorCause::DuplicateIdentifier {
+                        identifier: field.name.clone(),
+                    }),
+                });
+            }
+            fields.push(field.clone());
+        }
+        Ok(fields)
+    }
+    /// Check the default values of all fields. This requires all types
+    /// of the document to be resolved.
+    pub(crate) fn validate(&self) -> Result<(), ValidationError> {
+        for field in self.fields.iter() {
+            field.validate_default()?;
+        }
         Ok(())
     }
 }
 
 impl Field {
-    pub fn from_idl(
+    pub(crate) fn from_idl(
         ifield: &idl::Field,
         ns: &Namespace,
-        builtin_types: &HashMap<String, String>,
+        generics: &[String],
+        constants: &ConstantMap,
+        builtin_types: &HashSet<String>,
     ) -> Result<Self, ValidationError> {
-        let mut length: (Option<i64>, Option<i64>) = (None, None);
-        let mut format: Option<String> = None;
-        for option in &ifield.options {
+        let default = match &ifield.default {
+            Some(value) => Some(resolve_default(ifield, value, ns, constants)?),
+            None => None,
+        };
+        let mut field = Field {
+            name: ifield.name.clone(),
+            type_: Type::from_idl(&ifield.type_, ns, generics, builtin_types),
+            optional: ifield.optional,
+            default,
+            length: (None, None),
+            range: (None, None),
+            format: None,
+            help: None,
+            size: None,
+            deprecated: None,
+            position: ifield.position,
+        };
+        FieldOptions::from_idl(&ifield.options, ns, constants)?.apply(&mut field);
+        Ok(field)
+    }
+    fn validate_default(&self) -> Result<(), ValidationError> {
+        let Some(value) = &self.default else {
+            return Ok(());
+        };
+        // Optional fields are `null` if missing and can not have a
+        // default value.
+        if self.optional || !self.is_valid_default(value) {
+            return Err(ValidationError {
+                position: self.position,
+                cause: Box::new(ValidationErrorCause::InvalidDefaultValue {
+                    field: self.name.clone(),
+                }),
+            });
+        }
+        Ok(())
+    }
+    fn is_valid_default(&self, value: &idl::Value) -> bool {
+        if !self.type_.accepts_value(value) {
+            return false;
+        }
+        match (&self.type_, value) {
+            (Type::Integer, idl::Value::Integer(v)) => {
+                let fits = match self.size {
+                    Some(8) => i8::try_from(*v).is_ok(),
+                    Some(16) => i16::try_from(*v).is_ok(),
+                    Some(32) => i32::try_from(*v).is_ok(),
+                    _ => true,
+                };
+                fits && self.range.0.is_none_or(|min| *v >= min)
+                    && self.range.1.is_none_or(|max| *v <= max)
+            }
+            (Type::String, idl::Value::String(v)) => {
+                let length = v.chars().count() as i64;
+                self.length.0.is_none_or(|min| length >= min)
+                    && self.length.1.is_none_or(|max| length <= max)
+            }
+            _ => true,
+        }
+    }
+}
+
+/// Options of a field as given in the IDL. Options which are not given
+/// are `None` and are not changed when applying them to a field.
+#[derive(Clone, Default)]
+pub(crate) struct FieldOptions {
+    length: Option<(Option<i64>, Option<i64>)>,
+    range: Option<(Option<i64>, Option<i64>)>,
+    format: Option<String>,
+    help: Option<String>,
+    size: Option<i64>,
+    deprecated: Option<Deprecation>,
+}
+
+impl FieldOptions {
+    pub(crate) fn from_idl(
+        ioptions: &[idl::FieldOption],
+        ns: &Namespace,
+        constants: &ConstantMap,
+    ) -> Result<Self, ValidationError> {
+        let (deprecated, ioptions) = Deprecation::from_idl(ioptions)?;
+        let mut options = Self {
+            deprecated,
+            ..Self::default()
+        };
+        for option in ioptions {
             match (option.name.as_str(), &option.value) {
-                ("length", idl::Value::Range(min, max)) => length = (*min, *max),
-                //("format", format) => format = Some(format),
-                ("format", idl::Value::String(f)) => format = Some(f.clone()),
+                ("length", idl::Value::Range(min, max)) => {
+                    options.length = Some((
+                        resolve_bound(option, min, ns, constants)?,
+                        resolve_bound(option, max, ns, constants)?,
+                    ))
+                }
+                ("range", idl::Value::Range(min, max)) => {
+                    options.range = Some((
+                        resolve_bound(option, min, ns, constants)?,
+                        resolve_bound(option, max, ns, constants)?,
+                    ))
+                }
+                ("format", idl::Value::String(f)) => options.format = Some(f.clone()),
+                ("help", idl::Value::String(h)) => options.help = Some(h.clone()),
+                ("size", idl::Value::Integer(s @ (8 | 16 | 32 | 64))) => options.size = Some(*s),
+                ("size", _) => {
+                    return Err(ValidationError {
+                        position: option.position,
+                        cause: Box::new(ValidationErrorCause::InvalidOptionValue {
+                            name: "size".to_owned(),
+                        }),
+                    })
+                }
                 (name, _) => {
                     return Err(ValidationError {
                         position: option.position,
@@ -78,15 +280,195 @@ impl Field {
                 }
             }
         }
-        Ok(Field {
-            name: ifield.name.clone(),
-            type_: Type::from_idl(&ifield.type_, ns, builtin_types),
-            optional: ifield.optional,
-            // FIXME add options
-            //options: ifield.options
-            length,
-            format,
-            position: ifield.position,
-        })
+        Ok(options)
+    }
+    pub(crate) fn apply(&self, field: &mut Field) {
+        if let Some(length) = self.length {
+            field.length = length;
+        }
+        if let Some(range) = self.range {
+            field.range = range;
+        }
+        if let Some(format) = &self.format {
+            field.format = Some(format.clone());
+        }
+        if let Some(help) = &self.help {
+            field.help = Some(help.clone());
+        }
+        if let Some(size) = self.size {
+            field.size = Some(size);
+        }
+        if let Some(deprecated) = &self.deprecated {
+            field.deprecated = Some(deprecated.clone());
+        }
+    }
+}
+
+/// Get the value of a range bound which can refer to an integer constant.
+fn resolve_bound(
+    option: &idl::FieldOption,
+    bound: &Option<idl::RangeBound>,
+    ns: &Namespace,
+    constants: &ConstantMap,
+) -> Result<Option<i64>, ValidationError> {
+    match bound {
+        None => Ok(None),
+        Some(idl::RangeBound::Integer(value)) => Ok(Some(*value)),
+        Some(idl::RangeBound::Constant(constant)) => match constants.get(constant, ns) {
+            Some(idl::Value::Integer(value)) => Ok(Some(*value)),
+            Some(_) => Err(ValidationError {
+                position: option.position,
+                cause: Box::new(ValidationErrorCause::InvalidOptionValue {
+                    name: option.name.clone(),
+                }),
+            }),
+            None => Err(ValidationError {
+                position: option.position,
+                cause: Box::new(ValidationErrorCause::NoSuchConstant {
+                    name: constant.to_string(),
+                }),
+            }),
+        },
+    }
+}
+
+/// Replace references to constants in a default value by the value of
+/// the constant. A plain identifier only refers to a constant if one of
+/// that name is visible. Otherwise it is kept as enum variant.
+fn resolve_default(
+    ifield: &idl::Field,
+    value: &idl::Value,
+    ns: &Namespace,
+    constants: &ConstantMap,
+) -> Result<idl::Value, ValidationError> {
+    match value {
+        idl::Value::Constant(constant) => match constants.get(constant, ns) {
+            Some(value) => Ok(value.clone()),
+            None => Err(ValidationError {
+                position: ifield.position,
+                cause: Box::new(ValidationErrorCause::NoSuchConstant {
+                    name: constant.to_string(),
+                }),
+            }),
+        },
+        idl::Value::Identifier(name) => {
+            let constant = idl::ConstantRef {
+                abs: false,
+                ns: Vec::new(),
+                name: name.clone(),
+            };
+            Ok(constants.get(&constant, ns).unwrap_or(value).clone())
+        }
+        value => Ok(value.clone()),
+    }
+}
+
+#[test]
+fn test_schema_field_default() {
+    let parse = |idl: &str| {
+        let idocs = [crate::idl::parse_document(idl).unwrap()];
+        super::Document::from_idl(idocs.iter(), &HashSet::new())
+    };
+    let doc = parse(
+        r#"
+        enum Status { Active, Blocked(String) }
+        struct User {
+            name: String (length=1..9) = "anonymous",
+            age: Integer (size=8) = 127,
+            score: Float = 1,
+            status: Status = Active,
+        }
+        "#,
+    )
+    .unwrap();
+    let user = doc.ns.types.get("User").unwrap();
+    let super::UserDefinedType::Struct(user) = user else {
+        unreachable!()
+    };
+    assert_eq!(
+        user.borrow().fields[3].default,
+        Some(idl::Value::Identifier("Active".to_owned()))
+    );
+    let invalid = [
+        "struct A { a: String = 42 }",
+        "struct A { a: Integer (size=8) = 128 }",
+        "struct A { a: UInt8 = -1 }",
+        "struct A { a: Integer (range=1..10) = 0 }",
+        "struct A { a: String (length=1..3) = \"hello\" }",
+        "struct A { a: String (length=3..) = \"ab\" }",
+        "struct A { a?: Integer = 1 }",
+        "struct A { a: [Integer] = 1 }",
+        "struct A { a: Integer = 0..10 }",
+        "enum E { A(String) } struct A { a: E = A }",
+        "enum E { A } struct A { a: E = B }",
+        "enum E<T> { A, B(T) } struct A { a: E<String> = A }",
+    ];
+    for idl in invalid {
+        let error = parse(idl).err().unwrap_or_else(|| panic!("{}", idl));
+        assert!(
+            matches!(
+                *error.cause,
+                ValidationErrorCause::InvalidDefaultValue { ref field } if field == "a"
+            ),
+            "{}",
+            idl
+        );
+    }
+}
+
+#[test]
+fn test_schema_struct_extends() {
+    let parse = |idl: &str| {
+        let idocs = [crate::idl::parse_document(idl).unwrap()];
+        super::Document::from_idl(idocs.iter(), &HashSet::new())
+    };
+    // The extended struct is declared after the extending one on purpose
+    // as the types are resolved in alphabetical order.
+    let doc = parse(
+        r#"
+        struct A extends Entity<UUID> {
+            name: String,
+        }
+        fieldset AName for A { id, name }
+        struct Entity<ID> extends Timestamps {
+            id: ID,
+        }
+        struct Timestamps {
+            created_at: DateTime,
+        }
+        "#,
+    )
+    .unwrap();
+    let super::UserDefinedType::Struct(a) = doc.ns.types.get("A").unwrap() else {
+        unreachable!()
+    };
+    let a = a.borrow();
+    assert_eq!(a.fields.len(), 1);
+    assert_eq!(
+        a.all_fields
+            .iter()
+            .map(|f| f.name.as_str())
+            .collect::<Vec<_>>(),
+        ["created_at", "id", "name"]
+    );
+    assert!(matches!(a.all_fields[1].type_, Type::UUID));
+    let invalid = [
+        "struct A extends A { }",
+        "struct A extends B { } struct B extends A { }",
+        "struct A extends B { a: String } struct B { a: String }",
+        "enum B { } struct A extends B { }",
+    ];
+    for idl in invalid {
+        let error = parse(idl).err().unwrap_or_else(|| panic!("{}", idl));
+        assert!(
+            matches!(
+                *error.cause,
+                ValidationErrorCause::RecursiveExtends { .. }
+                    | ValidationErrorCause::DuplicateIdentifier { .. }
+                    | ValidationErrorCause::StructExtendsNonStruct { .. }
+            ),
+            "{}",
+            idl
+        );
     }
 }
@@ -1,10 +1,12 @@
 use std::cell::RefCell;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::rc::{Rc, Weak};
 
 use crate::common::FilePosition;
 use crate::idl;
 
+use super::alias::{Alias, Newtype};
+use super::annotations::Deprecation;
 use super::errors::{ValidationError, ValidationErrorCause};
 use super::fieldset::Fieldset;
 use super::fqtn::FQTN;
@@ -26,6 +28,18 @@ pub enum Type {
     Date,
     Time,
     DateTime,
+    Decimal,
+    Bytes,
+    Duration,
+    // sized integers
+    Int8,
+    Int16,
+    Int32,
+    Int64,
+    UInt8,
+    UInt16,
+    UInt32,
+    UInt64,
     // complex types
     Option(Box<Type>),
     Result(Box<Type>, Box<Type>),
@@ -33,18 +47,34 @@ pub enum Type {
     Map(Box<Map>),
     // named
     Ref(TypeRef),
+    // generic parameter of the surrounding type
+    Generic(String),
     // builtin (user provided)
     Builtin(String),
 }
 
 #[derive(Clone)]
 pub enum TypeRef {
+    Alias(AliasRef),
+    Newtype(NewtypeRef),
     Enum(EnumRef),
     Struct(StructRef),
     Fieldset(FieldsetRef),
     Unresolved { fqtn: FQTN, generics: Vec<Type> },
 }
 
+#[derive(Clone)]
+pub struct AliasRef {
+    pub alias: Weak<RefCell<Alias>>,
+    pub generics: Vec<Type>,
+}
+
+#[derive(Clone)]
+pub struct NewtypeRef {
+    pub newtype: Weak<RefCell<Newtype>>,
+    pub generics: Vec<Type>,
+}
+
 #[derive(Clone)]
 pub struct EnumRef {
     pub enum_: Weak<RefCell<Enum>>,
@@ -78,17 +108,29 @@ pub struct Map {
 
 #[derive(Clone)]
 pub enum UserDefinedType {
+    Alias(Rc<RefCell<Alias>>),
+    Newtype(Rc<RefCell<Newtype>>),
     Enum(Rc<RefCell<Enum>>),
     Struct(Rc<RefCell<Struct>>),
     Fieldset(Rc<RefCell<Fieldset>>),
 }
 
 impl Type {
+    /// Create a type from its IDL representation. `generics` contains the
+    /// generic parameters of the type which contains this type.
     pub(crate) fn from_idl_ref(
         ityperef: &idl::TypeRef,
         ns: &Namespace,
-        builtin_types: &HashMap<String, String>,
+        generics: &[String],
+        builtin_types: &HashSet<String>,
     ) -> Self {
+        if !ityperef.abs
+            && ityperef.ns.is_empty()
+            && ityperef.generics.is_empty()
+            && generics.contains(&ityperef.name)
+        {
+            return Self::Generic(ityperef.name.clone());
+        }
         // FIXME this should fail with an error when fqtn.ns is not empty
         match ityperef.name.as_str() {
             "None" => Self::None,
@@ -100,43 +142,67 @@ impl Type {
             "Date" => Self::Date,
             "Time" => Self::Time,
             "DateTime" => Self::DateTime,
+            "Decimal" => Self::Decimal,
+            "Bytes" => Self::Bytes,
+            "Duration" => Self::Duration,
+            "Int8" => Self::Int8,
+            "Int16" => Self::Int16,
+            "Int32" => Self::Int32,
+            "Int64" => Self::Int64,
+            "UInt8" => Self::UInt8,
+            "UInt16" => Self::UInt16,
+            "UInt32" => Self::UInt32,
+            "UInt64" => Self::UInt64,
             "Option" => Self::Option(Box::new(Type::from_idl(
                 &ityperef.generics[0],
                 ns,
+                generics,
                 builtin_types,
             ))),
             "Result" => Self::Result(
-                Box::new(Type::from_idl(&ityperef.generics[0], ns, builtin_types)),
-                Box::new(Type::from_idl(&ityperef.generics[1], ns, builtin_types)),
+                Box::new(Type::from_idl(
+                    &ityperef.generics[0],
+                    ns,
+                    generics,
+                    builtin_types,
+                )),
+                Box::new(Type::from_idl(
+                    &ityperef.generics[1],
+                    ns,
+                    generics,
+                    builtin_types,
+                )),
             ),
-            name => match builtin_types.get(name) {
-                Some(value) => Self::Builtin(value.to_owned()),
-                None => Self::Ref(TypeRef::from_idl(ityperef, ns, builtin_types)),
-            },
+            name if builtin_types.contains(name) => Self::Builtin(name.to_owned()),
+            _ => Self::Ref(TypeRef::from_idl(ityperef, ns, generics, builtin_types)),
         }
     }
     pub(crate) fn from_idl(
         itype: &idl::Type,
         ns: &Namespace,
-        builtin_types: &HashMap<String, String>,
+        generics: &[String],
+        builtin_types: &HashSet<String>,
     ) -> Self {
         match itype {
-            idl::Type::Ref(ityperef) => Self::from_idl_ref(ityperef, ns, builtin_types),
+            idl::Type::Ref(ityperef) => Self::from_idl_ref(ityperef, ns, generics, builtin_types),
             idl::Type::Array(item_type) => Self::Array(Box::new(Array {
-                item_type: Self::from_idl(item_type, ns, builtin_types),
+                item_type: Self::from_idl(item_type, ns, generics, builtin_types),
                 length: Range {
                     start: None,
                     end: None,
                 }, // FIXME
             })),
             idl::Type::Map(key_type, value_type) => Self::Map(Box::new(Map {
-                key_type: Self::from_idl(key_type, ns, builtin_types),
-                value_type: Self::from_idl(value_type, ns, builtin_types),
+                key_type: Self::from_idl(key_type, ns, generics, builtin_types),
+                value_type: Self::from_idl(value_type, ns, generics, builtin_types),
                 length: Range {
                     start: None,
                     end: None,
                 }, // FIXME
             })),
+            idl::Type::Struct(_) | idl::Type::Enum(_) => {
+                unreachable!("inline types are hoisted by Namespace::idl_convert")
+            }
         }
     }
     pub(crate) fn resolve(&mut self, type_map: &TypeMap) -> Result<(), ValidationError> {
@@ -149,7 +215,18 @@ impl Type {
             | Self::UUID
             | Self::Date
             | Self::Time
-            | Self::DateTime => Ok(()),
+            | Self::DateTime
+            | Self::Decimal
+            | Self::Bytes
+            | Self::Duration
+            | Self::Int8
+            | Self::Int16
+            | Self::Int32
+            | Self::Int64
+            | Self::UInt8
+            | Self::UInt16
+            | Self::UInt32
+            | Self::UInt64 => Ok(()),
             // complex types
             Self::Option(some) => some.resolve(type_map),
             Self::Result(ok, err) => {
@@ -161,54 +238,153 @@ impl Type {
             Self::Map(map) => map.resolve(type_map),
             // named
             Self::Ref(typeref) => typeref.resolve(type_map),
+            Self::Generic(_) => Ok(()),
             // builtin (user defined)
             Self::Builtin(_) => Ok(()),
         }
     }
-    /// Returns wether this type is scalar type or not.
-    pub(crate) fn is_scalar(&self) -> bool {
+    /// Calls `visit` for this type and all types nested in it including
+    /// the generic arguments of referenced types.
+    pub(crate) fn visit<F: FnMut(&Type)>(&self, visit: &mut F) {
+        visit(self);
         match self {
-            Self::None
-            | Self::Boolean
-            | Self::Integer
-            | Self::Float
-            | Self::String
-            | Self::UUID
-            | Self::Date
-            | Self::Time
-            | Self::DateTime => true,
-            Self::Option(type_) => type_.is_scalar(),
-            Self::Result(_, _) => false,
-            Self::Array(_) => false,
-            Self::Map(_) => false,
-            Self::Ref(_) => false,
-            Self::Builtin(_) => true,
+            Self::Option(some) => some.visit(visit),
+            Self::Result(ok, err) => {
+                ok.visit(visit);
+                err.visit(visit);
+            }
+            Self::Array(array) => array.item_type.visit(visit),
+            Self::Map(map) => {
+                map.key_type.visit(visit);
+                map.value_type.visit(visit);
+            }
+            Self::Ref(typeref) => {
+                for type_ in typeref.generics() {
+                    type_.visit(visit);
+                }
+            }
+            _ => {}
+        }
+    }
+    /// Whether the value is valid for a field or constant of this type.
+    /// Only variants without a value of non-generic enums are accepted
+    /// for enum types. Aliases accept the values of their type.
+    pub(crate) fn accepts_value(&self, value: &idl::Value) -> bool {
+        match (self, value) {
+            (Self::Boolean, idl::Value::Boolean(_)) => true,
+            (Self::Integer | Self::Int64, idl::Value::Integer(_)) => true,
+            (Self::Int8, idl::Value::Integer(v)) => i8::try_from(*v).is_ok(),
+            (Self::Int16, idl::Value::Integer(v)) => i16::try_from(*v).is_ok(),
+            (Self::Int32, idl::Value::Integer(v)) => i32::try_from(*v).is_ok(),
+            (Self::UInt8, idl::Value::Integer(v)) => u8::try_from(*v).is_ok(),
+            (Self::UInt16, idl::Value::Integer(v)) => u16::try_from(*v).is_ok(),
+            (Self::UInt32, idl::Value::Integer(v)) => u32::try_from(*v).is_ok(),
+            (Self::UInt64, idl::Value::Integer(v)) => u64::try_from(*v).is_ok(),
+            (Self::Float, idl::Value::Integer(_) | idl::Value::Float(_)) => true,
+            (Self::String, idl::Value::String(_)) => true,
+            (Self::Ref(TypeRef::Alias(alias_ref)), _) => alias_ref.target().accepts_value(value),
+            (Self::Ref(TypeRef::Enum(enum_ref)), idl::Value::Identifier(name)) => {
+                let enum_ = enum_ref.enum_.upgrade().unwrap();
+                let enum_ = enum_.borrow();
+                enum_.generics.is_empty()
+                    && enum_
+                        .all_variants
+                        .iter()
+                        .any(|variant| &variant.name == name && variant.value_type.is_none())
+            }
+            _ => false,
+        }
+    }
+    /// Replace generic parameters by the given types.
+    pub(crate) fn substitute(&self, generics: &HashMap<String, Type>) -> Self {
+        match self {
+            Self::Option(some) => Self::Option(Box::new(some.substitute(generics))),
+            Self::Result(ok, err) => Self::Result(
+                Box::new(ok.substitute(generics)),
+                Box::new(err.substitute(generics)),
+            ),
+            Self::Array(array) => Self::Array(Box::new(Array {
+                length: array.length.clone(),
+                item_type: array.item_type.substitute(generics),
+            })),
+            Self::Map(map) => Self::Map(Box::new(Map {
+                length: map.length.clone(),
+                key_type: map.key_type.substitute(generics),
+                value_type: map.value_type.substitute(generics),
+            })),
+            Self::Ref(typeref) => Self::Ref(typeref.substitute(generics)),
+            Self::Generic(name) => generics.get(name).cloned().unwrap_or_else(|| self.clone()),
+            _ => self.clone(),
+        }
+    }
+    /// Collect the names of the deprecated types this type refers to.
+    /// Aliases are not followed as the alias itself refers to the type.
+    pub fn collect_deprecated_types(&self, fqtns: &mut Vec<FQTN>) {
+        match self {
+            Self::Option(some) => some.collect_deprecated_types(fqtns),
+            Self::Result(ok, err) => {
+                ok.collect_deprecated_types(fqtns);
+                err.collect_deprecated_types(fqtns);
+            }
+            Self::Array(array) => array.item_type.collect_deprecated_types(fqtns),
+            Self::Map(map) => {
+                map.key_type.collect_deprecated_types(fqtns);
+                map.value_type.collect_deprecated_types(fqtns);
+            }
+            Self::Ref(typeref) => typeref.collect_deprecated_types(fqtns),
+            _ => {}
         }
     }
+    /// Returns `true` if the type refers to a deprecated type
+    pub fn uses_deprecated(&self) -> bool {
+        let mut fqtns = Vec::new();
+        self.collect_deprecated_types(&mut fqtns);
+        !fqtns.is_empty()
+    }
+}
+
+impl AliasRef {
+    /// Type the alias refers to with its generic parameters replaced by
+    /// the ones of this reference
+    pub fn target(&self) -> Type {
+        let alias = self.alias.upgrade().unwrap();
+        let alias = alias.borrow();
+        let generics = alias
+            .generics
+            .iter()
+            .cloned()
+            .zip(self.generics.iter().cloned())
+            .collect::<HashMap<_, _>>();
+        alias.type_.substitute(&generics)
+    }
 }
 
 impl TypeRef {
     pub(crate) fn from_idl(
         ityperef: &idl::TypeRef,
         ns: &Namespace,
-        builtin_types: &HashMap<String, String>,
+        generics: &[String],
+        builtin_types: &HashSet<String>,
     ) -> Self {
         Self::Unresolved {
             fqtn: FQTN::from_idl(ityperef, ns),
             generics: ityperef
                 .generics
                 .iter()
-                .map(|itype| Type::from_idl(itype, ns, builtin_types))
+                .map(|itype| Type::from_idl(itype, ns, generics, builtin_types))
                 .collect(),
         }
     }
     pub(crate) fn resolve(&mut self, type_map: &TypeMap) -> Result<(), ValidationError> {
+        for type_ in self.generics_mut().iter_mut() {
+            type_.resolve(type_map)?;
+        }
         if let Self::Unresolved { fqtn, generics } = self {
             let ud_type = type_map.get(fqtn);
             let position = FilePosition { line: 0, column: 0 }; // FIXME
             *self = match ud_type {
                 Some(ud_type) => {
-                    if generics.len() != ud_type.generics().len() {
+                    if Some(generics.len()) != type_map.generics_count(fqtn) {
                         return Err(ValidationError {
                             position,
                             cause: Box::new(ValidationErrorCause::GenericsMissmatch {
@@ -217,6 +393,14 @@ impl TypeRef {
                         });
                     }
                     match ud_type {
+                        UserDefinedType::Alias(alias) => TypeRef::Alias(AliasRef {
+                            alias: Rc::downgrade(alias),
+                            generics: generics.clone(),
+                        }),
+                        UserDefinedType::Newtype(newtype) => TypeRef::Newtype(NewtypeRef {
+                            newtype: Rc::downgrade(newtype),
+                            generics: generics.clone(),
+                        }),
                         UserDefinedType::Enum(enum_) => TypeRef::Enum(EnumRef {
                             enum_: Rc::downgrade(enum_),
                             generics: generics.clone(),
@@ -243,6 +427,8 @@ impl TypeRef {
     }
     pub fn fqtn(&self) -> FQTN {
         match self {
+            TypeRef::Alias(alias) => alias.alias.upgrade().unwrap().borrow().fqtn.clone(),
+            TypeRef::Newtype(newtype) => newtype.newtype.upgrade().unwrap().borrow().fqtn.clone(),
             TypeRef::Enum(enum_) => enum_.enum_.upgrade().unwrap().borrow().fqtn.clone(),
             TypeRef::Struct(struct_) => struct_.struct_.upgrade().unwrap().borrow().fqtn.clone(),
             TypeRef::Fieldset(fieldset) => {
@@ -251,14 +437,63 @@ impl TypeRef {
             TypeRef::Unresolved { fqtn, generics: _ } => fqtn.clone(),
         }
     }
+    /// Deprecation of the referenced struct, enum or newtype
+    pub fn deprecation(&self) -> Option<Deprecation> {
+        match self {
+            TypeRef::Newtype(newtype) => newtype
+                .newtype
+                .upgrade()
+                .unwrap()
+                .borrow()
+                .field
+                .deprecated
+                .clone(),
+            TypeRef::Enum(enum_) => enum_.enum_.upgrade().unwrap().borrow().deprecated.clone(),
+            TypeRef::Struct(struct_) => struct_
+                .struct_
+                .upgrade()
+                .unwrap()
+                .borrow()
+                .deprecated
+                .clone(),
+            TypeRef::Alias(_) | TypeRef::Fieldset(_) | TypeRef::Unresolved { .. } => None,
+        }
+    }
+    pub(crate) fn collect_deprecated_types(&self, fqtns: &mut Vec<FQTN>) {
+        if self.deprecation().is_some() {
+            fqtns.push(self.fqtn());
+        }
+        for type_ in self.generics() {
+            type_.collect_deprecated_types(fqtns);
+        }
+    }
     pub fn generics(&self) -> &Vec<Type> {
         match self {
+            TypeRef::Alias(alias) => &alias.generics,
+            TypeRef::Newtype(newtype) => &newtype.generics,
             TypeRef::Enum(enum_) => &enum_.generics,
             TypeRef::Struct(struct_) => &struct_.generics,
             TypeRef::Fieldset(fieldset) => &fieldset.generics,
             TypeRef::Unresolved { fqtn: _, generics } => generics,
         }
     }
+    fn generics_mut(&mut self) -> &mut Vec<Type> {
+        match self {
+            TypeRef::Alias(alias) => &mut alias.generics,
+            TypeRef::Newtype(newtype) => &mut newtype.generics,
+            TypeRef::Enum(enum_) => &mut enum_.generics,
+            TypeRef::Struct(struct_) => &mut struct_.generics,
+            TypeRef::Fieldset(fieldset) => &mut fieldset.generics,
+            TypeRef::Unresolved { fqtn: _, generics } => generics,
+        }
+    }
+    pub(crate) fn substitute(&self, generics: &HashMap<String, Type>) -> Self {
+        let mut typeref = self.clone();
+        for type_ in typeref.generics_mut().iter_mut() {
+            *type_ = type_.substitute(generics);
+        }
+        typeref
+    }
 }
 
 impl Array {
@@ -278,6 +513,8 @@ impl Map {
 impl UserDefinedType {
     pub fn fqtn(&self) -> FQTN {
         match self {
+            Self::Alias(t) => t.borrow().fqtn.clone(),
+            Self::Newtype(t) => t.borrow().fqtn.clone(),
             Self::Enum(t) => t.borrow().fqtn.clone(),
             Self::Fieldset(t) => t.borrow().fqtn.clone(),
             Self::Struct(t) => t.borrow().fqtn.clone(),
@@ -285,6 +522,8 @@ impl UserDefinedType {
     }
     pub(crate) fn resolve(&mut self, type_map: &TypeMap) -> Result<(), ValidationError> {
         match self {
+            Self::Alias(t) => t.borrow_mut().resolve(type_map),
+            Self::Newtype(t) => t.borrow_mut().resolve(type_map),
             Self::Enum(t) => t.borrow_mut().resolve(type_map),
             Self::Fieldset(t) => t.borrow_mut().resolve(type_map),
             Self::Struct(t) => t.borrow_mut().resolve(type_map),
@@ -292,9 +531,72 @@ impl UserDefinedType {
     }
     pub(crate) fn generics(&self) -> Vec<String> {
         match self {
+            Self::Alias(t) => t.borrow().generics.clone(),
+            Self::Newtype(t) => t.borrow().generics.clone(),
             Self::Enum(t) => t.borrow().generics.clone(),
             Self::Fieldset(t) => t.borrow().generics.clone(),
             Self::Struct(t) => t.borrow().generics.clone(),
         }
     }
 }
+
+#[test]
+fn test_schema_type_from_idl_ref_sized_integers() {
+    let ns = Namespace::default();
+    let type_ = |name: &str| {
+        let ityperef = idl::TypeRef {
+            abs: false,
+            ns: vec![],
+            name: name.to_owned(),
+            generics: vec![],
+        };
+        Type::from_idl_ref(&ityperef, &ns, &[], &HashSet::new())
+    };
+    assert!(matches!(type_("Int8"), Type::Int8));
+    assert!(matches!(type_("Int16"), Type::Int16));
+    assert!(matches!(type_("Int32"), Type::Int32));
+    assert!(matches!(type_("Int64"), Type::Int64));
+    assert!(matches!(type_("UInt8"), Type::UInt8));
+    assert!(matches!(type_("UInt16"), Type::UInt16));
+    assert!(matches!(type_("UInt32"), Type::UInt32));
+    assert!(matches!(type_("UInt64"), Type::UInt64));
+    assert!(matches!(type_("Bytes"), Type::Bytes));
+    assert!(matches!(type_("Duration"), Type::Duration));
+    // Generic parameters shadow builtin types.
+    let ityperef = idl::TypeRef {
+        abs: false,
+        ns: vec![],
+        name: "Int8".to_owned(),
+        generics: vec![],
+    };
+    assert!(matches!(
+        Type::from_idl_ref(&ityperef, &ns, &["Int8".to_owned()], &HashSet::new()),
+        Type::Generic(name) if name == "Int8"
+    ));
+}
+
+#[test]
+fn test_schema_type_accepts_value_sized_integers() {
+    let int = idl::Value::Integer;
+    for (type_, min, max) in [
+        (Type::Int8, i8::MIN as i64, i8::MAX as i64),
+        (Type::Int16, i16::MIN as i64, i16::MAX as i64),
+        (Type::Int32, i32::MIN as i64, i32::MAX as i64),
+        (Type::Int64, i64::MIN, i64::MAX),
+        (Type::UInt8, 0, u8::MAX as i64),
+        (Type::UInt16, 0, u16::MAX as i64),
+        (Type::UInt32, 0, u32::MAX as i64),
+        (Type::UInt64, 0, i64::MAX),
+    ] {
+        assert!(type_.accepts_value(&int(min)));
+        assert!(type_.accepts_value(&int(max)));
+        if min > i64::MIN {
+            assert!(!type_.accepts_value(&int(min - 1)));
+        }
+        if max < i64::MAX {
+            assert!(!type_.accepts_value(&int(max + 1)));
+        }
+        assert!(!type_.accepts_value(&idl::Value::Float(1.0)));
+        assert!(!type_.accepts_value(&idl::Value::String("1".to_owned())));
+    }
+}
@@ -5,18 +5,28 @@ use super::r#type::UserDefinedType;
 
 pub struct TypeMap {
     map: HashMap<FQTN, UserDefinedType>,
+    /// Number of generic parameters of the types. This is stored
+    /// separately as types referring to themselves are mutably borrowed
+    /// while their references are resolved.
+    generics: HashMap<FQTN, usize>,
 }
 
 impl TypeMap {
     pub fn new() -> Self {
         Self {
             map: HashMap::new(),
+            generics: HashMap::new(),
         }
     }
     pub fn insert(&mut self, ud_type: &UserDefinedType) {
         self.map.insert(ud_type.fqtn().clone(), ud_type.clone());
+        self.generics
+            .insert(ud_type.fqtn().clone(), ud_type.generics().len());
     }
     pub fn get(&self, fqtn: &FQTN) -> Option<&UserDefinedType> {
         self.map.get(fqtn)
     }
+    pub fn generics_count(&self, fqtn: &FQTN) -> Option<usize> {
+        self.generics.get(fqtn).copied()
+    }
 }
@@ -0,0 +1,207 @@
+use std::collections::HashSet;
+use std::fmt;
+
+use super::fqtn::FQTN;
+use super::namespace::Namespace;
+use super::r#type::{Type, UserDefinedType};
+use super::service::input_struct_name;
+
+/// Issue of a valid document which is reported by `webwire check`
+#[derive(Debug)]
+pub struct Warning {
+    /// Path of the item causing the warning, e.g. `user.User.email`
+    pub item: String,
+    pub cause: WarningCause,
+}
+
+#[derive(Debug)]
+pub enum WarningCause {
+    /// An item which is not deprecated refers to a deprecated type
+    DeprecatedType { fqtn: FQTN },
+}
+
+impl fmt::Display for Warning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.cause {
+            WarningCause::DeprecatedType { fqtn } => write!(
+                f,
+                "{} uses deprecated type {}",
+                self.item,
+                path(&fqtn.ns, &fqtn.name)
+            ),
+        }
+    }
+}
+
+fn path(ns: &[String], name: &str) -> String {
+    ns.iter()
+        .map(String::as_str)
+        .chain(std::iter::once(name))
+        .collect::<Vec<_>>()
+        .join(".")
+}
+
+/// Collect the warnings of the namespace and its child namespaces
+pub(crate) fn check_namespace(ns: &Namespace, warnings: &mut Vec<Warning>) {
+    let mut check = |item: String, types: &[&Type]| {
+        let mut fqtns = Vec::new();
+        for type_ in types {
+            type_.collect_deprecated_types(&mut fqtns);
+        }
+        for fqtn in fqtns {
+            warnings.push(Warning {
+                item: item.clone(),
+                cause: WarningCause::DeprecatedType { fqtn },
+            });
+        }
+    };
+    // The structs synthesized from parameter lists are checked as part
+    // of their methods.
+    let input_structs = ns
+        .services
+        .values()
+        .flat_map(|service| {
+            service
+                .methods
+                .iter()
+                .filter(|method| method.params.is_some())
+                .map(|method| input_struct_name(&service.name, &method.name))
+        })
+        .collect::<HashSet<_>>();
+    for type_ in ns.types.values() {
+        match type_ {
+            UserDefinedType::Alias(alias) => {
+                let alias = alias.borrow();
+                check(path(&ns.path, &alias.fqtn.name), &[&alias.type_]);
+            }
+            UserDefinedType::Newtype(newtype) => {
+                let newtype = newtype.borrow();
+                if newtype.field.deprecated.is_none() {
+                    check(path(&ns.path, &newtype.fqtn.name), &[&newtype.field.type_]);
+                }
+            }
+            UserDefinedType::Enum(enum_) => {
+                let enum_ = enum_.borrow();
+                if enum_.deprecated.is_some() {
+                    continue;
+                }
+                let name = path(&ns.path, &enum_.fqtn.name);
+                if let Some(extends) = &enum_.extends {
+                    check(name.clone(), &[&Type::Ref(extends.clone())]);
+                }
+                for variant in enum_.variants.iter() {
+                    if let (None, Some(value_type)) = (&variant.deprecated, &variant.value_type) {
+                        check(format!("{}.{}", name, variant.name), &[value_type]);
+                    }
+                }
+            }
+            UserDefinedType::Struct(struct_) => {
+                let struct_ = struct_.borrow();
+                if struct_.deprecated.is_some() || input_structs.contains(&struct_.fqtn.name) {
+                    continue;
+                }
+                let name = path(&ns.path, &struct_.fqtn.name);
+                if let Some(extends) = &struct_.extends {
+                    check(name.clone(), &[&Type::Ref(extends.clone())]);
+                }
+                for field in struct_.fields.iter() {
+                    if field.deprecated.is_none() {
+                        check(format!("{}.{}", name, field.name), &[&field.type_]);
+                    }
+                }
+            }
+            UserDefinedType::Fieldset(fieldset) => {
+                let fieldset = fieldset.borrow();
+                let name = path(&ns.path, &fieldset.fqtn.name);
+                check(name.clone(), &[&Type::Ref(fieldset.r#struct.clone())]);
+                for field in fieldset.fields.iter().filter_map(|f| f.field.as_ref()) {
+                    if field.deprecated.is_none() {
+                        check(format!("{}.{}", name, field.name), &[&field.type_]);
+                    }
+                }
+            }
+        }
+    }
+    for service in ns.services.values() {
+        if service.annotations.deprecated.is_some() {
+            continue;
+        }
+        let name = path(&ns.path, &service.name);
+        for method in service.methods.iter() {
+            if method.annotations.deprecated.is_some() {
+                continue;
+            }
+            let name = format!("{}.{}", name, method.name);
+            match &method.params {
+                Some(params) => {
+                    for param in params.iter().filter(|p| p.deprecated.is_none()) {
+                        check(format!("{}.{}", name, param.name), &[&param.type_]);
+                    }
+                    let types = method.output.iter().chain(method.throws.iter());
+                    check(name, &types.collect::<Vec<_>>());
+                }
+                None => {
+                    let types = method
+                        .input
+                        .iter()
+                        .chain(method.output.iter())
+                        .chain(method.throws.iter());
+                    check(name, &types.collect::<Vec<_>>());
+                }
+            }
+        }
+    }
+    for child_ns in ns.namespaces.values() {
+        check_namespace(child_ns, warnings);
+    }
+}
+
+#[test]
+fn test_deprecated_type_warnings() {
+    let idocs = [crate::idl::parse_document(
+        r#"
+        struct Login (deprecated="Use Account", since="1.2") {
+            name: String,
+        }
+        struct Account {
+            name: String,
+        }
+        enum Status {
+            Active,
+            Legacy(Login) (deprecated),
+            Migrated(Login),
+        }
+        struct User {
+            account: Account,
+            login: Login (deprecated),
+            logins: [Login],
+        }
+        struct Session (deprecated) {
+            login: Login,
+        }
+        service Users {
+            get: String -> User,
+            login: Login -> User (deprecated),
+            login_v2: Login -> Account,
+            login_v3(login: Login, session?: Session) -> Account,
+        }
+        "#,
+    )
+    .unwrap()];
+    let doc = super::Document::from_idl(idocs.iter(), &std::collections::HashSet::new()).unwrap();
+    let warnings = doc
+        .warnings()
+        .iter()
+        .map(|w| w.to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        warnings,
+        vec![
+            "Status.Migrated uses deprecated type Login",
+            "User.logins uses deprecated type Login",
+            "Users.login_v2 uses deprecated type Login",
+            "Users.login_v3.login uses deprecated type Login",
+            "Users.login_v3.session uses deprecated type Session",
+        ]
+    );
+}
@@ -0,0 +1,44 @@
+//! Helpers shared by the integration tests
+
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use webwire_cli::idl;
+use webwire_cli::schema;
+
+/// IDL files used for testing the code generators
+pub fn corpus() -> Vec<PathBuf> {
+    let tests_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests");
+    let mut files = vec![tests_dir.join("idl_complete.ww"), tests_dir.join("chat.ww")];
+    let mut fixtures = fs::read_dir(tests_dir.join("fixtures"))
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "ww"))
+        .collect::<Vec<_>>();
+    fixtures.sort();
+    files.extend(fixtures);
+    files
+}
+
+pub fn load(path: &Path) -> schema::Document {
+    let source = fs::read_to_string(path).unwrap();
+    let idoc = idl::parse_document(&source).unwrap_or_else(|e| panic!("{:?}: {}", path, e));
+    let builtin_types = HashSet::from(["Url".to_string()]);
+    schema::Document::from_idl([idoc].iter(), &builtin_types)
+        .unwrap_or_else(|e| panic!("{:?}: {}", path, e))
+}
+
+/// Builtin types of the fixtures mapped to Rust types
+pub fn rust_builtin_types() -> HashMap<String, String> {
+    HashMap::from([("Url".to_string(), "String".to_string())])
+}
+
+/// Builtin types of the fixtures mapped to TypeScript types
+pub fn ts_builtin_types() -> HashMap<String, String> {
+    HashMap::from([("Url".to_string(), "string".to_string())])
+}
+
+pub fn stem(path: &Path) -> String {
+    path.file_stem().unwrap().to_string_lossy().to_string()
+}
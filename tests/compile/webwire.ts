@@ -0,0 +1,26 @@
+// Stub of the webwire TypeScript library used to type check the
+// generated code.
+
+export type UUID = string
+export type Date = string
+export type Time = string
+export type DateTime = string
+export type Decimal = string
+export type Bytes = string
+export type Duration = string
+
+export type Option<T> = T | null
+export type Result<T, E> = { Ok: T } | { Err: E }
+
+export type Response<T> = Promise<T>
+
+export interface Client {
+    request(service: string, method: string, input?: unknown): Promise<any>
+    notify(service: string, method: string, input?: unknown): void
+}
+
+// Notifications resolve to `undefined`.
+export interface Provider {
+    service: string
+    call(method: string, input?: any): Promise<unknown>
+}
@@ -0,0 +1,178 @@
+//! Checks that the generated code compiles.
+//!
+//! The Rust code is generated into a temporary crate which depends on
+//! the crates used by the generated code and is checked using
+//! `cargo check`. The dependencies are fetched from crates.io and the
+//! test is skipped if that fails. `websocket-codec` and `websocket-lite`
+//! are pinned as the published `webwire` crate does not build with
+//! later releases.
+//!
+//! The TypeScript code is checked using `tsc --noEmit`. The compiler is
+//! looked up in the `PATH` or via the `TSC` environment variable and the
+//! test is skipped if it is not installed.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+
+use webwire_cli::codegen;
+
+mod common;
+
+use common::{corpus, load, rust_builtin_types, stem, ts_builtin_types};
+
+const DEPENDENCIES: &str = r#"
+async-trait = "0.1"
+bytes = "1"
+chrono = { version = "0.4", features = ["serde"] }
+futures = "0.3"
+jiff = { version = "0.2", features = ["serde"] }
+rust_decimal = { version = "1", features = ["serde"] }
+serde = { version = "1", features = ["derive"] }
+serde_json = "1"
+serde_with = { version = "3", features = ["base64", "time_0_3"] }
+time = { version = "0.3", features = ["serde-human-readable"] }
+uuid = { version = "0.8", features = ["serde"] }
+validator = { version = "0.14", features = ["derive"] }
+webwire = "0.4"
+websocket-codec = "=0.5.0"
+websocket-lite = "=0.5.0"
+"#;
+
+fn work_dir(name: &str) -> PathBuf {
+    let dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join(name);
+    if dir.exists() {
+        fs::remove_dir_all(&dir).unwrap();
+    }
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+fn cargo(dir: &Path, args: &[&str]) -> Output {
+    let cargo = std::env::var("CARGO").unwrap_or_else(|_| "cargo".to_string());
+    // The target directory lives outside of the work directory so the
+    // dependencies are not rebuilt on every run.
+    let target_dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("generated-rust-target");
+    Command::new(cargo)
+        .args(args)
+        .current_dir(dir)
+        .env("CARGO_TARGET_DIR", target_dir)
+        .output()
+        .unwrap()
+}
+
+#[test]
+fn test_rust_code_compiles() {
+    let dir = work_dir("generated-rust");
+    let manifest = format!(
+        "[package]\nname = \"generated\"\nversion = \"0.0.0\"\nedition = \"2021\"\n\n[workspace]\n\n[dependencies]{}",
+        DEPENDENCIES
+    );
+    fs::write(dir.join("Cargo.toml"), manifest).unwrap();
+
+    let src_dir = dir.join("src");
+    let mut lib = String::new();
+    for path in corpus() {
+        let doc = load(&path);
+        let options = codegen::rust::Options {
+            builtin_types: rust_builtin_types(),
+            ..Default::default()
+        };
+        fs::create_dir_all(&src_dir).unwrap();
+        fs::write(
+            src_dir.join(format!("{}.rs", stem(&path))),
+            codegen::rust::gen(&doc, &options),
+        )
+        .unwrap();
+        codegen::rust::gen_files(&doc, &options)
+            .write(&src_dir.join(format!("{}_tree", stem(&path))))
+            .unwrap();
+        lib += &format!("pub mod {0};\npub mod {0}_tree;\n", stem(&path));
+        for (time_crate, suffix) in [
+            (codegen::rust::TimeCrate::Time, "time"),
+            (codegen::rust::TimeCrate::Jiff, "jiff"),
+        ] {
+            let options = codegen::rust::Options {
+                time_crate,
+                builtin_types: rust_builtin_types(),
+                ..Default::default()
+            };
+            fs::write(
+                src_dir.join(format!("{}_{}.rs", stem(&path), suffix)),
+                codegen::rust::gen(&doc, &options),
+            )
+            .unwrap();
+            lib += &format!("pub mod {}_{};\n", stem(&path), suffix);
+        }
+    }
+    fs::write(src_dir.join("lib.rs"), lib).unwrap();
+
+    let output = cargo(&dir, &["fetch", "--quiet"]);
+    if !output.status.success() {
+        eprintln!(
+            "Skipping test: fetching the dependencies failed:\n{}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+        return;
+    }
+    let output = cargo(&dir, &["check", "--quiet"]);
+    assert!(
+        output.status.success(),
+        "Generated code in {:?} does not compile:\n{}",
+        dir,
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
+
+fn find_tsc() -> Option<PathBuf> {
+    if let Some(tsc) = std::env::var_os("TSC") {
+        return Some(PathBuf::from(tsc));
+    }
+    std::env::var_os("PATH").and_then(|paths| {
+        std::env::split_paths(&paths)
+            .map(|dir| dir.join("tsc"))
+            .find(|path| path.is_file())
+    })
+}
+
+#[test]
+fn test_ts_code_compiles() {
+    let Some(tsc) = find_tsc() else {
+        eprintln!("Skipping test: tsc not found in PATH and TSC is not set");
+        return;
+    };
+    let dir = work_dir("generated-ts");
+    let webwire_ts = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/compile/webwire.ts");
+    let mut files = Vec::new();
+    for path in corpus() {
+        let doc = load(&path);
+        let file_dir = dir.join(stem(&path));
+        let tree_dir = dir.join(format!("{}_tree", stem(&path)));
+        fs::create_dir_all(&file_dir).unwrap();
+        fs::write(
+            file_dir.join("index.ts"),
+            codegen::ts::gen(&doc, &ts_builtin_types()),
+        )
+        .unwrap();
+        fs::copy(&webwire_ts, file_dir.join("webwire.ts")).unwrap();
+        codegen::ts::gen_files(&doc, &ts_builtin_types())
+            .write(&tree_dir)
+            .unwrap();
+        fs::copy(&webwire_ts, tree_dir.join("webwire.ts")).unwrap();
+        files.push(file_dir.join("index.ts"));
+        files.push(tree_dir.join("index.ts"));
+    }
+    let output = Command::new(tsc)
+        .args([
+            "--noEmit", "--strict", "--target", "es2020", "--module", "es2020",
+        ])
+        .args(&files)
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "Generated code in {:?} does not compile:\n{}",
+        dir,
+        String::from_utf8_lossy(&output.stdout)
+    );
+}
@@ -20,28 +20,34 @@ fn test_schema_loader() {
                     parts: vec![
                         NamespacePart::Enum(Enum {
                             name: "UserState".to_string(),
+                            options: vec![],
                             generics: vec![],
                             position: FilePosition { line: 3, column: 5 },
                             extends: None,
                             variants: vec![
                                 EnumVariant {
                                     name: "Active".to_string(),
+                                    options: vec![],
                                     value_type: None,
                                 },
                                 EnumVariant {
                                     name: "Inactive".to_string(),
+                                    options: vec![],
                                     value_type: None,
                                 },
                                 EnumVariant {
                                     name: "Banned".to_string(),
+                                    options: vec![],
                                     value_type: None,
                                 },
                             ]
                         }),
                         NamespacePart::Struct(Struct {
                             name: "UserRequest".to_string(),
+                            options: vec![],
                             position: FilePosition { line: 9, column: 5 },
                             generics: vec![],
+                            extends: None,
                             fields: vec![Field {
                                 name: "email".to_string(),
                                 position: FilePosition {
@@ -55,16 +61,19 @@ fn test_schema_loader() {
                                     generics: vec![]
                                 }),
                                 optional: false,
+                                default: None,
                                 options: vec![]
                             }]
                         }),
                         NamespacePart::Struct(Struct {
                             name: "Name".to_string(),
+                            options: vec![],
                             position: FilePosition {
                                 line: 13,
                                 column: 5
                             },
                             generics: vec![],
+                            extends: None,
                             fields: vec![
                                 Field {
                                     name: "prefix".to_string(),
@@ -79,13 +88,17 @@ fn test_schema_loader() {
                                         generics: vec![]
                                     }),
                                     optional: false,
+                                    default: None,
                                     options: vec![FieldOption {
                                         position: FilePosition {
                                             line: 14,
                                             column: 25
                                         },
                                         name: "length".to_string(),
-                                        value: Value::Range(Some(0), Some(50))
+                                        value: Value::Range(
+                                            Some(RangeBound::Integer(0)),
+                                            Some(RangeBound::Integer(50))
+                                        )
                                     }]
                                 },
                                 Field {
@@ -101,13 +114,17 @@ fn test_schema_loader() {
                                         generics: vec![]
                                     }),
                                     optional: false,
+                                    default: None,
                                     options: vec![FieldOption {
                                         position: FilePosition {
                                             line: 15,
                                             column: 29
                                         },
                                         name: "length".to_string(),
-                                        value: Value::Range(Some(0), Some(100))
+                                        value: Value::Range(
+                                            Some(RangeBound::Integer(0)),
+                                            Some(RangeBound::Integer(100))
+                                        )
                                     }]
                                 },
                                 Field {
@@ -123,13 +140,17 @@ fn test_schema_loader() {
                                         generics: vec![]
                                     }),
                                     optional: false,
+                                    default: None,
                                     options: vec![FieldOption {
                                         position: FilePosition {
                                             line: 16,
                                             column: 30
                                         },
                                         name: "length".to_string(),
-                                        value: Value::Range(Some(0), Some(100))
+                                        value: Value::Range(
+                                            Some(RangeBound::Integer(0)),
+                                            Some(RangeBound::Integer(100))
+                                        )
                                     }]
                                 },
                                 Field {
@@ -145,6 +166,7 @@ fn test_schema_loader() {
                                         generics: vec![]
                                     }),
                                     optional: false,
+                                    default: None,
                                     options: vec![
                                         FieldOption {
                                             position: FilePosition {
@@ -152,7 +174,10 @@ fn test_schema_loader() {
                                                 column: 28
                                             },
                                             name: "length".to_string(),
-                                            value: Value::Range(Some(0), Some(100))
+                                            value: Value::Range(
+                                                Some(RangeBound::Integer(0)),
+                                                Some(RangeBound::Integer(100))
+                                            )
                                         },
                                         FieldOption {
                                             position: FilePosition {
@@ -177,13 +202,17 @@ fn test_schema_loader() {
                                         generics: vec![]
                                     }),
                                     optional: false,
+                                    default: None,
                                     options: vec![FieldOption {
                                         position: FilePosition {
                                             line: 18,
                                             column: 25
                                         },
                                         name: "length".to_string(),
-                                        value: Value::Range(Some(0), Some(50))
+                                        value: Value::Range(
+                                            Some(RangeBound::Integer(0)),
+                                            Some(RangeBound::Integer(50))
+                                        )
                                     }]
                                 },
                                 Field {
@@ -199,6 +228,7 @@ fn test_schema_loader() {
                                         generics: vec![],
                                     }),
                                     optional: false,
+                                    default: None,
                                     options: vec![]
                                 }
                             ]
@@ -216,36 +246,50 @@ fn test_schema_loader() {
                                 name: "Name".to_string(),
                                 generics: vec![],
                             },
+                            include_all: false,
+                            exclude: vec![],
                             fields: vec![
                                 FieldsetField {
                                     name: "prefix".to_string(),
-                                    optional: true
+                                    optional: true,
+                                    type_: None,
+                                    options: vec![]
                                 },
                                 FieldsetField {
                                     name: "first_name".to_string(),
-                                    optional: true
+                                    optional: true,
+                                    type_: None,
+                                    options: vec![]
                                 },
                                 FieldsetField {
                                     name: "middle_name".to_string(),
-                                    optional: true
+                                    optional: true,
+                                    type_: None,
+                                    options: vec![]
                                 },
                                 FieldsetField {
                                     name: "last_name".to_string(),
-                                    optional: true
+                                    optional: true,
+                                    type_: None,
+                                    options: vec![]
                                 },
                                 FieldsetField {
                                     name: "suffix".to_string(),
-                                    optional: true
+                                    optional: true,
+                                    type_: None,
+                                    options: vec![]
                                 }
                             ]
                         }),
                         NamespacePart::Struct(Struct {
                             name: "User".to_string(),
+                            options: vec![],
                             position: FilePosition {
                                 line: 30,
                                 column: 5
                             },
                             generics: vec![],
+                            extends: None,
                             fields: vec![
                                 Field {
                                     name: "id".to_string(),
@@ -260,6 +304,7 @@ fn test_schema_loader() {
                                         generics: vec![]
                                     }),
                                     optional: false,
+                                    default: None,
                                     options: vec![]
                                 },
                                 Field {
@@ -275,6 +320,7 @@ fn test_schema_loader() {
                                         generics: vec![]
                                     }),
                                     optional: false,
+                                    default: None,
                                     options: vec![]
                                 },
                                 Field {
@@ -290,6 +336,7 @@ fn test_schema_loader() {
                                         generics: vec![]
                                     }),
                                     optional: false,
+                                    default: None,
                                     options: vec![]
                                 },
                                 Field {
@@ -305,13 +352,17 @@ fn test_schema_loader() {
                                         generics: vec![]
                                     }),
                                     optional: false,
+                                    default: None,
                                     options: vec![FieldOption {
                                         position: FilePosition {
                                             line: 34,
                                             column: 27
                                         },
                                         name: "length".to_string(),
-                                        value: Value::Range(Some(5), Some(64))
+                                        value: Value::Range(
+                                            Some(RangeBound::Integer(5)),
+                                            Some(RangeBound::Integer(64))
+                                        )
                                     }]
                                 },
                                 Field {
@@ -327,6 +378,7 @@ fn test_schema_loader() {
                                         generics: vec![]
                                     }),
                                     optional: false,
+                                    default: None,
                                     options: vec![]
                                 }
                             ]
@@ -344,22 +396,32 @@ fn test_schema_loader() {
                                 name: "User".to_string(),
                                 generics: vec![],
                             },
+                            include_all: false,
+                            exclude: vec![],
                             fields: vec![
                                 FieldsetField {
                                     name: "id".to_string(),
-                                    optional: false
+                                    optional: false,
+                                    type_: None,
+                                    options: vec![]
                                 },
                                 FieldsetField {
                                     name: "email".to_string(),
-                                    optional: false
+                                    optional: false,
+                                    type_: None,
+                                    options: vec![]
                                 },
                                 FieldsetField {
                                     name: "is_admin".to_string(),
-                                    optional: false
+                                    optional: false,
+                                    type_: None,
+                                    options: vec![]
                                 },
                                 FieldsetField {
                                     name: "name".to_string(),
-                                    optional: false
+                                    optional: false,
+                                    type_: None,
+                                    options: vec![]
                                 }
                             ]
                         }),
@@ -376,36 +438,50 @@ fn test_schema_loader() {
                                 name: "User".to_string(),
                                 generics: vec![],
                             },
+                            include_all: false,
+                            exclude: vec![],
                             fields: vec![
                                 FieldsetField {
                                     name: "id".to_string(),
-                                    optional: false
+                                    optional: false,
+                                    type_: None,
+                                    options: vec![]
                                 },
                                 FieldsetField {
                                     name: "email".to_string(),
-                                    optional: true
+                                    optional: true,
+                                    type_: None,
+                                    options: vec![]
                                 },
                                 FieldsetField {
                                     name: "is_admin".to_string(),
-                                    optional: true
+                                    optional: true,
+                                    type_: None,
+                                    options: vec![]
                                 },
                                 FieldsetField {
                                     name: "name".to_string(),
-                                    optional: true
+                                    optional: true,
+                                    type_: None,
+                                    options: vec![]
                                 },
                                 FieldsetField {
                                     name: "password".to_string(),
-                                    optional: true
+                                    optional: true,
+                                    type_: None,
+                                    options: vec![]
                                 }
                             ]
                         }),
                         NamespacePart::Struct(Struct {
                             name: "UserListRequest".to_string(),
+                            options: vec![],
                             position: FilePosition {
                                 line: 53,
                                 column: 5
                             },
                             generics: vec![],
+                            extends: None,
                             fields: vec![
                                 Field {
                                     name: "offset".to_string(),
@@ -420,6 +496,7 @@ fn test_schema_loader() {
                                         generics: vec![]
                                     }),
                                     optional: true,
+                                    default: None,
                                     options: vec![
                                         FieldOption {
                                             position: FilePosition {
@@ -435,7 +512,7 @@ fn test_schema_loader() {
                                                 column: 36
                                             },
                                             name: "range".to_string(),
-                                            value: Value::Range(Some(0), None)
+                                            value: Value::Range(Some(RangeBound::Integer(0)), None)
                                         }
                                     ]
                                 },
@@ -452,24 +529,30 @@ fn test_schema_loader() {
                                         generics: vec![]
                                     }),
                                     optional: true,
+                                    default: None,
                                     options: vec![FieldOption {
                                         position: FilePosition {
                                             line: 55,
                                             column: 26
                                         },
                                         name: "range".to_string(),
-                                        value: Value::Range(Some(1), Some(200))
+                                        value: Value::Range(
+                                            Some(RangeBound::Integer(1)),
+                                            Some(RangeBound::Integer(200))
+                                        )
                                     }]
                                 }
                             ]
                         }),
                         NamespacePart::Struct(Struct {
                             name: "UserList".to_string(),
+                            options: vec![],
                             position: FilePosition {
                                 line: 58,
                                 column: 5
                             },
                             generics: vec![],
+                            extends: None,
                             fields: vec![
                                 Field {
                                     name: "count".to_string(),
@@ -484,6 +567,7 @@ fn test_schema_loader() {
                                         generics: vec![]
                                     }),
                                     optional: false,
+                                    default: None,
                                     options: vec![
                                         FieldOption {
                                             position: FilePosition {
@@ -491,7 +575,10 @@ fn test_schema_loader() {
                                                 column: 13
                                             },
                                             name: "range".to_string(),
-                                            value: Value::Range(Some(0), Some(65535))
+                                            value: Value::Range(
+                                                Some(RangeBound::Integer(0)),
+                                                Some(RangeBound::Integer(65535))
+                                            )
                                         },
                                         FieldOption {
                                             position: FilePosition {
@@ -518,13 +605,17 @@ fn test_schema_loader() {
                                         generics: vec![],
                                     }))),
                                     optional: false,
+                                    default: None,
                                     options: vec![FieldOption {
                                         position: FilePosition {
                                             line: 63,
                                             column: 28
                                         },
                                         name: "length".to_string(),
-                                        value: Value::Range(Some(0), Some(128))
+                                        value: Value::Range(
+                                            Some(RangeBound::Integer(0)),
+                                            Some(RangeBound::Integer(128))
+                                        )
                                     }]
                                 },
                                 Field {
@@ -548,12 +639,14 @@ fn test_schema_loader() {
                                         })),
                                     ),
                                     optional: false,
+                                    default: None,
                                     options: vec![]
                                 }
                             ]
                         }),
                         NamespacePart::Enum(Enum {
                             name: "GetError".to_string(),
+                            options: vec![],
                             generics: vec![],
                             position: FilePosition {
                                 line: 67,
@@ -563,16 +656,19 @@ fn test_schema_loader() {
                             variants: vec![
                                 EnumVariant {
                                     name: "PermissionDenied".to_string(),
+                                    options: vec![],
                                     value_type: None,
                                 },
                                 EnumVariant {
                                     name: "DoesNotExist".to_string(),
+                                    options: vec![],
                                     value_type: None,
                                 },
                             ],
                         }),
                         NamespacePart::Enum(Enum {
                             name: "ListError".to_string(),
+                            options: vec![],
                             generics: vec![],
                             position: FilePosition {
                                 line: 72,
@@ -581,11 +677,14 @@ fn test_schema_loader() {
                             extends: None,
                             variants: vec![EnumVariant {
                                 name: "PermissionDenied".to_string(),
+                                options: vec![],
                                 value_type: None,
                             },],
                         }),
                         NamespacePart::Service(Service {
                             name: "ExampleService".to_string(),
+                            side: ServiceSide::Server,
+                            options: vec![],
                             position: FilePosition {
                                 line: 76,
                                 column: 5
@@ -594,12 +693,18 @@ fn test_schema_loader() {
                                 Method {
                                     name: "get_version".to_string(),
                                     input: None,
+                                    params: None,
                                     output: Some(Type::Ref(TypeRef {
                                         abs: false,
                                         ns: vec![],
                                         name: "String".to_string(),
                                         generics: vec![]
                                     })),
+                                    throws: None,
+                                    input_stream: false,
+                                    output_stream: false,
+                                    notify: false,
+                                    options: vec![],
                                 },
                                 Method {
                                     name: "user_get".to_string(),
@@ -609,6 +714,7 @@ fn test_schema_loader() {
                                         name: "UserRequest".to_string(),
                                         generics: vec![],
                                     })),
+                                    params: None,
                                     output: Some(Type::Ref(TypeRef {
                                         abs: false,
                                         ns: vec![],
@@ -627,7 +733,12 @@ fn test_schema_loader() {
                                                 generics: vec![],
                                             })
                                         ]
-                                    }))
+                                    })),
+                                    throws: None,
+                                    input_stream: false,
+                                    output_stream: false,
+                                    notify: false,
+                                    options: vec![],
                                 },
                                 Method {
                                     name: "user_list".to_string(),
@@ -637,6 +748,7 @@ fn test_schema_loader() {
                                         name: "UserListRequest".to_string(),
                                         generics: vec![],
                                     })),
+                                    params: None,
                                     output: Some(Type::Ref(TypeRef {
                                         abs: false,
                                         ns: vec![],
@@ -655,7 +767,12 @@ fn test_schema_loader() {
                                                 generics: vec![],
                                             })
                                         ]
-                                    }))
+                                    })),
+                                    throws: None,
+                                    input_stream: false,
+                                    output_stream: false,
+                                    notify: false,
+                                    options: vec![],
                                 },
                             ]
                         }),
@@ -0,0 +1,102 @@
+//! Snapshot tests for the code generators
+//!
+//! The output of the generators for every IDL file of the corpus is
+//! compared with the files in `tests/snapshots`. Run the tests with
+//! `WEBWIRE_BLESS=1` to update the snapshots after changing a generator
+//! and review the changes before committing them.
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+use webwire_cli::codegen;
+
+mod common;
+
+use common::{corpus, load, rust_builtin_types, stem, ts_builtin_types};
+
+fn check_snapshots(dir: &str, extension: &str, gen: impl Fn(&Path) -> String) {
+    let snapshot_dir = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/snapshots")
+        .join(dir);
+    let bless = std::env::var_os("WEBWIRE_BLESS").is_some_and(|v| v != "0");
+    let mut mismatches = Vec::new();
+    for path in corpus() {
+        let snapshot = snapshot_dir.join(format!("{}.{}", stem(&path), extension));
+        let actual = gen(&path);
+        if bless {
+            fs::create_dir_all(&snapshot_dir).unwrap();
+            fs::write(&snapshot, &actual).unwrap();
+            continue;
+        }
+        let expected = fs::read_to_string(&snapshot).unwrap_or_default();
+        if actual != expected {
+            eprintln!("--- {}\n{}", snapshot.display(), actual);
+            mismatches.push(snapshot.display().to_string());
+        }
+    }
+    assert!(
+        mismatches.is_empty(),
+        "Generated code does not match the snapshots (run with WEBWIRE_BLESS=1 to update them):\n{}",
+        mismatches.join("\n")
+    );
+}
+
+#[test]
+fn test_rust_snapshots() {
+    check_snapshots("rust", "rs", |path| {
+        let options = codegen::rust::Options {
+            builtin_types: rust_builtin_types(),
+            ..Default::default()
+        };
+        codegen::rust::gen(&load(path), &options)
+    });
+}
+
+#[test]
+fn test_ts_snapshots() {
+    check_snapshots("ts", "ts", |path| {
+        codegen::ts::gen(&load(path), &ts_builtin_types())
+    });
+}
+
+#[test]
+fn test_kotlin_snapshots() {
+    check_snapshots("kotlin", "kt", |path| {
+        let options = codegen::kotlin::Options {
+            builtin_types: HashMap::from([("Url".to_string(), "String".to_string())]),
+            ..Default::default()
+        };
+        codegen::kotlin::gen(&load(path), &options)
+    });
+}
+
+#[test]
+fn test_swift_snapshots() {
+    check_snapshots("swift", "swift", |path| {
+        let builtin_types = HashMap::from([("Url".to_string(), "URL".to_string())]);
+        codegen::swift::gen(&load(path), &builtin_types)
+    });
+}
+
+#[test]
+fn test_doc_snapshots() {
+    check_snapshots("doc", "md", |path| codegen::doc::gen(&load(path)));
+}
+
+/// The files generated by the example templates are concatenated into a
+/// single snapshot per IDL file.
+#[test]
+fn test_template_snapshots() {
+    let template_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/templates/python");
+    check_snapshots("template", "txt", |path| {
+        let output = codegen::template::gen(&load(path), &template_dir)
+            .unwrap_or_else(|e| panic!("{:?}: {}", path, e));
+        output
+            .files
+            .iter()
+            .map(|(name, content)| format!("==> {} <==\n{}", name, content))
+            .collect::<Vec<_>>()
+            .join("\n")
+    });
+}
@@ -0,0 +1,74 @@
+// GENERATED CODE - DO NOT EDIT!
+
+package api
+
+import kotlinx.serialization.KSerializer
+import kotlinx.serialization.SerialName
+import kotlinx.serialization.Serializable
+import kotlinx.serialization.SerializationException
+import kotlinx.serialization.builtins.*
+import kotlinx.serialization.descriptors.SerialDescriptor
+import kotlinx.serialization.encoding.Decoder
+import kotlinx.serialization.encoding.Encoder
+import kotlinx.serialization.json.*
+import kotlinx.serialization.serializer
+
+object shop {
+
+    @Serializable
+    data class Customer(
+        val emails: List<shop.Email>,
+        val orders: List<shop.Order>,
+        val latest: shop.Order? = null,
+        @SerialName("by_id")
+        val byId: Map<String, shop.Order>,
+    )
+
+    @Serializable
+    @JvmInline
+    value class Email(val value: String)
+
+    @Serializable
+    data class Order(
+        val id: shop.OrderId,
+        @SerialName("user_id")
+        val userId: String,
+        val email: shop.Email,
+        val quantity: shop.Quantity,
+        @SerialName("created_at")
+        val createdAt: kotlinx.datetime.Instant,
+    )
+
+    @Serializable
+    @JvmInline
+    value class OrderId(val value: String)
+
+    @Serializable
+    @JvmInline
+    value class Quantity(val value: UInt)
+
+    interface Orders {
+        suspend fun get(input: shop.OrderId): shop.Order?
+        suspend fun list(input: String): List<shop.Order>
+        suspend fun setEmail(input: shop.Email)
+    }
+
+    class OrdersConsumer(private val client: webwire.Client) : Orders {
+        override suspend fun get(input: shop.OrderId): shop.Order? {
+            val data = Json.encodeToString(serializer<shop.OrderId>(), input)
+            val output = client.request("shop.Orders", "get", data)
+            return Json.decodeFromString(serializer<shop.Order?>(), output)
+        }
+
+        override suspend fun list(input: String): List<shop.Order> {
+            val data = Json.encodeToString(serializer<String>(), input)
+            val output = client.request("shop.Orders", "list", data)
+            return Json.decodeFromString(serializer<List<shop.Order>>(), output)
+        }
+
+        override suspend fun setEmail(input: shop.Email) {
+            val data = Json.encodeToString(serializer<shop.Email>(), input)
+            client.request("shop.Orders", "set_email", data)
+        }
+    }
+}
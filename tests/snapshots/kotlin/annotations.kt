@@ -0,0 +1,90 @@
+// GENERATED CODE - DO NOT EDIT!
+
+package api
+
+import kotlinx.serialization.KSerializer
+import kotlinx.serialization.SerialName
+import kotlinx.serialization.Serializable
+import kotlinx.serialization.SerializationException
+import kotlinx.serialization.builtins.*
+import kotlinx.serialization.descriptors.SerialDescriptor
+import kotlinx.serialization.encoding.Decoder
+import kotlinx.serialization.encoding.Encoder
+import kotlinx.serialization.json.*
+import kotlinx.serialization.serializer
+
+@Serializable
+data class User(
+    val id: String,
+    val name: String,
+)
+
+@Deprecated("Deprecated")
+interface Legacy {
+    suspend fun ping()
+}
+
+@Deprecated("Deprecated")
+class LegacyConsumer(private val client: webwire.Client) : Legacy {
+    override suspend fun ping() {
+        client.request("Legacy", "ping", "")
+    }
+}
+
+interface Plain {
+    suspend fun ping()
+}
+
+class PlainConsumer(private val client: webwire.Client) : Plain {
+    override suspend fun ping() {
+        client.request("Plain", "ping", "")
+    }
+}
+
+interface Users {
+    suspend fun get(input: String): User
+    @Deprecated("Use `search` instead")
+    suspend fun getByName(input: String): User
+    suspend fun search(input: String): List<User>
+    fun touch(input: String)
+}
+
+class UsersConsumer(private val client: webwire.Client) : Users {
+    override suspend fun get(input: String): User {
+        val data = Json.encodeToString(serializer<String>(), input)
+        val output = client.request("Users", "get", data)
+        return Json.decodeFromString(serializer<User>(), output)
+    }
+
+    @Deprecated("Use `search` instead")
+    override suspend fun getByName(input: String): User {
+        val data = Json.encodeToString(serializer<String>(), input)
+        val output = client.request("Users", "get_by_name", data)
+        return Json.decodeFromString(serializer<User>(), output)
+    }
+
+    override suspend fun search(input: String): List<User> {
+        val data = Json.encodeToString(serializer<String>(), input)
+        val output = client.request("Users", "search", data)
+        return Json.decodeFromString(serializer<List<User>>(), output)
+    }
+
+    override fun touch(input: String) {
+        val data = Json.encodeToString(serializer<String>(), input)
+        client.notify("Users", "touch", data)
+    }
+}
+
+object admin {
+
+    interface Audit {
+        suspend fun list(): List<User>
+    }
+
+    class AuditConsumer(private val client: webwire.Client) : Audit {
+        override suspend fun list(): List<User> {
+            val output = client.request("admin.Audit", "list", "")
+            return Json.decodeFromString(serializer<List<User>>(), output)
+        }
+    }
+}
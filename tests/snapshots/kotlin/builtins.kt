@@ -0,0 +1,31 @@
+// GENERATED CODE - DO NOT EDIT!
+
+package api
+
+import kotlinx.serialization.KSerializer
+import kotlinx.serialization.SerialName
+import kotlinx.serialization.Serializable
+import kotlinx.serialization.SerializationException
+import kotlinx.serialization.builtins.*
+import kotlinx.serialization.descriptors.SerialDescriptor
+import kotlinx.serialization.encoding.Decoder
+import kotlinx.serialization.encoding.Encoder
+import kotlinx.serialization.json.*
+import kotlinx.serialization.serializer
+
+@Serializable
+data class Bookmark(
+    val url: String,
+    val title: String,
+)
+
+interface Bookmarks {
+    suspend fun list(): List<Bookmark>
+}
+
+class BookmarksConsumer(private val client: webwire.Client) : Bookmarks {
+    override suspend fun list(): List<Bookmark> {
+        val output = client.request("Bookmarks", "list", "")
+        return Json.decodeFromString(serializer<List<Bookmark>>(), output)
+    }
+}
@@ -0,0 +1,75 @@
+// GENERATED CODE - DO NOT EDIT!
+
+package api
+
+import kotlinx.serialization.KSerializer
+import kotlinx.serialization.SerialName
+import kotlinx.serialization.Serializable
+import kotlinx.serialization.SerializationException
+import kotlinx.serialization.builtins.*
+import kotlinx.serialization.descriptors.SerialDescriptor
+import kotlinx.serialization.encoding.Decoder
+import kotlinx.serialization.encoding.Encoder
+import kotlinx.serialization.json.*
+import kotlinx.serialization.serializer
+
+object chat {
+
+    @Serializable
+    data class Message(
+        val text: String,
+    )
+
+    @Serializable(with = SendError.Serializer::class)
+    sealed class SendError {
+        object PermissionDenied : SendError()
+
+        object Serializer : KSerializer<SendError> {
+            override val descriptor: SerialDescriptor = JsonElement.serializer().descriptor
+
+            override fun serialize(encoder: Encoder, value: SendError) {
+                val output = encoder as JsonEncoder
+                val element = when (value) {
+                    is PermissionDenied -> JsonPrimitive("PermissionDenied")
+                }
+                output.encodeJsonElement(element)
+            }
+
+            override fun deserialize(decoder: Decoder): SendError {
+                val input = decoder as JsonDecoder
+                val (variant, value) = when (val element = input.decodeJsonElement()) {
+                    is JsonPrimitive -> element.content to null
+                    is JsonObject -> element.entries.singleOrNull()?.toPair() ?: throw SerializationException("Expected exactly one variant of SendError")
+                    else -> throw SerializationException("Unexpected JSON for enum SendError")
+                }
+                return when (variant) {
+                    "PermissionDenied" -> PermissionDenied
+                    else -> throw SerializationException("Unknown variant $variant")
+                }
+            }
+        }
+    }
+
+    interface Client {
+        suspend fun onMessage(input: chat.Message)
+    }
+
+    class ClientConsumer(private val client: webwire.Client) : Client {
+        override suspend fun onMessage(input: chat.Message) {
+            val data = Json.encodeToString(serializer<chat.Message>(), input)
+            client.request("chat.Client", "on_message", data)
+        }
+    }
+
+    interface Server {
+        suspend fun send(input: chat.Message): webwire.Result<JsonNull, chat.SendError>
+    }
+
+    class ServerConsumer(private val client: webwire.Client) : Server {
+        override suspend fun send(input: chat.Message): webwire.Result<JsonNull, chat.SendError> {
+            val data = Json.encodeToString(serializer<chat.Message>(), input)
+            val output = client.request("chat.Server", "send", data)
+            return Json.decodeFromString(serializer<webwire.Result<JsonNull, chat.SendError>>(), output)
+        }
+    }
+}
@@ -0,0 +1,61 @@
+// GENERATED CODE - DO NOT EDIT!
+
+package api
+
+import kotlinx.serialization.KSerializer
+import kotlinx.serialization.SerialName
+import kotlinx.serialization.Serializable
+import kotlinx.serialization.SerializationException
+import kotlinx.serialization.builtins.*
+import kotlinx.serialization.descriptors.SerialDescriptor
+import kotlinx.serialization.encoding.Decoder
+import kotlinx.serialization.encoding.Encoder
+import kotlinx.serialization.json.*
+import kotlinx.serialization.serializer
+
+@Serializable
+data class Message(
+    val author: String,
+    val text: String,
+)
+
+interface Chat {
+    suspend fun send(input: Message)
+    suspend fun history(): List<Message>
+}
+
+class ChatConsumer(private val client: webwire.Client) : Chat {
+    override suspend fun send(input: Message) {
+        val data = Json.encodeToString(serializer<Message>(), input)
+        client.request("Chat", "send", data)
+    }
+
+    override suspend fun history(): List<Message> {
+        val output = client.request("Chat", "history", "")
+        return Json.decodeFromString(serializer<List<Message>>(), output)
+    }
+}
+
+interface ChatEvents {
+    suspend fun onMessage(input: Message)
+    suspend fun ping(): Boolean
+}
+
+class ChatEventsProvider(private val service: ChatEvents) : webwire.Provider {
+    override val name: String = "ChatEvents"
+
+    override suspend fun call(method: String, data: String): String {
+        return when (method) {
+            "on_message" -> {
+                val input = Json.decodeFromString(serializer<Message>(), data)
+                service.onMessage(input)
+                "null"
+            }
+            "ping" -> {
+                val output = service.ping()
+                Json.encodeToString(serializer<Boolean>(), output)
+            }
+            else -> throw IllegalArgumentException("Unknown method $method")
+        }
+    }
+}
@@ -0,0 +1,90 @@
+// GENERATED CODE - DO NOT EDIT!
+
+package api
+
+import kotlinx.serialization.KSerializer
+import kotlinx.serialization.SerialName
+import kotlinx.serialization.Serializable
+import kotlinx.serialization.SerializationException
+import kotlinx.serialization.builtins.*
+import kotlinx.serialization.descriptors.SerialDescriptor
+import kotlinx.serialization.encoding.Decoder
+import kotlinx.serialization.encoding.Encoder
+import kotlinx.serialization.json.*
+import kotlinx.serialization.serializer
+
+@Serializable
+data class Label(
+    val id: String,
+    val text: String,
+)
+
+@Serializable(with = Order.Serializer::class)
+sealed class Order {
+    object Ascending : Order()
+    object Descending : Order()
+
+    object Serializer : KSerializer<Order> {
+        override val descriptor: SerialDescriptor = JsonElement.serializer().descriptor
+
+        override fun serialize(encoder: Encoder, value: Order) {
+            val output = encoder as JsonEncoder
+            val element = when (value) {
+                is Ascending -> JsonPrimitive("Ascending")
+                is Descending -> JsonPrimitive("Descending")
+            }
+            output.encodeJsonElement(element)
+        }
+
+        override fun deserialize(decoder: Decoder): Order {
+            val input = decoder as JsonDecoder
+            val (variant, value) = when (val element = input.decodeJsonElement()) {
+                is JsonPrimitive -> element.content to null
+                is JsonObject -> element.entries.singleOrNull()?.toPair() ?: throw SerializationException("Expected exactly one variant of Order")
+                else -> throw SerializationException("Unexpected JSON for enum Order")
+            }
+            return when (variant) {
+                "Ascending" -> Ascending
+                "Descending" -> Descending
+                else -> throw SerializationException("Unknown variant $variant")
+            }
+        }
+    }
+}
+
+@Serializable
+data class Page<T>(
+    val items: List<T>,
+    @SerialName("size_limit")
+    val sizeLimit: Long,
+)
+
+@Serializable
+data class PageSize(
+    val limit: Long,
+)
+
+@Serializable
+data class Query(
+    val search: String,
+    val limit: Long,
+    val offset: UInt,
+    val order: Order,
+    val exact: Boolean,
+    val boost: Double,
+    @SerialName("min_score")
+    val minScore: Double,
+    val tags: List<String>? = null,
+)
+
+interface Search {
+    suspend fun search(input: Query): List<Label>
+}
+
+class SearchConsumer(private val client: webwire.Client) : Search {
+    override suspend fun search(input: Query): List<Label> {
+        val data = Json.encodeToString(serializer<Query>(), input)
+        val output = client.request("Search", "search", data)
+        return Json.decodeFromString(serializer<List<Label>>(), output)
+    }
+}
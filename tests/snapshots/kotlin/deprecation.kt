@@ -0,0 +1,185 @@
+// GENERATED CODE - DO NOT EDIT!
+
+package api
+
+import kotlinx.serialization.KSerializer
+import kotlinx.serialization.SerialName
+import kotlinx.serialization.Serializable
+import kotlinx.serialization.SerializationException
+import kotlinx.serialization.builtins.*
+import kotlinx.serialization.descriptors.SerialDescriptor
+import kotlinx.serialization.encoding.Decoder
+import kotlinx.serialization.encoding.Encoder
+import kotlinx.serialization.json.*
+import kotlinx.serialization.serializer
+
+@Serializable
+data class Account(
+    val id: String,
+    val name: String,
+    @Deprecated("Deprecated since 1.4")
+    val email: String,
+    val emails: List<String>,
+    @Deprecated("Logins are migrated to accounts")
+    val login: Login? = null,
+    val state: AccountState,
+    @Deprecated("Deprecated")
+    @SerialName("legacy_state")
+    val legacyState: AccountState,
+)
+
+@Serializable
+data class AccountRead(
+    val id: String,
+    val name: String,
+    @Deprecated("Deprecated since 1.4")
+    val email: String,
+)
+
+@Serializable(with = AccountState.Serializer::class)
+sealed class AccountState {
+    object Active : AccountState()
+    @Deprecated("Use `Suspended`")
+    object Disabled : AccountState()
+    object Suspended : AccountState()
+
+    object Serializer : KSerializer<AccountState> {
+        override val descriptor: SerialDescriptor = JsonElement.serializer().descriptor
+
+        override fun serialize(encoder: Encoder, value: AccountState) {
+            val output = encoder as JsonEncoder
+            val element = when (value) {
+                is Active -> JsonPrimitive("Active")
+                is Disabled -> JsonPrimitive("Disabled")
+                is Suspended -> JsonPrimitive("Suspended")
+            }
+            output.encodeJsonElement(element)
+        }
+
+        override fun deserialize(decoder: Decoder): AccountState {
+            val input = decoder as JsonDecoder
+            val (variant, value) = when (val element = input.decodeJsonElement()) {
+                is JsonPrimitive -> element.content to null
+                is JsonObject -> element.entries.singleOrNull()?.toPair() ?: throw SerializationException("Expected exactly one variant of AccountState")
+                else -> throw SerializationException("Unexpected JSON for enum AccountState")
+            }
+            return when (variant) {
+                "Active" -> Active
+                "Disabled" -> Disabled
+                "Suspended" -> Suspended
+                else -> throw SerializationException("Unknown variant $variant")
+            }
+        }
+    }
+}
+
+@Serializable(with = Credentials.Serializer::class)
+sealed class Credentials {
+    data class Token(val value: String) : Credentials()
+    @Deprecated("Deprecated since 1.2")
+    data class Password(val value: Login) : Credentials()
+
+    object Serializer : KSerializer<Credentials> {
+        override val descriptor: SerialDescriptor = JsonElement.serializer().descriptor
+
+        override fun serialize(encoder: Encoder, value: Credentials) {
+            val output = encoder as JsonEncoder
+            val element = when (value) {
+                is Token -> JsonObject(mapOf("Token" to output.json.encodeToJsonElement(serializer<String>(), value.value)))
+                is Password -> JsonObject(mapOf("Password" to output.json.encodeToJsonElement(serializer<Login>(), value.value)))
+            }
+            output.encodeJsonElement(element)
+        }
+
+        override fun deserialize(decoder: Decoder): Credentials {
+            val input = decoder as JsonDecoder
+            val (variant, value) = when (val element = input.decodeJsonElement()) {
+                is JsonPrimitive -> element.content to null
+                is JsonObject -> element.entries.singleOrNull()?.toPair() ?: throw SerializationException("Expected exactly one variant of Credentials")
+                else -> throw SerializationException("Unexpected JSON for enum Credentials")
+            }
+            return when (variant) {
+                "Token" -> Token(input.json.decodeFromJsonElement(serializer<String>(), value ?: throw SerializationException("Missing value of variant Token")))
+                "Password" -> Password(input.json.decodeFromJsonElement(serializer<Login>(), value ?: throw SerializationException("Missing value of variant Password")))
+                else -> throw SerializationException("Unknown variant $variant")
+            }
+        }
+    }
+}
+
+@Serializable(with = ExtendedCredentials.Serializer::class)
+sealed class ExtendedCredentials {
+    data class Certificate(val value: String) : ExtendedCredentials()
+    data class Token(val value: String) : ExtendedCredentials()
+    @Deprecated("Deprecated since 1.2")
+    data class Password(val value: Login) : ExtendedCredentials()
+
+    object Serializer : KSerializer<ExtendedCredentials> {
+        override val descriptor: SerialDescriptor = JsonElement.serializer().descriptor
+
+        override fun serialize(encoder: Encoder, value: ExtendedCredentials) {
+            val output = encoder as JsonEncoder
+            val element = when (value) {
+                is Certificate -> JsonObject(mapOf("Certificate" to output.json.encodeToJsonElement(serializer<String>(), value.value)))
+                is Token -> JsonObject(mapOf("Token" to output.json.encodeToJsonElement(serializer<String>(), value.value)))
+                is Password -> JsonObject(mapOf("Password" to output.json.encodeToJsonElement(serializer<Login>(), value.value)))
+            }
+            output.encodeJsonElement(element)
+        }
+
+        override fun deserialize(decoder: Decoder): ExtendedCredentials {
+            val input = decoder as JsonDecoder
+            val (variant, value) = when (val element = input.decodeJsonElement()) {
+                is JsonPrimitive -> element.content to null
+                is JsonObject -> element.entries.singleOrNull()?.toPair() ?: throw SerializationException("Expected exactly one variant of ExtendedCredentials")
+                else -> throw SerializationException("Unexpected JSON for enum ExtendedCredentials")
+            }
+            return when (variant) {
+                "Certificate" -> Certificate(input.json.decodeFromJsonElement(serializer<String>(), value ?: throw SerializationException("Missing value of variant Certificate")))
+                "Token" -> Token(input.json.decodeFromJsonElement(serializer<String>(), value ?: throw SerializationException("Missing value of variant Token")))
+                "Password" -> Password(input.json.decodeFromJsonElement(serializer<Login>(), value ?: throw SerializationException("Missing value of variant Password")))
+                else -> throw SerializationException("Unknown variant $variant")
+            }
+        }
+    }
+}
+
+@Deprecated("Use `Account` instead (since 1.2)")
+@Serializable
+data class Login(
+    val name: String,
+    val password: String,
+)
+
+@Deprecated("Deprecated")
+@Serializable
+@JvmInline
+value class Username(val value: String)
+
+interface Accounts {
+    suspend fun get(input: String): Account
+    @Deprecated("Deprecated since 1.2")
+    suspend fun login(input: Login): Account
+    suspend fun rename(input: Username): Account
+}
+
+class AccountsConsumer(private val client: webwire.Client) : Accounts {
+    override suspend fun get(input: String): Account {
+        val data = Json.encodeToString(serializer<String>(), input)
+        val output = client.request("Accounts", "get", data)
+        return Json.decodeFromString(serializer<Account>(), output)
+    }
+
+    @Deprecated("Deprecated since 1.2")
+    override suspend fun login(input: Login): Account {
+        val data = Json.encodeToString(serializer<Login>(), input)
+        val output = client.request("Accounts", "login", data)
+        return Json.decodeFromString(serializer<Account>(), output)
+    }
+
+    override suspend fun rename(input: Username): Account {
+        val data = Json.encodeToString(serializer<Username>(), input)
+        val output = client.request("Accounts", "rename", data)
+        return Json.decodeFromString(serializer<Account>(), output)
+    }
+}
@@ -0,0 +1,230 @@
+// GENERATED CODE - DO NOT EDIT!
+
+package api
+
+import kotlinx.serialization.KSerializer
+import kotlinx.serialization.SerialName
+import kotlinx.serialization.Serializable
+import kotlinx.serialization.SerializationException
+import kotlinx.serialization.builtins.*
+import kotlinx.serialization.descriptors.SerialDescriptor
+import kotlinx.serialization.encoding.Decoder
+import kotlinx.serialization.encoding.Encoder
+import kotlinx.serialization.json.*
+import kotlinx.serialization.serializer
+
+@Suppress("UNCHECKED_CAST")
+private fun <T> webwireNullable(serializer: KSerializer<T>): KSerializer<T?> =
+    (serializer as KSerializer<Any>).nullable as KSerializer<T?>
+
+@Serializable(with = Base.Serializer::class)
+sealed class Base {
+    object A : Base()
+    data class B(val value: String) : Base()
+
+    object Serializer : KSerializer<Base> {
+        override val descriptor: SerialDescriptor = JsonElement.serializer().descriptor
+
+        override fun serialize(encoder: Encoder, value: Base) {
+            val output = encoder as JsonEncoder
+            val element = when (value) {
+                is A -> JsonPrimitive("A")
+                is B -> JsonObject(mapOf("B" to output.json.encodeToJsonElement(serializer<String>(), value.value)))
+            }
+            output.encodeJsonElement(element)
+        }
+
+        override fun deserialize(decoder: Decoder): Base {
+            val input = decoder as JsonDecoder
+            val (variant, value) = when (val element = input.decodeJsonElement()) {
+                is JsonPrimitive -> element.content to null
+                is JsonObject -> element.entries.singleOrNull()?.toPair() ?: throw SerializationException("Expected exactly one variant of Base")
+                else -> throw SerializationException("Unexpected JSON for enum Base")
+            }
+            return when (variant) {
+                "A" -> A
+                "B" -> B(input.json.decodeFromJsonElement(serializer<String>(), value ?: throw SerializationException("Missing value of variant B")))
+                else -> throw SerializationException("Unknown variant $variant")
+            }
+        }
+    }
+}
+
+@Serializable(with = Empty.Serializer::class)
+sealed class Empty {
+
+    object Serializer : KSerializer<Empty> {
+        override val descriptor: SerialDescriptor = JsonElement.serializer().descriptor
+
+        override fun serialize(encoder: Encoder, value: Empty) {
+            val output = encoder as JsonEncoder
+            throw SerializationException("Enum Empty has no variants")
+        }
+
+        override fun deserialize(decoder: Decoder): Empty {
+            val input = decoder as JsonDecoder
+            val (variant, value) = when (val element = input.decodeJsonElement()) {
+                is JsonPrimitive -> element.content to null
+                is JsonObject -> element.entries.singleOrNull()?.toPair() ?: throw SerializationException("Expected exactly one variant of Empty")
+                else -> throw SerializationException("Unexpected JSON for enum Empty")
+            }
+            return when (variant) {
+                else -> throw SerializationException("Unknown variant $variant")
+            }
+        }
+    }
+}
+
+@Serializable(with = GenericBase.Serializer::class)
+sealed class GenericBase<out T> {
+    data class Value<T>(val value: T) : GenericBase<T>()
+    object Missing : GenericBase<Nothing>()
+
+    class Serializer<T>(private val tSerializer: KSerializer<T>) : KSerializer<GenericBase<T>> {
+        override val descriptor: SerialDescriptor = JsonElement.serializer().descriptor
+
+        override fun serialize(encoder: Encoder, value: GenericBase<T>) {
+            val output = encoder as JsonEncoder
+            val element = when (value) {
+                is Value -> JsonObject(mapOf("Value" to output.json.encodeToJsonElement(tSerializer, value.value)))
+                is Missing -> JsonPrimitive("Missing")
+            }
+            output.encodeJsonElement(element)
+        }
+
+        override fun deserialize(decoder: Decoder): GenericBase<T> {
+            val input = decoder as JsonDecoder
+            val (variant, value) = when (val element = input.decodeJsonElement()) {
+                is JsonPrimitive -> element.content to null
+                is JsonObject -> element.entries.singleOrNull()?.toPair() ?: throw SerializationException("Expected exactly one variant of GenericBase")
+                else -> throw SerializationException("Unexpected JSON for enum GenericBase")
+            }
+            return when (variant) {
+                "Value" -> Value(input.json.decodeFromJsonElement(tSerializer, value ?: throw SerializationException("Missing value of variant Value")))
+                "Missing" -> Missing
+                else -> throw SerializationException("Unknown variant $variant")
+            }
+        }
+    }
+}
+
+object errors {
+
+    @Serializable(with = Extended.Serializer::class)
+    sealed class Extended {
+        data class C(val value: Long) : Extended()
+        object A : Extended()
+        data class B(val value: String) : Extended()
+
+        object Serializer : KSerializer<Extended> {
+            override val descriptor: SerialDescriptor = JsonElement.serializer().descriptor
+
+            override fun serialize(encoder: Encoder, value: Extended) {
+                val output = encoder as JsonEncoder
+                val element = when (value) {
+                    is C -> JsonObject(mapOf("C" to output.json.encodeToJsonElement(serializer<Long>(), value.value)))
+                    is A -> JsonPrimitive("A")
+                    is B -> JsonObject(mapOf("B" to output.json.encodeToJsonElement(serializer<String>(), value.value)))
+                }
+                output.encodeJsonElement(element)
+            }
+
+            override fun deserialize(decoder: Decoder): Extended {
+                val input = decoder as JsonDecoder
+                val (variant, value) = when (val element = input.decodeJsonElement()) {
+                    is JsonPrimitive -> element.content to null
+                    is JsonObject -> element.entries.singleOrNull()?.toPair() ?: throw SerializationException("Expected exactly one variant of Extended")
+                    else -> throw SerializationException("Unexpected JSON for enum Extended")
+                }
+                return when (variant) {
+                    "C" -> C(input.json.decodeFromJsonElement(serializer<Long>(), value ?: throw SerializationException("Missing value of variant C")))
+                    "A" -> A
+                    "B" -> B(input.json.decodeFromJsonElement(serializer<String>(), value ?: throw SerializationException("Missing value of variant B")))
+                    else -> throw SerializationException("Unknown variant $variant")
+                }
+            }
+        }
+    }
+
+    @Serializable(with = ExtendedGeneric.Serializer::class)
+    sealed class ExtendedGeneric {
+        object Other : ExtendedGeneric()
+        data class Value(val value: Double) : ExtendedGeneric()
+        object Missing : ExtendedGeneric()
+
+        object Serializer : KSerializer<ExtendedGeneric> {
+            override val descriptor: SerialDescriptor = JsonElement.serializer().descriptor
+
+            override fun serialize(encoder: Encoder, value: ExtendedGeneric) {
+                val output = encoder as JsonEncoder
+                val element = when (value) {
+                    is Other -> JsonPrimitive("Other")
+                    is Value -> JsonObject(mapOf("Value" to output.json.encodeToJsonElement(serializer<Double>(), value.value)))
+                    is Missing -> JsonPrimitive("Missing")
+                }
+                output.encodeJsonElement(element)
+            }
+
+            override fun deserialize(decoder: Decoder): ExtendedGeneric {
+                val input = decoder as JsonDecoder
+                val (variant, value) = when (val element = input.decodeJsonElement()) {
+                    is JsonPrimitive -> element.content to null
+                    is JsonObject -> element.entries.singleOrNull()?.toPair() ?: throw SerializationException("Expected exactly one variant of ExtendedGeneric")
+                    else -> throw SerializationException("Unexpected JSON for enum ExtendedGeneric")
+                }
+                return when (variant) {
+                    "Other" -> Other
+                    "Value" -> Value(input.json.decodeFromJsonElement(serializer<Double>(), value ?: throw SerializationException("Missing value of variant Value")))
+                    "Missing" -> Missing
+                    else -> throw SerializationException("Unknown variant $variant")
+                }
+            }
+        }
+    }
+
+    @Serializable(with = ExtendedTwice.Serializer::class)
+    sealed class ExtendedTwice {
+        object D : ExtendedTwice()
+        data class C(val value: Long) : ExtendedTwice()
+        object A : ExtendedTwice()
+        data class B(val value: String) : ExtendedTwice()
+
+        object Serializer : KSerializer<ExtendedTwice> {
+            override val descriptor: SerialDescriptor = JsonElement.serializer().descriptor
+
+            override fun serialize(encoder: Encoder, value: ExtendedTwice) {
+                val output = encoder as JsonEncoder
+                val element = when (value) {
+                    is D -> JsonPrimitive("D")
+                    is C -> JsonObject(mapOf("C" to output.json.encodeToJsonElement(serializer<Long>(), value.value)))
+                    is A -> JsonPrimitive("A")
+                    is B -> JsonObject(mapOf("B" to output.json.encodeToJsonElement(serializer<String>(), value.value)))
+                }
+                output.encodeJsonElement(element)
+            }
+
+            override fun deserialize(decoder: Decoder): ExtendedTwice {
+                val input = decoder as JsonDecoder
+                val (variant, value) = when (val element = input.decodeJsonElement()) {
+                    is JsonPrimitive -> element.content to null
+                    is JsonObject -> element.entries.singleOrNull()?.toPair() ?: throw SerializationException("Expected exactly one variant of ExtendedTwice")
+                    else -> throw SerializationException("Unexpected JSON for enum ExtendedTwice")
+                }
+                return when (variant) {
+                    "D" -> D
+                    "C" -> C(input.json.decodeFromJsonElement(serializer<Long>(), value ?: throw SerializationException("Missing value of variant C")))
+                    "A" -> A
+                    "B" -> B(input.json.decodeFromJsonElement(serializer<String>(), value ?: throw SerializationException("Missing value of variant B")))
+                    else -> throw SerializationException("Unknown variant $variant")
+                }
+            }
+        }
+    }
+
+    @Serializable
+    data class Holder(
+        val empty: Empty?,
+        val extended: errors.Extended,
+        val generic: errors.ExtendedGeneric,
+    )
+}
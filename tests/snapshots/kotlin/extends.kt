@@ -0,0 +1,65 @@
+// GENERATED CODE - DO NOT EDIT!
+
+package api
+
+import kotlinx.serialization.KSerializer
+import kotlinx.serialization.SerialName
+import kotlinx.serialization.Serializable
+import kotlinx.serialization.SerializationException
+import kotlinx.serialization.builtins.*
+import kotlinx.serialization.descriptors.SerialDescriptor
+import kotlinx.serialization.encoding.Decoder
+import kotlinx.serialization.encoding.Encoder
+import kotlinx.serialization.json.*
+import kotlinx.serialization.serializer
+
+@Serializable
+data class Entity<ID>(
+    @SerialName("created_at")
+    val createdAt: kotlinx.datetime.Instant,
+    @SerialName("updated_at")
+    val updatedAt: kotlinx.datetime.Instant,
+    val id: ID,
+    val version: Long,
+)
+
+@Serializable
+data class Timestamps(
+    @SerialName("created_at")
+    val createdAt: kotlinx.datetime.Instant,
+    @SerialName("updated_at")
+    val updatedAt: kotlinx.datetime.Instant,
+)
+
+object user {
+
+    @Serializable
+    data class User(
+        @SerialName("created_at")
+        val createdAt: kotlinx.datetime.Instant,
+        @SerialName("updated_at")
+        val updatedAt: kotlinx.datetime.Instant,
+        val id: String,
+        val version: Long,
+        val name: String,
+        val email: String? = null,
+    )
+
+    @Serializable
+    data class UserSummary(
+        val id: String,
+        val name: String,
+    )
+
+    interface Users {
+        suspend fun get(input: String): user.UserSummary
+    }
+
+    class UsersConsumer(private val client: webwire.Client) : Users {
+        override suspend fun get(input: String): user.UserSummary {
+            val data = Json.encodeToString(serializer<String>(), input)
+            val output = client.request("user.Users", "get", data)
+            return Json.decodeFromString(serializer<user.UserSummary>(), output)
+        }
+    }
+}
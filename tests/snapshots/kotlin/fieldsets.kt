@@ -0,0 +1,70 @@
+// GENERATED CODE - DO NOT EDIT!
+
+package api
+
+import kotlinx.serialization.KSerializer
+import kotlinx.serialization.SerialName
+import kotlinx.serialization.Serializable
+import kotlinx.serialization.SerializationException
+import kotlinx.serialization.builtins.*
+import kotlinx.serialization.descriptors.SerialDescriptor
+import kotlinx.serialization.encoding.Decoder
+import kotlinx.serialization.encoding.Encoder
+import kotlinx.serialization.json.*
+import kotlinx.serialization.serializer
+
+@Serializable
+data class User(
+    val id: String,
+    val name: String,
+    val email: String,
+    val password: String,
+    val bio: String? = null,
+)
+
+@Serializable
+data class UserPublic(
+    val id: String,
+    val name: String,
+    val bio: String? = null,
+)
+
+@Serializable
+data class UserSignup(
+    val name: String,
+    val email: String,
+    val password: String,
+    val bio: String? = null,
+)
+
+@Serializable
+data class UserUpdate(
+    val name: String? = null,
+    val bio: String? = null,
+    val password: String? = null,
+)
+
+interface Users {
+    suspend fun get(input: String): UserPublic
+    suspend fun update(input: UserUpdate)
+    suspend fun signup(input: UserSignup): UserPublic
+}
+
+class UsersConsumer(private val client: webwire.Client) : Users {
+    override suspend fun get(input: String): UserPublic {
+        val data = Json.encodeToString(serializer<String>(), input)
+        val output = client.request("Users", "get", data)
+        return Json.decodeFromString(serializer<UserPublic>(), output)
+    }
+
+    override suspend fun update(input: UserUpdate) {
+        val data = Json.encodeToString(serializer<UserUpdate>(), input)
+        client.request("Users", "update", data)
+    }
+
+    override suspend fun signup(input: UserSignup): UserPublic {
+        val data = Json.encodeToString(serializer<UserSignup>(), input)
+        val output = client.request("Users", "signup", data)
+        return Json.decodeFromString(serializer<UserPublic>(), output)
+    }
+}
@@ -0,0 +1,144 @@
+// GENERATED CODE - DO NOT EDIT!
+
+package api
+
+import kotlinx.serialization.KSerializer
+import kotlinx.serialization.SerialName
+import kotlinx.serialization.Serializable
+import kotlinx.serialization.SerializationException
+import kotlinx.serialization.builtins.*
+import kotlinx.serialization.descriptors.SerialDescriptor
+import kotlinx.serialization.encoding.Decoder
+import kotlinx.serialization.encoding.Encoder
+import kotlinx.serialization.json.*
+import kotlinx.serialization.serializer
+
+@Suppress("UNCHECKED_CAST")
+private fun <T> webwireNullable(serializer: KSerializer<T>): KSerializer<T?> =
+    (serializer as KSerializer<Any>).nullable as KSerializer<T?>
+
+@Serializable(with = Either.Serializer::class)
+sealed class Either<out L, out R> {
+    data class Left<L, R>(val value: L) : Either<L, R>()
+    data class Right<L, R>(val value: R) : Either<L, R>()
+
+    class Serializer<L, R>(private val lSerializer: KSerializer<L>, private val rSerializer: KSerializer<R>) : KSerializer<Either<L, R>> {
+        override val descriptor: SerialDescriptor = JsonElement.serializer().descriptor
+
+        override fun serialize(encoder: Encoder, value: Either<L, R>) {
+            val output = encoder as JsonEncoder
+            val element = when (value) {
+                is Left -> JsonObject(mapOf("Left" to output.json.encodeToJsonElement(lSerializer, value.value)))
+                is Right -> JsonObject(mapOf("Right" to output.json.encodeToJsonElement(rSerializer, value.value)))
+            }
+            output.encodeJsonElement(element)
+        }
+
+        override fun deserialize(decoder: Decoder): Either<L, R> {
+            val input = decoder as JsonDecoder
+            val (variant, value) = when (val element = input.decodeJsonElement()) {
+                is JsonPrimitive -> element.content to null
+                is JsonObject -> element.entries.singleOrNull()?.toPair() ?: throw SerializationException("Expected exactly one variant of Either")
+                else -> throw SerializationException("Unexpected JSON for enum Either")
+            }
+            return when (variant) {
+                "Left" -> Left(input.json.decodeFromJsonElement(lSerializer, value ?: throw SerializationException("Missing value of variant Left")))
+                "Right" -> Right(input.json.decodeFromJsonElement(rSerializer, value ?: throw SerializationException("Missing value of variant Right")))
+                else -> throw SerializationException("Unknown variant $variant")
+            }
+        }
+    }
+}
+
+@Serializable
+data class Listing(
+    val users: Page<User>,
+    val pair: Pair<String, List<Long>>,
+    val either: Either<User, Page<String>>,
+    val tree: Tree<User>,
+)
+
+@Serializable
+data class Page<T>(
+    val items: List<T>,
+    val count: Long,
+    val first: T? = null,
+)
+
+@Serializable
+data class Pair<A, B>(
+    val first: A,
+    val second: B,
+)
+
+@Serializable(with = Tree.Serializer::class)
+sealed class Tree<out T> {
+    data class Leaf<T>(val value: T) : Tree<T>()
+    data class Many<T>(val value: List<T>) : Tree<T>()
+    data class Maybe<T>(val value: T?) : Tree<T>()
+    data class Keyed<T>(val value: Map<String, T>) : Tree<T>()
+    data class Paged<T>(val value: Page<T>) : Tree<T>()
+    data class Split<T>(val value: Either<T, Long>) : Tree<T>()
+    object Empty : Tree<Nothing>()
+
+    class Serializer<T>(private val tSerializer: KSerializer<T>) : KSerializer<Tree<T>> {
+        override val descriptor: SerialDescriptor = JsonElement.serializer().descriptor
+
+        override fun serialize(encoder: Encoder, value: Tree<T>) {
+            val output = encoder as JsonEncoder
+            val element = when (value) {
+                is Leaf -> JsonObject(mapOf("Leaf" to output.json.encodeToJsonElement(tSerializer, value.value)))
+                is Many -> JsonObject(mapOf("Many" to output.json.encodeToJsonElement(ListSerializer(tSerializer), value.value)))
+                is Maybe -> JsonObject(mapOf("Maybe" to output.json.encodeToJsonElement(webwireNullable(tSerializer), value.value)))
+                is Keyed -> JsonObject(mapOf("Keyed" to output.json.encodeToJsonElement(MapSerializer(serializer<String>(), tSerializer), value.value)))
+                is Paged -> JsonObject(mapOf("Paged" to output.json.encodeToJsonElement(Page.serializer(tSerializer), value.value)))
+                is Split -> JsonObject(mapOf("Split" to output.json.encodeToJsonElement(Either.Serializer(tSerializer, serializer<Long>()), value.value)))
+                is Empty -> JsonPrimitive("Empty")
+            }
+            output.encodeJsonElement(element)
+        }
+
+        override fun deserialize(decoder: Decoder): Tree<T> {
+            val input = decoder as JsonDecoder
+            val (variant, value) = when (val element = input.decodeJsonElement()) {
+                is JsonPrimitive -> element.content to null
+                is JsonObject -> element.entries.singleOrNull()?.toPair() ?: throw SerializationException("Expected exactly one variant of Tree")
+                else -> throw SerializationException("Unexpected JSON for enum Tree")
+            }
+            return when (variant) {
+                "Leaf" -> Leaf(input.json.decodeFromJsonElement(tSerializer, value ?: throw SerializationException("Missing value of variant Leaf")))
+                "Many" -> Many(input.json.decodeFromJsonElement(ListSerializer(tSerializer), value ?: throw SerializationException("Missing value of variant Many")))
+                "Maybe" -> Maybe(input.json.decodeFromJsonElement(webwireNullable(tSerializer), value ?: throw SerializationException("Missing value of variant Maybe")))
+                "Keyed" -> Keyed(input.json.decodeFromJsonElement(MapSerializer(serializer<String>(), tSerializer), value ?: throw SerializationException("Missing value of variant Keyed")))
+                "Paged" -> Paged(input.json.decodeFromJsonElement(Page.serializer(tSerializer), value ?: throw SerializationException("Missing value of variant Paged")))
+                "Split" -> Split(input.json.decodeFromJsonElement(Either.Serializer(tSerializer, serializer<Long>()), value ?: throw SerializationException("Missing value of variant Split")))
+                "Empty" -> Empty
+                else -> throw SerializationException("Unknown variant $variant")
+            }
+        }
+    }
+}
+
+@Serializable
+data class User(
+    val name: String,
+)
+
+interface Users {
+    suspend fun list(input: Long): Page<User>
+    suspend fun pair(input: Pair<User, Long>): Either<User, String>
+}
+
+class UsersConsumer(private val client: webwire.Client) : Users {
+    override suspend fun list(input: Long): Page<User> {
+        val data = Json.encodeToString(serializer<Long>(), input)
+        val output = client.request("Users", "list", data)
+        return Json.decodeFromString(serializer<Page<User>>(), output)
+    }
+
+    override suspend fun pair(input: Pair<User, Long>): Either<User, String> {
+        val data = Json.encodeToString(serializer<Pair<User, Long>>(), input)
+        val output = client.request("Users", "pair", data)
+        return Json.decodeFromString(serializer<Either<User, String>>(), output)
+    }
+}
@@ -0,0 +1,214 @@
+// GENERATED CODE - DO NOT EDIT!
+
+package api
+
+import kotlinx.serialization.KSerializer
+import kotlinx.serialization.SerialName
+import kotlinx.serialization.Serializable
+import kotlinx.serialization.SerializationException
+import kotlinx.serialization.builtins.*
+import kotlinx.serialization.descriptors.SerialDescriptor
+import kotlinx.serialization.encoding.Decoder
+import kotlinx.serialization.encoding.Encoder
+import kotlinx.serialization.json.*
+import kotlinx.serialization.serializer
+
+object user {
+
+    @Serializable(with = GetError.Serializer::class)
+    sealed class GetError {
+        object PermissionDenied : GetError()
+        object DoesNotExist : GetError()
+
+        object Serializer : KSerializer<GetError> {
+            override val descriptor: SerialDescriptor = JsonElement.serializer().descriptor
+
+            override fun serialize(encoder: Encoder, value: GetError) {
+                val output = encoder as JsonEncoder
+                val element = when (value) {
+                    is PermissionDenied -> JsonPrimitive("PermissionDenied")
+                    is DoesNotExist -> JsonPrimitive("DoesNotExist")
+                }
+                output.encodeJsonElement(element)
+            }
+
+            override fun deserialize(decoder: Decoder): GetError {
+                val input = decoder as JsonDecoder
+                val (variant, value) = when (val element = input.decodeJsonElement()) {
+                    is JsonPrimitive -> element.content to null
+                    is JsonObject -> element.entries.singleOrNull()?.toPair() ?: throw SerializationException("Expected exactly one variant of GetError")
+                    else -> throw SerializationException("Unexpected JSON for enum GetError")
+                }
+                return when (variant) {
+                    "PermissionDenied" -> PermissionDenied
+                    "DoesNotExist" -> DoesNotExist
+                    else -> throw SerializationException("Unknown variant $variant")
+                }
+            }
+        }
+    }
+
+    @Serializable(with = ListError.Serializer::class)
+    sealed class ListError {
+        object PermissionDenied : ListError()
+
+        object Serializer : KSerializer<ListError> {
+            override val descriptor: SerialDescriptor = JsonElement.serializer().descriptor
+
+            override fun serialize(encoder: Encoder, value: ListError) {
+                val output = encoder as JsonEncoder
+                val element = when (value) {
+                    is PermissionDenied -> JsonPrimitive("PermissionDenied")
+                }
+                output.encodeJsonElement(element)
+            }
+
+            override fun deserialize(decoder: Decoder): ListError {
+                val input = decoder as JsonDecoder
+                val (variant, value) = when (val element = input.decodeJsonElement()) {
+                    is JsonPrimitive -> element.content to null
+                    is JsonObject -> element.entries.singleOrNull()?.toPair() ?: throw SerializationException("Expected exactly one variant of ListError")
+                    else -> throw SerializationException("Unexpected JSON for enum ListError")
+                }
+                return when (variant) {
+                    "PermissionDenied" -> PermissionDenied
+                    else -> throw SerializationException("Unknown variant $variant")
+                }
+            }
+        }
+    }
+
+    @Serializable
+    data class Name(
+        val prefix: String,
+        @SerialName("first_name")
+        val firstName: String,
+        @SerialName("middle_name")
+        val middleName: String,
+        @SerialName("last_name")
+        val lastName: String,
+        val suffix: String,
+        @SerialName("full_name")
+        val fullName: String,
+    )
+
+    @Serializable
+    data class NameUpdate(
+        val prefix: String? = null,
+        @SerialName("first_name")
+        val firstName: String? = null,
+        @SerialName("middle_name")
+        val middleName: String? = null,
+        @SerialName("last_name")
+        val lastName: String? = null,
+        val suffix: String? = null,
+    )
+
+    @Serializable
+    data class User(
+        val id: String,
+        val email: String,
+        val name: user.Name,
+        val password: String,
+        @SerialName("is_admin")
+        val isAdmin: Boolean,
+    )
+
+    @Serializable
+    data class UserList(
+        val count: Long,
+        val users: List<user.UserRead>,
+        val permissions: Map<String, String>,
+    )
+
+    @Serializable
+    data class UserListRequest(
+        val offset: Long? = null,
+        val limit: Long? = null,
+    )
+
+    @Serializable
+    data class UserRead(
+        val id: String,
+        val email: String,
+        @SerialName("is_admin")
+        val isAdmin: Boolean,
+        val name: user.Name,
+    )
+
+    @Serializable
+    data class UserRequest(
+        val email: String,
+    )
+
+    @Serializable(with = UserState.Serializer::class)
+    sealed class UserState {
+        object Active : UserState()
+        object Inactive : UserState()
+        object Banned : UserState()
+
+        object Serializer : KSerializer<UserState> {
+            override val descriptor: SerialDescriptor = JsonElement.serializer().descriptor
+
+            override fun serialize(encoder: Encoder, value: UserState) {
+                val output = encoder as JsonEncoder
+                val element = when (value) {
+                    is Active -> JsonPrimitive("Active")
+                    is Inactive -> JsonPrimitive("Inactive")
+                    is Banned -> JsonPrimitive("Banned")
+                }
+                output.encodeJsonElement(element)
+            }
+
+            override fun deserialize(decoder: Decoder): UserState {
+                val input = decoder as JsonDecoder
+                val (variant, value) = when (val element = input.decodeJsonElement()) {
+                    is JsonPrimitive -> element.content to null
+                    is JsonObject -> element.entries.singleOrNull()?.toPair() ?: throw SerializationException("Expected exactly one variant of UserState")
+                    else -> throw SerializationException("Unexpected JSON for enum UserState")
+                }
+                return when (variant) {
+                    "Active" -> Active
+                    "Inactive" -> Inactive
+                    "Banned" -> Banned
+                    else -> throw SerializationException("Unknown variant $variant")
+                }
+            }
+        }
+    }
+
+    @Serializable
+    data class UserWrite(
+        val id: String,
+        val email: String? = null,
+        @SerialName("is_admin")
+        val isAdmin: Boolean? = null,
+        val name: user.Name? = null,
+        val password: String? = null,
+    )
+
+    interface ExampleService {
+        suspend fun getVersion(): String
+        suspend fun userGet(input: user.UserRequest): webwire.Result<user.UserRead, user.GetError>
+        suspend fun userList(input: user.UserListRequest): webwire.Result<user.UserList, user.ListError>
+    }
+
+    class ExampleServiceConsumer(private val client: webwire.Client) : ExampleService {
+        override suspend fun getVersion(): String {
+            val output = client.request("user.ExampleService", "get_version", "")
+            return Json.decodeFromString(serializer<String>(), output)
+        }
+
+        override suspend fun userGet(input: user.UserRequest): webwire.Result<user.UserRead, user.GetError> {
+            val data = Json.encodeToString(serializer<user.UserRequest>(), input)
+            val output = client.request("user.ExampleService", "user_get", data)
+            return Json.decodeFromString(serializer<webwire.Result<user.UserRead, user.GetError>>(), output)
+        }
+
+        override suspend fun userList(input: user.UserListRequest): webwire.Result<user.UserList, user.ListError> {
+            val data = Json.encodeToString(serializer<user.UserListRequest>(), input)
+            val output = client.request("user.ExampleService", "user_list", data)
+            return Json.decodeFromString(serializer<webwire.Result<user.UserList, user.ListError>>(), output)
+        }
+    }
+}
@@ -0,0 +1,133 @@
+// GENERATED CODE - DO NOT EDIT!
+
+package api
+
+import kotlinx.serialization.KSerializer
+import kotlinx.serialization.SerialName
+import kotlinx.serialization.Serializable
+import kotlinx.serialization.SerializationException
+import kotlinx.serialization.builtins.*
+import kotlinx.serialization.descriptors.SerialDescriptor
+import kotlinx.serialization.encoding.Decoder
+import kotlinx.serialization.encoding.Encoder
+import kotlinx.serialization.json.*
+import kotlinx.serialization.serializer
+
+@Serializable
+data class Page<T>(
+    val items: List<T>,
+    val cursor: PageCursor<T>,
+)
+
+@Serializable
+data class PageCursor<T>(
+    val first: T,
+    val next: String? = null,
+)
+
+@Serializable
+data class User(
+    val id: String,
+    val address: UserAddress,
+    val status: UserStatus,
+    val tags: List<UserTags>,
+)
+
+@Serializable
+data class UserAddress(
+    val street: String,
+    val zip: String,
+    val geo: UserAddressGeo? = null,
+)
+
+@Serializable
+data class UserAddressGeo(
+    val lat: Double,
+    val lng: Double,
+)
+
+@Serializable(with = UserStatus.Serializer::class)
+sealed class UserStatus {
+    object Active : UserStatus()
+    data class Banned(val value: UserStatusBanned) : UserStatus()
+
+    object Serializer : KSerializer<UserStatus> {
+        override val descriptor: SerialDescriptor = JsonElement.serializer().descriptor
+
+        override fun serialize(encoder: Encoder, value: UserStatus) {
+            val output = encoder as JsonEncoder
+            val element = when (value) {
+                is Active -> JsonPrimitive("Active")
+                is Banned -> JsonObject(mapOf("Banned" to output.json.encodeToJsonElement(serializer<UserStatusBanned>(), value.value)))
+            }
+            output.encodeJsonElement(element)
+        }
+
+        override fun deserialize(decoder: Decoder): UserStatus {
+            val input = decoder as JsonDecoder
+            val (variant, value) = when (val element = input.decodeJsonElement()) {
+                is JsonPrimitive -> element.content to null
+                is JsonObject -> element.entries.singleOrNull()?.toPair() ?: throw SerializationException("Expected exactly one variant of UserStatus")
+                else -> throw SerializationException("Unexpected JSON for enum UserStatus")
+            }
+            return when (variant) {
+                "Active" -> Active
+                "Banned" -> Banned(input.json.decodeFromJsonElement(serializer<UserStatusBanned>(), value ?: throw SerializationException("Missing value of variant Banned")))
+                else -> throw SerializationException("Unknown variant $variant")
+            }
+        }
+    }
+}
+
+@Serializable
+data class UserStatusBanned(
+    val reason: String,
+    val until: kotlinx.datetime.Instant? = null,
+)
+
+@Serializable
+data class UserTags(
+    val name: String,
+    val color: String? = null,
+)
+
+@Serializable
+data class UsersSearchFilter(
+    val name: String? = null,
+    val status: UserStatus? = null,
+)
+
+@Serializable
+data class UsersSearchInput(
+    val filter: UsersSearchFilter,
+)
+
+@Serializable
+data class UsersSearchOutput(
+    val id: String,
+    val name: String,
+)
+
+@Serializable
+data class UsersStatsOutput(
+    val total: ULong,
+    val active: ULong,
+)
+
+interface Users {
+    suspend fun search(input: UsersSearchInput): List<UsersSearchOutput>
+    suspend fun stats(): UsersStatsOutput
+}
+
+class UsersConsumer(private val client: webwire.Client) : Users {
+    override suspend fun search(input: UsersSearchInput): List<UsersSearchOutput> {
+        val data = Json.encodeToString(serializer<UsersSearchInput>(), input)
+        val output = client.request("Users", "search", data)
+        return Json.decodeFromString(serializer<List<UsersSearchOutput>>(), output)
+    }
+
+    override suspend fun stats(): UsersStatsOutput {
+        val output = client.request("Users", "stats", "")
+        return Json.decodeFromString(serializer<UsersStatsOutput>(), output)
+    }
+}
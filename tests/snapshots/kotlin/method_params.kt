@@ -0,0 +1,122 @@
+// GENERATED CODE - DO NOT EDIT!
+
+package api
+
+import kotlinx.serialization.KSerializer
+import kotlinx.serialization.SerialName
+import kotlinx.serialization.Serializable
+import kotlinx.serialization.SerializationException
+import kotlinx.serialization.builtins.*
+import kotlinx.serialization.descriptors.SerialDescriptor
+import kotlinx.serialization.encoding.Decoder
+import kotlinx.serialization.encoding.Encoder
+import kotlinx.serialization.json.*
+import kotlinx.serialization.serializer
+
+@Serializable(with = GetError.Serializer::class)
+sealed class GetError {
+    object NotFound : GetError()
+
+    object Serializer : KSerializer<GetError> {
+        override val descriptor: SerialDescriptor = JsonElement.serializer().descriptor
+
+        override fun serialize(encoder: Encoder, value: GetError) {
+            val output = encoder as JsonEncoder
+            val element = when (value) {
+                is NotFound -> JsonPrimitive("NotFound")
+            }
+            output.encodeJsonElement(element)
+        }
+
+        override fun deserialize(decoder: Decoder): GetError {
+            val input = decoder as JsonDecoder
+            val (variant, value) = when (val element = input.decodeJsonElement()) {
+                is JsonPrimitive -> element.content to null
+                is JsonObject -> element.entries.singleOrNull()?.toPair() ?: throw SerializationException("Expected exactly one variant of GetError")
+                else -> throw SerializationException("Unexpected JSON for enum GetError")
+            }
+            return when (variant) {
+                "NotFound" -> NotFound
+                else -> throw SerializationException("Unknown variant $variant")
+            }
+        }
+    }
+}
+
+@Serializable
+data class User(
+    val id: String,
+    val name: String,
+)
+
+@Serializable
+data class UsersUserGetInput(
+    val id: String,
+    @SerialName("include_deleted")
+    val includeDeleted: Boolean? = null,
+)
+
+@Serializable
+data class UsersUserRenameInput(
+    val id: String,
+    val name: String,
+    @SerialName("notify_user")
+    val notifyUser: Boolean,
+    val reason: String? = null,
+)
+
+@Serializable
+data class UsersUserSeenInput(
+    val id: String,
+)
+
+interface Users {
+    suspend fun userGet(input: UsersUserGetInput): webwire.Result<User, GetError>
+    suspend fun userRename(input: UsersUserRenameInput)
+    suspend fun userCount(): ULong
+    fun userSeen(input: UsersUserSeenInput)
+}
+
+class UsersConsumer(private val client: webwire.Client) : Users {
+    override suspend fun userGet(input: UsersUserGetInput): webwire.Result<User, GetError> {
+        val data = Json.encodeToString(serializer<UsersUserGetInput>(), input)
+        val output = client.request("Users", "user_get", data)
+        return Json.decodeFromString(serializer<webwire.Result<User, GetError>>(), output)
+    }
+
+    override suspend fun userRename(input: UsersUserRenameInput) {
+        val data = Json.encodeToString(serializer<UsersUserRenameInput>(), input)
+        client.request("Users", "user_rename", data)
+    }
+
+    override suspend fun userCount(): ULong {
+        val output = client.request("Users", "user_count", "")
+        return Json.decodeFromString(serializer<ULong>(), output)
+    }
+
+    override fun userSeen(input: UsersUserSeenInput) {
+        val data = Json.encodeToString(serializer<UsersUserSeenInput>(), input)
+        client.notify("Users", "user_seen", data)
+    }
+}
+
+object admin {
+
+    @Serializable
+    data class UsersUserDeleteInput(
+        val id: String,
+        val reason: String? = null,
+        val force: Boolean,
+    )
+
+    interface Users {
+        suspend fun userDelete(input: admin.UsersUserDeleteInput)
+    }
+
+    class UsersConsumer(private val client: webwire.Client) : Users {
+        override suspend fun userDelete(input: admin.UsersUserDeleteInput) {
+            val data = Json.encodeToString(serializer<admin.UsersUserDeleteInput>(), input)
+            client.request("admin.Users", "user_delete", data)
+        }
+    }
+}
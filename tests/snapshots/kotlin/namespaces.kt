@@ -0,0 +1,60 @@
+// GENERATED CODE - DO NOT EDIT!
+
+package api
+
+import kotlinx.serialization.KSerializer
+import kotlinx.serialization.SerialName
+import kotlinx.serialization.Serializable
+import kotlinx.serialization.SerializationException
+import kotlinx.serialization.builtins.*
+import kotlinx.serialization.descriptors.SerialDescriptor
+import kotlinx.serialization.encoding.Decoder
+import kotlinx.serialization.encoding.Encoder
+import kotlinx.serialization.json.*
+import kotlinx.serialization.serializer
+
+@Serializable
+data class Root(
+    val id: String,
+)
+
+object a {
+
+    @Serializable
+    data class A(
+        val root: Root,
+        val b: a.b.B,
+    )
+
+    object b {
+
+        @Serializable
+        data class B(
+            val value: String,
+        )
+
+        @Serializable
+        data class C(
+            val a: a.A,
+            val root: Root,
+        )
+
+        interface Nested {
+            suspend fun get(input: a.A): a.b.C?
+            suspend fun root(): Root
+        }
+
+        class NestedConsumer(private val client: webwire.Client) : Nested {
+            override suspend fun get(input: a.A): a.b.C? {
+                val data = Json.encodeToString(serializer<a.A>(), input)
+                val output = client.request("a.b.Nested", "get", data)
+                return Json.decodeFromString(serializer<a.b.C?>(), output)
+            }
+
+            override suspend fun root(): Root {
+                val output = client.request("a.b.Nested", "root", "")
+                return Json.decodeFromString(serializer<Root>(), output)
+            }
+        }
+    }
+}
@@ -0,0 +1,101 @@
+// GENERATED CODE - DO NOT EDIT!
+
+package api
+
+import kotlinx.serialization.KSerializer
+import kotlinx.serialization.SerialName
+import kotlinx.serialization.Serializable
+import kotlinx.serialization.SerializationException
+import kotlinx.serialization.builtins.*
+import kotlinx.serialization.descriptors.SerialDescriptor
+import kotlinx.serialization.encoding.Decoder
+import kotlinx.serialization.encoding.Encoder
+import kotlinx.serialization.json.*
+import kotlinx.serialization.serializer
+
+@Serializable(with = Level.Serializer::class)
+sealed class Level {
+    object Debug : Level()
+    object Info : Level()
+    object Error : Level()
+
+    object Serializer : KSerializer<Level> {
+        override val descriptor: SerialDescriptor = JsonElement.serializer().descriptor
+
+        override fun serialize(encoder: Encoder, value: Level) {
+            val output = encoder as JsonEncoder
+            val element = when (value) {
+                is Debug -> JsonPrimitive("Debug")
+                is Info -> JsonPrimitive("Info")
+                is Error -> JsonPrimitive("Error")
+            }
+            output.encodeJsonElement(element)
+        }
+
+        override fun deserialize(decoder: Decoder): Level {
+            val input = decoder as JsonDecoder
+            val (variant, value) = when (val element = input.decodeJsonElement()) {
+                is JsonPrimitive -> element.content to null
+                is JsonObject -> element.entries.singleOrNull()?.toPair() ?: throw SerializationException("Expected exactly one variant of Level")
+                else -> throw SerializationException("Unexpected JSON for enum Level")
+            }
+            return when (variant) {
+                "Debug" -> Debug
+                "Info" -> Info
+                "Error" -> Error
+                else -> throw SerializationException("Unknown variant $variant")
+            }
+        }
+    }
+}
+
+@Serializable
+data class LogEntry(
+    val level: Level,
+    val message: String,
+)
+
+interface Events {
+    fun message(input: String)
+    fun reload()
+}
+
+class EventsProvider(private val service: Events) : webwire.Provider {
+    override val name: String = "Events"
+
+    override suspend fun call(method: String, data: String): String {
+        return when (method) {
+            "message" -> {
+                val input = Json.decodeFromString(serializer<String>(), data)
+                service.message(input)
+                ""
+            }
+            "reload" -> {
+                service.reload()
+                ""
+            }
+            else -> throw IllegalArgumentException("Unknown method $method")
+        }
+    }
+}
+
+interface Telemetry {
+    fun log(input: LogEntry)
+    fun heartbeat()
+    suspend fun flush()
+}
+
+class TelemetryConsumer(private val client: webwire.Client) : Telemetry {
+    override fun log(input: LogEntry) {
+        val data = Json.encodeToString(serializer<LogEntry>(), input)
+        client.notify("Telemetry", "log", data)
+    }
+
+    override fun heartbeat() {
+        client.notify("Telemetry", "heartbeat", "")
+    }
+
+    override suspend fun flush() {
+        client.request("Telemetry", "flush", "")
+    }
+}
@@ -0,0 +1,98 @@
+// GENERATED CODE - DO NOT EDIT!
+
+package api
+
+import kotlinx.coroutines.flow.*
+import kotlinx.serialization.KSerializer
+import kotlinx.serialization.SerialName
+import kotlinx.serialization.Serializable
+import kotlinx.serialization.SerializationException
+import kotlinx.serialization.builtins.*
+import kotlinx.serialization.descriptors.SerialDescriptor
+import kotlinx.serialization.encoding.Decoder
+import kotlinx.serialization.encoding.Encoder
+import kotlinx.serialization.json.*
+import kotlinx.serialization.serializer
+
+@Serializable
+data class Chunk(
+    val data: String,
+)
+
+@Serializable
+data class Event(
+    val topic: String,
+    val payload: String,
+)
+
+@Serializable
+data class Filter(
+    val topic: String,
+)
+
+@Serializable
+data class Receipt(
+    val size: Long,
+)
+
+interface Events {
+    fun watch(input: Filter): Flow<Event>
+    fun tail(): Flow<Event>
+    suspend fun upload(input: Flow<Chunk>): Receipt
+    fun echo(input: Flow<Event>): Flow<Event>
+    suspend fun ping()
+}
+
+class EventsConsumer(private val client: webwire.Client) : Events {
+    override fun watch(input: Filter): Flow<Event> {
+        return flow {
+            val data = Json.encodeToString(serializer<Filter>(), input)
+            val output = client.request("Events", "watch", data)
+            emitAll(Json.decodeFromString(serializer<List<Event>>(), output).asFlow())
+        }
+    }
+
+    override fun tail(): Flow<Event> {
+        return flow {
+            val output = client.request("Events", "tail", "")
+            emitAll(Json.decodeFromString(serializer<List<Event>>(), output).asFlow())
+        }
+    }
+
+    override suspend fun upload(input: Flow<Chunk>): Receipt {
+        val data = Json.encodeToString(serializer<List<Chunk>>(), input.toList())
+        val output = client.request("Events", "upload", data)
+        return Json.decodeFromString(serializer<Receipt>(), output)
+    }
+
+    override fun echo(input: Flow<Event>): Flow<Event> {
+        return flow {
+            val data = Json.encodeToString(serializer<List<Event>>(), input.toList())
+            val output = client.request("Events", "echo", data)
+            emitAll(Json.decodeFromString(serializer<List<Event>>(), output).asFlow())
+        }
+    }
+
+    override suspend fun ping() {
+        client.request("Events", "ping", "")
+    }
+}
+
+interface Notifications {
+    fun subscribe(input: Filter): Flow<Event>
+}
+
+class NotificationsProvider(private val service: Notifications) : webwire.Provider {
+    override val name: String = "Notifications"
+
+    override suspend fun call(method: String, data: String): String {
+        return when (method) {
+            "subscribe" -> {
+                val input = Json.decodeFromString(serializer<Filter>(), data)
+                val output = service.subscribe(input).toList()
+                Json.encodeToString(serializer<List<Event>>(), output)
+            }
+            else -> throw IllegalArgumentException("Unknown method $method")
+        }
+    }
+}
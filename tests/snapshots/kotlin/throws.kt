@@ -0,0 +1,169 @@
+// GENERATED CODE - DO NOT EDIT!
+
+package api
+
+import kotlinx.serialization.KSerializer
+import kotlinx.serialization.SerialName
+import kotlinx.serialization.Serializable
+import kotlinx.serialization.SerializationException
+import kotlinx.serialization.builtins.*
+import kotlinx.serialization.descriptors.SerialDescriptor
+import kotlinx.serialization.encoding.Decoder
+import kotlinx.serialization.encoding.Encoder
+import kotlinx.serialization.json.*
+import kotlinx.serialization.serializer
+
+@Serializable(with = GetError.Serializer::class)
+sealed class GetError {
+    object NotFound : GetError()
+    object PermissionDenied : GetError()
+    data class RateLimited(val value: UInt) : GetError()
+
+    object Serializer : KSerializer<GetError> {
+        override val descriptor: SerialDescriptor = JsonElement.serializer().descriptor
+
+        override fun serialize(encoder: Encoder, value: GetError) {
+            val output = encoder as JsonEncoder
+            val element = when (value) {
+                is NotFound -> JsonPrimitive("NotFound")
+                is PermissionDenied -> JsonPrimitive("PermissionDenied")
+                is RateLimited -> JsonObject(mapOf("RateLimited" to output.json.encodeToJsonElement(serializer<UInt>(), value.value)))
+            }
+            output.encodeJsonElement(element)
+        }
+
+        override fun deserialize(decoder: Decoder): GetError {
+            val input = decoder as JsonDecoder
+            val (variant, value) = when (val element = input.decodeJsonElement()) {
+                is JsonPrimitive -> element.content to null
+                is JsonObject -> element.entries.singleOrNull()?.toPair() ?: throw SerializationException("Expected exactly one variant of GetError")
+                else -> throw SerializationException("Unexpected JSON for enum GetError")
+            }
+            return when (variant) {
+                "NotFound" -> NotFound
+                "PermissionDenied" -> PermissionDenied
+                "RateLimited" -> RateLimited(input.json.decodeFromJsonElement(serializer<UInt>(), value ?: throw SerializationException("Missing value of variant RateLimited")))
+                else -> throw SerializationException("Unknown variant $variant")
+            }
+        }
+    }
+}
+
+@Serializable(with = PromptsConfirmError.Serializer::class)
+sealed class PromptsConfirmError {
+    object Dismissed : PromptsConfirmError()
+
+    object Serializer : KSerializer<PromptsConfirmError> {
+        override val descriptor: SerialDescriptor = JsonElement.serializer().descriptor
+
+        override fun serialize(encoder: Encoder, value: PromptsConfirmError) {
+            val output = encoder as JsonEncoder
+            val element = when (value) {
+                is Dismissed -> JsonPrimitive("Dismissed")
+            }
+            output.encodeJsonElement(element)
+        }
+
+        override fun deserialize(decoder: Decoder): PromptsConfirmError {
+            val input = decoder as JsonDecoder
+            val (variant, value) = when (val element = input.decodeJsonElement()) {
+                is JsonPrimitive -> element.content to null
+                is JsonObject -> element.entries.singleOrNull()?.toPair() ?: throw SerializationException("Expected exactly one variant of PromptsConfirmError")
+                else -> throw SerializationException("Unexpected JSON for enum PromptsConfirmError")
+            }
+            return when (variant) {
+                "Dismissed" -> Dismissed
+                else -> throw SerializationException("Unknown variant $variant")
+            }
+        }
+    }
+}
+
+@Serializable
+data class User(
+    val id: String,
+    val name: String,
+)
+
+@Serializable(with = UsersRenameError.Serializer::class)
+sealed class UsersRenameError {
+    object NotFound : UsersRenameError()
+    object NameTaken : UsersRenameError()
+
+    object Serializer : KSerializer<UsersRenameError> {
+        override val descriptor: SerialDescriptor = JsonElement.serializer().descriptor
+
+        override fun serialize(encoder: Encoder, value: UsersRenameError) {
+            val output = encoder as JsonEncoder
+            val element = when (value) {
+                is NotFound -> JsonPrimitive("NotFound")
+                is NameTaken -> JsonPrimitive("NameTaken")
+            }
+            output.encodeJsonElement(element)
+        }
+
+        override fun deserialize(decoder: Decoder): UsersRenameError {
+            val input = decoder as JsonDecoder
+            val (variant, value) = when (val element = input.decodeJsonElement()) {
+                is JsonPrimitive -> element.content to null
+                is JsonObject -> element.entries.singleOrNull()?.toPair() ?: throw SerializationException("Expected exactly one variant of UsersRenameError")
+                else -> throw SerializationException("Unexpected JSON for enum UsersRenameError")
+            }
+            return when (variant) {
+                "NotFound" -> NotFound
+                "NameTaken" -> NameTaken
+                else -> throw SerializationException("Unknown variant $variant")
+            }
+        }
+    }
+}
+
+@Serializable
+data class UsersRenameInput(
+    val id: String,
+    val name: String,
+)
+
+interface Prompts {
+    suspend fun confirm(input: String): webwire.Result<Boolean, PromptsConfirmError>
+}
+
+class PromptsProvider(private val service: Prompts) : webwire.Provider {
+    override val name: String = "Prompts"
+
+    override suspend fun call(method: String, data: String): String {
+        return when (method) {
+            "confirm" -> {
+                val input = Json.decodeFromString(serializer<String>(), data)
+                val output = service.confirm(input)
+                Json.encodeToString(serializer<webwire.Result<Boolean, PromptsConfirmError>>(), output)
+            }
+            else -> throw IllegalArgumentException("Unknown method $method")
+        }
+    }
+}
+
+interface Users {
+    suspend fun get(input: String): webwire.Result<User, GetError>
+    suspend fun rename(input: UsersRenameInput): webwire.Result<JsonNull, UsersRenameError>
+    suspend fun list(): List<User>
+}
+
+class UsersConsumer(private val client: webwire.Client) : Users {
+    override suspend fun get(input: String): webwire.Result<User, GetError> {
+        val data = Json.encodeToString(serializer<String>(), input)
+        val output = client.request("Users", "get", data)
+        return Json.decodeFromString(serializer<webwire.Result<User, GetError>>(), output)
+    }
+
+    override suspend fun rename(input: UsersRenameInput): webwire.Result<JsonNull, UsersRenameError> {
+        val data = Json.encodeToString(serializer<UsersRenameInput>(), input)
+        val output = client.request("Users", "rename", data)
+        return Json.decodeFromString(serializer<webwire.Result<JsonNull, UsersRenameError>>(), output)
+    }
+
+    override suspend fun list(): List<User> {
+        val output = client.request("Users", "list", "")
+        return Json.decodeFromString(serializer<List<User>>(), output)
+    }
+}
@@ -0,0 +1,103 @@
+// GENERATED CODE - DO NOT EDIT!
+
+package api
+
+import kotlinx.serialization.KSerializer
+import kotlinx.serialization.SerialName
+import kotlinx.serialization.Serializable
+import kotlinx.serialization.SerializationException
+import kotlinx.serialization.builtins.*
+import kotlinx.serialization.descriptors.SerialDescriptor
+import kotlinx.serialization.encoding.Decoder
+import kotlinx.serialization.encoding.Encoder
+import kotlinx.serialization.json.*
+import kotlinx.serialization.serializer
+
+@Serializable
+data class AllTypes(
+    val boolean: Boolean,
+    val integer: Long,
+    val small: Long,
+    val float: Double,
+    val string: String,
+    val uuid: String,
+    val date: kotlinx.datetime.LocalDate,
+    val time: kotlinx.datetime.LocalTime,
+    @SerialName("date_time")
+    val dateTime: kotlinx.datetime.Instant,
+    val decimal: String,
+    val bytes: String,
+    val duration: kotlin.time.Duration,
+    val int8: Byte,
+    val int16: Short,
+    val int32: Int,
+    val int64: Long,
+    val uint8: UByte,
+    val uint16: UShort,
+    val uint32: UInt,
+    val uint64: ULong,
+    val list: List<String>,
+    val map: Map<String, Long>,
+    @SerialName("int_map")
+    val intMap: Map<Long, List<String>>,
+    @SerialName("nested_list")
+    val nestedList: List<List<Long>>,
+    val option: String?,
+    @SerialName("nested_option")
+    val nestedOption: Long??,
+    val result: webwire.Result<String?, List<Long>>,
+    val optional: Long? = null,
+)
+
+@Serializable
+data class AllTypesPartial(
+    val integer: Long,
+    val date: kotlinx.datetime.LocalDate? = null,
+    @SerialName("nested_option")
+    val nestedOption: Long??? = null,
+)
+
+interface Types {
+    suspend fun echo(input: AllTypes): AllTypes
+    suspend fun nothing()
+    suspend fun partial(input: AllTypesPartial): webwire.Result<AllTypes?, JsonNull>
+    suspend fun list(input: List<AllTypes>): Map<String, AllTypes>
+    suspend fun dates(input: List<kotlinx.datetime.LocalDate>): Map<String, kotlinx.datetime.Instant>
+    suspend fun wait(input: kotlin.time.Duration): String?
+}
+
+class TypesConsumer(private val client: webwire.Client) : Types {
+    override suspend fun echo(input: AllTypes): AllTypes {
+        val data = Json.encodeToString(serializer<AllTypes>(), input)
+        val output = client.request("Types", "echo", data)
+        return Json.decodeFromString(serializer<AllTypes>(), output)
+    }
+
+    override suspend fun nothing() {
+        client.request("Types", "nothing", "")
+    }
+
+    override suspend fun partial(input: AllTypesPartial): webwire.Result<AllTypes?, JsonNull> {
+        val data = Json.encodeToString(serializer<AllTypesPartial>(), input)
+        val output = client.request("Types", "partial", data)
+        return Json.decodeFromString(serializer<webwire.Result<AllTypes?, JsonNull>>(), output)
+    }
+
+    override suspend fun list(input: List<AllTypes>): Map<String, AllTypes> {
+        val data = Json.encodeToString(serializer<List<AllTypes>>(), input)
+        val output = client.request("Types", "list", data)
+        return Json.decodeFromString(serializer<Map<String, AllTypes>>(), output)
+    }
+
+    override suspend fun dates(input: List<kotlinx.datetime.LocalDate>): Map<String, kotlinx.datetime.Instant> {
+        val data = Json.encodeToString(serializer<List<kotlinx.datetime.LocalDate>>(), input)
+        val output = client.request("Types", "dates", data)
+        return Json.decodeFromString(serializer<Map<String, kotlinx.datetime.Instant>>(), output)
+    }
+
+    override suspend fun wait(input: kotlin.time.Duration): String? {
+        val data = Json.encodeToString(serializer<kotlin.time.Duration>(), input)
+        val output = client.request("Types", "wait", data)
+        return Json.decodeFromString(serializer<String?>(), output)
+    }
+}
@@ -0,0 +1,211 @@
+// GENERATED CODE - DO NOT EDIT!
+
+#[allow(dead_code)]
+pub mod shop {
+    use ::validator::Validate as _;
+    #[derive(
+        Clone,
+        Debug,
+        Eq,
+        PartialEq,
+        ::serde::Serialize,
+        ::serde::Deserialize,
+        ::validator::Validate
+    )]
+    pub struct Customer {
+        #[validate]
+        pub emails: Vec<Email>,
+        pub orders: Page<Order>,
+        #[validate]
+        pub latest: Option<Order>,
+        pub by_id: std::collections::HashMap<::uuid::Uuid, Order>,
+    }
+    #[derive(
+        Clone,
+        Debug,
+        Eq,
+        PartialEq,
+        ::serde::Serialize,
+        ::serde::Deserialize,
+        ::validator::Validate
+    )]
+    #[serde(transparent)]
+    pub struct Email {
+        #[validate(email, length(min = 3i64, max = 254i64))]
+        pub value: String,
+    }
+    #[derive(
+        Clone,
+        Debug,
+        Eq,
+        PartialEq,
+        ::serde::Serialize,
+        ::serde::Deserialize,
+        ::validator::Validate
+    )]
+    pub struct Order {
+        #[validate]
+        pub id: OrderId,
+        pub user_id: UserId,
+        #[validate]
+        pub email: Email,
+        #[validate]
+        pub quantity: Quantity,
+        pub created_at: Timestamp,
+    }
+    #[derive(
+        Clone,
+        Debug,
+        Eq,
+        PartialEq,
+        ::serde::Serialize,
+        ::serde::Deserialize,
+        ::validator::Validate
+    )]
+    #[serde(transparent)]
+    pub struct OrderId {
+        pub value: ::uuid::Uuid,
+    }
+    pub type Page<T> = Vec<T>;
+    #[derive(
+        Clone,
+        Debug,
+        Eq,
+        PartialEq,
+        ::serde::Serialize,
+        ::serde::Deserialize,
+        ::validator::Validate
+    )]
+    #[serde(transparent)]
+    pub struct Quantity {
+        #[validate(range(min = 1i64, max = 1000i64))]
+        pub value: u32,
+    }
+    pub type Timestamp = ::chrono::DateTime<::chrono::Utc>;
+    pub type UserId = ::uuid::Uuid;
+    #[::async_trait::async_trait]
+    pub trait Orders {
+        type Error: Into<::webwire::ProviderError>;
+        async fn get(&self, input: &OrderId) -> Result<Option<Order>, Self::Error>;
+        async fn list(&self, input: &UserId) -> Result<Page<Order>, Self::Error>;
+        async fn set_email(&self, input: &Email) -> Result<(), Self::Error>;
+    }
+    pub struct OrdersProvider<F>(pub F);
+    impl<F: Sync + Send, S: Sync + Send, T: Sync + Send> ::webwire::NamedProvider<S>
+    for OrdersProvider<F>
+    where
+        F: Fn(::std::sync::Arc<S>) -> T,
+        T: Orders + 'static,
+    {
+        const NAME: &'static str = "shop.Orders";
+    }
+    impl<F: Sync + Send, S: Sync + Send, T: Sync + Send> ::webwire::Provider<S>
+    for OrdersProvider<F>
+    where
+        F: Fn(::std::sync::Arc<S>) -> T,
+        T: Orders + 'static,
+    {
+        fn call(
+            &self,
+            session: &::std::sync::Arc<S>,
+            _service: &str,
+            method: &str,
+            input: ::bytes::Bytes,
+        ) -> ::futures::future::BoxFuture<
+            'static,
+            Result<::bytes::Bytes, ::webwire::ProviderError>,
+        > {
+            let service = self.0(session.clone());
+            match method {
+                "get" => {
+                    Box::pin(async move {
+                        let input = serde_json::from_slice::<OrderId>(&input)
+                            .map_err(::webwire::ProviderError::DeserializerError)?;
+                        ::validator::Validate::validate(&input)
+                            .map_err(::webwire::ProviderError::ValidationError)?;
+                        let output = service.get(&input).await.map_err(|e| e.into())?;
+                        let response = serde_json::to_vec(&output)
+                            .map_err(|e| ::webwire::ProviderError::SerializerError(e))
+                            .map(::bytes::Bytes::from)?;
+                        Ok(response)
+                    })
+                }
+                "list" => {
+                    Box::pin(async move {
+                        let input = serde_json::from_slice::<UserId>(&input)
+                            .map_err(::webwire::ProviderError::DeserializerError)?;
+                        let output = service.list(&input).await.map_err(|e| e.into())?;
+                        let response = serde_json::to_vec(&output)
+                            .map_err(|e| ::webwire::ProviderError::SerializerError(e))
+                            .map(::bytes::Bytes::from)?;
+                        Ok(response)
+                    })
+                }
+                "set_email" => {
+                    Box::pin(async move {
+                        let input = serde_json::from_slice::<Email>(&input)
+                            .map_err(::webwire::ProviderError::DeserializerError)?;
+                        ::validator::Validate::validate(&input)
+                            .map_err(::webwire::ProviderError::ValidationError)?;
+                        let output = service
+                            .set_email(&input)
+                            .await
+                            .map_err(|e| e.into())?;
+                        let response = serde_json::to_vec(&output)
+                            .map_err(|e| ::webwire::ProviderError::SerializerError(e))
+                            .map(::bytes::Bytes::from)?;
+                        Ok(response)
+                    })
+                }
+                _ => {
+                    Box::pin(
+                        ::futures::future::ready(
+                            Err(::webwire::ProviderError::MethodNotFound),
+                        ),
+                    )
+                }
+            }
+        }
+    }
+    pub struct OrdersConsumer<'a>(
+        pub &'a (dyn ::webwire::Consumer + ::std::marker::Sync + ::std::marker::Send),
+    );
+    impl<'a> OrdersConsumer<'a> {
+        pub async fn get(
+            &self,
+            input: &OrderId,
+        ) -> Result<Option<Order>, ::webwire::ConsumerError> {
+            let data: ::bytes::Bytes = serde_json::to_vec(input)
+                .map_err(|e| ::webwire::ConsumerError::SerializerError(e))?
+                .into();
+            let output = self.0.request("shop.Orders", "get", data).await?;
+            let response = ::serde_json::from_slice(&output)
+                .map_err(|e| ::webwire::ConsumerError::DeserializerError(e))?;
+            Ok(response)
+        }
+        pub async fn list(
+            &self,
+            input: &UserId,
+        ) -> Result<Page<Order>, ::webwire::ConsumerError> {
+            let data: ::bytes::Bytes = serde_json::to_vec(input)
+                .map_err(|e| ::webwire::ConsumerError::SerializerError(e))?
+                .into();
+            let output = self.0.request("shop.Orders", "list", data).await?;
+            let response = ::serde_json::from_slice(&output)
+                .map_err(|e| ::webwire::ConsumerError::DeserializerError(e))?;
+            Ok(response)
+        }
+        pub async fn set_email(
+            &self,
+            input: &Email,
+        ) -> Result<(), ::webwire::ConsumerError> {
+            let data: ::bytes::Bytes = serde_json::to_vec(input)
+                .map_err(|e| ::webwire::ConsumerError::SerializerError(e))?
+                .into();
+            let output = self.0.request("shop.Orders", "set_email", data).await?;
+            let response = ::serde_json::from_slice(&output)
+                .map_err(|e| ::webwire::ConsumerError::DeserializerError(e))?;
+            Ok(response)
+        }
+    }
+}
@@ -0,0 +1,493 @@
+// GENERATED CODE - DO NOT EDIT!
+
+#[allow(dead_code)]
+#[derive(
+    Clone,
+    Debug,
+    Eq,
+    PartialEq,
+    ::serde::Serialize,
+    ::serde::Deserialize,
+    ::validator::Validate
+)]
+pub struct User {
+    pub id: ::uuid::Uuid,
+    pub name: String,
+}
+#[deprecated]
+#[::async_trait::async_trait]
+pub trait Legacy {
+    type Error: Into<::webwire::ProviderError>;
+    async fn ping(&self) -> Result<(), Self::Error>;
+}
+pub struct LegacyProvider<F>(pub F);
+impl<F> LegacyProvider<F> {
+    /// Annotations of the service
+    pub const ANNOTATIONS: webwire_annotations::Annotations = webwire_annotations::Annotations {
+        deprecated: true,
+        auth: None,
+        timeout: None,
+        idempotent: false,
+        rate_limit: None,
+    };
+    /// Annotations of the methods by method name
+    pub const METHOD_ANNOTATIONS: &'static [(
+        &'static str,
+        webwire_annotations::Annotations,
+    )] = &[
+        (
+            "ping",
+            webwire_annotations::Annotations {
+                deprecated: false,
+                auth: None,
+                timeout: None,
+                idempotent: false,
+                rate_limit: None,
+            },
+        ),
+    ];
+}
+#[allow(deprecated)]
+impl<F: Sync + Send, S: Sync + Send, T: Sync + Send> ::webwire::NamedProvider<S>
+for LegacyProvider<F>
+where
+    F: Fn(::std::sync::Arc<S>) -> T,
+    T: Legacy + 'static,
+{
+    const NAME: &'static str = "Legacy";
+}
+#[allow(deprecated)]
+impl<F: Sync + Send, S: Sync + Send, T: Sync + Send> ::webwire::Provider<S>
+for LegacyProvider<F>
+where
+    F: Fn(::std::sync::Arc<S>) -> T,
+    T: Legacy + 'static,
+{
+    fn call(
+        &self,
+        session: &::std::sync::Arc<S>,
+        _service: &str,
+        method: &str,
+        input: ::bytes::Bytes,
+    ) -> ::futures::future::BoxFuture<
+        'static,
+        Result<::bytes::Bytes, ::webwire::ProviderError>,
+    > {
+        let service = self.0(session.clone());
+        match method {
+            "ping" => {
+                Box::pin(async move {
+                    let output = service.ping().await.map_err(|e| e.into())?;
+                    let response = serde_json::to_vec(&output)
+                        .map_err(|e| ::webwire::ProviderError::SerializerError(e))
+                        .map(::bytes::Bytes::from)?;
+                    Ok(response)
+                })
+            }
+            _ => {
+                Box::pin(
+                    ::futures::future::ready(
+                        Err(::webwire::ProviderError::MethodNotFound),
+                    ),
+                )
+            }
+        }
+    }
+}
+#[deprecated]
+pub struct LegacyConsumer<'a>(
+    pub &'a (dyn ::webwire::Consumer + ::std::marker::Sync + ::std::marker::Send),
+);
+#[allow(deprecated)]
+impl<'a> LegacyConsumer<'a> {
+    pub async fn ping(&self) -> Result<(), ::webwire::ConsumerError> {
+        let data = ::bytes::Bytes::new();
+        let output = self.0.request("Legacy", "ping", data).await?;
+        let response = ::serde_json::from_slice(&output)
+            .map_err(|e| ::webwire::ConsumerError::DeserializerError(e))?;
+        Ok(response)
+    }
+}
+#[::async_trait::async_trait]
+pub trait Plain {
+    type Error: Into<::webwire::ProviderError>;
+    async fn ping(&self) -> Result<(), Self::Error>;
+}
+pub struct PlainProvider<F>(pub F);
+impl<F: Sync + Send, S: Sync + Send, T: Sync + Send> ::webwire::NamedProvider<S>
+for PlainProvider<F>
+where
+    F: Fn(::std::sync::Arc<S>) -> T,
+    T: Plain + 'static,
+{
+    const NAME: &'static str = "Plain";
+}
+impl<F: Sync + Send, S: Sync + Send, T: Sync + Send> ::webwire::Provider<S>
+for PlainProvider<F>
+where
+    F: Fn(::std::sync::Arc<S>) -> T,
+    T: Plain + 'static,
+{
+    fn call(
+        &self,
+        session: &::std::sync::Arc<S>,
+        _service: &str,
+        method: &str,
+        input: ::bytes::Bytes,
+    ) -> ::futures::future::BoxFuture<
+        'static,
+        Result<::bytes::Bytes, ::webwire::ProviderError>,
+    > {
+        let service = self.0(session.clone());
+        match method {
+            "ping" => {
+                Box::pin(async move {
+                    let output = service.ping().await.map_err(|e| e.into())?;
+                    let response = serde_json::to_vec(&output)
+                        .map_err(|e| ::webwire::ProviderError::SerializerError(e))
+                        .map(::bytes::Bytes::from)?;
+                    Ok(response)
+                })
+            }
+            _ => {
+                Box::pin(
+                    ::futures::future::ready(
+                        Err(::webwire::ProviderError::MethodNotFound),
+                    ),
+                )
+            }
+        }
+    }
+}
+pub struct PlainConsumer<'a>(
+    pub &'a (dyn ::webwire::Consumer + ::std::marker::Sync + ::std::marker::Send),
+);
+impl<'a> PlainConsumer<'a> {
+    pub async fn ping(&self) -> Result<(), ::webwire::ConsumerError> {
+        let data = ::bytes::Bytes::new();
+        let output = self.0.request("Plain", "ping", data).await?;
+        let response = ::serde_json::from_slice(&output)
+            .map_err(|e| ::webwire::ConsumerError::DeserializerError(e))?;
+        Ok(response)
+    }
+}
+#[::async_trait::async_trait]
+pub trait Users {
+    type Error: Into<::webwire::ProviderError>;
+    async fn get(&self, input: &::uuid::Uuid) -> Result<User, Self::Error>;
+    #[deprecated(note = "Use `search` instead")]
+    async fn get_by_name(&self, input: &String) -> Result<User, Self::Error>;
+    async fn search(&self, input: &String) -> Result<Vec<User>, Self::Error>;
+    async fn touch(&self, input: &::uuid::Uuid) -> Result<(), Self::Error>;
+}
+pub struct UsersProvider<F>(pub F);
+impl<F> UsersProvider<F> {
+    /// Annotations of the service
+    pub const ANNOTATIONS: webwire_annotations::Annotations = webwire_annotations::Annotations {
+        deprecated: false,
+        auth: Some("user"),
+        timeout: Some(::std::time::Duration::from_millis(5000)),
+        idempotent: false,
+        rate_limit: None,
+    };
+    /// Annotations of the methods by method name
+    pub const METHOD_ANNOTATIONS: &'static [(
+        &'static str,
+        webwire_annotations::Annotations,
+    )] = &[
+        (
+            "get",
+            webwire_annotations::Annotations {
+                deprecated: false,
+                auth: None,
+                timeout: None,
+                idempotent: true,
+                rate_limit: None,
+            },
+        ),
+        (
+            "get_by_name",
+            webwire_annotations::Annotations {
+                deprecated: true,
+                auth: None,
+                timeout: None,
+                idempotent: false,
+                rate_limit: None,
+            },
+        ),
+        (
+            "search",
+            webwire_annotations::Annotations {
+                deprecated: false,
+                auth: None,
+                timeout: Some(::std::time::Duration::from_millis(30000)),
+                idempotent: true,
+                rate_limit: Some(webwire_annotations::RateLimit {
+                    requests: 10,
+                    per: ::std::time::Duration::from_millis(1000),
+                }),
+            },
+        ),
+        (
+            "touch",
+            webwire_annotations::Annotations {
+                deprecated: false,
+                auth: None,
+                timeout: None,
+                idempotent: false,
+                rate_limit: Some(webwire_annotations::RateLimit {
+                    requests: 100,
+                    per: ::std::time::Duration::from_millis(300000),
+                }),
+            },
+        ),
+    ];
+}
+#[allow(deprecated)]
+impl<F: Sync + Send, S: Sync + Send, T: Sync + Send> ::webwire::NamedProvider<S>
+for UsersProvider<F>
+where
+    F: Fn(::std::sync::Arc<S>) -> T,
+    T: Users + 'static,
+{
+    const NAME: &'static str = "Users";
+}
+#[allow(deprecated)]
+impl<F: Sync + Send, S: Sync + Send, T: Sync + Send> ::webwire::Provider<S>
+for UsersProvider<F>
+where
+    F: Fn(::std::sync::Arc<S>) -> T,
+    T: Users + 'static,
+{
+    fn call(
+        &self,
+        session: &::std::sync::Arc<S>,
+        _service: &str,
+        method: &str,
+        input: ::bytes::Bytes,
+    ) -> ::futures::future::BoxFuture<
+        'static,
+        Result<::bytes::Bytes, ::webwire::ProviderError>,
+    > {
+        let service = self.0(session.clone());
+        match method {
+            "get" => {
+                Box::pin(async move {
+                    let input = serde_json::from_slice::<::uuid::Uuid>(&input)
+                        .map_err(::webwire::ProviderError::DeserializerError)?;
+                    let output = service.get(&input).await.map_err(|e| e.into())?;
+                    let response = serde_json::to_vec(&output)
+                        .map_err(|e| ::webwire::ProviderError::SerializerError(e))
+                        .map(::bytes::Bytes::from)?;
+                    Ok(response)
+                })
+            }
+            "get_by_name" => {
+                Box::pin(async move {
+                    let input = serde_json::from_slice::<String>(&input)
+                        .map_err(::webwire::ProviderError::DeserializerError)?;
+                    let output = service
+                        .get_by_name(&input)
+                        .await
+                        .map_err(|e| e.into())?;
+                    let response = serde_json::to_vec(&output)
+                        .map_err(|e| ::webwire::ProviderError::SerializerError(e))
+                        .map(::bytes::Bytes::from)?;
+                    Ok(response)
+                })
+            }
+            "search" => {
+                Box::pin(async move {
+                    let input = serde_json::from_slice::<String>(&input)
+                        .map_err(::webwire::ProviderError::DeserializerError)?;
+                    let output = service.search(&input).await.map_err(|e| e.into())?;
+                    let response = serde_json::to_vec(&output)
+                        .map_err(|e| ::webwire::ProviderError::SerializerError(e))
+                        .map(::bytes::Bytes::from)?;
+                    Ok(response)
+                })
+            }
+            "touch" => {
+                Box::pin(async move {
+                    let input = serde_json::from_slice::<::uuid::Uuid>(&input)
+                        .map_err(::webwire::ProviderError::DeserializerError)?;
+                    service.touch(&input).await.map_err(|e| e.into())?;
+                    Ok(::bytes::Bytes::new())
+                })
+            }
+            _ => {
+                Box::pin(
+                    ::futures::future::ready(
+                        Err(::webwire::ProviderError::MethodNotFound),
+                    ),
+                )
+            }
+        }
+    }
+}
+pub struct UsersConsumer<'a>(
+    pub &'a (dyn ::webwire::Consumer + ::std::marker::Sync + ::std::marker::Send),
+);
+#[allow(deprecated)]
+impl<'a> UsersConsumer<'a> {
+    pub async fn get(
+        &self,
+        input: &::uuid::Uuid,
+    ) -> Result<User, ::webwire::ConsumerError> {
+        let data: ::bytes::Bytes = serde_json::to_vec(input)
+            .map_err(|e| ::webwire::ConsumerError::SerializerError(e))?
+            .into();
+        let output = self.0.request("Users", "get", data).await?;
+        let response = ::serde_json::from_slice(&output)
+            .map_err(|e| ::webwire::ConsumerError::DeserializerError(e))?;
+        Ok(response)
+    }
+    #[deprecated(note = "Use `search` instead")]
+    pub async fn get_by_name(
+        &self,
+        input: &String,
+    ) -> Result<User, ::webwire::ConsumerError> {
+        let data: ::bytes::Bytes = serde_json::to_vec(input)
+            .map_err(|e| ::webwire::ConsumerError::SerializerError(e))?
+            .into();
+        let output = self.0.request("Users", "get_by_name", data).await?;
+        let response = ::serde_json::from_slice(&output)
+            .map_err(|e| ::webwire::ConsumerError::DeserializerError(e))?;
+        Ok(response)
+    }
+    pub async fn search(
+        &self,
+        input: &String,
+    ) -> Result<Vec<User>, ::webwire::ConsumerError> {
+        let data: ::bytes::Bytes = serde_json::to_vec(input)
+            .map_err(|e| ::webwire::ConsumerError::SerializerError(e))?
+            .into();
+        let output = self.0.request("Users", "search", data).await?;
+        let response = ::serde_json::from_slice(&output)
+            .map_err(|e| ::webwire::ConsumerError::DeserializerError(e))?;
+        Ok(response)
+    }
+    pub fn touch(&self, input: &::uuid::Uuid) -> Result<(), ::webwire::ConsumerError> {
+        let data: ::bytes::Bytes = serde_json::to_vec(input)
+            .map_err(|e| ::webwire::ConsumerError::SerializerError(e))?
+            .into();
+        self.0.notify("Users", "touch", data);
+        Ok(())
+    }
+}
+pub mod admin {
+    #[::async_trait::async_trait]
+    pub trait Audit {
+        type Error: Into<::webwire::ProviderError>;
+        async fn list(&self) -> Result<Vec<super::User>, Self::Error>;
+    }
+    pub struct AuditProvider<F>(pub F);
+    impl<F> AuditProvider<F> {
+        /// Annotations of the service
+        pub const ANNOTATIONS: super::webwire_annotations::Annotations = super::webwire_annotations::Annotations {
+            deprecated: false,
+            auth: Some("admin"),
+            timeout: None,
+            idempotent: false,
+            rate_limit: None,
+        };
+        /// Annotations of the methods by method name
+        pub const METHOD_ANNOTATIONS: &'static [(
+            &'static str,
+            super::webwire_annotations::Annotations,
+        )] = &[
+            (
+                "list",
+                super::webwire_annotations::Annotations {
+                    deprecated: false,
+                    auth: None,
+                    timeout: None,
+                    idempotent: false,
+                    rate_limit: Some(super::webwire_annotations::RateLimit {
+                        requests: 1,
+                        per: ::std::time::Duration::from_millis(1000),
+                    }),
+                },
+            ),
+        ];
+    }
+    impl<F: Sync + Send, S: Sync + Send, T: Sync + Send> ::webwire::NamedProvider<S>
+    for AuditProvider<F>
+    where
+        F: Fn(::std::sync::Arc<S>) -> T,
+        T: Audit + 'static,
+    {
+        const NAME: &'static str = "admin.Audit";
+    }
+    impl<F: Sync + Send, S: Sync + Send, T: Sync + Send> ::webwire::Provider<S>
+    for AuditProvider<F>
+    where
+        F: Fn(::std::sync::Arc<S>) -> T,
+        T: Audit + 'static,
+    {
+        fn call(
+            &self,
+            session: &::std::sync::Arc<S>,
+            _service: &str,
+            method: &str,
+            input: ::bytes::Bytes,
+        ) -> ::futures::future::BoxFuture<
+            'static,
+            Result<::bytes::Bytes, ::webwire::ProviderError>,
+        > {
+            let service = self.0(session.clone());
+            match method {
+                "list" => {
+                    Box::pin(async move {
+                        let output = service.list().await.map_err(|e| e.into())?;
+                        let response = serde_json::to_vec(&output)
+                            .map_err(|e| ::webwire::ProviderError::SerializerError(e))
+                            .map(::bytes::Bytes::from)?;
+                        Ok(response)
+                    })
+                }
+                _ => {
+                    Box::pin(
+                        ::futures::future::ready(
+                            Err(::webwire::ProviderError::MethodNotFound),
+                        ),
+                    )
+                }
+            }
+        }
+    }
+    pub struct AuditConsumer<'a>(
+        pub &'a (dyn ::webwire::Consumer + ::std::marker::Sync + ::std::marker::Send),
+    );
+    impl<'a> AuditConsumer<'a> {
+        pub async fn list(&self) -> Result<Vec<super::User>, ::webwire::ConsumerError> {
+            let data = ::bytes::Bytes::new();
+            let output = self.0.request("admin.Audit", "list", data).await?;
+            let response = ::serde_json::from_slice(&output)
+                .map_err(|e| ::webwire::ConsumerError::DeserializerError(e))?;
+            Ok(response)
+        }
+    }
+}
+pub mod webwire_annotations {
+    /// Annotations of a service or method
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub struct Annotations {
+        /// The service or method is deprecated
+        pub deprecated: bool,
+        /// Role or scope required to call the service or method
+        pub auth: Option<&'static str>,
+        /// Maximum duration of a call
+        pub timeout: Option<::std::time::Duration>,
+        /// The method can safely be retried
+        pub idempotent: bool,
+        /// Maximum number of requests
+        pub rate_limit: Option<RateLimit>,
+    }
+    /// Maximum number of requests per time period
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub struct RateLimit {
+        pub requests: u64,
+        pub per: ::std::time::Duration,
+    }
+}
@@ -0,0 +1,80 @@
+// GENERATED CODE - DO NOT EDIT!
+
+#[allow(dead_code)]
+#[derive(
+    Clone,
+    Debug,
+    Eq,
+    PartialEq,
+    ::serde::Serialize,
+    ::serde::Deserialize,
+    ::validator::Validate
+)]
+pub struct Bookmark {
+    pub url: String,
+    #[validate(length(min = 1i64, max = 100i64))]
+    pub title: String,
+}
+#[::async_trait::async_trait]
+pub trait Bookmarks {
+    type Error: Into<::webwire::ProviderError>;
+    async fn list(&self) -> Result<Vec<Bookmark>, Self::Error>;
+}
+pub struct BookmarksProvider<F>(pub F);
+impl<F: Sync + Send, S: Sync + Send, T: Sync + Send> ::webwire::NamedProvider<S>
+for BookmarksProvider<F>
+where
+    F: Fn(::std::sync::Arc<S>) -> T,
+    T: Bookmarks + 'static,
+{
+    const NAME: &'static str = "Bookmarks";
+}
+impl<F: Sync + Send, S: Sync + Send, T: Sync + Send> ::webwire::Provider<S>
+for BookmarksProvider<F>
+where
+    F: Fn(::std::sync::Arc<S>) -> T,
+    T: Bookmarks + 'static,
+{
+    fn call(
+        &self,
+        session: &::std::sync::Arc<S>,
+        _service: &str,
+        method: &str,
+        input: ::bytes::Bytes,
+    ) -> ::futures::future::BoxFuture<
+        'static,
+        Result<::bytes::Bytes, ::webwire::ProviderError>,
+    > {
+        let service = self.0(session.clone());
+        match method {
+            "list" => {
+                Box::pin(async move {
+                    let output = service.list().await.map_err(|e| e.into())?;
+                    let response = serde_json::to_vec(&output)
+                        .map_err(|e| ::webwire::ProviderError::SerializerError(e))
+                        .map(::bytes::Bytes::from)?;
+                    Ok(response)
+                })
+            }
+            _ => {
+                Box::pin(
+                    ::futures::future::ready(
+                        Err(::webwire::ProviderError::MethodNotFound),
+                    ),
+                )
+            }
+        }
+    }
+}
+pub struct BookmarksConsumer<'a>(
+    pub &'a (dyn ::webwire::Consumer + ::std::marker::Sync + ::std::marker::Send),
+);
+impl<'a> BookmarksConsumer<'a> {
+    pub async fn list(&self) -> Result<Vec<Bookmark>, ::webwire::ConsumerError> {
+        let data = ::bytes::Bytes::new();
+        let output = self.0.request("Bookmarks", "list", data).await?;
+        let response = ::serde_json::from_slice(&output)
+            .map_err(|e| ::webwire::ConsumerError::DeserializerError(e))?;
+        Ok(response)
+    }
+}
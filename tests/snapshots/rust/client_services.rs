@@ -0,0 +1,128 @@
+// GENERATED CODE - DO NOT EDIT!
+
+#[allow(dead_code)]
+#[derive(
+    Clone,
+    Debug,
+    Eq,
+    PartialEq,
+    ::serde::Serialize,
+    ::serde::Deserialize,
+    ::validator::Validate
+)]
+pub struct Message {
+    #[validate(length(min = 1i64, max = 64i64))]
+    pub author: String,
+    #[validate(length(min = 1i64, max = 1000i64))]
+    pub text: String,
+}
+#[::async_trait::async_trait]
+pub trait Chat {
+    type Error: Into<::webwire::ProviderError>;
+    async fn send(&self, input: &Message) -> Result<(), Self::Error>;
+    async fn history(&self) -> Result<Vec<Message>, Self::Error>;
+}
+pub struct ChatProvider<F>(pub F);
+impl<F: Sync + Send, S: Sync + Send, T: Sync + Send> ::webwire::NamedProvider<S>
+for ChatProvider<F>
+where
+    F: Fn(::std::sync::Arc<S>) -> T,
+    T: Chat + 'static,
+{
+    const NAME: &'static str = "Chat";
+}
+impl<F: Sync + Send, S: Sync + Send, T: Sync + Send> ::webwire::Provider<S>
+for ChatProvider<F>
+where
+    F: Fn(::std::sync::Arc<S>) -> T,
+    T: Chat + 'static,
+{
+    fn call(
+        &self,
+        session: &::std::sync::Arc<S>,
+        _service: &str,
+        method: &str,
+        input: ::bytes::Bytes,
+    ) -> ::futures::future::BoxFuture<
+        'static,
+        Result<::bytes::Bytes, ::webwire::ProviderError>,
+    > {
+        let service = self.0(session.clone());
+        match method {
+            "send" => {
+                Box::pin(async move {
+                    let input = serde_json::from_slice::<Message>(&input)
+                        .map_err(::webwire::ProviderError::DeserializerError)?;
+                    ::validator::Validate::validate(&input)
+                        .map_err(::webwire::ProviderError::ValidationError)?;
+                    let output = service.send(&input).await.map_err(|e| e.into())?;
+                    let response = serde_json::to_vec(&output)
+                        .map_err(|e| ::webwire::ProviderError::SerializerError(e))
+                        .map(::bytes::Bytes::from)?;
+                    Ok(response)
+                })
+            }
+            "history" => {
+                Box::pin(async move {
+                    let output = service.history().await.map_err(|e| e.into())?;
+                    let response = serde_json::to_vec(&output)
+                        .map_err(|e| ::webwire::ProviderError::SerializerError(e))
+                        .map(::bytes::Bytes::from)?;
+                    Ok(response)
+                })
+            }
+            _ => {
+                Box::pin(
+                    ::futures::future::ready(
+                        Err(::webwire::ProviderError::MethodNotFound),
+                    ),
+                )
+            }
+        }
+    }
+}
+pub struct ChatConsumer<'a>(
+    pub &'a (dyn ::webwire::Consumer + ::std::marker::Sync + ::std::marker::Send),
+);
+impl<'a> ChatConsumer<'a> {
+    pub async fn send(&self, input: &Message) -> Result<(), ::webwire::ConsumerError> {
+        let data: ::bytes::Bytes = serde_json::to_vec(input)
+            .map_err(|e| ::webwire::ConsumerError::SerializerError(e))?
+            .into();
+        let output = self.0.request("Chat", "send", data).await?;
+        let response = ::serde_json::from_slice(&output)
+            .map_err(|e| ::webwire::ConsumerError::DeserializerError(e))?;
+        Ok(response)
+    }
+    pub async fn history(&self) -> Result<Vec<Message>, ::webwire::ConsumerError> {
+        let data = ::bytes::Bytes::new();
+        let output = self.0.request("Chat", "history", data).await?;
+        let response = ::serde_json::from_slice(&output)
+            .map_err(|e| ::webwire::ConsumerError::DeserializerError(e))?;
+        Ok(response)
+    }
+}
+pub struct ChatEventsConsumer<'a>(
+    pub &'a (dyn ::webwire::Consumer + ::std::marker::Sync + ::std::marker::Send),
+);
+impl<'a> ChatEventsConsumer<'a> {
+    pub async fn on_message(
+        &self,
+        input: &Message,
+    ) -> Result<(), ::webwire::ConsumerError> {
+        let data: ::bytes::Bytes = serde_json::to_vec(input)
+            .map_err(|e| ::webwire::ConsumerError::SerializerError(e))?
+            .into();
+        let output = self.0.request("ChatEvents", "on_message", data).await?;
+        let response = ::serde_json::from_slice(&output)
+            .map_err(|e| ::webwire::ConsumerError::DeserializerError(e))?;
+        Ok(response)
+    }
+    pub async fn ping(&self) -> Result<bool, ::webwire::ConsumerError> {
+        let data = ::bytes::Bytes::new();
+        let output = self.0.request("ChatEvents", "ping", data).await?;
+        let response = ::serde_json::from_slice(&output)
+            .map_err(|e| ::webwire::ConsumerError::DeserializerError(e))?;
+        Ok(response)
+    }
+}
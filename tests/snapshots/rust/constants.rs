@@ -0,0 +1,26 @@
+// GENERATED CODE - DO NOT EDIT!
+
+#[allow(dead_code)]
+pub const API_VERSION: &str = "1.2.0";
+pub const DEBUG: bool = false;
+pub const MAX_PAGE_SIZE: u16 = 500;
+pub const PI: f64 = 3.14;
+pub mod user {
+    pub const MAX_NAME_LENGTH: i64 = 64;
+    pub const MIN_NAME_LENGTH: i64 = 2;
+    #[derive(
+        Clone,
+        Debug,
+        Eq,
+        PartialEq,
+        ::serde::Serialize,
+        ::serde::Deserialize,
+        ::validator::Validate
+    )]
+    pub struct User {
+        #[validate(length(min = 2i64, max = 64i64))]
+        pub name: String,
+        #[validate(range(min = 0i64, max = 150i64))]
+        pub age: i64,
+    }
+}
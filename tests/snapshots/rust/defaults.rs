@@ -0,0 +1,211 @@
+// GENERATED CODE - DO NOT EDIT!
+
+#[allow(dead_code)]
+#[derive(
+    Clone,
+    Debug,
+    Eq,
+    PartialEq,
+    ::serde::Serialize,
+    ::serde::Deserialize,
+    ::validator::Validate
+)]
+pub struct Label {
+    pub id: ::uuid::Uuid,
+    #[validate(length(min = 1i64, max = 32i64))]
+    #[serde(default = "Label::default_text")]
+    pub text: String,
+}
+impl Label {
+    fn default_text() -> String {
+        "unnamed \"label\"".to_owned()
+    }
+}
+#[derive(Clone, Debug, Eq, PartialEq, ::serde::Serialize, ::serde::Deserialize)]
+pub enum Order {
+    Ascending,
+    Descending,
+}
+#[derive(
+    Clone,
+    Debug,
+    Eq,
+    PartialEq,
+    ::serde::Serialize,
+    ::serde::Deserialize,
+    ::validator::Validate
+)]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    #[serde(default = "Page::<T>::default_size_limit")]
+    pub size_limit: i64,
+}
+impl<T> Page<T> {
+    fn default_size_limit() -> i64 {
+        10
+    }
+}
+impl<T: Default> Default for Page<T> {
+    fn default() -> Self {
+        Self {
+            items: Default::default(),
+            size_limit: Self::default_size_limit(),
+        }
+    }
+}
+#[derive(
+    Clone,
+    Debug,
+    Eq,
+    PartialEq,
+    ::serde::Serialize,
+    ::serde::Deserialize,
+    ::validator::Validate
+)]
+pub struct PageSize {
+    #[serde(default = "PageSize::default_limit")]
+    pub limit: i64,
+}
+impl PageSize {
+    fn default_limit() -> i64 {
+        20
+    }
+}
+impl Default for PageSize {
+    fn default() -> Self {
+        Self {
+            limit: Self::default_limit(),
+        }
+    }
+}
+#[derive(
+    Clone,
+    Debug,
+    PartialEq,
+    ::serde::Serialize,
+    ::serde::Deserialize,
+    ::validator::Validate
+)]
+pub struct Query {
+    pub search: String,
+    #[validate(range(min = 1i64, max = 100i64))]
+    #[serde(default = "Query::default_limit")]
+    pub limit: i64,
+    #[serde(default = "Query::default_offset")]
+    pub offset: u32,
+    #[serde(default = "Query::default_order")]
+    pub order: Order,
+    #[serde(default = "Query::default_exact")]
+    pub exact: bool,
+    #[serde(default = "Query::default_boost")]
+    pub boost: f64,
+    #[serde(default = "Query::default_min_score")]
+    pub min_score: f64,
+    pub tags: Option<Vec<String>>,
+}
+impl Query {
+    fn default_limit() -> i64 {
+        50
+    }
+    fn default_offset() -> u32 {
+        0
+    }
+    fn default_order() -> Order {
+        Order::Descending
+    }
+    fn default_exact() -> bool {
+        false
+    }
+    fn default_boost() -> f64 {
+        1.0
+    }
+    fn default_min_score() -> f64 {
+        -0.5
+    }
+}
+impl Default for Query {
+    fn default() -> Self {
+        Self {
+            search: Default::default(),
+            limit: Self::default_limit(),
+            offset: Self::default_offset(),
+            order: Self::default_order(),
+            exact: Self::default_exact(),
+            boost: Self::default_boost(),
+            min_score: Self::default_min_score(),
+            tags: Default::default(),
+        }
+    }
+}
+#[::async_trait::async_trait]
+pub trait Search {
+    type Error: Into<::webwire::ProviderError>;
+    async fn search(&self, input: &Query) -> Result<Vec<Label>, Self::Error>;
+}
+pub struct SearchProvider<F>(pub F);
+impl<F: Sync + Send, S: Sync + Send, T: Sync + Send> ::webwire::NamedProvider<S>
+for SearchProvider<F>
+where
+    F: Fn(::std::sync::Arc<S>) -> T,
+    T: Search + 'static,
+{
+    const NAME: &'static str = "Search";
+}
+impl<F: Sync + Send, S: Sync + Send, T: Sync + Send> ::webwire::Provider<S>
+for SearchProvider<F>
+where
+    F: Fn(::std::sync::Arc<S>) -> T,
+    T: Search + 'static,
+{
+    fn call(
+        &self,
+        session: &::std::sync::Arc<S>,
+        _service: &str,
+        method: &str,
+        input: ::bytes::Bytes,
+    ) -> ::futures::future::BoxFuture<
+        'static,
+        Result<::bytes::Bytes, ::webwire::ProviderError>,
+    > {
+        let service = self.0(session.clone());
+        match method {
+            "search" => {
+                Box::pin(async move {
+                    let input = serde_json::from_slice::<Query>(&input)
+                        .map_err(::webwire::ProviderError::DeserializerError)?;
+                    ::validator::Validate::validate(&input)
+                        .map_err(::webwire::ProviderError::ValidationError)?;
+                    let output = service.search(&input).await.map_err(|e| e.into())?;
+                    let response = serde_json::to_vec(&output)
+                        .map_err(|e| ::webwire::ProviderError::SerializerError(e))
+                        .map(::bytes::Bytes::from)?;
+                    Ok(response)
+                })
+            }
+            _ => {
+                Box::pin(
+                    ::futures::future::ready(
+                        Err(::webwire::ProviderError::MethodNotFound),
+                    ),
+                )
+            }
+        }
+    }
+}
+pub struct SearchConsumer<'a>(
+    pub &'a (dyn ::webwire::Consumer + ::std::marker::Sync + ::std::marker::Send),
+);
+impl<'a> SearchConsumer<'a> {
+    pub async fn search(
+        &self,
+        input: &Query,
+    ) -> Result<Vec<Label>, ::webwire::ConsumerError> {
+        let data: ::bytes::Bytes = serde_json::to_vec(input)
+            .map_err(|e| ::webwire::ConsumerError::SerializerError(e))?
+            .into();
+        let output = self.0.request("Search", "search", data).await?;
+        let response = ::serde_json::from_slice(&output)
+            .map_err(|e| ::webwire::ConsumerError::DeserializerError(e))?;
+        Ok(response)
+    }
+}
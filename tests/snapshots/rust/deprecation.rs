@@ -0,0 +1,314 @@
+// GENERATED CODE - DO NOT EDIT!
+
+#[allow(dead_code)]
+use ::validator::Validate as _;
+#[derive(
+    Clone,
+    Debug,
+    Eq,
+    PartialEq,
+    ::serde::Serialize,
+    ::serde::Deserialize,
+    ::validator::Validate
+)]
+#[allow(deprecated)]
+pub struct Account {
+    pub id: ::uuid::Uuid,
+    #[validate(length(min = 1i64, max = 64i64))]
+    pub name: String,
+    #[deprecated(since = "1.4")]
+    pub email: String,
+    pub emails: Vec<String>,
+    #[validate]
+    #[deprecated(note = "Logins are migrated to accounts")]
+    pub login: Option<Login>,
+    #[serde(default = "Account::default_state")]
+    pub state: AccountState,
+    #[serde(default = "Account::default_legacy_state")]
+    #[deprecated]
+    pub legacy_state: AccountState,
+}
+impl Account {
+    #[allow(deprecated)]
+    fn default_state() -> AccountState {
+        AccountState::Active
+    }
+    #[allow(deprecated)]
+    fn default_legacy_state() -> AccountState {
+        AccountState::Disabled
+    }
+}
+#[derive(
+    Clone,
+    Debug,
+    Eq,
+    PartialEq,
+    ::serde::Serialize,
+    ::serde::Deserialize,
+    ::validator::Validate
+)]
+pub struct AccountRead {
+    pub id: ::uuid::Uuid,
+    #[validate(length(min = 1i64, max = 64i64))]
+    pub name: String,
+    #[deprecated(since = "1.4")]
+    pub email: String,
+}
+#[derive(Clone, Debug, Eq, PartialEq, ::serde::Serialize, ::serde::Deserialize)]
+pub enum AccountState {
+    Active,
+    #[deprecated(note = "Use `Suspended`")]
+    Disabled,
+    Suspended,
+}
+#[derive(Clone, Debug, Eq, PartialEq, ::serde::Serialize, ::serde::Deserialize)]
+#[allow(deprecated)]
+pub enum Credentials {
+    Token(String),
+    #[deprecated(since = "1.2")]
+    Password(Login),
+}
+#[derive(Clone, Debug, Eq, PartialEq, ::serde::Serialize, ::serde::Deserialize)]
+#[allow(deprecated)]
+pub enum ExtendedCredentials {
+    Certificate(String),
+    Token(String),
+    #[deprecated(since = "1.2")]
+    Password(Login),
+}
+#[allow(deprecated)]
+impl From<Credentials> for ExtendedCredentials {
+    fn from(other: Credentials) -> Self {
+        match other {
+            Credentials::Token(value) => Self::Token(value),
+            Credentials::Password(value) => Self::Password(value),
+        }
+    }
+}
+#[allow(deprecated)]
+pub type LegacyLogin = Login;
+#[derive(
+    Clone,
+    Debug,
+    Eq,
+    PartialEq,
+    ::serde::Serialize,
+    ::serde::Deserialize,
+    ::validator::Validate
+)]
+#[deprecated(since = "1.2", note = "Use `Account` instead")]
+pub struct Login {
+    pub name: String,
+    pub password: String,
+}
+#[derive(
+    Clone,
+    Debug,
+    Eq,
+    PartialEq,
+    ::serde::Serialize,
+    ::serde::Deserialize,
+    ::validator::Validate
+)]
+#[serde(transparent)]
+#[deprecated]
+pub struct Username {
+    #[validate(length(min = 1i64, max = 32i64))]
+    pub value: String,
+}
+#[allow(deprecated)]
+#[::async_trait::async_trait]
+pub trait Accounts {
+    type Error: Into<::webwire::ProviderError>;
+    async fn get(&self, input: &::uuid::Uuid) -> Result<Account, Self::Error>;
+    #[deprecated(since = "1.2")]
+    async fn login(&self, input: &Login) -> Result<Account, Self::Error>;
+    async fn rename(&self, input: &Username) -> Result<Account, Self::Error>;
+}
+pub struct AccountsProvider<F>(pub F);
+impl<F> AccountsProvider<F> {
+    /// Annotations of the service
+    pub const ANNOTATIONS: webwire_annotations::Annotations = webwire_annotations::Annotations {
+        deprecated: false,
+        auth: None,
+        timeout: None,
+        idempotent: false,
+        rate_limit: None,
+    };
+    /// Annotations of the methods by method name
+    pub const METHOD_ANNOTATIONS: &'static [(
+        &'static str,
+        webwire_annotations::Annotations,
+    )] = &[
+        (
+            "get",
+            webwire_annotations::Annotations {
+                deprecated: false,
+                auth: None,
+                timeout: None,
+                idempotent: false,
+                rate_limit: None,
+            },
+        ),
+        (
+            "login",
+            webwire_annotations::Annotations {
+                deprecated: true,
+                auth: None,
+                timeout: None,
+                idempotent: false,
+                rate_limit: None,
+            },
+        ),
+        (
+            "rename",
+            webwire_annotations::Annotations {
+                deprecated: false,
+                auth: None,
+                timeout: None,
+                idempotent: false,
+                rate_limit: None,
+            },
+        ),
+    ];
+}
+#[allow(deprecated)]
+impl<F: Sync + Send, S: Sync + Send, T: Sync + Send> ::webwire::NamedProvider<S>
+for AccountsProvider<F>
+where
+    F: Fn(::std::sync::Arc<S>) -> T,
+    T: Accounts + 'static,
+{
+    const NAME: &'static str = "Accounts";
+}
+#[allow(deprecated)]
+impl<F: Sync + Send, S: Sync + Send, T: Sync + Send> ::webwire::Provider<S>
+for AccountsProvider<F>
+where
+    F: Fn(::std::sync::Arc<S>) -> T,
+    T: Accounts + 'static,
+{
+    fn call(
+        &self,
+        session: &::std::sync::Arc<S>,
+        _service: &str,
+        method: &str,
+        input: ::bytes::Bytes,
+    ) -> ::futures::future::BoxFuture<
+        'static,
+        Result<::bytes::Bytes, ::webwire::ProviderError>,
+    > {
+        let service = self.0(session.clone());
+        match method {
+            "get" => {
+                Box::pin(async move {
+                    let input = serde_json::from_slice::<::uuid::Uuid>(&input)
+                        .map_err(::webwire::ProviderError::DeserializerError)?;
+                    let output = service.get(&input).await.map_err(|e| e.into())?;
+                    let response = serde_json::to_vec(&output)
+                        .map_err(|e| ::webwire::ProviderError::SerializerError(e))
+                        .map(::bytes::Bytes::from)?;
+                    Ok(response)
+                })
+            }
+            "login" => {
+                Box::pin(async move {
+                    let input = serde_json::from_slice::<Login>(&input)
+                        .map_err(::webwire::ProviderError::DeserializerError)?;
+                    ::validator::Validate::validate(&input)
+                        .map_err(::webwire::ProviderError::ValidationError)?;
+                    let output = service.login(&input).await.map_err(|e| e.into())?;
+                    let response = serde_json::to_vec(&output)
+                        .map_err(|e| ::webwire::ProviderError::SerializerError(e))
+                        .map(::bytes::Bytes::from)?;
+                    Ok(response)
+                })
+            }
+            "rename" => {
+                Box::pin(async move {
+                    let input = serde_json::from_slice::<Username>(&input)
+                        .map_err(::webwire::ProviderError::DeserializerError)?;
+                    ::validator::Validate::validate(&input)
+                        .map_err(::webwire::ProviderError::ValidationError)?;
+                    let output = service.rename(&input).await.map_err(|e| e.into())?;
+                    let response = serde_json::to_vec(&output)
+                        .map_err(|e| ::webwire::ProviderError::SerializerError(e))
+                        .map(::bytes::Bytes::from)?;
+                    Ok(response)
+                })
+            }
+            _ => {
+                Box::pin(
+                    ::futures::future::ready(
+                        Err(::webwire::ProviderError::MethodNotFound),
+                    ),
+                )
+            }
+        }
+    }
+}
+pub struct AccountsConsumer<'a>(
+    pub &'a (dyn ::webwire::Consumer + ::std::marker::Sync + ::std::marker::Send),
+);
+#[allow(deprecated)]
+impl<'a> AccountsConsumer<'a> {
+    pub async fn get(
+        &self,
+        input: &::uuid::Uuid,
+    ) -> Result<Account, ::webwire::ConsumerError> {
+        let data: ::bytes::Bytes = serde_json::to_vec(input)
+            .map_err(|e| ::webwire::ConsumerError::SerializerError(e))?
+            .into();
+        let output = self.0.request("Accounts", "get", data).await?;
+        let response = ::serde_json::from_slice(&output)
+            .map_err(|e| ::webwire::ConsumerError::DeserializerError(e))?;
+        Ok(response)
+    }
+    #[deprecated(since = "1.2")]
+    pub async fn login(
+        &self,
+        input: &Login,
+    ) -> Result<Account, ::webwire::ConsumerError> {
+        let data: ::bytes::Bytes = serde_json::to_vec(input)
+            .map_err(|e| ::webwire::ConsumerError::SerializerError(e))?
+            .into();
+        let output = self.0.request("Accounts", "login", data).await?;
+        let response = ::serde_json::from_slice(&output)
+            .map_err(|e| ::webwire::ConsumerError::DeserializerError(e))?;
+        Ok(response)
+    }
+    pub async fn rename(
+        &self,
+        input: &Username,
+    ) -> Result<Account, ::webwire::ConsumerError> {
+        let data: ::bytes::Bytes = serde_json::to_vec(input)
+            .map_err(|e| ::webwire::ConsumerError::SerializerError(e))?
+            .into();
+        let output = self.0.request("Accounts", "rename", data).await?;
+        let response = ::serde_json::from_slice(&output)
+            .map_err(|e| ::webwire::ConsumerError::DeserializerError(e))?;
+        Ok(response)
+    }
+}
+pub mod webwire_annotations {
+    /// Annotations of a service or method
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub struct Annotations {
+        /// The service or method is deprecated
+        pub deprecated: bool,
+        /// Role or scope required to call the service or method
+        pub auth: Option<&'static str>,
+        /// Maximum duration of a call
+        pub timeout: Option<::std::time::Duration>,
+        /// The method can safely be retried
+        pub idempotent: bool,
+        /// Maximum number of requests
+        pub rate_limit: Option<RateLimit>,
+    }
+    /// Maximum number of requests per time period
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub struct RateLimit {
+        pub requests: u64,
+        pub per: ::std::time::Duration,
+    }
+}
@@ -0,0 +1,74 @@
+// GENERATED CODE - DO NOT EDIT!
+
+#[allow(dead_code)]
+#[derive(Clone, Debug, Eq, PartialEq, ::serde::Serialize, ::serde::Deserialize)]
+pub enum Base {
+    A,
+    B(String),
+}
+#[derive(Clone, Debug, Eq, PartialEq, ::serde::Serialize, ::serde::Deserialize)]
+pub enum Empty {}
+#[derive(Clone, Debug, Eq, PartialEq, ::serde::Serialize, ::serde::Deserialize)]
+pub enum GenericBase<T> {
+    Value(T),
+    Missing,
+}
+pub mod errors {
+    #[derive(Clone, Debug, Eq, PartialEq, ::serde::Serialize, ::serde::Deserialize)]
+    pub enum Extended {
+        C(i64),
+        A,
+        B(String),
+    }
+    impl From<super::Base> for Extended {
+        fn from(other: super::Base) -> Self {
+            match other {
+                super::Base::A => Self::A,
+                super::Base::B(value) => Self::B(value),
+            }
+        }
+    }
+    #[derive(Clone, Debug, PartialEq, ::serde::Serialize, ::serde::Deserialize)]
+    pub enum ExtendedGeneric {
+        Other,
+        Value(f64),
+        Missing,
+    }
+    impl From<super::GenericBase<f64>> for ExtendedGeneric {
+        fn from(other: super::GenericBase<f64>) -> Self {
+            match other {
+                super::GenericBase::Value(value) => Self::Value(value),
+                super::GenericBase::Missing => Self::Missing,
+            }
+        }
+    }
+    #[derive(Clone, Debug, Eq, PartialEq, ::serde::Serialize, ::serde::Deserialize)]
+    pub enum ExtendedTwice {
+        D,
+        C(i64),
+        A,
+        B(String),
+    }
+    impl From<Extended> for ExtendedTwice {
+        fn from(other: Extended) -> Self {
+            match other {
+                Extended::C(value) => Self::C(value),
+                Extended::A => Self::A,
+                Extended::B(value) => Self::B(value),
+            }
+        }
+    }
+    #[derive(
+        Clone,
+        Debug,
+        PartialEq,
+        ::serde::Serialize,
+        ::serde::Deserialize,
+        ::validator::Validate
+    )]
+    pub struct Holder {
+        pub empty: Option<super::Empty>,
+        pub extended: Extended,
+        pub generic: ExtendedGeneric,
+    }
+}
@@ -0,0 +1,148 @@
+// GENERATED CODE - DO NOT EDIT!
+
+#[allow(dead_code)]
+#[derive(
+    Clone,
+    Debug,
+    Eq,
+    PartialEq,
+    ::serde::Serialize,
+    ::serde::Deserialize,
+    ::validator::Validate
+)]
+pub struct Entity<ID> {
+    pub created_at: ::chrono::DateTime<::chrono::Utc>,
+    pub updated_at: ::chrono::DateTime<::chrono::Utc>,
+    pub id: ID,
+    #[serde(default = "Entity::<ID>::default_version")]
+    pub version: i64,
+}
+impl<ID> Entity<ID> {
+    fn default_version() -> i64 {
+        1
+    }
+}
+#[derive(
+    Clone,
+    Debug,
+    Eq,
+    PartialEq,
+    ::serde::Serialize,
+    ::serde::Deserialize,
+    ::validator::Validate
+)]
+pub struct Timestamps {
+    pub created_at: ::chrono::DateTime<::chrono::Utc>,
+    pub updated_at: ::chrono::DateTime<::chrono::Utc>,
+}
+pub mod user {
+    #[derive(
+        Clone,
+        Debug,
+        Eq,
+        PartialEq,
+        ::serde::Serialize,
+        ::serde::Deserialize,
+        ::validator::Validate
+    )]
+    pub struct User {
+        pub created_at: ::chrono::DateTime<::chrono::Utc>,
+        pub updated_at: ::chrono::DateTime<::chrono::Utc>,
+        pub id: ::uuid::Uuid,
+        #[serde(default = "User::default_version")]
+        pub version: i64,
+        #[validate(length(min = 1i64, max = 64i64))]
+        pub name: String,
+        #[validate(email)]
+        pub email: Option<String>,
+    }
+    impl User {
+        fn default_version() -> i64 {
+            1
+        }
+    }
+    #[derive(
+        Clone,
+        Debug,
+        Eq,
+        PartialEq,
+        ::serde::Serialize,
+        ::serde::Deserialize,
+        ::validator::Validate
+    )]
+    pub struct UserSummary {
+        pub id: ::uuid::Uuid,
+        #[validate(length(min = 1i64, max = 64i64))]
+        pub name: String,
+    }
+    #[::async_trait::async_trait]
+    pub trait Users {
+        type Error: Into<::webwire::ProviderError>;
+        async fn get(&self, input: &::uuid::Uuid) -> Result<UserSummary, Self::Error>;
+    }
+    pub struct UsersProvider<F>(pub F);
+    impl<F: Sync + Send, S: Sync + Send, T: Sync + Send> ::webwire::NamedProvider<S>
+    for UsersProvider<F>
+    where
+        F: Fn(::std::sync::Arc<S>) -> T,
+        T: Users + 'static,
+    {
+        const NAME: &'static str = "user.Users";
+    }
+    impl<F: Sync + Send, S: Sync + Send, T: Sync + Send> ::webwire::Provider<S>
+    for UsersProvider<F>
+    where
+        F: Fn(::std::sync::Arc<S>) -> T,
+        T: Users + 'static,
+    {
+        fn call(
+            &self,
+            session: &::std::sync::Arc<S>,
+            _service: &str,
+            method: &str,
+            input: ::bytes::Bytes,
+        ) -> ::futures::future::BoxFuture<
+            'static,
+            Result<::bytes::Bytes, ::webwire::ProviderError>,
+        > {
+            let service = self.0(session.clone());
+            match method {
+                "get" => {
+                    Box::pin(async move {
+                        let input = serde_json::from_slice::<::uuid::Uuid>(&input)
+                            .map_err(::webwire::ProviderError::DeserializerError)?;
+                        let output = service.get(&input).await.map_err(|e| e.into())?;
+                        let response = serde_json::to_vec(&output)
+                            .map_err(|e| ::webwire::ProviderError::SerializerError(e))
+                            .map(::bytes::Bytes::from)?;
+                        Ok(response)
+                    })
+                }
+                _ => {
+                    Box::pin(
+                        ::futures::future::ready(
+                            Err(::webwire::ProviderError::MethodNotFound),
+                        ),
+                    )
+                }
+            }
+        }
+    }
+    pub struct UsersConsumer<'a>(
+        pub &'a (dyn ::webwire::Consumer + ::std::marker::Sync + ::std::marker::Send),
+    );
+    impl<'a> UsersConsumer<'a> {
+        pub async fn get(
+            &self,
+            input: &::uuid::Uuid,
+        ) -> Result<UserSummary, ::webwire::ConsumerError> {
+            let data: ::bytes::Bytes = serde_json::to_vec(input)
+                .map_err(|e| ::webwire::ConsumerError::SerializerError(e))?
+                .into();
+            let output = self.0.request("user.Users", "get", data).await?;
+            let response = ::serde_json::from_slice(&output)
+                .map_err(|e| ::webwire::ConsumerError::DeserializerError(e))?;
+            Ok(response)
+        }
+    }
+}
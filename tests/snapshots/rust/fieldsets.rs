@@ -0,0 +1,193 @@
+// GENERATED CODE - DO NOT EDIT!
+
+#[allow(dead_code)]
+#[derive(
+    Clone,
+    Debug,
+    Eq,
+    PartialEq,
+    ::serde::Serialize,
+    ::serde::Deserialize,
+    ::validator::Validate
+)]
+pub struct User {
+    pub id: ::uuid::Uuid,
+    #[validate(length(min = 1i64, max = 64i64))]
+    pub name: String,
+    #[validate(email)]
+    pub email: String,
+    #[validate(length(min = 8i64))]
+    pub password: String,
+    pub bio: Option<String>,
+}
+#[derive(
+    Clone,
+    Debug,
+    Eq,
+    PartialEq,
+    ::serde::Serialize,
+    ::serde::Deserialize,
+    ::validator::Validate
+)]
+pub struct UserPublic {
+    pub id: ::uuid::Uuid,
+    #[validate(length(min = 1i64, max = 64i64))]
+    pub name: String,
+    pub bio: Option<String>,
+}
+#[derive(
+    Clone,
+    Debug,
+    Eq,
+    PartialEq,
+    ::serde::Serialize,
+    ::serde::Deserialize,
+    ::validator::Validate
+)]
+pub struct UserSignup {
+    #[validate(length(min = 1i64, max = 64i64))]
+    pub name: String,
+    #[validate(email, length(max = 254i64))]
+    pub email: String,
+    #[validate(length(min = 8i64))]
+    pub password: String,
+    pub bio: Option<String>,
+}
+#[derive(
+    Clone,
+    Debug,
+    Eq,
+    PartialEq,
+    ::serde::Serialize,
+    ::serde::Deserialize,
+    ::validator::Validate
+)]
+pub struct UserUpdate {
+    #[validate(length(min = 1i64, max = 64i64))]
+    pub name: Option<String>,
+    pub bio: Option<String>,
+    #[validate(length(min = 12i64))]
+    pub password: Option<String>,
+}
+#[::async_trait::async_trait]
+pub trait Users {
+    type Error: Into<::webwire::ProviderError>;
+    async fn get(&self, input: &::uuid::Uuid) -> Result<UserPublic, Self::Error>;
+    async fn update(&self, input: &UserUpdate) -> Result<(), Self::Error>;
+    async fn signup(&self, input: &UserSignup) -> Result<UserPublic, Self::Error>;
+}
+pub struct UsersProvider<F>(pub F);
+impl<F: Sync + Send, S: Sync + Send, T: Sync + Send> ::webwire::NamedProvider<S>
+for UsersProvider<F>
+where
+    F: Fn(::std::sync::Arc<S>) -> T,
+    T: Users + 'static,
+{
+    const NAME: &'static str = "Users";
+}
+impl<F: Sync + Send, S: Sync + Send, T: Sync + Send> ::webwire::Provider<S>
+for UsersProvider<F>
+where
+    F: Fn(::std::sync::Arc<S>) -> T,
+    T: Users + 'static,
+{
+    fn call(
+        &self,
+        session: &::std::sync::Arc<S>,
+        _service: &str,
+        method: &str,
+        input: ::bytes::Bytes,
+    ) -> ::futures::future::BoxFuture<
+        'static,
+        Result<::bytes::Bytes, ::webwire::ProviderError>,
+    > {
+        let service = self.0(session.clone());
+        match method {
+            "get" => {
+                Box::pin(async move {
+                    let input = serde_json::from_slice::<::uuid::Uuid>(&input)
+                        .map_err(::webwire::ProviderError::DeserializerError)?;
+                    let output = service.get(&input).await.map_err(|e| e.into())?;
+                    let response = serde_json::to_vec(&output)
+                        .map_err(|e| ::webwire::ProviderError::SerializerError(e))
+                        .map(::bytes::Bytes::from)?;
+                    Ok(response)
+                })
+            }
+            "update" => {
+                Box::pin(async move {
+                    let input = serde_json::from_slice::<UserUpdate>(&input)
+                        .map_err(::webwire::ProviderError::DeserializerError)?;
+                    ::validator::Validate::validate(&input)
+                        .map_err(::webwire::ProviderError::ValidationError)?;
+                    let output = service.update(&input).await.map_err(|e| e.into())?;
+                    let response = serde_json::to_vec(&output)
+                        .map_err(|e| ::webwire::ProviderError::SerializerError(e))
+                        .map(::bytes::Bytes::from)?;
+                    Ok(response)
+                })
+            }
+            "signup" => {
+                Box::pin(async move {
+                    let input = serde_json::from_slice::<UserSignup>(&input)
+                        .map_err(::webwire::ProviderError::DeserializerError)?;
+                    ::validator::Validate::validate(&input)
+                        .map_err(::webwire::ProviderError::ValidationError)?;
+                    let output = service.signup(&input).await.map_err(|e| e.into())?;
+                    let response = serde_json::to_vec(&output)
+                        .map_err(|e| ::webwire::ProviderError::SerializerError(e))
+                        .map(::bytes::Bytes::from)?;
+                    Ok(response)
+                })
+            }
+            _ => {
+                Box::pin(
+                    ::futures::future::ready(
+                        Err(::webwire::ProviderError::MethodNotFound),
+                    ),
+                )
+            }
+        }
+    }
+}
+pub struct UsersConsumer<'a>(
+    pub &'a (dyn ::webwire::Consumer + ::std::marker::Sync + ::std::marker::Send),
+);
+impl<'a> UsersConsumer<'a> {
+    pub async fn get(
+        &self,
+        input: &::uuid::Uuid,
+    ) -> Result<UserPublic, ::webwire::ConsumerError> {
+        let data: ::bytes::Bytes = serde_json::to_vec(input)
+            .map_err(|e| ::webwire::ConsumerError::SerializerError(e))?
+            .into();
+        let output = self.0.request("Users", "get", data).await?;
+        let response = ::serde_json::from_slice(&output)
+            .map_err(|e| ::webwire::ConsumerError::DeserializerError(e))?;
+        Ok(response)
+    }
+    pub async fn update(
+        &self,
+        input: &UserUpdate,
+    ) -> Result<(), ::webwire::ConsumerError> {
+        let data: ::bytes::Bytes = serde_json::to_vec(input)
+            .map_err(|e| ::webwire::ConsumerError::SerializerError(e))?
+            .into();
+        let output = self.0.request("Users", "update", data).await?;
+        let response = ::serde_json::from_slice(&output)
+            .map_err(|e| ::webwire::ConsumerError::DeserializerError(e))?;
+        Ok(response)
+    }
+    pub async fn signup(
+        &self,
+        input: &UserSignup,
+    ) -> Result<UserPublic, ::webwire::ConsumerError> {
+        let data: ::bytes::Bytes = serde_json::to_vec(input)
+            .map_err(|e| ::webwire::ConsumerError::SerializerError(e))?
+            .into();
+        let output = self.0.request("Users", "signup", data).await?;
+        let response = ::serde_json::from_slice(&output)
+            .map_err(|e| ::webwire::ConsumerError::DeserializerError(e))?;
+        Ok(response)
+    }
+}
@@ -0,0 +1,174 @@
+// GENERATED CODE - DO NOT EDIT!
+
+#[allow(dead_code)]
+use ::validator::Validate as _;
+#[derive(Clone, Debug, Eq, PartialEq, ::serde::Serialize, ::serde::Deserialize)]
+pub enum Either<L, R> {
+    Left(L),
+    Right(R),
+}
+#[derive(
+    Clone,
+    Debug,
+    Eq,
+    PartialEq,
+    ::serde::Serialize,
+    ::serde::Deserialize,
+    ::validator::Validate
+)]
+pub struct Listing {
+    #[validate]
+    pub users: Page<User>,
+    #[validate]
+    pub pair: Pair<String, Vec<i64>>,
+    pub either: Either<User, Page<String>>,
+    pub tree: Tree<User>,
+}
+#[derive(
+    Clone,
+    Debug,
+    Eq,
+    PartialEq,
+    ::serde::Serialize,
+    ::serde::Deserialize,
+    ::validator::Validate
+)]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    pub count: i64,
+    pub first: Option<T>,
+}
+#[derive(
+    Clone,
+    Debug,
+    Eq,
+    PartialEq,
+    ::serde::Serialize,
+    ::serde::Deserialize,
+    ::validator::Validate
+)]
+pub struct Pair<A, B> {
+    pub first: A,
+    pub second: B,
+}
+#[derive(Clone, Debug, Eq, PartialEq, ::serde::Serialize, ::serde::Deserialize)]
+pub enum Tree<T> {
+    Leaf(T),
+    Many(Vec<T>),
+    Maybe(Option<T>),
+    Keyed(std::collections::HashMap<String, T>),
+    Paged(Page<T>),
+    Split(Either<T, i64>),
+    Empty,
+}
+#[derive(
+    Clone,
+    Debug,
+    Eq,
+    PartialEq,
+    ::serde::Serialize,
+    ::serde::Deserialize,
+    ::validator::Validate
+)]
+pub struct User {
+    pub name: String,
+}
+#[::async_trait::async_trait]
+pub trait Users {
+    type Error: Into<::webwire::ProviderError>;
+    async fn list(&self, input: &i64) -> Result<Page<User>, Self::Error>;
+    async fn pair(
+        &self,
+        input: &Pair<User, i64>,
+    ) -> Result<Either<User, String>, Self::Error>;
+}
+pub struct UsersProvider<F>(pub F);
+impl<F: Sync + Send, S: Sync + Send, T: Sync + Send> ::webwire::NamedProvider<S>
+for UsersProvider<F>
+where
+    F: Fn(::std::sync::Arc<S>) -> T,
+    T: Users + 'static,
+{
+    const NAME: &'static str = "Users";
+}
+impl<F: Sync + Send, S: Sync + Send, T: Sync + Send> ::webwire::Provider<S>
+for UsersProvider<F>
+where
+    F: Fn(::std::sync::Arc<S>) -> T,
+    T: Users + 'static,
+{
+    fn call(
+        &self,
+        session: &::std::sync::Arc<S>,
+        _service: &str,
+        method: &str,
+        input: ::bytes::Bytes,
+    ) -> ::futures::future::BoxFuture<
+        'static,
+        Result<::bytes::Bytes, ::webwire::ProviderError>,
+    > {
+        let service = self.0(session.clone());
+        match method {
+            "list" => {
+                Box::pin(async move {
+                    let input = serde_json::from_slice::<i64>(&input)
+                        .map_err(::webwire::ProviderError::DeserializerError)?;
+                    let output = service.list(&input).await.map_err(|e| e.into())?;
+                    let response = serde_json::to_vec(&output)
+                        .map_err(|e| ::webwire::ProviderError::SerializerError(e))
+                        .map(::bytes::Bytes::from)?;
+                    Ok(response)
+                })
+            }
+            "pair" => {
+                Box::pin(async move {
+                    let input = serde_json::from_slice::<Pair<User, i64>>(&input)
+                        .map_err(::webwire::ProviderError::DeserializerError)?;
+                    ::validator::Validate::validate(&input)
+                        .map_err(::webwire::ProviderError::ValidationError)?;
+                    let output = service.pair(&input).await.map_err(|e| e.into())?;
+                    let response = serde_json::to_vec(&output)
+                        .map_err(|e| ::webwire::ProviderError::SerializerError(e))
+                        .map(::bytes::Bytes::from)?;
+                    Ok(response)
+                })
+            }
+            _ => {
+                Box::pin(
+                    ::futures::future::ready(
+                        Err(::webwire::ProviderError::MethodNotFound),
+                    ),
+                )
+            }
+        }
+    }
+}
+pub struct UsersConsumer<'a>(
+    pub &'a (dyn ::webwire::Consumer + ::std::marker::Sync + ::std::marker::Send),
+);
+impl<'a> UsersConsumer<'a> {
+    pub async fn list(
+        &self,
+        input: &i64,
+    ) -> Result<Page<User>, ::webwire::ConsumerError> {
+        let data: ::bytes::Bytes = serde_json::to_vec(input)
+            .map_err(|e| ::webwire::ConsumerError::SerializerError(e))?
+            .into();
+        let output = self.0.request("Users", "list", data).await?;
+        let response = ::serde_json::from_slice(&output)
+            .map_err(|e| ::webwire::ConsumerError::DeserializerError(e))?;
+        Ok(response)
+    }
+    pub async fn pair(
+        &self,
+        input: &Pair<User, i64>,
+    ) -> Result<Either<User, String>, ::webwire::ConsumerError> {
+        let data: ::bytes::Bytes = serde_json::to_vec(input)
+            .map_err(|e| ::webwire::ConsumerError::SerializerError(e))?
+            .into();
+        let output = self.0.request("Users", "pair", data).await?;
+        let response = ::serde_json::from_slice(&output)
+            .map_err(|e| ::webwire::ConsumerError::DeserializerError(e))?;
+        Ok(response)
+    }
+}
@@ -0,0 +1,290 @@
+// GENERATED CODE - DO NOT EDIT!
+
+#[allow(dead_code)]
+pub mod user {
+    use ::validator::Validate as _;
+    #[derive(Clone, Debug, Eq, PartialEq, ::serde::Serialize, ::serde::Deserialize)]
+    pub enum GetError {
+        PermissionDenied,
+        DoesNotExist,
+    }
+    #[derive(Clone, Debug, Eq, PartialEq, ::serde::Serialize, ::serde::Deserialize)]
+    pub enum ListError {
+        PermissionDenied,
+    }
+    #[derive(
+        Clone,
+        Debug,
+        Eq,
+        PartialEq,
+        ::serde::Serialize,
+        ::serde::Deserialize,
+        ::validator::Validate
+    )]
+    pub struct Name {
+        #[validate(length(min = 0i64, max = 50i64))]
+        pub prefix: String,
+        #[validate(length(min = 0i64, max = 100i64))]
+        pub first_name: String,
+        #[validate(length(min = 0i64, max = 100i64))]
+        pub middle_name: String,
+        #[validate(length(min = 0i64, max = 100i64))]
+        pub last_name: String,
+        #[validate(length(min = 0i64, max = 50i64))]
+        pub suffix: String,
+        pub full_name: String,
+    }
+    #[derive(
+        Clone,
+        Debug,
+        Eq,
+        PartialEq,
+        ::serde::Serialize,
+        ::serde::Deserialize,
+        ::validator::Validate
+    )]
+    pub struct NameUpdate {
+        #[validate(length(min = 0i64, max = 50i64))]
+        pub prefix: Option<String>,
+        #[validate(length(min = 0i64, max = 100i64))]
+        pub first_name: Option<String>,
+        #[validate(length(min = 0i64, max = 100i64))]
+        pub middle_name: Option<String>,
+        #[validate(length(min = 0i64, max = 100i64))]
+        pub last_name: Option<String>,
+        #[validate(length(min = 0i64, max = 50i64))]
+        pub suffix: Option<String>,
+    }
+    #[derive(
+        Clone,
+        Debug,
+        Eq,
+        PartialEq,
+        ::serde::Serialize,
+        ::serde::Deserialize,
+        ::validator::Validate
+    )]
+    pub struct User {
+        pub id: ::uuid::Uuid,
+        pub email: String,
+        #[validate]
+        pub name: Name,
+        #[validate(length(min = 5i64, max = 64i64))]
+        pub password: String,
+        pub is_admin: bool,
+    }
+    #[derive(
+        Clone,
+        Debug,
+        Eq,
+        PartialEq,
+        ::serde::Serialize,
+        ::serde::Deserialize,
+        ::validator::Validate
+    )]
+    pub struct UserList {
+        #[validate(range(min = 0i64, max = 65535i64))]
+        pub count: i64,
+        #[validate(length(min = 0i64, max = 128i64))]
+        #[validate]
+        pub users: Vec<UserRead>,
+        pub permissions: std::collections::HashMap<::uuid::Uuid, String>,
+    }
+    #[derive(
+        Clone,
+        Debug,
+        Eq,
+        PartialEq,
+        ::serde::Serialize,
+        ::serde::Deserialize,
+        ::validator::Validate
+    )]
+    pub struct UserListRequest {
+        #[validate(range(min = 0i64))]
+        pub offset: Option<i32>,
+        #[validate(range(min = 1i64, max = 200i64))]
+        pub limit: Option<i64>,
+    }
+    #[derive(
+        Clone,
+        Debug,
+        Eq,
+        PartialEq,
+        ::serde::Serialize,
+        ::serde::Deserialize,
+        ::validator::Validate
+    )]
+    pub struct UserRead {
+        pub id: ::uuid::Uuid,
+        pub email: String,
+        pub is_admin: bool,
+        #[validate]
+        pub name: Name,
+    }
+    #[derive(
+        Clone,
+        Debug,
+        Eq,
+        PartialEq,
+        ::serde::Serialize,
+        ::serde::Deserialize,
+        ::validator::Validate
+    )]
+    pub struct UserRequest {
+        pub email: String,
+    }
+    #[derive(Clone, Debug, Eq, PartialEq, ::serde::Serialize, ::serde::Deserialize)]
+    pub enum UserState {
+        Active,
+        Inactive,
+        Banned,
+    }
+    #[derive(
+        Clone,
+        Debug,
+        Eq,
+        PartialEq,
+        ::serde::Serialize,
+        ::serde::Deserialize,
+        ::validator::Validate
+    )]
+    pub struct UserWrite {
+        pub id: ::uuid::Uuid,
+        pub email: Option<String>,
+        pub is_admin: Option<bool>,
+        #[validate]
+        pub name: Option<Name>,
+        #[validate(length(min = 5i64, max = 64i64))]
+        pub password: Option<String>,
+    }
+    #[::async_trait::async_trait]
+    pub trait ExampleService {
+        type Error: Into<::webwire::ProviderError>;
+        async fn get_version(&self) -> Result<String, Self::Error>;
+        async fn user_get(
+            &self,
+            input: &UserRequest,
+        ) -> Result<std::result::Result<UserRead, GetError>, Self::Error>;
+        async fn user_list(
+            &self,
+            input: &UserListRequest,
+        ) -> Result<std::result::Result<UserList, ListError>, Self::Error>;
+    }
+    pub struct ExampleServiceProvider<F>(pub F);
+    impl<F: Sync + Send, S: Sync + Send, T: Sync + Send> ::webwire::NamedProvider<S>
+    for ExampleServiceProvider<F>
+    where
+        F: Fn(::std::sync::Arc<S>) -> T,
+        T: ExampleService + 'static,
+    {
+        const NAME: &'static str = "user.ExampleService";
+    }
+    impl<F: Sync + Send, S: Sync + Send, T: Sync + Send> ::webwire::Provider<S>
+    for ExampleServiceProvider<F>
+    where
+        F: Fn(::std::sync::Arc<S>) -> T,
+        T: ExampleService + 'static,
+    {
+        fn call(
+            &self,
+            session: &::std::sync::Arc<S>,
+            _service: &str,
+            method: &str,
+            input: ::bytes::Bytes,
+        ) -> ::futures::future::BoxFuture<
+            'static,
+            Result<::bytes::Bytes, ::webwire::ProviderError>,
+        > {
+            let service = self.0(session.clone());
+            match method {
+                "get_version" => {
+                    Box::pin(async move {
+                        let output = service.get_version().await.map_err(|e| e.into())?;
+                        let response = serde_json::to_vec(&output)
+                            .map_err(|e| ::webwire::ProviderError::SerializerError(e))
+                            .map(::bytes::Bytes::from)?;
+                        Ok(response)
+                    })
+                }
+                "user_get" => {
+                    Box::pin(async move {
+                        let input = serde_json::from_slice::<UserRequest>(&input)
+                            .map_err(::webwire::ProviderError::DeserializerError)?;
+                        ::validator::Validate::validate(&input)
+                            .map_err(::webwire::ProviderError::ValidationError)?;
+                        let output = service
+                            .user_get(&input)
+                            .await
+                            .map_err(|e| e.into())?;
+                        let response = serde_json::to_vec(&output)
+                            .map_err(|e| ::webwire::ProviderError::SerializerError(e))
+                            .map(::bytes::Bytes::from)?;
+                        Ok(response)
+                    })
+                }
+                "user_list" => {
+                    Box::pin(async move {
+                        let input = serde_json::from_slice::<UserListRequest>(&input)
+                            .map_err(::webwire::ProviderError::DeserializerError)?;
+                        ::validator::Validate::validate(&input)
+                            .map_err(::webwire::ProviderError::ValidationError)?;
+                        let output = service
+                            .user_list(&input)
+                            .await
+                            .map_err(|e| e.into())?;
+                        let response = serde_json::to_vec(&output)
+                            .map_err(|e| ::webwire::ProviderError::SerializerError(e))
+                            .map(::bytes::Bytes::from)?;
+                        Ok(response)
+                    })
+                }
+                _ => {
+                    Box::pin(
+                        ::futures::future::ready(
+                            Err(::webwire::ProviderError::MethodNotFound),
+                        ),
+                    )
+                }
+            }
+        }
+    }
+    pub struct ExampleServiceConsumer<'a>(
+        pub &'a (dyn ::webwire::Consumer + ::std::marker::Sync + ::std::marker::Send),
+    );
+    impl<'a> ExampleServiceConsumer<'a> {
+        pub async fn get_version(&self) -> Result<String, ::webwire::ConsumerError> {
+            let data = ::bytes::Bytes::new();
+            let output = self
+                .0
+                .request("user.ExampleService", "get_version", data)
+                .await?;
+            let response = ::serde_json::from_slice(&output)
+                .map_err(|e| ::webwire::ConsumerError::DeserializerError(e))?;
+            Ok(response)
+        }
+        pub async fn user_get(
+            &self,
+            input: &UserRequest,
+        ) -> Result<std::result::Result<UserRead, GetError>, ::webwire::ConsumerError> {
+            let data: ::bytes::Bytes = serde_json::to_vec(input)
+                .map_err(|e| ::webwire::ConsumerError::SerializerError(e))?
+                .into();
+            let output = self.0.request("user.ExampleService", "user_get", data).await?;
+            let response = ::serde_json::from_slice(&output)
+                .map_err(|e| ::webwire::ConsumerError::DeserializerError(e))?;
+            Ok(response)
+        }
+        pub async fn user_list(
+            &self,
+            input: &UserListRequest,
+        ) -> Result<std::result::Result<UserList, ListError>, ::webwire::ConsumerError> {
+            let data: ::bytes::Bytes = serde_json::to_vec(input)
+                .map_err(|e| ::webwire::ConsumerError::SerializerError(e))?
+                .into();
+            let output = self.0.request("user.ExampleService", "user_list", data).await?;
+            let response = ::serde_json::from_slice(&output)
+                .map_err(|e| ::webwire::ConsumerError::DeserializerError(e))?;
+            Ok(response)
+        }
+    }
+}
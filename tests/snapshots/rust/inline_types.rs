@@ -0,0 +1,256 @@
+// GENERATED CODE - DO NOT EDIT!
+
+#[allow(dead_code)]
+use ::validator::Validate as _;
+#[derive(
+    Clone,
+    Debug,
+    Eq,
+    PartialEq,
+    ::serde::Serialize,
+    ::serde::Deserialize,
+    ::validator::Validate
+)]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    #[validate]
+    pub cursor: PageCursor<T>,
+}
+#[derive(
+    Clone,
+    Debug,
+    Eq,
+    PartialEq,
+    ::serde::Serialize,
+    ::serde::Deserialize,
+    ::validator::Validate
+)]
+pub struct PageCursor<T> {
+    pub first: T,
+    pub next: Option<String>,
+}
+#[derive(
+    Clone,
+    Debug,
+    PartialEq,
+    ::serde::Serialize,
+    ::serde::Deserialize,
+    ::validator::Validate
+)]
+pub struct User {
+    pub id: ::uuid::Uuid,
+    #[validate]
+    pub address: UserAddress,
+    #[serde(default = "User::default_status")]
+    pub status: UserStatus,
+    #[validate]
+    pub tags: Vec<UserTags>,
+}
+impl User {
+    fn default_status() -> UserStatus {
+        UserStatus::Active
+    }
+}
+#[derive(
+    Clone,
+    Debug,
+    PartialEq,
+    ::serde::Serialize,
+    ::serde::Deserialize,
+    ::validator::Validate
+)]
+pub struct UserAddress {
+    #[validate(length(min = 1i64, max = 100i64))]
+    pub street: String,
+    pub zip: String,
+    #[validate]
+    pub geo: Option<UserAddressGeo>,
+}
+#[derive(
+    Clone,
+    Debug,
+    PartialEq,
+    ::serde::Serialize,
+    ::serde::Deserialize,
+    ::validator::Validate
+)]
+pub struct UserAddressGeo {
+    pub lat: f64,
+    pub lng: f64,
+}
+#[derive(Clone, Debug, Eq, PartialEq, ::serde::Serialize, ::serde::Deserialize)]
+pub enum UserStatus {
+    Active,
+    Banned(UserStatusBanned),
+}
+#[derive(
+    Clone,
+    Debug,
+    Eq,
+    PartialEq,
+    ::serde::Serialize,
+    ::serde::Deserialize,
+    ::validator::Validate
+)]
+pub struct UserStatusBanned {
+    pub reason: String,
+    pub until: Option<::chrono::DateTime<::chrono::Utc>>,
+}
+#[derive(
+    Clone,
+    Debug,
+    Eq,
+    PartialEq,
+    ::serde::Serialize,
+    ::serde::Deserialize,
+    ::validator::Validate
+)]
+pub struct UserTags {
+    pub name: String,
+    pub color: Option<String>,
+}
+#[derive(
+    Clone,
+    Debug,
+    Eq,
+    PartialEq,
+    ::serde::Serialize,
+    ::serde::Deserialize,
+    ::validator::Validate
+)]
+pub struct UsersSearchFilter {
+    pub name: Option<String>,
+    pub status: Option<UserStatus>,
+}
+#[derive(
+    Clone,
+    Debug,
+    Eq,
+    PartialEq,
+    ::serde::Serialize,
+    ::serde::Deserialize,
+    ::validator::Validate
+)]
+pub struct UsersSearchInput {
+    #[validate]
+    pub filter: UsersSearchFilter,
+}
+#[derive(
+    Clone,
+    Debug,
+    Eq,
+    PartialEq,
+    ::serde::Serialize,
+    ::serde::Deserialize,
+    ::validator::Validate
+)]
+pub struct UsersSearchOutput {
+    pub id: ::uuid::Uuid,
+    pub name: String,
+}
+#[derive(
+    Clone,
+    Debug,
+    Eq,
+    PartialEq,
+    ::serde::Serialize,
+    ::serde::Deserialize,
+    ::validator::Validate
+)]
+pub struct UsersStatsOutput {
+    pub total: u64,
+    pub active: u64,
+}
+#[::async_trait::async_trait]
+pub trait Users {
+    type Error: Into<::webwire::ProviderError>;
+    async fn search(
+        &self,
+        filter: UsersSearchFilter,
+    ) -> Result<Vec<UsersSearchOutput>, Self::Error>;
+    async fn stats(&self) -> Result<UsersStatsOutput, Self::Error>;
+}
+pub struct UsersProvider<F>(pub F);
+impl<F: Sync + Send, S: Sync + Send, T: Sync + Send> ::webwire::NamedProvider<S>
+for UsersProvider<F>
+where
+    F: Fn(::std::sync::Arc<S>) -> T,
+    T: Users + 'static,
+{
+    const NAME: &'static str = "Users";
+}
+impl<F: Sync + Send, S: Sync + Send, T: Sync + Send> ::webwire::Provider<S>
+for UsersProvider<F>
+where
+    F: Fn(::std::sync::Arc<S>) -> T,
+    T: Users + 'static,
+{
+    fn call(
+        &self,
+        session: &::std::sync::Arc<S>,
+        _service: &str,
+        method: &str,
+        input: ::bytes::Bytes,
+    ) -> ::futures::future::BoxFuture<
+        'static,
+        Result<::bytes::Bytes, ::webwire::ProviderError>,
+    > {
+        let service = self.0(session.clone());
+        match method {
+            "search" => {
+                Box::pin(async move {
+                    let input = serde_json::from_slice::<UsersSearchInput>(&input)
+                        .map_err(::webwire::ProviderError::DeserializerError)?;
+                    ::validator::Validate::validate(&input)
+                        .map_err(::webwire::ProviderError::ValidationError)?;
+                    let UsersSearchInput { filter } = input;
+                    let output = service.search(filter).await.map_err(|e| e.into())?;
+                    let response = serde_json::to_vec(&output)
+                        .map_err(|e| ::webwire::ProviderError::SerializerError(e))
+                        .map(::bytes::Bytes::from)?;
+                    Ok(response)
+                })
+            }
+            "stats" => {
+                Box::pin(async move {
+                    let output = service.stats().await.map_err(|e| e.into())?;
+                    let response = serde_json::to_vec(&output)
+                        .map_err(|e| ::webwire::ProviderError::SerializerError(e))
+                        .map(::bytes::Bytes::from)?;
+                    Ok(response)
+                })
+            }
+            _ => {
+                Box::pin(
+                    ::futures::future::ready(
+                        Err(::webwire::ProviderError::MethodNotFound),
+                    ),
+                )
+            }
+        }
+    }
+}
+pub struct UsersConsumer<'a>(
+    pub &'a (dyn ::webwire::Consumer + ::std::marker::Sync + ::std::marker::Send),
+);
+impl<'a> UsersConsumer<'a> {
+    pub async fn search(
+        &self,
+        filter: UsersSearchFilter,
+    ) -> Result<Vec<UsersSearchOutput>, ::webwire::ConsumerError> {
+        let data: ::bytes::Bytes = serde_json::to_vec(&UsersSearchInput { filter })
+            .map_err(|e| ::webwire::ConsumerError::SerializerError(e))?
+            .into();
+        let output = self.0.request("Users", "search", data).await?;
+        let response = ::serde_json::from_slice(&output)
+            .map_err(|e| ::webwire::ConsumerError::DeserializerError(e))?;
+        Ok(response)
+    }
+    pub async fn stats(&self) -> Result<UsersStatsOutput, ::webwire::ConsumerError> {
+        let data = ::bytes::Bytes::new();
+        let output = self.0.request("Users", "stats", data).await?;
+        let response = ::serde_json::from_slice(&output)
+            .map_err(|e| ::webwire::ConsumerError::DeserializerError(e))?;
+        Ok(response)
+    }
+}
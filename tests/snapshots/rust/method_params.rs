@@ -0,0 +1,342 @@
+// GENERATED CODE - DO NOT EDIT!
+
+#[allow(dead_code)]
+#[derive(Clone, Debug, Eq, PartialEq, ::serde::Serialize, ::serde::Deserialize)]
+pub enum GetError {
+    NotFound,
+}
+#[derive(
+    Clone,
+    Debug,
+    Eq,
+    PartialEq,
+    ::serde::Serialize,
+    ::serde::Deserialize,
+    ::validator::Validate
+)]
+pub struct User {
+    pub id: ::uuid::Uuid,
+    pub name: String,
+}
+#[derive(
+    Clone,
+    Debug,
+    Eq,
+    PartialEq,
+    ::serde::Serialize,
+    ::serde::Deserialize,
+    ::validator::Validate
+)]
+pub struct UsersUserGetInput {
+    pub id: ::uuid::Uuid,
+    pub include_deleted: Option<bool>,
+}
+#[derive(
+    Clone,
+    Debug,
+    Eq,
+    PartialEq,
+    ::serde::Serialize,
+    ::serde::Deserialize,
+    ::validator::Validate
+)]
+pub struct UsersUserRenameInput {
+    pub id: ::uuid::Uuid,
+    #[validate(length(min = 1i64, max = 100i64))]
+    pub name: String,
+    #[serde(default = "UsersUserRenameInput::default_notify_user")]
+    pub notify_user: bool,
+    pub reason: Option<String>,
+}
+impl UsersUserRenameInput {
+    fn default_notify_user() -> bool {
+        false
+    }
+}
+#[derive(
+    Clone,
+    Debug,
+    Eq,
+    PartialEq,
+    ::serde::Serialize,
+    ::serde::Deserialize,
+    ::validator::Validate
+)]
+pub struct UsersUserSeenInput {
+    pub id: ::uuid::Uuid,
+}
+#[::async_trait::async_trait]
+pub trait Users {
+    type Error: Into<::webwire::ProviderError>;
+    async fn user_get(
+        &self,
+        id: ::uuid::Uuid,
+        include_deleted: Option<bool>,
+    ) -> Result<std::result::Result<User, GetError>, Self::Error>;
+    async fn user_rename(
+        &self,
+        id: ::uuid::Uuid,
+        name: String,
+        notify_user: bool,
+        reason: Option<String>,
+    ) -> Result<(), Self::Error>;
+    async fn user_count(&self) -> Result<u64, Self::Error>;
+    async fn user_seen(&self, id: ::uuid::Uuid) -> Result<(), Self::Error>;
+}
+pub struct UsersProvider<F>(pub F);
+impl<F: Sync + Send, S: Sync + Send, T: Sync + Send> ::webwire::NamedProvider<S>
+for UsersProvider<F>
+where
+    F: Fn(::std::sync::Arc<S>) -> T,
+    T: Users + 'static,
+{
+    const NAME: &'static str = "Users";
+}
+impl<F: Sync + Send, S: Sync + Send, T: Sync + Send> ::webwire::Provider<S>
+for UsersProvider<F>
+where
+    F: Fn(::std::sync::Arc<S>) -> T,
+    T: Users + 'static,
+{
+    fn call(
+        &self,
+        session: &::std::sync::Arc<S>,
+        _service: &str,
+        method: &str,
+        input: ::bytes::Bytes,
+    ) -> ::futures::future::BoxFuture<
+        'static,
+        Result<::bytes::Bytes, ::webwire::ProviderError>,
+    > {
+        let service = self.0(session.clone());
+        match method {
+            "user_get" => {
+                Box::pin(async move {
+                    let input = serde_json::from_slice::<UsersUserGetInput>(&input)
+                        .map_err(::webwire::ProviderError::DeserializerError)?;
+                    ::validator::Validate::validate(&input)
+                        .map_err(::webwire::ProviderError::ValidationError)?;
+                    let UsersUserGetInput { id, include_deleted } = input;
+                    let output = service
+                        .user_get(id, include_deleted)
+                        .await
+                        .map_err(|e| e.into())?;
+                    let response = serde_json::to_vec(&output)
+                        .map_err(|e| ::webwire::ProviderError::SerializerError(e))
+                        .map(::bytes::Bytes::from)?;
+                    Ok(response)
+                })
+            }
+            "user_rename" => {
+                Box::pin(async move {
+                    let input = serde_json::from_slice::<UsersUserRenameInput>(&input)
+                        .map_err(::webwire::ProviderError::DeserializerError)?;
+                    ::validator::Validate::validate(&input)
+                        .map_err(::webwire::ProviderError::ValidationError)?;
+                    let UsersUserRenameInput { id, name, notify_user, reason } = input;
+                    let output = service
+                        .user_rename(id, name, notify_user, reason)
+                        .await
+                        .map_err(|e| e.into())?;
+                    let response = serde_json::to_vec(&output)
+                        .map_err(|e| ::webwire::ProviderError::SerializerError(e))
+                        .map(::bytes::Bytes::from)?;
+                    Ok(response)
+                })
+            }
+            "user_count" => {
+                Box::pin(async move {
+                    let output = service.user_count().await.map_err(|e| e.into())?;
+                    let response = serde_json::to_vec(&output)
+                        .map_err(|e| ::webwire::ProviderError::SerializerError(e))
+                        .map(::bytes::Bytes::from)?;
+                    Ok(response)
+                })
+            }
+            "user_seen" => {
+                Box::pin(async move {
+                    let input = serde_json::from_slice::<UsersUserSeenInput>(&input)
+                        .map_err(::webwire::ProviderError::DeserializerError)?;
+                    ::validator::Validate::validate(&input)
+                        .map_err(::webwire::ProviderError::ValidationError)?;
+                    let UsersUserSeenInput { id } = input;
+                    service.user_seen(id).await.map_err(|e| e.into())?;
+                    Ok(::bytes::Bytes::new())
+                })
+            }
+            _ => {
+                Box::pin(
+                    ::futures::future::ready(
+                        Err(::webwire::ProviderError::MethodNotFound),
+                    ),
+                )
+            }
+        }
+    }
+}
+pub struct UsersConsumer<'a>(
+    pub &'a (dyn ::webwire::Consumer + ::std::marker::Sync + ::std::marker::Send),
+);
+impl<'a> UsersConsumer<'a> {
+    pub async fn user_get(
+        &self,
+        id: ::uuid::Uuid,
+        include_deleted: Option<bool>,
+    ) -> Result<std::result::Result<User, GetError>, ::webwire::ConsumerError> {
+        let data: ::bytes::Bytes = serde_json::to_vec(
+                &UsersUserGetInput {
+                    id,
+                    include_deleted,
+                },
+            )
+            .map_err(|e| ::webwire::ConsumerError::SerializerError(e))?
+            .into();
+        let output = self.0.request("Users", "user_get", data).await?;
+        let response = ::serde_json::from_slice(&output)
+            .map_err(|e| ::webwire::ConsumerError::DeserializerError(e))?;
+        Ok(response)
+    }
+    pub async fn user_rename(
+        &self,
+        id: ::uuid::Uuid,
+        name: String,
+        notify_user: bool,
+        reason: Option<String>,
+    ) -> Result<(), ::webwire::ConsumerError> {
+        let data: ::bytes::Bytes = serde_json::to_vec(
+                &UsersUserRenameInput {
+                    id,
+                    name,
+                    notify_user,
+                    reason,
+                },
+            )
+            .map_err(|e| ::webwire::ConsumerError::SerializerError(e))?
+            .into();
+        let output = self.0.request("Users", "user_rename", data).await?;
+        let response = ::serde_json::from_slice(&output)
+            .map_err(|e| ::webwire::ConsumerError::DeserializerError(e))?;
+        Ok(response)
+    }
+    pub async fn user_count(&self) -> Result<u64, ::webwire::ConsumerError> {
+        let data = ::bytes::Bytes::new();
+        let output = self.0.request("Users", "user_count", data).await?;
+        let response = ::serde_json::from_slice(&output)
+            .map_err(|e| ::webwire::ConsumerError::DeserializerError(e))?;
+        Ok(response)
+    }
+    pub fn user_seen(&self, id: ::uuid::Uuid) -> Result<(), ::webwire::ConsumerError> {
+        let data: ::bytes::Bytes = serde_json::to_vec(&UsersUserSeenInput { id })
+            .map_err(|e| ::webwire::ConsumerError::SerializerError(e))?
+            .into();
+        self.0.notify("Users", "user_seen", data);
+        Ok(())
+    }
+}
+pub mod admin {
+    #[derive(
+        Clone,
+        Debug,
+        Eq,
+        PartialEq,
+        ::serde::Serialize,
+        ::serde::Deserialize,
+        ::validator::Validate
+    )]
+    pub struct UsersUserDeleteInput {
+        pub id: ::uuid::Uuid,
+        pub reason: Option<String>,
+        pub force: bool,
+    }
+    #[::async_trait::async_trait]
+    pub trait Users {
+        type Error: Into<::webwire::ProviderError>;
+        async fn user_delete(
+            &self,
+            id: ::uuid::Uuid,
+            reason: Option<String>,
+            force: bool,
+        ) -> Result<(), Self::Error>;
+    }
+    pub struct UsersProvider<F>(pub F);
+    impl<F: Sync + Send, S: Sync + Send, T: Sync + Send> ::webwire::NamedProvider<S>
+    for UsersProvider<F>
+    where
+        F: Fn(::std::sync::Arc<S>) -> T,
+        T: Users + 'static,
+    {
+        const NAME: &'static str = "admin.Users";
+    }
+    impl<F: Sync + Send, S: Sync + Send, T: Sync + Send> ::webwire::Provider<S>
+    for UsersProvider<F>
+    where
+        F: Fn(::std::sync::Arc<S>) -> T,
+        T: Users + 'static,
+    {
+        fn call(
+            &self,
+            session: &::std::sync::Arc<S>,
+            _service: &str,
+            method: &str,
+            input: ::bytes::Bytes,
+        ) -> ::futures::future::BoxFuture<
+            'static,
+            Result<::bytes::Bytes, ::webwire::ProviderError>,
+        > {
+            let service = self.0(session.clone());
+            match method {
+                "user_delete" => {
+                    Box::pin(async move {
+                        let input = serde_json::from_slice::<
+                            UsersUserDeleteInput,
+                        >(&input)
+                            .map_err(::webwire::ProviderError::DeserializerError)?;
+                        ::validator::Validate::validate(&input)
+                            .map_err(::webwire::ProviderError::ValidationError)?;
+                        let UsersUserDeleteInput { id, reason, force } = input;
+                        let output = service
+                            .user_delete(id, reason, force)
+                            .await
+                            .map_err(|e| e.into())?;
+                        let response = serde_json::to_vec(&output)
+                            .map_err(|e| ::webwire::ProviderError::SerializerError(e))
+                            .map(::bytes::Bytes::from)?;
+                        Ok(response)
+                    })
+                }
+                _ => {
+                    Box::pin(
+                        ::futures::future::ready(
+                            Err(::webwire::ProviderError::MethodNotFound),
+                        ),
+                    )
+                }
+            }
+        }
+    }
+    pub struct UsersConsumer<'a>(
+        pub &'a (dyn ::webwire::Consumer + ::std::marker::Sync + ::std::marker::Send),
+    );
+    impl<'a> UsersConsumer<'a> {
+        pub async fn user_delete(
+            &self,
+            id: ::uuid::Uuid,
+            reason: Option<String>,
+            force: bool,
+        ) -> Result<(), ::webwire::ConsumerError> {
+            let data: ::bytes::Bytes = serde_json::to_vec(
+                    &UsersUserDeleteInput {
+                        id,
+                        reason,
+                        force,
+                    },
+                )
+                .map_err(|e| ::webwire::ConsumerError::SerializerError(e))?
+                .into();
+            let output = self.0.request("admin.Users", "user_delete", data).await?;
+            let response = ::serde_json::from_slice(&output)
+                .map_err(|e| ::webwire::ConsumerError::DeserializerError(e))?;
+            Ok(response)
+        }
+    }
+}
@@ -0,0 +1,157 @@
+// GENERATED CODE - DO NOT EDIT!
+
+#[allow(dead_code)]
+#[derive(
+    Clone,
+    Debug,
+    Eq,
+    PartialEq,
+    ::serde::Serialize,
+    ::serde::Deserialize,
+    ::validator::Validate
+)]
+pub struct Root {
+    pub id: ::uuid::Uuid,
+}
+pub mod a {
+    use ::validator::Validate as _;
+    #[derive(
+        Clone,
+        Debug,
+        Eq,
+        PartialEq,
+        ::serde::Serialize,
+        ::serde::Deserialize,
+        ::validator::Validate
+    )]
+    pub struct A {
+        #[validate]
+        pub root: super::Root,
+        #[validate]
+        pub b: b::B,
+    }
+    pub mod b {
+        use ::validator::Validate as _;
+        #[derive(
+            Clone,
+            Debug,
+            Eq,
+            PartialEq,
+            ::serde::Serialize,
+            ::serde::Deserialize,
+            ::validator::Validate
+        )]
+        pub struct B {
+            pub value: String,
+        }
+        #[derive(
+            Clone,
+            Debug,
+            Eq,
+            PartialEq,
+            ::serde::Serialize,
+            ::serde::Deserialize,
+            ::validator::Validate
+        )]
+        pub struct C {
+            #[validate]
+            pub a: super::A,
+            #[validate]
+            pub root: super::super::Root,
+        }
+        #[::async_trait::async_trait]
+        pub trait Nested {
+            type Error: Into<::webwire::ProviderError>;
+            async fn get(&self, input: &super::A) -> Result<Option<C>, Self::Error>;
+            async fn root(&self) -> Result<super::super::Root, Self::Error>;
+        }
+        pub struct NestedProvider<F>(pub F);
+        impl<F: Sync + Send, S: Sync + Send, T: Sync + Send> ::webwire::NamedProvider<S>
+        for NestedProvider<F>
+        where
+            F: Fn(::std::sync::Arc<S>) -> T,
+            T: Nested + 'static,
+        {
+            const NAME: &'static str = "a.b.Nested";
+        }
+        impl<F: Sync + Send, S: Sync + Send, T: Sync + Send> ::webwire::Provider<S>
+        for NestedProvider<F>
+        where
+            F: Fn(::std::sync::Arc<S>) -> T,
+            T: Nested + 'static,
+        {
+            fn call(
+                &self,
+                session: &::std::sync::Arc<S>,
+                _service: &str,
+                method: &str,
+                input: ::bytes::Bytes,
+            ) -> ::futures::future::BoxFuture<
+                'static,
+                Result<::bytes::Bytes, ::webwire::ProviderError>,
+            > {
+                let service = self.0(session.clone());
+                match method {
+                    "get" => {
+                        Box::pin(async move {
+                            let input = serde_json::from_slice::<super::A>(&input)
+                                .map_err(::webwire::ProviderError::DeserializerError)?;
+                            ::validator::Validate::validate(&input)
+                                .map_err(::webwire::ProviderError::ValidationError)?;
+                            let output = service
+                                .get(&input)
+                                .await
+                                .map_err(|e| e.into())?;
+                            let response = serde_json::to_vec(&output)
+                                .map_err(|e| ::webwire::ProviderError::SerializerError(e))
+                                .map(::bytes::Bytes::from)?;
+                            Ok(response)
+                        })
+                    }
+                    "root" => {
+                        Box::pin(async move {
+                            let output = service.root().await.map_err(|e| e.into())?;
+                            let response = serde_json::to_vec(&output)
+                                .map_err(|e| ::webwire::ProviderError::SerializerError(e))
+                                .map(::bytes::Bytes::from)?;
+                            Ok(response)
+                        })
+                    }
+                    _ => {
+                        Box::pin(
+                            ::futures::future::ready(
+                                Err(::webwire::ProviderError::MethodNotFound),
+                            ),
+                        )
+                    }
+                }
+            }
+        }
+        pub struct NestedConsumer<'a>(
+            pub &'a (dyn ::webwire::Consumer + ::std::marker::Sync + ::std::marker::Send),
+        );
+        impl<'a> NestedConsumer<'a> {
+            pub async fn get(
+                &self,
+                input: &super::A,
+            ) -> Result<Option<C>, ::webwire::ConsumerError> {
+                let data: ::bytes::Bytes = serde_json::to_vec(input)
+                    .map_err(|e| ::webwire::ConsumerError::SerializerError(e))?
+                    .into();
+                let output = self.0.request("a.b.Nested", "get", data).await?;
+                let response = ::serde_json::from_slice(&output)
+                    .map_err(|e| ::webwire::ConsumerError::DeserializerError(e))?;
+                Ok(response)
+            }
+            pub async fn root(
+                &self,
+            ) -> Result<super::super::Root, ::webwire::ConsumerError> {
+                let data = ::bytes::Bytes::new();
+                let output = self.0.request("a.b.Nested", "root", data).await?;
+                let response = ::serde_json::from_slice(&output)
+                    .map_err(|e| ::webwire::ConsumerError::DeserializerError(e))?;
+                Ok(response)
+            }
+        }
+    }
+}
@@ -0,0 +1,133 @@
+// GENERATED CODE - DO NOT EDIT!
+
+#[allow(dead_code)]
+#[derive(Clone, Debug, Eq, PartialEq, ::serde::Serialize, ::serde::Deserialize)]
+pub enum Level {
+    Debug,
+    Info,
+    Error,
+}
+#[derive(
+    Clone,
+    Debug,
+    Eq,
+    PartialEq,
+    ::serde::Serialize,
+    ::serde::Deserialize,
+    ::validator::Validate
+)]
+pub struct LogEntry {
+    pub level: Level,
+    #[validate(length(min = 1i64, max = 4096i64))]
+    pub message: String,
+}
+pub struct EventsConsumer<'a>(
+    pub &'a (dyn ::webwire::Consumer + ::std::marker::Sync + ::std::marker::Send),
+);
+impl<'a> EventsConsumer<'a> {
+    pub fn message(&self, input: &String) -> Result<(), ::webwire::ConsumerError> {
+        let data: ::bytes::Bytes = serde_json::to_vec(input)
+            .map_err(|e| ::webwire::ConsumerError::SerializerError(e))?
+            .into();
+        self.0.notify("Events", "message", data);
+        Ok(())
+    }
+    pub fn reload(&self) -> Result<(), ::webwire::ConsumerError> {
+        let data = ::bytes::Bytes::new();
+        self.0.notify("Events", "reload", data);
+        Ok(())
+    }
+}
+#[::async_trait::async_trait]
+pub trait Telemetry {
+    type Error: Into<::webwire::ProviderError>;
+    async fn log(&self, input: &LogEntry) -> Result<(), Self::Error>;
+    async fn heartbeat(&self) -> Result<(), Self::Error>;
+    async fn flush(&self) -> Result<(), Self::Error>;
+}
+pub struct TelemetryProvider<F>(pub F);
+impl<F: Sync + Send, S: Sync + Send, T: Sync + Send> ::webwire::NamedProvider<S>
+for TelemetryProvider<F>
+where
+    F: Fn(::std::sync::Arc<S>) -> T,
+    T: Telemetry + 'static,
+{
+    const NAME: &'static str = "Telemetry";
+}
+impl<F: Sync + Send, S: Sync + Send, T: Sync + Send> ::webwire::Provider<S>
+for TelemetryProvider<F>
+where
+    F: Fn(::std::sync::Arc<S>) -> T,
+    T: Telemetry + 'static,
+{
+    fn call(
+        &self,
+        session: &::std::sync::Arc<S>,
+        _service: &str,
+        method: &str,
+        input: ::bytes::Bytes,
+    ) -> ::futures::future::BoxFuture<
+        'static,
+        Result<::bytes::Bytes, ::webwire::ProviderError>,
+    > {
+        let service = self.0(session.clone());
+        match method {
+            "log" => {
+                Box::pin(async move {
+                    let input = serde_json::from_slice::<LogEntry>(&input)
+                        .map_err(::webwire::ProviderError::DeserializerError)?;
+                    ::validator::Validate::validate(&input)
+                        .map_err(::webwire::ProviderError::ValidationError)?;
+                    service.log(&input).await.map_err(|e| e.into())?;
+                    Ok(::bytes::Bytes::new())
+                })
+            }
+            "heartbeat" => {
+                Box::pin(async move {
+                    service.heartbeat().await.map_err(|e| e.into())?;
+                    Ok(::bytes::Bytes::new())
+                })
+            }
+            "flush" => {
+                Box::pin(async move {
+                    let output = service.flush().await.map_err(|e| e.into())?;
+                    let response = serde_json::to_vec(&output)
+                        .map_err(|e| ::webwire::ProviderError::SerializerError(e))
+                        .map(::bytes::Bytes::from)?;
+                    Ok(response)
+                })
+            }
+            _ => {
+                Box::pin(
+                    ::futures::future::ready(
+                        Err(::webwire::ProviderError::MethodNotFound),
+                    ),
+                )
+            }
+        }
+    }
+}
+pub struct TelemetryConsumer<'a>(
+    pub &'a (dyn ::webwire::Consumer + ::std::marker::Sync + ::std::marker::Send),
+);
+impl<'a> TelemetryConsumer<'a> {
+    pub fn log(&self, input: &LogEntry) -> Result<(), ::webwire::ConsumerError> {
+        let data: ::bytes::Bytes = serde_json::to_vec(input)
+            .map_err(|e| ::webwire::ConsumerError::SerializerError(e))?
+            .into();
+        self.0.notify("Telemetry", "log", data);
+        Ok(())
+    }
+    pub fn heartbeat(&self) -> Result<(), ::webwire::ConsumerError> {
+        let data = ::bytes::Bytes::new();
+        self.0.notify("Telemetry", "heartbeat", data);
+        Ok(())
+    }
+    pub async fn flush(&self) -> Result<(), ::webwire::ConsumerError> {
+        let data = ::bytes::Bytes::new();
+        let output = self.0.request("Telemetry", "flush", data).await?;
+        let response = ::serde_json::from_slice(&output)
+            .map_err(|e| ::webwire::ConsumerError::DeserializerError(e))?;
+        Ok(response)
+    }
+}
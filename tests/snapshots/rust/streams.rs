@@ -0,0 +1,315 @@
+// GENERATED CODE - DO NOT EDIT!
+
+#[allow(dead_code)]
+#[::serde_with::serde_as]
+#[derive(
+    Clone,
+    Debug,
+    Eq,
+    PartialEq,
+    ::serde::Serialize,
+    ::serde::Deserialize,
+    ::validator::Validate
+)]
+pub struct Chunk {
+    #[serde_as(as = "::serde_with::base64::Base64")]
+    pub data: Vec<u8>,
+}
+#[derive(
+    Clone,
+    Debug,
+    Eq,
+    PartialEq,
+    ::serde::Serialize,
+    ::serde::Deserialize,
+    ::validator::Validate
+)]
+pub struct Event {
+    pub topic: String,
+    pub payload: String,
+}
+#[derive(
+    Clone,
+    Debug,
+    Eq,
+    PartialEq,
+    ::serde::Serialize,
+    ::serde::Deserialize,
+    ::validator::Validate
+)]
+pub struct Filter {
+    #[validate(length(min = 1i64, max = 64i64))]
+    pub topic: String,
+}
+#[derive(
+    Clone,
+    Debug,
+    Eq,
+    PartialEq,
+    ::serde::Serialize,
+    ::serde::Deserialize,
+    ::validator::Validate
+)]
+pub struct Receipt {
+    pub size: i64,
+}
+#[::async_trait::async_trait]
+pub trait Events {
+    type Error: Into<::webwire::ProviderError>;
+    async fn watch(
+        &self,
+        input: &Filter,
+    ) -> Result<
+        ::futures::stream::BoxStream<'static, Result<Event, Self::Error>>,
+        Self::Error,
+    >;
+    async fn tail(
+        &self,
+    ) -> Result<
+        ::futures::stream::BoxStream<'static, Result<Event, Self::Error>>,
+        Self::Error,
+    >;
+    async fn upload(
+        &self,
+        input: ::futures::stream::BoxStream<
+            'static,
+            Result<Chunk, ::webwire::ProviderError>,
+        >,
+    ) -> Result<Receipt, Self::Error>;
+    async fn echo(
+        &self,
+        input: ::futures::stream::BoxStream<
+            'static,
+            Result<Event, ::webwire::ProviderError>,
+        >,
+    ) -> Result<
+        ::futures::stream::BoxStream<'static, Result<Event, Self::Error>>,
+        Self::Error,
+    >;
+    async fn ping(&self) -> Result<(), Self::Error>;
+}
+pub struct EventsProvider<F>(pub F);
+impl<F: Sync + Send, S: Sync + Send, T: Sync + Send> ::webwire::NamedProvider<S>
+for EventsProvider<F>
+where
+    F: Fn(::std::sync::Arc<S>) -> T,
+    T: Events + 'static,
+{
+    const NAME: &'static str = "Events";
+}
+impl<F: Sync + Send, S: Sync + Send, T: Sync + Send> ::webwire::Provider<S>
+for EventsProvider<F>
+where
+    F: Fn(::std::sync::Arc<S>) -> T,
+    T: Events + 'static,
+{
+    fn call(
+        &self,
+        session: &::std::sync::Arc<S>,
+        _service: &str,
+        method: &str,
+        input: ::bytes::Bytes,
+    ) -> ::futures::future::BoxFuture<
+        'static,
+        Result<::bytes::Bytes, ::webwire::ProviderError>,
+    > {
+        let service = self.0(session.clone());
+        match method {
+            "watch" => {
+                Box::pin(async move {
+                    let input = serde_json::from_slice::<Filter>(&input)
+                        .map_err(::webwire::ProviderError::DeserializerError)?;
+                    ::validator::Validate::validate(&input)
+                        .map_err(::webwire::ProviderError::ValidationError)?;
+                    let output = service.watch(&input).await.map_err(|e| e.into())?;
+                    let output = ::futures::TryStreamExt::try_collect::<Vec<_>>(output)
+                        .await
+                        .map_err(|e| e.into())?;
+                    let response = serde_json::to_vec(&output)
+                        .map_err(|e| ::webwire::ProviderError::SerializerError(e))
+                        .map(::bytes::Bytes::from)?;
+                    Ok(response)
+                })
+            }
+            "tail" => {
+                Box::pin(async move {
+                    let output = service.tail().await.map_err(|e| e.into())?;
+                    let output = ::futures::TryStreamExt::try_collect::<Vec<_>>(output)
+                        .await
+                        .map_err(|e| e.into())?;
+                    let response = serde_json::to_vec(&output)
+                        .map_err(|e| ::webwire::ProviderError::SerializerError(e))
+                        .map(::bytes::Bytes::from)?;
+                    Ok(response)
+                })
+            }
+            "upload" => {
+                Box::pin(async move {
+                    let input = serde_json::from_slice::<Vec<Chunk>>(&input)
+                        .map_err(::webwire::ProviderError::DeserializerError)?;
+                    let input = ::futures::StreamExt::boxed(
+                        ::futures::stream::iter(
+                            input
+                                .into_iter()
+                                .map(|item| {
+                                    ::validator::Validate::validate(&item)
+                                        .map(|()| item)
+                                        .map_err(::webwire::ProviderError::ValidationError)
+                                }),
+                        ),
+                    );
+                    let output = service.upload(input).await.map_err(|e| e.into())?;
+                    let response = serde_json::to_vec(&output)
+                        .map_err(|e| ::webwire::ProviderError::SerializerError(e))
+                        .map(::bytes::Bytes::from)?;
+                    Ok(response)
+                })
+            }
+            "echo" => {
+                Box::pin(async move {
+                    let input = serde_json::from_slice::<Vec<Event>>(&input)
+                        .map_err(::webwire::ProviderError::DeserializerError)?;
+                    let input = ::futures::StreamExt::boxed(
+                        ::futures::stream::iter(
+                            input
+                                .into_iter()
+                                .map(|item| {
+                                    ::validator::Validate::validate(&item)
+                                        .map(|()| item)
+                                        .map_err(::webwire::ProviderError::ValidationError)
+                                }),
+                        ),
+                    );
+                    let output = service.echo(input).await.map_err(|e| e.into())?;
+                    let output = ::futures::TryStreamExt::try_collect::<Vec<_>>(output)
+                        .await
+                        .map_err(|e| e.into())?;
+                    let response = serde_json::to_vec(&output)
+                        .map_err(|e| ::webwire::ProviderError::SerializerError(e))
+                        .map(::bytes::Bytes::from)?;
+                    Ok(response)
+                })
+            }
+            "ping" => {
+                Box::pin(async move {
+                    let output = service.ping().await.map_err(|e| e.into())?;
+                    let response = serde_json::to_vec(&output)
+                        .map_err(|e| ::webwire::ProviderError::SerializerError(e))
+                        .map(::bytes::Bytes::from)?;
+                    Ok(response)
+                })
+            }
+            _ => {
+                Box::pin(
+                    ::futures::future::ready(
+                        Err(::webwire::ProviderError::MethodNotFound),
+                    ),
+                )
+            }
+        }
+    }
+}
+pub struct EventsConsumer<'a>(
+    pub &'a (dyn ::webwire::Consumer + ::std::marker::Sync + ::std::marker::Send),
+);
+impl<'a> EventsConsumer<'a> {
+    pub async fn watch(
+        &self,
+        input: &Filter,
+    ) -> Result<
+        ::futures::stream::BoxStream<'a, Result<Event, ::webwire::ConsumerError>>,
+        ::webwire::ConsumerError,
+    > {
+        let data: ::bytes::Bytes = serde_json::to_vec(input)
+            .map_err(|e| ::webwire::ConsumerError::SerializerError(e))?
+            .into();
+        let output = self.0.request("Events", "watch", data).await?;
+        let response: Vec<Event> = ::serde_json::from_slice(&output)
+            .map_err(|e| ::webwire::ConsumerError::DeserializerError(e))?;
+        Ok(
+            ::futures::StreamExt::boxed(
+                ::futures::stream::iter(response.into_iter().map(Ok)),
+            ),
+        )
+    }
+    pub async fn tail(
+        &self,
+    ) -> Result<
+        ::futures::stream::BoxStream<'a, Result<Event, ::webwire::ConsumerError>>,
+        ::webwire::ConsumerError,
+    > {
+        let data = ::bytes::Bytes::new();
+        let output = self.0.request("Events", "tail", data).await?;
+        let response: Vec<Event> = ::serde_json::from_slice(&output)
+            .map_err(|e| ::webwire::ConsumerError::DeserializerError(e))?;
+        Ok(
+            ::futures::StreamExt::boxed(
+                ::futures::stream::iter(response.into_iter().map(Ok)),
+            ),
+        )
+    }
+    pub async fn upload(
+        &self,
+        input: ::futures::stream::BoxStream<'static, Chunk>,
+    ) -> Result<Receipt, ::webwire::ConsumerError> {
+        let input = ::futures::StreamExt::collect::<Vec<_>>(input).await;
+        let data: ::bytes::Bytes = serde_json::to_vec(&input)
+            .map_err(|e| ::webwire::ConsumerError::SerializerError(e))?
+            .into();
+        let output = self.0.request("Events", "upload", data).await?;
+        let response = ::serde_json::from_slice(&output)
+            .map_err(|e| ::webwire::ConsumerError::DeserializerError(e))?;
+        Ok(response)
+    }
+    pub async fn echo(
+        &self,
+        input: ::futures::stream::BoxStream<'static, Event>,
+    ) -> Result<
+        ::futures::stream::BoxStream<'a, Result<Event, ::webwire::ConsumerError>>,
+        ::webwire::ConsumerError,
+    > {
+        let input = ::futures::StreamExt::collect::<Vec<_>>(input).await;
+        let data: ::bytes::Bytes = serde_json::to_vec(&input)
+            .map_err(|e| ::webwire::ConsumerError::SerializerError(e))?
+            .into();
+        let output = self.0.request("Events", "echo", data).await?;
+        let response: Vec<Event> = ::serde_json::from_slice(&output)
+            .map_err(|e| ::webwire::ConsumerError::DeserializerError(e))?;
+        Ok(
+            ::futures::StreamExt::boxed(
+                ::futures::stream::iter(response.into_iter().map(Ok)),
+            ),
+        )
+    }
+    pub async fn ping(&self) -> Result<(), ::webwire::ConsumerError> {
+        let data = ::bytes::Bytes::new();
+        let output = self.0.request("Events", "ping", data).await?;
+        let response = ::serde_json::from_slice(&output)
+            .map_err(|e| ::webwire::ConsumerError::DeserializerError(e))?;
+        Ok(response)
+    }
+}
+pub struct NotificationsConsumer<'a>(
+    pub &'a (dyn ::webwire::Consumer + ::std::marker::Sync + ::std::marker::Send),
+);
+impl<'a> NotificationsConsumer<'a> {
+    pub async fn subscribe(
+        &self,
+        input: &Filter,
+    ) -> Result<
+        ::futures::stream::BoxStream<'a, Result<Event, ::webwire::ConsumerError>>,
+        ::webwire::ConsumerError,
+    > {
+        let data: ::bytes::Bytes = serde_json::to_vec(input)
+            .map_err(|e| ::webwire::ConsumerError::SerializerError(e))?
+            .into();
+        let output = self.0.request("Notifications", "subscribe", data).await?;
+        let response: Vec<Event> = ::serde_json::from_slice(&output)
+            .map_err(|e| ::webwire::ConsumerError::DeserializerError(e))?;
+        Ok(
+            ::futures::StreamExt::boxed(
+                ::futures::stream::iter(response.into_iter().map(Ok)),
+            ),
+        )
+    }
+}
@@ -0,0 +1,267 @@
+// GENERATED CODE - DO NOT EDIT!
+
+#[allow(dead_code)]
+#[derive(Clone, Debug, Eq, PartialEq, ::serde::Serialize, ::serde::Deserialize)]
+pub enum GetError {
+    NotFound,
+    PermissionDenied,
+    RateLimited(u32),
+}
+#[derive(Clone, Debug, Eq, PartialEq, ::serde::Serialize, ::serde::Deserialize)]
+pub enum PromptsConfirmError {
+    Dismissed,
+}
+#[derive(
+    Clone,
+    Debug,
+    Eq,
+    PartialEq,
+    ::serde::Serialize,
+    ::serde::Deserialize,
+    ::validator::Validate
+)]
+pub struct User {
+    pub id: ::uuid::Uuid,
+    pub name: String,
+}
+#[derive(Clone, Debug, Eq, PartialEq, ::serde::Serialize, ::serde::Deserialize)]
+pub enum UsersRenameError {
+    NotFound,
+    NameTaken,
+}
+#[derive(
+    Clone,
+    Debug,
+    Eq,
+    PartialEq,
+    ::serde::Serialize,
+    ::serde::Deserialize,
+    ::validator::Validate
+)]
+pub struct UsersRenameInput {
+    pub id: ::uuid::Uuid,
+    pub name: String,
+}
+#[derive(Debug)]
+pub enum PromptsConfirmConsumerError {
+    Consumer(::webwire::ConsumerError),
+    Service(PromptsConfirmError),
+}
+impl From<::webwire::ConsumerError> for PromptsConfirmConsumerError {
+    fn from(error: ::webwire::ConsumerError) -> Self {
+        Self::Consumer(error)
+    }
+}
+impl ::std::fmt::Display for PromptsConfirmConsumerError {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        match self {
+            Self::Consumer(error) => write!(f, "{}", error),
+            Self::Service(error) => write!(f, "Prompts.confirm failed: {:?}", error),
+        }
+    }
+}
+impl ::std::error::Error for PromptsConfirmConsumerError {
+    fn source(&self) -> Option<&(dyn ::std::error::Error + 'static)> {
+        match self {
+            Self::Consumer(error) => Some(error),
+            Self::Service(_) => None,
+        }
+    }
+}
+pub struct PromptsConsumer<'a>(
+    pub &'a (dyn ::webwire::Consumer + ::std::marker::Sync + ::std::marker::Send),
+);
+impl<'a> PromptsConsumer<'a> {
+    pub async fn confirm(
+        &self,
+        input: &String,
+    ) -> Result<bool, PromptsConfirmConsumerError> {
+        let data: ::bytes::Bytes = serde_json::to_vec(input)
+            .map_err(|e| ::webwire::ConsumerError::SerializerError(e))?
+            .into();
+        let output = self.0.request("Prompts", "confirm", data).await?;
+        let response: std::result::Result<bool, PromptsConfirmError> = ::serde_json::from_slice(
+                &output,
+            )
+            .map_err(|e| ::webwire::ConsumerError::DeserializerError(e))?;
+        response.map_err(PromptsConfirmConsumerError::Service)
+    }
+}
+#[::async_trait::async_trait]
+pub trait Users {
+    type Error: Into<::webwire::ProviderError>;
+    async fn get(
+        &self,
+        input: &::uuid::Uuid,
+    ) -> Result<std::result::Result<User, GetError>, Self::Error>;
+    async fn rename(
+        &self,
+        id: ::uuid::Uuid,
+        name: String,
+    ) -> Result<std::result::Result<(), UsersRenameError>, Self::Error>;
+    async fn list(&self) -> Result<Vec<User>, Self::Error>;
+}
+pub struct UsersProvider<F>(pub F);
+impl<F: Sync + Send, S: Sync + Send, T: Sync + Send> ::webwire::NamedProvider<S>
+for UsersProvider<F>
+where
+    F: Fn(::std::sync::Arc<S>) -> T,
+    T: Users + 'static,
+{
+    const NAME: &'static str = "Users";
+}
+impl<F: Sync + Send, S: Sync + Send, T: Sync + Send> ::webwire::Provider<S>
+for UsersProvider<F>
+where
+    F: Fn(::std::sync::Arc<S>) -> T,
+    T: Users + 'static,
+{
+    fn call(
+        &self,
+        session: &::std::sync::Arc<S>,
+        _service: &str,
+        method: &str,
+        input: ::bytes::Bytes,
+    ) -> ::futures::future::BoxFuture<
+        'static,
+        Result<::bytes::Bytes, ::webwire::ProviderError>,
+    > {
+        let service = self.0(session.clone());
+        match method {
+            "get" => {
+                Box::pin(async move {
+                    let input = serde_json::from_slice::<::uuid::Uuid>(&input)
+                        .map_err(::webwire::ProviderError::DeserializerError)?;
+                    let output = service.get(&input).await.map_err(|e| e.into())?;
+                    let response = serde_json::to_vec(&output)
+                        .map_err(|e| ::webwire::ProviderError::SerializerError(e))
+                        .map(::bytes::Bytes::from)?;
+                    Ok(response)
+                })
+            }
+            "rename" => {
+                Box::pin(async move {
+                    let input = serde_json::from_slice::<UsersRenameInput>(&input)
+                        .map_err(::webwire::ProviderError::DeserializerError)?;
+                    ::validator::Validate::validate(&input)
+                        .map_err(::webwire::ProviderError::ValidationError)?;
+                    let UsersRenameInput { id, name } = input;
+                    let output = service.rename(id, name).await.map_err(|e| e.into())?;
+                    let response = serde_json::to_vec(&output)
+                        .map_err(|e| ::webwire::ProviderError::SerializerError(e))
+                        .map(::bytes::Bytes::from)?;
+                    Ok(response)
+                })
+            }
+            "list" => {
+                Box::pin(async move {
+                    let output = service.list().await.map_err(|e| e.into())?;
+                    let response = serde_json::to_vec(&output)
+                        .map_err(|e| ::webwire::ProviderError::SerializerError(e))
+                        .map(::bytes::Bytes::from)?;
+                    Ok(response)
+                })
+            }
+            _ => {
+                Box::pin(
+                    ::futures::future::ready(
+                        Err(::webwire::ProviderError::MethodNotFound),
+                    ),
+                )
+            }
+        }
+    }
+}
+#[derive(Debug)]
+pub enum UsersGetConsumerError {
+    Consumer(::webwire::ConsumerError),
+    Service(GetError),
+}
+impl From<::webwire::ConsumerError> for UsersGetConsumerError {
+    fn from(error: ::webwire::ConsumerError) -> Self {
+        Self::Consumer(error)
+    }
+}
+impl ::std::fmt::Display for UsersGetConsumerError {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        match self {
+            Self::Consumer(error) => write!(f, "{}", error),
+            Self::Service(error) => write!(f, "Users.get failed: {:?}", error),
+        }
+    }
+}
+impl ::std::error::Error for UsersGetConsumerError {
+    fn source(&self) -> Option<&(dyn ::std::error::Error + 'static)> {
+        match self {
+            Self::Consumer(error) => Some(error),
+            Self::Service(_) => None,
+        }
+    }
+}
+#[derive(Debug)]
+pub enum UsersRenameConsumerError {
+    Consumer(::webwire::ConsumerError),
+    Service(UsersRenameError),
+}
+impl From<::webwire::ConsumerError> for UsersRenameConsumerError {
+    fn from(error: ::webwire::ConsumerError) -> Self {
+        Self::Consumer(error)
+    }
+}
+impl ::std::fmt::Display for UsersRenameConsumerError {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        match self {
+            Self::Consumer(error) => write!(f, "{}", error),
+            Self::Service(error) => write!(f, "Users.rename failed: {:?}", error),
+        }
+    }
+}
+impl ::std::error::Error for UsersRenameConsumerError {
+    fn source(&self) -> Option<&(dyn ::std::error::Error + 'static)> {
+        match self {
+            Self::Consumer(error) => Some(error),
+            Self::Service(_) => None,
+        }
+    }
+}
+pub struct UsersConsumer<'a>(
+    pub &'a (dyn ::webwire::Consumer + ::std::marker::Sync + ::std::marker::Send),
+);
+impl<'a> UsersConsumer<'a> {
+    pub async fn get(
+        &self,
+        input: &::uuid::Uuid,
+    ) -> Result<User, UsersGetConsumerError> {
+        let data: ::bytes::Bytes = serde_json::to_vec(input)
+            .map_err(|e| ::webwire::ConsumerError::SerializerError(e))?
+            .into();
+        let output = self.0.request("Users", "get", data).await?;
+        let response: std::result::Result<User, GetError> = ::serde_json::from_slice(
+                &output,
+            )
+            .map_err(|e| ::webwire::ConsumerError::DeserializerError(e))?;
+        response.map_err(UsersGetConsumerError::Service)
+    }
+    pub async fn rename(
+        &self,
+        id: ::uuid::Uuid,
+        name: String,
+    ) -> Result<(), UsersRenameConsumerError> {
+        let data: ::bytes::Bytes = serde_json::to_vec(&UsersRenameInput { id, name })
+            .map_err(|e| ::webwire::ConsumerError::SerializerError(e))?
+            .into();
+        let output = self.0.request("Users", "rename", data).await?;
+        let response: std::result::Result<(), UsersRenameError> = ::serde_json::from_slice(
+                &output,
+            )
+            .map_err(|e| ::webwire::ConsumerError::DeserializerError(e))?;
+        response.map_err(UsersRenameConsumerError::Service)
+    }
+    pub async fn list(&self) -> Result<Vec<User>, ::webwire::ConsumerError> {
+        let data = ::bytes::Bytes::new();
+        let output = self.0.request("Users", "list", data).await?;
+        let response = ::serde_json::from_slice(&output)
+            .map_err(|e| ::webwire::ConsumerError::DeserializerError(e))?;
+        Ok(response)
+    }
+}
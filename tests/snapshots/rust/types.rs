@@ -0,0 +1,280 @@
+// GENERATED CODE - DO NOT EDIT!
+
+#[allow(dead_code)]
+#[::serde_with::serde_as]
+#[derive(
+    Clone,
+    Debug,
+    PartialEq,
+    ::serde::Serialize,
+    ::serde::Deserialize,
+    ::validator::Validate
+)]
+pub struct AllTypes {
+    pub boolean: bool,
+    pub integer: i64,
+    #[validate(range(min = 0i64, max = 1000i64))]
+    pub small: i16,
+    pub float: f64,
+    #[validate(length(min = 1i64, max = 10i64))]
+    pub string: String,
+    pub uuid: ::uuid::Uuid,
+    pub date: ::chrono::NaiveDate,
+    pub time: ::chrono::NaiveTime,
+    pub date_time: ::chrono::DateTime<::chrono::Utc>,
+    pub decimal: ::rust_decimal::Decimal,
+    #[serde_as(as = "::serde_with::base64::Base64")]
+    pub bytes: Vec<u8>,
+    pub duration: ::jiff::SignedDuration,
+    pub int8: i8,
+    pub int16: i16,
+    pub int32: i32,
+    pub int64: i64,
+    pub uint8: u8,
+    pub uint16: u16,
+    pub uint32: u32,
+    pub uint64: u64,
+    pub list: Vec<String>,
+    pub map: std::collections::HashMap<String, i64>,
+    pub int_map: std::collections::HashMap<i64, Vec<String>>,
+    pub nested_list: Vec<Vec<i64>>,
+    pub option: Option<String>,
+    pub nested_option: Option<Option<i64>>,
+    pub result: std::result::Result<Option<String>, Vec<i64>>,
+    pub optional: Option<i64>,
+}
+#[derive(
+    Clone,
+    Debug,
+    Eq,
+    PartialEq,
+    ::serde::Serialize,
+    ::serde::Deserialize,
+    ::validator::Validate
+)]
+pub struct AllTypesPartial {
+    pub integer: i64,
+    pub date: Option<::chrono::NaiveDate>,
+    pub nested_option: Option<Option<Option<i64>>>,
+}
+#[::async_trait::async_trait]
+pub trait Types {
+    type Error: Into<::webwire::ProviderError>;
+    async fn echo(&self, input: &AllTypes) -> Result<AllTypes, Self::Error>;
+    async fn nothing(&self) -> Result<(), Self::Error>;
+    async fn partial(
+        &self,
+        input: &AllTypesPartial,
+    ) -> Result<std::result::Result<Option<AllTypes>, ()>, Self::Error>;
+    async fn list(
+        &self,
+        input: &Vec<AllTypes>,
+    ) -> Result<std::collections::HashMap<::uuid::Uuid, AllTypes>, Self::Error>;
+    async fn dates(
+        &self,
+        input: &Vec<::chrono::NaiveDate>,
+    ) -> Result<
+        std::collections::HashMap<String, ::chrono::DateTime<::chrono::Utc>>,
+        Self::Error,
+    >;
+    async fn wait(
+        &self,
+        input: &::jiff::SignedDuration,
+    ) -> Result<Option<Vec<u8>>, Self::Error>;
+}
+pub struct TypesProvider<F>(pub F);
+impl<F: Sync + Send, S: Sync + Send, T: Sync + Send> ::webwire::NamedProvider<S>
+for TypesProvider<F>
+where
+    F: Fn(::std::sync::Arc<S>) -> T,
+    T: Types + 'static,
+{
+    const NAME: &'static str = "Types";
+}
+impl<F: Sync + Send, S: Sync + Send, T: Sync + Send> ::webwire::Provider<S>
+for TypesProvider<F>
+where
+    F: Fn(::std::sync::Arc<S>) -> T,
+    T: Types + 'static,
+{
+    fn call(
+        &self,
+        session: &::std::sync::Arc<S>,
+        _service: &str,
+        method: &str,
+        input: ::bytes::Bytes,
+    ) -> ::futures::future::BoxFuture<
+        'static,
+        Result<::bytes::Bytes, ::webwire::ProviderError>,
+    > {
+        let service = self.0(session.clone());
+        match method {
+            "echo" => {
+                Box::pin(async move {
+                    let input = serde_json::from_slice::<AllTypes>(&input)
+                        .map_err(::webwire::ProviderError::DeserializerError)?;
+                    ::validator::Validate::validate(&input)
+                        .map_err(::webwire::ProviderError::ValidationError)?;
+                    let output = service.echo(&input).await.map_err(|e| e.into())?;
+                    let response = serde_json::to_vec(&output)
+                        .map_err(|e| ::webwire::ProviderError::SerializerError(e))
+                        .map(::bytes::Bytes::from)?;
+                    Ok(response)
+                })
+            }
+            "nothing" => {
+                Box::pin(async move {
+                    let output = service.nothing().await.map_err(|e| e.into())?;
+                    let response = serde_json::to_vec(&output)
+                        .map_err(|e| ::webwire::ProviderError::SerializerError(e))
+                        .map(::bytes::Bytes::from)?;
+                    Ok(response)
+                })
+            }
+            "partial" => {
+                Box::pin(async move {
+                    let input = serde_json::from_slice::<AllTypesPartial>(&input)
+                        .map_err(::webwire::ProviderError::DeserializerError)?;
+                    ::validator::Validate::validate(&input)
+                        .map_err(::webwire::ProviderError::ValidationError)?;
+                    let output = service.partial(&input).await.map_err(|e| e.into())?;
+                    let response = serde_json::to_vec(&output)
+                        .map_err(|e| ::webwire::ProviderError::SerializerError(e))
+                        .map(::bytes::Bytes::from)?;
+                    Ok(response)
+                })
+            }
+            "list" => {
+                Box::pin(async move {
+                    let input = serde_json::from_slice::<Vec<AllTypes>>(&input)
+                        .map_err(::webwire::ProviderError::DeserializerError)?;
+                    let output = service.list(&input).await.map_err(|e| e.into())?;
+                    let response = serde_json::to_vec(&output)
+                        .map_err(|e| ::webwire::ProviderError::SerializerError(e))
+                        .map(::bytes::Bytes::from)?;
+                    Ok(response)
+                })
+            }
+            "dates" => {
+                Box::pin(async move {
+                    let input = serde_json::from_slice::<
+                        Vec<::chrono::NaiveDate>,
+                    >(&input)
+                        .map_err(::webwire::ProviderError::DeserializerError)?;
+                    let output = service.dates(&input).await.map_err(|e| e.into())?;
+                    let response = serde_json::to_vec(&output)
+                        .map_err(|e| ::webwire::ProviderError::SerializerError(e))
+                        .map(::bytes::Bytes::from)?;
+                    Ok(response)
+                })
+            }
+            "wait" => {
+                Box::pin(async move {
+                    let input = serde_json::from_slice::<::jiff::SignedDuration>(&input)
+                        .map_err(::webwire::ProviderError::DeserializerError)?;
+                    let output = service.wait(&input).await.map_err(|e| e.into())?;
+                    let response = serde_json::to_vec(
+                            &::serde_with::ser::SerializeAsWrap::<
+                                Option<Vec<u8>>,
+                                Option<::serde_with::base64::Base64>,
+                            >::new(&output),
+                        )
+                        .map_err(|e| ::webwire::ProviderError::SerializerError(e))
+                        .map(::bytes::Bytes::from)?;
+                    Ok(response)
+                })
+            }
+            _ => {
+                Box::pin(
+                    ::futures::future::ready(
+                        Err(::webwire::ProviderError::MethodNotFound),
+                    ),
+                )
+            }
+        }
+    }
+}
+pub struct TypesConsumer<'a>(
+    pub &'a (dyn ::webwire::Consumer + ::std::marker::Sync + ::std::marker::Send),
+);
+impl<'a> TypesConsumer<'a> {
+    pub async fn echo(
+        &self,
+        input: &AllTypes,
+    ) -> Result<AllTypes, ::webwire::ConsumerError> {
+        let data: ::bytes::Bytes = serde_json::to_vec(input)
+            .map_err(|e| ::webwire::ConsumerError::SerializerError(e))?
+            .into();
+        let output = self.0.request("Types", "echo", data).await?;
+        let response = ::serde_json::from_slice(&output)
+            .map_err(|e| ::webwire::ConsumerError::DeserializerError(e))?;
+        Ok(response)
+    }
+    pub async fn nothing(&self) -> Result<(), ::webwire::ConsumerError> {
+        let data = ::bytes::Bytes::new();
+        let output = self.0.request("Types", "nothing", data).await?;
+        let response = ::serde_json::from_slice(&output)
+            .map_err(|e| ::webwire::ConsumerError::DeserializerError(e))?;
+        Ok(response)
+    }
+    pub async fn partial(
+        &self,
+        input: &AllTypesPartial,
+    ) -> Result<std::result::Result<Option<AllTypes>, ()>, ::webwire::ConsumerError> {
+        let data: ::bytes::Bytes = serde_json::to_vec(input)
+            .map_err(|e| ::webwire::ConsumerError::SerializerError(e))?
+            .into();
+        let output = self.0.request("Types", "partial", data).await?;
+        let response = ::serde_json::from_slice(&output)
+            .map_err(|e| ::webwire::ConsumerError::DeserializerError(e))?;
+        Ok(response)
+    }
+    pub async fn list(
+        &self,
+        input: &Vec<AllTypes>,
+    ) -> Result<
+        std::collections::HashMap<::uuid::Uuid, AllTypes>,
+        ::webwire::ConsumerError,
+    > {
+        let data: ::bytes::Bytes = serde_json::to_vec(input)
+            .map_err(|e| ::webwire::ConsumerError::SerializerError(e))?
+            .into();
+        let output = self.0.request("Types", "list", data).await?;
+        let response = ::serde_json::from_slice(&output)
+            .map_err(|e| ::webwire::ConsumerError::DeserializerError(e))?;
+        Ok(response)
+    }
+    pub async fn dates(
+        &self,
+        input: &Vec<::chrono::NaiveDate>,
+    ) -> Result<
+        std::collections::HashMap<String, ::chrono::DateTime<::chrono::Utc>>,
+        ::webwire::ConsumerError,
+    > {
+        let data: ::bytes::Bytes = serde_json::to_vec(input)
+            .map_err(|e| ::webwire::ConsumerError::SerializerError(e))?
+            .into();
+        let output = self.0.request("Types", "dates", data).await?;
+        let response = ::serde_json::from_slice(&output)
+            .map_err(|e| ::webwire::ConsumerError::DeserializerError(e))?;
+        Ok(response)
+    }
+    pub async fn wait(
+        &self,
+        input: &::jiff::SignedDuration,
+    ) -> Result<Option<Vec<u8>>, ::webwire::ConsumerError> {
+        let data: ::bytes::Bytes = serde_json::to_vec(input)
+            .map_err(|e| ::webwire::ConsumerError::SerializerError(e))?
+            .into();
+        let output = self.0.request("Types", "wait", data).await?;
+        let response = ::serde_json::from_slice::<
+            ::serde_with::de::DeserializeAsWrap<
+                Option<Vec<u8>>,
+                Option<::serde_with::base64::Base64>,
+            >,
+        >(&output)
+            .map(::serde_with::de::DeserializeAsWrap::into_inner)
+            .map_err(|e| ::webwire::ConsumerError::DeserializerError(e))?;
+        Ok(response)
+    }
+}
@@ -0,0 +1,121 @@
+// GENERATED CODE - DO NOT EDIT!
+
+import Foundation
+import Webwire
+
+enum shop {
+
+    struct Customer: Codable, Equatable {
+        var emails: [shop.Email]
+        var orders: shop.Page<shop.Order>
+        var latest: shop.Order?
+        var byId: [String: shop.Order]
+
+        enum CodingKeys: String, CodingKey {
+            case emails
+            case orders
+            case latest
+            case byId = "by_id"
+        }
+    }
+
+    struct Email: Codable, Equatable {
+        var value: String
+
+        init(_ value: String) {
+            self.value = value
+        }
+
+        init(from decoder: Decoder) throws {
+            let container = try decoder.singleValueContainer()
+            value = try container.decode(String.self)
+        }
+
+        func encode(to encoder: Encoder) throws {
+            var container = encoder.singleValueContainer()
+            try container.encode(value)
+        }
+    }
+
+    struct Order: Codable, Equatable {
+        var id: shop.OrderId
+        var userId: shop.UserId
+        var email: shop.Email
+        var quantity: shop.Quantity
+        var createdAt: shop.Timestamp
+
+        enum CodingKeys: String, CodingKey {
+            case id
+            case userId = "user_id"
+            case email
+            case quantity
+            case createdAt = "created_at"
+        }
+    }
+
+    struct OrderId: Codable, Equatable {
+        var value: UUID
+
+        init(_ value: UUID) {
+            self.value = value
+        }
+
+        init(from decoder: Decoder) throws {
+            let container = try decoder.singleValueContainer()
+            value = try container.decode(UUID.self)
+        }
+
+        func encode(to encoder: Encoder) throws {
+            var container = encoder.singleValueContainer()
+            try container.encode(value)
+        }
+    }
+
+    typealias Page<T> = [T]
+
+    struct Quantity: Codable, Equatable {
+        var value: UInt32
+
+        init(_ value: UInt32) {
+            self.value = value
+        }
+
+        init(from decoder: Decoder) throws {
+            let container = try decoder.singleValueContainer()
+            value = try container.decode(UInt32.self)
+        }
+
+        func encode(to encoder: Encoder) throws {
+            var container = encoder.singleValueContainer()
+            try container.encode(value)
+        }
+    }
+
+    typealias Timestamp = Webwire.DateTime
+
+    typealias UserId = UUID
+
+    protocol Orders {
+        func get(_ input: shop.OrderId) async throws -> shop.Order?
+        func list(_ input: shop.UserId) async throws -> shop.Page<shop.Order>
+        func setEmail(_ input: shop.Email) async throws
+    }
+
+    struct OrdersConsumer: Orders {
+        let client: any Webwire.Client
+
+        func get(_ input: shop.OrderId) async throws -> shop.Order? {
+            let output = try await client.request(service: "shop.Orders", method: "get", data: try JSONEncoder().encode(input))
+            return try JSONDecoder().decode(shop.Order?.self, from: output)
+        }
+
+        func list(_ input: shop.UserId) async throws -> shop.Page<shop.Order> {
+            let output = try await client.request(service: "shop.Orders", method: "list", data: try JSONEncoder().encode(input))
+            return try JSONDecoder().decode(shop.Page<shop.Order>.self, from: output)
+        }
+
+        func setEmail(_ input: shop.Email) async throws {
+            _ = try await client.request(service: "shop.Orders", method: "set_email", data: try JSONEncoder().encode(input))
+        }
+    }
+}
@@ -0,0 +1,88 @@
+// GENERATED CODE - DO NOT EDIT!
+
+import Foundation
+import Webwire
+
+struct User: Codable, Equatable {
+    var id: UUID
+    var name: String
+
+    enum CodingKeys: String, CodingKey {
+        case id
+        case name
+    }
+}
+
+@available(*, deprecated)
+protocol Legacy {
+    func ping() async throws
+}
+
+@available(*, deprecated)
+struct LegacyConsumer: Legacy {
+    let client: any Webwire.Client
+
+    func ping() async throws {
+        _ = try await client.request(service: "Legacy", method: "ping", data: Data())
+    }
+}
+
+protocol Plain {
+    func ping() async throws
+}
+
+struct PlainConsumer: Plain {
+    let client: any Webwire.Client
+
+    func ping() async throws {
+        _ = try await client.request(service: "Plain", method: "ping", data: Data())
+    }
+}
+
+protocol Users {
+    func get(_ input: UUID) async throws -> User
+    @available(*, deprecated, message: "Use `search` instead")
+    func getByName(_ input: String) async throws -> User
+    func search(_ input: String) async throws -> [User]
+    func touch(_ input: UUID) throws
+}
+
+struct UsersConsumer: Users {
+    let client: any Webwire.Client
+
+    func get(_ input: UUID) async throws -> User {
+        let output = try await client.request(service: "Users", method: "get", data: try JSONEncoder().encode(input))
+        return try JSONDecoder().decode(User.self, from: output)
+    }
+
+    @available(*, deprecated, message: "Use `search` instead")
+    func getByName(_ input: String) async throws -> User {
+        let output = try await client.request(service: "Users", method: "get_by_name", data: try JSONEncoder().encode(input))
+        return try JSONDecoder().decode(User.self, from: output)
+    }
+
+    func search(_ input: String) async throws -> [User] {
+        let output = try await client.request(service: "Users", method: "search", data: try JSONEncoder().encode(input))
+        return try JSONDecoder().decode([User].self, from: output)
+    }
+
+    func touch(_ input: UUID) throws {
+        try client.notify(service: "Users", method: "touch", data: try JSONEncoder().encode(input))
+    }
+}
+
+enum admin {
+
+    protocol Audit {
+        func list() async throws -> [User]
+    }
+
+    struct AuditConsumer: Audit {
+        let client: any Webwire.Client
+
+        func list() async throws -> [User] {
+            let output = try await client.request(service: "admin.Audit", method: "list", data: Data())
+            return try JSONDecoder().decode([User].self, from: output)
+        }
+    }
+}
@@ -0,0 +1,27 @@
+// GENERATED CODE - DO NOT EDIT!
+
+import Foundation
+import Webwire
+
+struct Bookmark: Codable, Equatable {
+    var url: URL
+    var title: String
+
+    enum CodingKeys: String, CodingKey {
+        case url
+        case title
+    }
+}
+
+protocol Bookmarks {
+    func list() async throws -> [Bookmark]
+}
+
+struct BookmarksConsumer: Bookmarks {
+    let client: any Webwire.Client
+
+    func list() async throws -> [Bookmark] {
+        let output = try await client.request(service: "Bookmarks", method: "list", data: Data())
+        return try JSONDecoder().decode([Bookmark].self, from: output)
+    }
+}
@@ -0,0 +1,44 @@
+// GENERATED CODE - DO NOT EDIT!
+
+import Foundation
+import Webwire
+
+enum chat {
+
+    struct Message: Codable, Equatable {
+        var text: String
+
+        enum CodingKeys: String, CodingKey {
+            case text
+        }
+    }
+
+    enum SendError: String, Codable, Equatable {
+        case permissionDenied = "PermissionDenied"
+    }
+
+    protocol Client {
+        func onMessage(_ input: chat.Message) async throws
+    }
+
+    struct ClientConsumer: Client {
+        let client: any Webwire.Client
+
+        func onMessage(_ input: chat.Message) async throws {
+            _ = try await client.request(service: "chat.Client", method: "on_message", data: try JSONEncoder().encode(input))
+        }
+    }
+
+    protocol Server {
+        func send(_ input: chat.Message) async throws -> Webwire.Result<Webwire.Null, chat.SendError>
+    }
+
+    struct ServerConsumer: Server {
+        let client: any Webwire.Client
+
+        func send(_ input: chat.Message) async throws -> Webwire.Result<Webwire.Null, chat.SendError> {
+            let output = try await client.request(service: "chat.Server", method: "send", data: try JSONEncoder().encode(input))
+            return try JSONDecoder().decode(Webwire.Result<Webwire.Null, chat.SendError>.self, from: output)
+        }
+    }
+}
@@ -0,0 +1,56 @@
+// GENERATED CODE - DO NOT EDIT!
+
+import Foundation
+import Webwire
+
+struct Message: Codable, Equatable {
+    var author: String
+    var text: String
+
+    enum CodingKeys: String, CodingKey {
+        case author
+        case text
+    }
+}
+
+protocol Chat {
+    func send(_ input: Message) async throws
+    func history() async throws -> [Message]
+}
+
+struct ChatConsumer: Chat {
+    let client: any Webwire.Client
+
+    func send(_ input: Message) async throws {
+        _ = try await client.request(service: "Chat", method: "send", data: try JSONEncoder().encode(input))
+    }
+
+    func history() async throws -> [Message] {
+        let output = try await client.request(service: "Chat", method: "history", data: Data())
+        return try JSONDecoder().decode([Message].self, from: output)
+    }
+}
+
+protocol ChatEvents {
+    func onMessage(_ input: Message) async throws
+    func ping() async throws -> Bool
+}
+
+struct ChatEventsProvider: Webwire.Provider {
+    let service: any ChatEvents
+    let name = "ChatEvents"
+
+    func call(method: String, data: Data) async throws -> Data {
+        switch method {
+        case "on_message":
+            let input = try JSONDecoder().decode(Message.self, from: data)
+            try await service.onMessage(input)
+            return Data("null".utf8)
+        case "ping":
+            let output = try await service.ping()
+            return try JSONEncoder().encode(output)
+        default:
+            throw Webwire.MethodNotFound(method: method)
+        }
+    }
+}
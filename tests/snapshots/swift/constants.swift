@@ -0,0 +1,17 @@
+// GENERATED CODE - DO NOT EDIT!
+
+import Foundation
+import Webwire
+
+enum user {
+
+    struct User: Codable, Equatable {
+        var name: String
+        var age: Int64
+
+        enum CodingKeys: String, CodingKey {
+            case name
+            case age
+        }
+    }
+}
@@ -0,0 +1,72 @@
+// GENERATED CODE - DO NOT EDIT!
+
+import Foundation
+import Webwire
+
+struct Label: Codable, Equatable {
+    var id: UUID
+    var text: String
+
+    enum CodingKeys: String, CodingKey {
+        case id
+        case text
+    }
+}
+
+enum Order: String, Codable, Equatable {
+    case ascending = "Ascending"
+    case descending = "Descending"
+}
+
+struct Page<T: Codable & Equatable>: Codable, Equatable {
+    var items: [T]
+    var sizeLimit: Int64
+
+    enum CodingKeys: String, CodingKey {
+        case items
+        case sizeLimit = "size_limit"
+    }
+}
+
+struct PageSize: Codable, Equatable {
+    var limit: Int64
+
+    enum CodingKeys: String, CodingKey {
+        case limit
+    }
+}
+
+struct Query: Codable, Equatable {
+    var search: String
+    var limit: Int64
+    var offset: UInt32
+    var order: Order
+    var exact: Bool
+    var boost: Double
+    var minScore: Double
+    var tags: [String]?
+
+    enum CodingKeys: String, CodingKey {
+        case search
+        case limit
+        case offset
+        case order
+        case exact
+        case boost
+        case minScore = "min_score"
+        case tags
+    }
+}
+
+protocol Search {
+    func search(_ input: Query) async throws -> [Label]
+}
+
+struct SearchConsumer: Search {
+    let client: any Webwire.Client
+
+    func search(_ input: Query) async throws -> [Label] {
+        let output = try await client.request(service: "Search", method: "search", data: try JSONEncoder().encode(input))
+        return try JSONDecoder().decode([Label].self, from: output)
+    }
+}
@@ -0,0 +1,178 @@
+// GENERATED CODE - DO NOT EDIT!
+
+import Foundation
+import Webwire
+
+struct Account: Codable, Equatable {
+    var id: UUID
+    var name: String
+    @available(*, deprecated, message: "Deprecated since 1.4")
+    var email: String
+    var emails: [String]
+    @available(*, deprecated, message: "Logins are migrated to accounts")
+    var login: Login?
+    var state: AccountState
+    @available(*, deprecated)
+    var legacyState: AccountState
+
+    enum CodingKeys: String, CodingKey {
+        case id
+        case name
+        case email
+        case emails
+        case login
+        case state
+        case legacyState = "legacy_state"
+    }
+}
+
+struct AccountRead: Codable, Equatable {
+    var id: UUID
+    var name: String
+    @available(*, deprecated, message: "Deprecated since 1.4")
+    var email: String
+
+    enum CodingKeys: String, CodingKey {
+        case id
+        case name
+        case email
+    }
+}
+
+enum AccountState: String, Codable, Equatable {
+    case active = "Active"
+    @available(*, deprecated, message: "Use `Suspended`")
+    case disabled = "Disabled"
+    case suspended = "Suspended"
+}
+
+enum Credentials: Codable, Equatable {
+    case token(String)
+    @available(*, deprecated, message: "Deprecated since 1.2")
+    case password(Login)
+
+    private enum CodingKeys: String, CodingKey {
+        case token = "Token"
+        case password = "Password"
+    }
+
+    init(from decoder: Decoder) throws {
+        let container = try decoder.container(keyedBy: CodingKeys.self)
+        guard container.allKeys.count == 1, let key = container.allKeys.first else {
+            throw DecodingError.dataCorrupted(DecodingError.Context(codingPath: decoder.codingPath, debugDescription: "Expected exactly one variant of Credentials"))
+        }
+        switch key {
+        case .token: self = .token(try container.decode(String.self, forKey: .token))
+        case .password: self = .password(try container.decode(Login.self, forKey: .password))
+        }
+    }
+
+    func encode(to encoder: Encoder) throws {
+        switch self {
+        case .token(let value):
+            var container = encoder.container(keyedBy: CodingKeys.self)
+            try container.encode(value, forKey: .token)
+        case .password(let value):
+            var container = encoder.container(keyedBy: CodingKeys.self)
+            try container.encode(value, forKey: .password)
+        }
+    }
+}
+
+enum ExtendedCredentials: Codable, Equatable {
+    case certificate(String)
+    case token(String)
+    @available(*, deprecated, message: "Deprecated since 1.2")
+    case password(Login)
+
+    private enum CodingKeys: String, CodingKey {
+        case certificate = "Certificate"
+        case token = "Token"
+        case password = "Password"
+    }
+
+    init(from decoder: Decoder) throws {
+        let container = try decoder.container(keyedBy: CodingKeys.self)
+        guard container.allKeys.count == 1, let key = container.allKeys.first else {
+            throw DecodingError.dataCorrupted(DecodingError.Context(codingPath: decoder.codingPath, debugDescription: "Expected exactly one variant of ExtendedCredentials"))
+        }
+        switch key {
+        case .certificate: self = .certificate(try container.decode(String.self, forKey: .certificate))
+        case .token: self = .token(try container.decode(String.self, forKey: .token))
+        case .password: self = .password(try container.decode(Login.self, forKey: .password))
+        }
+    }
+
+    func encode(to encoder: Encoder) throws {
+        switch self {
+        case .certificate(let value):
+            var container = encoder.container(keyedBy: CodingKeys.self)
+            try container.encode(value, forKey: .certificate)
+        case .token(let value):
+            var container = encoder.container(keyedBy: CodingKeys.self)
+            try container.encode(value, forKey: .token)
+        case .password(let value):
+            var container = encoder.container(keyedBy: CodingKeys.self)
+            try container.encode(value, forKey: .password)
+        }
+    }
+}
+
+typealias LegacyLogin = Login
+
+@available(*, deprecated, message: "Use `Account` instead (since 1.2)")
+struct Login: Codable, Equatable {
+    var name: String
+    var password: String
+
+    enum CodingKeys: String, CodingKey {
+        case name
+        case password
+    }
+}
+
+@available(*, deprecated)
+struct Username: Codable, Equatable {
+    var value: String
+
+    init(_ value: String) {
+        self.value = value
+    }
+
+    init(from decoder: Decoder) throws {
+        let container = try decoder.singleValueContainer()
+        value = try container.decode(String.self)
+    }
+
+    func encode(to encoder: Encoder) throws {
+        var container = encoder.singleValueContainer()
+        try container.encode(value)
+    }
+}
+
+protocol Accounts {
+    func get(_ input: UUID) async throws -> Account
+    @available(*, deprecated, message: "Deprecated since 1.2")
+    func login(_ input: Login) async throws -> Account
+    func rename(_ input: Username) async throws -> Account
+}
+
+struct AccountsConsumer: Accounts {
+    let client: any Webwire.Client
+
+    func get(_ input: UUID) async throws -> Account {
+        let output = try await client.request(service: "Accounts", method: "get", data: try JSONEncoder().encode(input))
+        return try JSONDecoder().decode(Account.self, from: output)
+    }
+
+    @available(*, deprecated, message: "Deprecated since 1.2")
+    func login(_ input: Login) async throws -> Account {
+        let output = try await client.request(service: "Accounts", method: "login", data: try JSONEncoder().encode(input))
+        return try JSONDecoder().decode(Account.self, from: output)
+    }
+
+    func rename(_ input: Username) async throws -> Account {
+        let output = try await client.request(service: "Accounts", method: "rename", data: try JSONEncoder().encode(input))
+        return try JSONDecoder().decode(Account.self, from: output)
+    }
+}
@@ -0,0 +1,238 @@
+// GENERATED CODE - DO NOT EDIT!
+
+import Foundation
+import Webwire
+
+enum Base: Codable, Equatable {
+    case a
+    case b(String)
+
+    private enum CodingKeys: String, CodingKey {
+        case b = "B"
+    }
+
+    init(from decoder: Decoder) throws {
+        if let container = try? decoder.singleValueContainer(), let variant = try? container.decode(String.self) {
+            switch variant {
+            case "A": self = .a
+            default: throw DecodingError.dataCorruptedError(in: container, debugDescription: "Unknown variant \(variant) of Base")
+            }
+            return
+        }
+        let container = try decoder.container(keyedBy: CodingKeys.self)
+        guard container.allKeys.count == 1, let key = container.allKeys.first else {
+            throw DecodingError.dataCorrupted(DecodingError.Context(codingPath: decoder.codingPath, debugDescription: "Expected exactly one variant of Base"))
+        }
+        switch key {
+        case .b: self = .b(try container.decode(String.self, forKey: .b))
+        }
+    }
+
+    func encode(to encoder: Encoder) throws {
+        switch self {
+        case .a:
+            var container = encoder.singleValueContainer()
+            try container.encode("A")
+        case .b(let value):
+            var container = encoder.container(keyedBy: CodingKeys.self)
+            try container.encode(value, forKey: .b)
+        }
+    }
+}
+
+enum Empty: Codable, Equatable {
+
+    init(from decoder: Decoder) throws {
+        throw DecodingError.dataCorrupted(DecodingError.Context(codingPath: decoder.codingPath, debugDescription: "Invalid value for Empty"))
+    }
+
+    func encode(to encoder: Encoder) throws {
+        switch self {
+        }
+    }
+}
+
+enum GenericBase<T: Codable & Equatable>: Codable, Equatable {
+    case value(T)
+    case missing
+
+    private enum CodingKeys: String, CodingKey {
+        case value = "Value"
+    }
+
+    init(from decoder: Decoder) throws {
+        if let container = try? decoder.singleValueContainer(), let variant = try? container.decode(String.self) {
+            switch variant {
+            case "Missing": self = .missing
+            default: throw DecodingError.dataCorruptedError(in: container, debugDescription: "Unknown variant \(variant) of GenericBase")
+            }
+            return
+        }
+        let container = try decoder.container(keyedBy: CodingKeys.self)
+        guard container.allKeys.count == 1, let key = container.allKeys.first else {
+            throw DecodingError.dataCorrupted(DecodingError.Context(codingPath: decoder.codingPath, debugDescription: "Expected exactly one variant of GenericBase"))
+        }
+        switch key {
+        case .value: self = .value(try container.decode(T.self, forKey: .value))
+        }
+    }
+
+    func encode(to encoder: Encoder) throws {
+        switch self {
+        case .value(let value):
+            var container = encoder.container(keyedBy: CodingKeys.self)
+            try container.encode(value, forKey: .value)
+        case .missing:
+            var container = encoder.singleValueContainer()
+            try container.encode("Missing")
+        }
+    }
+}
+
+enum errors {
+
+    enum Extended: Codable, Equatable {
+        case c(Int64)
+        case a
+        case b(String)
+
+        private enum CodingKeys: String, CodingKey {
+            case c = "C"
+            case b = "B"
+        }
+
+        init(from decoder: Decoder) throws {
+            if let container = try? decoder.singleValueContainer(), let variant = try? container.decode(String.self) {
+                switch variant {
+                case "A": self = .a
+                default: throw DecodingError.dataCorruptedError(in: container, debugDescription: "Unknown variant \(variant) of Extended")
+                }
+                return
+            }
+            let container = try decoder.container(keyedBy: CodingKeys.self)
+            guard container.allKeys.count == 1, let key = container.allKeys.first else {
+                throw DecodingError.dataCorrupted(DecodingError.Context(codingPath: decoder.codingPath, debugDescription: "Expected exactly one variant of Extended"))
+            }
+            switch key {
+            case .c: self = .c(try container.decode(Int64.self, forKey: .c))
+            case .b: self = .b(try container.decode(String.self, forKey: .b))
+            }
+        }
+
+        func encode(to encoder: Encoder) throws {
+            switch self {
+            case .c(let value):
+                var container = encoder.container(keyedBy: CodingKeys.self)
+                try container.encode(value, forKey: .c)
+            case .a:
+                var container = encoder.singleValueContainer()
+                try container.encode("A")
+            case .b(let value):
+                var container = encoder.container(keyedBy: CodingKeys.self)
+                try container.encode(value, forKey: .b)
+            }
+        }
+    }
+
+    enum ExtendedGeneric: Codable, Equatable {
+        case other
+        case value(Double)
+        case missing
+
+        private enum CodingKeys: String, CodingKey {
+            case value = "Value"
+        }
+
+        init(from decoder: Decoder) throws {
+            if let container = try? decoder.singleValueContainer(), let variant = try? container.decode(String.self) {
+                switch variant {
+                case "Other": self = .other
+                case "Missing": self = .missing
+                default: throw DecodingError.dataCorruptedError(in: container, debugDescription: "Unknown variant \(variant) of ExtendedGeneric")
+                }
+                return
+            }
+            let container = try decoder.container(keyedBy: CodingKeys.self)
+            guard container.allKeys.count == 1, let key = container.allKeys.first else {
+                throw DecodingError.dataCorrupted(DecodingError.Context(codingPath: decoder.codingPath, debugDescription: "Expected exactly one variant of ExtendedGeneric"))
+            }
+            switch key {
+            case .value: self = .value(try container.decode(Double.self, forKey: .value))
+            }
+        }
+
+        func encode(to encoder: Encoder) throws {
+            switch self {
+            case .other:
+                var container = encoder.singleValueContainer()
+                try container.encode("Other")
+            case .value(let value):
+                var container = encoder.container(keyedBy: CodingKeys.self)
+                try container.encode(value, forKey: .value)
+            case .missing:
+                var container = encoder.singleValueContainer()
+                try container.encode("Missing")
+            }
+        }
+    }
+
+    enum ExtendedTwice: Codable, Equatable {
+        case d
+        case c(Int64)
+        case a
+        case b(String)
+
+        private enum CodingKeys: String, CodingKey {
+            case c = "C"
+            case b = "B"
+        }
+
+        init(from decoder: Decoder) throws {
+            if let container = try? decoder.singleValueContainer(), let variant = try? container.decode(String.self) {
+                switch variant {
+                case "D": self = .d
+                case "A": self = .a
+                default: throw DecodingError.dataCorruptedError(in: container, debugDescription: "Unknown variant \(variant) of ExtendedTwice")
+                }
+                return
+            }
+            let container = try decoder.container(keyedBy: CodingKeys.self)
+            guard container.allKeys.count == 1, let key = container.allKeys.first else {
+                throw DecodingError.dataCorrupted(DecodingError.Context(codingPath: decoder.codingPath, debugDescription: "Expected exactly one variant of ExtendedTwice"))
+            }
+            switch key {
+            case .c: self = .c(try container.decode(Int64.self, forKey: .c))
+            case .b: self = .b(try container.decode(String.self, forKey: .b))
+            }
+        }
+
+        func encode(to encoder: Encoder) throws {
+            switch self {
+            case .d:
+                var container = encoder.singleValueContainer()
+                try container.encode("D")
+            case .c(let value):
+                var container = encoder.container(keyedBy: CodingKeys.self)
+                try container.encode(value, forKey: .c)
+            case .a:
+                var container = encoder.singleValueContainer()
+                try container.encode("A")
+            case .b(let value):
+                var container = encoder.container(keyedBy: CodingKeys.self)
+                try container.encode(value, forKey: .b)
+            }
+        }
+    }
+
+    struct Holder: Codable, Equatable {
+        var empty: Empty?
+        var extended: errors.Extended
+        var generic: errors.ExtendedGeneric
+
+        enum CodingKeys: String, CodingKey {
+            case empty
+            case extended
+            case generic
+        }
+    }
+}
@@ -0,0 +1,72 @@
+// GENERATED CODE - DO NOT EDIT!
+
+import Foundation
+import Webwire
+
+struct Entity<ID: Codable & Equatable>: Codable, Equatable {
+    var createdAt: Webwire.DateTime
+    var updatedAt: Webwire.DateTime
+    var id: ID
+    var version: Int64
+
+    enum CodingKeys: String, CodingKey {
+        case createdAt = "created_at"
+        case updatedAt = "updated_at"
+        case id
+        case version
+    }
+}
+
+struct Timestamps: Codable, Equatable {
+    var createdAt: Webwire.DateTime
+    var updatedAt: Webwire.DateTime
+
+    enum CodingKeys: String, CodingKey {
+        case createdAt = "created_at"
+        case updatedAt = "updated_at"
+    }
+}
+
+enum user {
+
+    struct User: Codable, Equatable {
+        var createdAt: Webwire.DateTime
+        var updatedAt: Webwire.DateTime
+        var id: UUID
+        var version: Int64
+        var name: String
+        var email: String?
+
+        enum CodingKeys: String, CodingKey {
+            case createdAt = "created_at"
+            case updatedAt = "updated_at"
+            case id
+            case version
+            case name
+            case email
+        }
+    }
+
+    struct UserSummary: Codable, Equatable {
+        var id: UUID
+        var name: String
+
+        enum CodingKeys: String, CodingKey {
+            case id
+            case name
+        }
+    }
+
+    protocol Users {
+        func get(_ input: UUID) async throws -> user.UserSummary
+    }
+
+    struct UsersConsumer: Users {
+        let client: any Webwire.Client
+
+        func get(_ input: UUID) async throws -> user.UserSummary {
+            let output = try await client.request(service: "user.Users", method: "get", data: try JSONEncoder().encode(input))
+            return try JSONDecoder().decode(user.UserSummary.self, from: output)
+        }
+    }
+}
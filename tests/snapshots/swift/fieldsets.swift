@@ -0,0 +1,82 @@
+// GENERATED CODE - DO NOT EDIT!
+
+import Foundation
+import Webwire
+
+struct User: Codable, Equatable {
+    var id: UUID
+    var name: String
+    var email: String
+    var password: String
+    var bio: String?
+
+    enum CodingKeys: String, CodingKey {
+        case id
+        case name
+        case email
+        case password
+        case bio
+    }
+}
+
+struct UserPublic: Codable, Equatable {
+    var id: UUID
+    var name: String
+    var bio: String?
+
+    enum CodingKeys: String, CodingKey {
+        case id
+        case name
+        case bio
+    }
+}
+
+struct UserSignup: Codable, Equatable {
+    var name: String
+    var email: String
+    var password: String
+    var bio: String?
+
+    enum CodingKeys: String, CodingKey {
+        case name
+        case email
+        case password
+        case bio
+    }
+}
+
+struct UserUpdate: Codable, Equatable {
+    var name: String?
+    var bio: String?
+    var password: String?
+
+    enum CodingKeys: String, CodingKey {
+        case name
+        case bio
+        case password
+    }
+}
+
+protocol Users {
+    func get(_ input: UUID) async throws -> UserPublic
+    func update(_ input: UserUpdate) async throws
+    func signup(_ input: UserSignup) async throws -> UserPublic
+}
+
+struct UsersConsumer: Users {
+    let client: any Webwire.Client
+
+    func get(_ input: UUID) async throws -> UserPublic {
+        let output = try await client.request(service: "Users", method: "get", data: try JSONEncoder().encode(input))
+        return try JSONDecoder().decode(UserPublic.self, from: output)
+    }
+
+    func update(_ input: UserUpdate) async throws {
+        _ = try await client.request(service: "Users", method: "update", data: try JSONEncoder().encode(input))
+    }
+
+    func signup(_ input: UserSignup) async throws -> UserPublic {
+        let output = try await client.request(service: "Users", method: "signup", data: try JSONEncoder().encode(input))
+        return try JSONDecoder().decode(UserPublic.self, from: output)
+    }
+}
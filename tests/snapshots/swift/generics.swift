@@ -0,0 +1,166 @@
+// GENERATED CODE - DO NOT EDIT!
+
+import Foundation
+import Webwire
+
+enum Either<L: Codable & Equatable, R: Codable & Equatable>: Codable, Equatable {
+    case left(L)
+    case right(R)
+
+    private enum CodingKeys: String, CodingKey {
+        case left = "Left"
+        case right = "Right"
+    }
+
+    init(from decoder: Decoder) throws {
+        let container = try decoder.container(keyedBy: CodingKeys.self)
+        guard container.allKeys.count == 1, let key = container.allKeys.first else {
+            throw DecodingError.dataCorrupted(DecodingError.Context(codingPath: decoder.codingPath, debugDescription: "Expected exactly one variant of Either"))
+        }
+        switch key {
+        case .left: self = .left(try container.decode(L.self, forKey: .left))
+        case .right: self = .right(try container.decode(R.self, forKey: .right))
+        }
+    }
+
+    func encode(to encoder: Encoder) throws {
+        switch self {
+        case .left(let value):
+            var container = encoder.container(keyedBy: CodingKeys.self)
+            try container.encode(value, forKey: .left)
+        case .right(let value):
+            var container = encoder.container(keyedBy: CodingKeys.self)
+            try container.encode(value, forKey: .right)
+        }
+    }
+}
+
+struct Listing: Codable, Equatable {
+    var users: Page<User>
+    var pair: Pair<String, [Int64]>
+    var either: Either<User, Page<String>>
+    var tree: Tree<User>
+
+    enum CodingKeys: String, CodingKey {
+        case users
+        case pair
+        case either
+        case tree
+    }
+}
+
+struct Page<T: Codable & Equatable>: Codable, Equatable {
+    var items: [T]
+    var count: Int64
+    var first: T?
+
+    enum CodingKeys: String, CodingKey {
+        case items
+        case count
+        case first
+    }
+}
+
+struct Pair<A: Codable & Equatable, B: Codable & Equatable>: Codable, Equatable {
+    var first: A
+    var second: B
+
+    enum CodingKeys: String, CodingKey {
+        case first
+        case second
+    }
+}
+
+enum Tree<T: Codable & Equatable>: Codable, Equatable {
+    case leaf(T)
+    case many([T])
+    case maybe(T?)
+    case keyed([String: T])
+    case paged(Page<T>)
+    case split(Either<T, Int64>)
+    case empty
+
+    private enum CodingKeys: String, CodingKey {
+        case leaf = "Leaf"
+        case many = "Many"
+        case maybe = "Maybe"
+        case keyed = "Keyed"
+        case paged = "Paged"
+        case split = "Split"
+    }
+
+    init(from decoder: Decoder) throws {
+        if let container = try? decoder.singleValueContainer(), let variant = try? container.decode(String.self) {
+            switch variant {
+            case "Empty": self = .empty
+            default: throw DecodingError.dataCorruptedError(in: container, debugDescription: "Unknown variant \(variant) of Tree")
+            }
+            return
+        }
+        let container = try decoder.container(keyedBy: CodingKeys.self)
+        guard container.allKeys.count == 1, let key = container.allKeys.first else {
+            throw DecodingError.dataCorrupted(DecodingError.Context(codingPath: decoder.codingPath, debugDescription: "Expected exactly one variant of Tree"))
+        }
+        switch key {
+        case .leaf: self = .leaf(try container.decode(T.self, forKey: .leaf))
+        case .many: self = .many(try container.decode([T].self, forKey: .many))
+        case .maybe: self = .maybe(try container.decode(T?.self, forKey: .maybe))
+        case .keyed: self = .keyed(try container.decode([String: T].self, forKey: .keyed))
+        case .paged: self = .paged(try container.decode(Page<T>.self, forKey: .paged))
+        case .split: self = .split(try container.decode(Either<T, Int64>.self, forKey: .split))
+        }
+    }
+
+    func encode(to encoder: Encoder) throws {
+        switch self {
+        case .leaf(let value):
+            var container = encoder.container(keyedBy: CodingKeys.self)
+            try container.encode(value, forKey: .leaf)
+        case .many(let value):
+            var container = encoder.container(keyedBy: CodingKeys.self)
+            try container.encode(value, forKey: .many)
+        case .maybe(let value):
+            var container = encoder.container(keyedBy: CodingKeys.self)
+            try container.encode(value, forKey: .maybe)
+        case .keyed(let value):
+            var container = encoder.container(keyedBy: CodingKeys.self)
+            try container.encode(value, forKey: .keyed)
+        case .paged(let value):
+            var container = encoder.container(keyedBy: CodingKeys.self)
+            try container.encode(value, forKey: .paged)
+        case .split(let value):
+            var container = encoder.container(keyedBy: CodingKeys.self)
+            try container.encode(value, forKey: .split)
+        case .empty:
+            var container = encoder.singleValueContainer()
+            try container.encode("Empty")
+        }
+    }
+}
+
+struct User: Codable, Equatable {
+    var name: String
+
+    enum CodingKeys: String, CodingKey {
+        case name
+    }
+}
+
+protocol Users {
+    func list(_ input: Int64) async throws -> Page<User>
+    func pair(_ input: Pair<User, Int64>) async throws -> Either<User, String>
+}
+
+struct UsersConsumer: Users {
+    let client: any Webwire.Client
+
+    func list(_ input: Int64) async throws -> Page<User> {
+        let output = try await client.request(service: "Users", method: "list", data: try JSONEncoder().encode(input))
+        return try JSONDecoder().decode(Page<User>.self, from: output)
+    }
+
+    func pair(_ input: Pair<User, Int64>) async throws -> Either<User, String> {
+        let output = try await client.request(service: "Users", method: "pair", data: try JSONEncoder().encode(input))
+        return try JSONDecoder().decode(Either<User, String>.self, from: output)
+    }
+}
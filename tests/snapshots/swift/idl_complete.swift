@@ -0,0 +1,157 @@
+// GENERATED CODE - DO NOT EDIT!
+
+import Foundation
+import Webwire
+
+enum user {
+
+    enum GetError: String, Codable, Equatable {
+        case permissionDenied = "PermissionDenied"
+        case doesNotExist = "DoesNotExist"
+    }
+
+    enum ListError: String, Codable, Equatable {
+        case permissionDenied = "PermissionDenied"
+    }
+
+    struct Name: Codable, Equatable {
+        var prefix: String
+        var firstName: String
+        var middleName: String
+        var lastName: String
+        var suffix: String
+        var fullName: String
+
+        enum CodingKeys: String, CodingKey {
+            case prefix
+            case firstName = "first_name"
+            case middleName = "middle_name"
+            case lastName = "last_name"
+            case suffix
+            case fullName = "full_name"
+        }
+    }
+
+    struct NameUpdate: Codable, Equatable {
+        var prefix: String?
+        var firstName: String?
+        var middleName: String?
+        var lastName: String?
+        var suffix: String?
+
+        enum CodingKeys: String, CodingKey {
+            case prefix
+            case firstName = "first_name"
+            case middleName = "middle_name"
+            case lastName = "last_name"
+            case suffix
+        }
+    }
+
+    struct User: Codable, Equatable {
+        var id: UUID
+        var email: String
+        var name: user.Name
+        var password: String
+        var isAdmin: Bool
+
+        enum CodingKeys: String, CodingKey {
+            case id
+            case email
+            case name
+            case password
+            case isAdmin = "is_admin"
+        }
+    }
+
+    struct UserList: Codable, Equatable {
+        var count: Int64
+        var users: [user.UserRead]
+        var permissions: [String: String]
+
+        enum CodingKeys: String, CodingKey {
+            case count
+            case users
+            case permissions
+        }
+    }
+
+    struct UserListRequest: Codable, Equatable {
+        var offset: Int64?
+        var limit: Int64?
+
+        enum CodingKeys: String, CodingKey {
+            case offset
+            case limit
+        }
+    }
+
+    struct UserRead: Codable, Equatable {
+        var id: UUID
+        var email: String
+        var isAdmin: Bool
+        var name: user.Name
+
+        enum CodingKeys: String, CodingKey {
+            case id
+            case email
+            case isAdmin = "is_admin"
+            case name
+        }
+    }
+
+    struct UserRequest: Codable, Equatable {
+        var email: String
+
+        enum CodingKeys: String, CodingKey {
+            case email
+        }
+    }
+
+    enum UserState: String, Codable, Equatable {
+        case active = "Active"
+        case inactive = "Inactive"
+        case banned = "Banned"
+    }
+
+    struct UserWrite: Codable, Equatable {
+        var id: UUID
+        var email: String?
+        var isAdmin: Bool?
+        var name: user.Name?
+        var password: String?
+
+        enum CodingKeys: String, CodingKey {
+            case id
+            case email
+            case isAdmin = "is_admin"
+            case name
+            case password
+        }
+    }
+
+    protocol ExampleService {
+        func getVersion() async throws -> String
+        func userGet(_ input: user.UserRequest) async throws -> Webwire.Result<user.UserRead, user.GetError>
+        func userList(_ input: user.UserListRequest) async throws -> Webwire.Result<user.UserList, user.ListError>
+    }
+
+    struct ExampleServiceConsumer: ExampleService {
+        let client: any Webwire.Client
+
+        func getVersion() async throws -> String {
+            let output = try await client.request(service: "user.ExampleService", method: "get_version", data: Data())
+            return try JSONDecoder().decode(String.self, from: output)
+        }
+
+        func userGet(_ input: user.UserRequest) async throws -> Webwire.Result<user.UserRead, user.GetError> {
+            let output = try await client.request(service: "user.ExampleService", method: "user_get", data: try JSONEncoder().encode(input))
+            return try JSONDecoder().decode(Webwire.Result<user.UserRead, user.GetError>.self, from: output)
+        }
+
+        func userList(_ input: user.UserListRequest) async throws -> Webwire.Result<user.UserList, user.ListError> {
+            let output = try await client.request(service: "user.ExampleService", method: "user_list", data: try JSONEncoder().encode(input))
+            return try JSONDecoder().decode(Webwire.Result<user.UserList, user.ListError>.self, from: output)
+        }
+    }
+}
@@ -0,0 +1,174 @@
+// GENERATED CODE - DO NOT EDIT!
+
+import Foundation
+import Webwire
+
+struct Page<T: Codable & Equatable>: Codable, Equatable {
+    var items: [T]
+    var cursor: PageCursor<T>
+
+    enum CodingKeys: String, CodingKey {
+        case items
+        case cursor
+    }
+}
+
+struct PageCursor<T: Codable & Equatable>: Codable, Equatable {
+    var first: T
+    var next: String?
+
+    enum CodingKeys: String, CodingKey {
+        case first
+        case next
+    }
+}
+
+struct User: Codable, Equatable {
+    var id: UUID
+    var address: UserAddress
+    var status: UserStatus
+    var tags: [UserTags]
+
+    enum CodingKeys: String, CodingKey {
+        case id
+        case address
+        case status
+        case tags
+    }
+}
+
+struct UserAddress: Codable, Equatable {
+    var street: String
+    var zip: String
+    var geo: UserAddressGeo?
+
+    enum CodingKeys: String, CodingKey {
+        case street
+        case zip
+        case geo
+    }
+}
+
+struct UserAddressGeo: Codable, Equatable {
+    var lat: Double
+    var lng: Double
+
+    enum CodingKeys: String, CodingKey {
+        case lat
+        case lng
+    }
+}
+
+enum UserStatus: Codable, Equatable {
+    case active
+    case banned(UserStatusBanned)
+
+    private enum CodingKeys: String, CodingKey {
+        case banned = "Banned"
+    }
+
+    init(from decoder: Decoder) throws {
+        if let container = try? decoder.singleValueContainer(), let variant = try? container.decode(String.self) {
+            switch variant {
+            case "Active": self = .active
+            default: throw DecodingError.dataCorruptedError(in: container, debugDescription: "Unknown variant \(variant) of UserStatus")
+            }
+            return
+        }
+        let container = try decoder.container(keyedBy: CodingKeys.self)
+        guard container.allKeys.count == 1, let key = container.allKeys.first else {
+            throw DecodingError.dataCorrupted(DecodingError.Context(codingPath: decoder.codingPath, debugDescription: "Expected exactly one variant of UserStatus"))
+        }
+        switch key {
+        case .banned: self = .banned(try container.decode(UserStatusBanned.self, forKey: .banned))
+        }
+    }
+
+    func encode(to encoder: Encoder) throws {
+        switch self {
+        case .active:
+            var container = encoder.singleValueContainer()
+            try container.encode("Active")
+        case .banned(let value):
+            var container = encoder.container(keyedBy: CodingKeys.self)
+            try container.encode(value, forKey: .banned)
+        }
+    }
+}
+
+struct UserStatusBanned: Codable, Equatable {
+    var reason: String
+    var until: Webwire.DateTime?
+
+    enum CodingKeys: String, CodingKey {
+        case reason
+        case until
+    }
+}
+
+struct UserTags: Codable, Equatable {
+    var name: String
+    var color: String?
+
+    enum CodingKeys: String, CodingKey {
+        case name
+        case color
+    }
+}
+
+struct UsersSearchFilter: Codable, Equatable {
+    var name: String?
+    var status: UserStatus?
+
+    enum CodingKeys: String, CodingKey {
+        case name
+        case status
+    }
+}
+
+struct UsersSearchInput: Codable, Equatable {
+    var filter: UsersSearchFilter
+
+    enum CodingKeys: String, CodingKey {
+        case filter
+    }
+}
+
+struct UsersSearchOutput: Codable, Equatable {
+    var id: UUID
+    var name: String
+
+    enum CodingKeys: String, CodingKey {
+        case id
+        case name
+    }
+}
+
+struct UsersStatsOutput: Codable, Equatable {
+    var total: UInt64
+    var active: UInt64
+
+    enum CodingKeys: String, CodingKey {
+        case total
+        case active
+    }
+}
+
+protocol Users {
+    func search(_ input: UsersSearchInput) async throws -> [UsersSearchOutput]
+    func stats() async throws -> UsersStatsOutput
+}
+
+struct UsersConsumer: Users {
+    let client: any Webwire.Client
+
+    func search(_ input: UsersSearchInput) async throws -> [UsersSearchOutput] {
+        let output = try await client.request(service: "Users", method: "search", data: try JSONEncoder().encode(input))
+        return try JSONDecoder().decode([UsersSearchOutput].self, from: output)
+    }
+
+    func stats() async throws -> UsersStatsOutput {
+        let output = try await client.request(service: "Users", method: "stats", data: Data())
+        return try JSONDecoder().decode(UsersStatsOutput.self, from: output)
+    }
+}
@@ -0,0 +1,106 @@
+// GENERATED CODE - DO NOT EDIT!
+
+import Foundation
+import Webwire
+
+enum GetError: String, Codable, Equatable {
+    case notFound = "NotFound"
+}
+
+struct User: Codable, Equatable {
+    var id: UUID
+    var name: String
+
+    enum CodingKeys: String, CodingKey {
+        case id
+        case name
+    }
+}
+
+struct UsersUserGetInput: Codable, Equatable {
+    var id: UUID
+    var includeDeleted: Bool?
+
+    enum CodingKeys: String, CodingKey {
+        case id
+        case includeDeleted = "include_deleted"
+    }
+}
+
+struct UsersUserRenameInput: Codable, Equatable {
+    var id: UUID
+    var name: String
+    var notifyUser: Bool
+    var reason: String?
+
+    enum CodingKeys: String, CodingKey {
+        case id
+        case name
+        case notifyUser = "notify_user"
+        case reason
+    }
+}
+
+struct UsersUserSeenInput: Codable, Equatable {
+    var id: UUID
+
+    enum CodingKeys: String, CodingKey {
+        case id
+    }
+}
+
+protocol Users {
+    func userGet(_ input: UsersUserGetInput) async throws -> Webwire.Result<User, GetError>
+    func userRename(_ input: UsersUserRenameInput) async throws
+    func userCount() async throws -> UInt64
+    func userSeen(_ input: UsersUserSeenInput) throws
+}
+
+struct UsersConsumer: Users {
+    let client: any Webwire.Client
+
+    func userGet(_ input: UsersUserGetInput) async throws -> Webwire.Result<User, GetError> {
+        let output = try await client.request(service: "Users", method: "user_get", data: try JSONEncoder().encode(input))
+        return try JSONDecoder().decode(Webwire.Result<User, GetError>.self, from: output)
+    }
+
+    func userRename(_ input: UsersUserRenameInput) async throws {
+        _ = try await client.request(service: "Users", method: "user_rename", data: try JSONEncoder().encode(input))
+    }
+
+    func userCount() async throws -> UInt64 {
+        let output = try await client.request(service: "Users", method: "user_count", data: Data())
+        return try JSONDecoder().decode(UInt64.self, from: output)
+    }
+
+    func userSeen(_ input: UsersUserSeenInput) throws {
+        try client.notify(service: "Users", method: "user_seen", data: try JSONEncoder().encode(input))
+    }
+}
+
+enum admin {
+
+    struct UsersUserDeleteInput: Codable, Equatable {
+        var id: UUID
+        var reason: String?
+        var force: Bool
+
+        enum CodingKeys: String, CodingKey {
+            case id
+            case reason
+            case force
+        }
+    }
+
+    protocol Users {
+        func userDelete(_ input: admin.UsersUserDeleteInput) async throws
+    }
+
+    struct UsersConsumer: Users {
+        let client: any Webwire.Client
+
+        func userDelete(_ input: admin.UsersUserDeleteInput) async throws {
+            _ = try await client.request(service: "admin.Users", method: "user_delete", data: try JSONEncoder().encode(input))
+        }
+    }
+}
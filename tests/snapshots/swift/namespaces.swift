@@ -0,0 +1,65 @@
+// GENERATED CODE - DO NOT EDIT!
+
+import Foundation
+import Webwire
+
+struct Root: Codable, Equatable {
+    var id: UUID
+
+    enum CodingKeys: String, CodingKey {
+        case id
+    }
+}
+
+enum a {
+
+    struct A: Codable, Equatable {
+        var root: Root
+        var b: a.b.B
+
+        enum CodingKeys: String, CodingKey {
+            case root
+            case b
+        }
+    }
+
+    enum b {
+
+        struct B: Codable, Equatable {
+            var value: String
+
+            enum CodingKeys: String, CodingKey {
+                case value
+            }
+        }
+
+        struct C: Codable, Equatable {
+            var a: a.A
+            var root: Root
+
+            enum CodingKeys: String, CodingKey {
+                case a
+                case root
+            }
+        }
+
+        protocol Nested {
+            func get(_ input: a.A) async throws -> a.b.C?
+            func root() async throws -> Root
+        }
+
+        struct NestedConsumer: Nested {
+            let client: any Webwire.Client
+
+            func get(_ input: a.A) async throws -> a.b.C? {
+                let output = try await client.request(service: "a.b.Nested", method: "get", data: try JSONEncoder().encode(input))
+                return try JSONDecoder().decode(a.b.C?.self, from: output)
+            }
+
+            func root() async throws -> Root {
+                let output = try await client.request(service: "a.b.Nested", method: "root", data: Data())
+                return try JSONDecoder().decode(Root.self, from: output)
+            }
+        }
+    }
+}
@@ -0,0 +1,66 @@
+// GENERATED CODE - DO NOT EDIT!
+
+import Foundation
+import Webwire
+
+enum Level: String, Codable, Equatable {
+    case debug = "Debug"
+    case info = "Info"
+    case error = "Error"
+}
+
+struct LogEntry: Codable, Equatable {
+    var level: Level
+    var message: String
+
+    enum CodingKeys: String, CodingKey {
+        case level
+        case message
+    }
+}
+
+protocol Events {
+    func message(_ input: String) throws
+    func reload() throws
+}
+
+struct EventsProvider: Webwire.Provider {
+    let service: any Events
+    let name = "Events"
+
+    func call(method: String, data: Data) async throws -> Data {
+        switch method {
+        case "message":
+            let input = try JSONDecoder().decode(String.self, from: data)
+            try service.message(input)
+            return Data()
+        case "reload":
+            try service.reload()
+            return Data()
+        default:
+            throw Webwire.MethodNotFound(method: method)
+        }
+    }
+}
+
+protocol Telemetry {
+    func log(_ input: LogEntry) throws
+    func heartbeat() throws
+    func flush() async throws
+}
+
+struct TelemetryConsumer: Telemetry {
+    let client: any Webwire.Client
+
+    func log(_ input: LogEntry) throws {
+        try client.notify(service: "Telemetry", method: "log", data: try JSONEncoder().encode(input))
+    }
+
+    func heartbeat() throws {
+        try client.notify(service: "Telemetry", method: "heartbeat", data: Data())
+    }
+
+    func flush() async throws {
+        _ = try await client.request(service: "Telemetry", method: "flush", data: Data())
+    }
+}
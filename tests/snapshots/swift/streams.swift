@@ -0,0 +1,124 @@
+// GENERATED CODE - DO NOT EDIT!
+
+import Foundation
+import Webwire
+
+struct Chunk: Codable, Equatable {
+    var data: Data
+
+    enum CodingKeys: String, CodingKey {
+        case data
+    }
+}
+
+struct Event: Codable, Equatable {
+    var topic: String
+    var payload: String
+
+    enum CodingKeys: String, CodingKey {
+        case topic
+        case payload
+    }
+}
+
+struct Filter: Codable, Equatable {
+    var topic: String
+
+    enum CodingKeys: String, CodingKey {
+        case topic
+    }
+}
+
+struct Receipt: Codable, Equatable {
+    var size: Int64
+
+    enum CodingKeys: String, CodingKey {
+        case size
+    }
+}
+
+protocol Events {
+    func watch(_ input: Filter) async throws -> AsyncThrowingStream<Event, any Error>
+    func tail() async throws -> AsyncThrowingStream<Event, any Error>
+    func upload(_ input: AsyncThrowingStream<Chunk, any Error>) async throws -> Receipt
+    func echo(_ input: AsyncThrowingStream<Event, any Error>) async throws -> AsyncThrowingStream<Event, any Error>
+    func ping() async throws
+}
+
+struct EventsConsumer: Events {
+    let client: any Webwire.Client
+
+    func watch(_ input: Filter) async throws -> AsyncThrowingStream<Event, any Error> {
+        let output = try await client.request(service: "Events", method: "watch", data: try JSONEncoder().encode(input))
+        let items = try JSONDecoder().decode([Event].self, from: output)
+        return AsyncThrowingStream<Event, any Error> { continuation in
+            for item in items {
+                continuation.yield(item)
+            }
+            continuation.finish()
+        }
+    }
+
+    func tail() async throws -> AsyncThrowingStream<Event, any Error> {
+        let output = try await client.request(service: "Events", method: "tail", data: Data())
+        let items = try JSONDecoder().decode([Event].self, from: output)
+        return AsyncThrowingStream<Event, any Error> { continuation in
+            for item in items {
+                continuation.yield(item)
+            }
+            continuation.finish()
+        }
+    }
+
+    func upload(_ input: AsyncThrowingStream<Chunk, any Error>) async throws -> Receipt {
+        var items: [Chunk] = []
+        for try await item in input {
+            items.append(item)
+        }
+        let output = try await client.request(service: "Events", method: "upload", data: try JSONEncoder().encode(items))
+        return try JSONDecoder().decode(Receipt.self, from: output)
+    }
+
+    func echo(_ input: AsyncThrowingStream<Event, any Error>) async throws -> AsyncThrowingStream<Event, any Error> {
+        var items: [Event] = []
+        for try await item in input {
+            items.append(item)
+        }
+        let output = try await client.request(service: "Events", method: "echo", data: try JSONEncoder().encode(items))
+        let items = try JSONDecoder().decode([Event].self, from: output)
+        return AsyncThrowingStream<Event, any Error> { continuation in
+            for item in items {
+                continuation.yield(item)
+            }
+            continuation.finish()
+        }
+    }
+
+    func ping() async throws {
+        _ = try await client.request(service: "Events", method: "ping", data: Data())
+    }
+}
+
+protocol Notifications {
+    func subscribe(_ input: Filter) async throws -> AsyncThrowingStream<Event, any Error>
+}
+
+struct NotificationsProvider: Webwire.Provider {
+    let service: any Notifications
+    let name = "Notifications"
+
+    func call(method: String, data: Data) async throws -> Data {
+        switch method {
+        case "subscribe":
+            let input = try JSONDecoder().decode(Filter.self, from: data)
+            let output = try await service.subscribe(input)
+            var items: [Event] = []
+            for try await item in output {
+                items.append(item)
+            }
+            return try JSONEncoder().encode(items)
+        default:
+            throw Webwire.MethodNotFound(method: method)
+        }
+    }
+}
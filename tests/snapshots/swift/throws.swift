@@ -0,0 +1,120 @@
+// GENERATED CODE - DO NOT EDIT!
+
+import Foundation
+import Webwire
+
+enum GetError: Codable, Equatable {
+    case notFound
+    case permissionDenied
+    case rateLimited(UInt32)
+
+    private enum CodingKeys: String, CodingKey {
+        case rateLimited = "RateLimited"
+    }
+
+    init(from decoder: Decoder) throws {
+        if let container = try? decoder.singleValueContainer(), let variant = try? container.decode(String.self) {
+            switch variant {
+            case "NotFound": self = .notFound
+            case "PermissionDenied": self = .permissionDenied
+            default: throw DecodingError.dataCorruptedError(in: container, debugDescription: "Unknown variant \(variant) of GetError")
+            }
+            return
+        }
+        let container = try decoder.container(keyedBy: CodingKeys.self)
+        guard container.allKeys.count == 1, let key = container.allKeys.first else {
+            throw DecodingError.dataCorrupted(DecodingError.Context(codingPath: decoder.codingPath, debugDescription: "Expected exactly one variant of GetError"))
+        }
+        switch key {
+        case .rateLimited: self = .rateLimited(try container.decode(UInt32.self, forKey: .rateLimited))
+        }
+    }
+
+    func encode(to encoder: Encoder) throws {
+        switch self {
+        case .notFound:
+            var container = encoder.singleValueContainer()
+            try container.encode("NotFound")
+        case .permissionDenied:
+            var container = encoder.singleValueContainer()
+            try container.encode("PermissionDenied")
+        case .rateLimited(let value):
+            var container = encoder.container(keyedBy: CodingKeys.self)
+            try container.encode(value, forKey: .rateLimited)
+        }
+    }
+}
+
+enum PromptsConfirmError: String, Codable, Equatable {
+    case dismissed = "Dismissed"
+}
+
+struct User: Codable, Equatable {
+    var id: UUID
+    var name: String
+
+    enum CodingKeys: String, CodingKey {
+        case id
+        case name
+    }
+}
+
+enum UsersRenameError: String, Codable, Equatable {
+    case notFound = "NotFound"
+    case nameTaken = "NameTaken"
+}
+
+struct UsersRenameInput: Codable, Equatable {
+    var id: UUID
+    var name: String
+
+    enum CodingKeys: String, CodingKey {
+        case id
+        case name
+    }
+}
+
+protocol Prompts {
+    func confirm(_ input: String) async throws -> Webwire.Result<Bool, PromptsConfirmError>
+}
+
+struct PromptsProvider: Webwire.Provider {
+    let service: any Prompts
+    let name = "Prompts"
+
+    func call(method: String, data: Data) async throws -> Data {
+        switch method {
+        case "confirm":
+            let input = try JSONDecoder().decode(String.self, from: data)
+            let output = try await service.confirm(input)
+            return try JSONEncoder().encode(output)
+        default:
+            throw Webwire.MethodNotFound(method: method)
+        }
+    }
+}
+
+protocol Users {
+    func get(_ input: UUID) async throws -> Webwire.Result<User, GetError>
+    func rename(_ input: UsersRenameInput) async throws -> Webwire.Result<Webwire.Null, UsersRenameError>
+    func list() async throws -> [User]
+}
+
+struct UsersConsumer: Users {
+    let client: any Webwire.Client
+
+    func get(_ input: UUID) async throws -> Webwire.Result<User, GetError> {
+        let output = try await client.request(service: "Users", method: "get", data: try JSONEncoder().encode(input))
+        return try JSONDecoder().decode(Webwire.Result<User, GetError>.self, from: output)
+    }
+
+    func rename(_ input: UsersRenameInput) async throws -> Webwire.Result<Webwire.Null, UsersRenameError> {
+        let output = try await client.request(service: "Users", method: "rename", data: try JSONEncoder().encode(input))
+        return try JSONDecoder().decode(Webwire.Result<Webwire.Null, UsersRenameError>.self, from: output)
+    }
+
+    func list() async throws -> [User] {
+        let output = try await client.request(service: "Users", method: "list", data: Data())
+        return try JSONDecoder().decode([User].self, from: output)
+    }
+}
@@ -0,0 +1,120 @@
+// GENERATED CODE - DO NOT EDIT!
+
+import Foundation
+import Webwire
+
+struct AllTypes: Codable, Equatable {
+    var boolean: Bool
+    var integer: Int64
+    var small: Int64
+    var float: Double
+    var string: String
+    var uuid: UUID
+    var date: Webwire.Date
+    var time: Webwire.Time
+    var dateTime: Webwire.DateTime
+    var decimal: Webwire.Decimal
+    var bytes: Data
+    var duration: Webwire.Duration
+    var int8: Int8
+    var int16: Int16
+    var int32: Int32
+    var int64: Int64
+    var uint8: UInt8
+    var uint16: UInt16
+    var uint32: UInt32
+    var uint64: UInt64
+    var list: [String]
+    var map: [String: Int64]
+    var intMap: [String: [String]]
+    var nestedList: [[Int64]]
+    var option: String?
+    var nestedOption: Int64??
+    var result: Webwire.Result<String?, [Int64]>
+    var optional: Int64?
+
+    enum CodingKeys: String, CodingKey {
+        case boolean
+        case integer
+        case small
+        case float
+        case string
+        case uuid
+        case date
+        case time
+        case dateTime = "date_time"
+        case decimal
+        case bytes
+        case duration
+        case int8
+        case int16
+        case int32
+        case int64
+        case uint8
+        case uint16
+        case uint32
+        case uint64
+        case list
+        case map
+        case intMap = "int_map"
+        case nestedList = "nested_list"
+        case option
+        case nestedOption = "nested_option"
+        case result
+        case optional
+    }
+}
+
+struct AllTypesPartial: Codable, Equatable {
+    var integer: Int64
+    var date: Webwire.Date?
+    var nestedOption: Int64???
+
+    enum CodingKeys: String, CodingKey {
+        case integer
+        case date
+        case nestedOption = "nested_option"
+    }
+}
+
+protocol Types {
+    func echo(_ input: AllTypes) async throws -> AllTypes
+    func nothing() async throws
+    func partial(_ input: AllTypesPartial) async throws -> Webwire.Result<AllTypes?, Webwire.Null>
+    func list(_ input: [AllTypes]) async throws -> [String: AllTypes]
+    func dates(_ input: [Webwire.Date]) async throws -> [String: Webwire.DateTime]
+    func wait(_ input: Webwire.Duration) async throws -> Data?
+}
+
+struct TypesConsumer: Types {
+    let client: any Webwire.Client
+
+    func echo(_ input: AllTypes) async throws -> AllTypes {
+        let output = try await client.request(service: "Types", method: "echo", data: try JSONEncoder().encode(input))
+        return try JSONDecoder().decode(AllTypes.self, from: output)
+    }
+
+    func nothing() async throws {
+        _ = try await client.request(service: "Types", method: "nothing", data: Data())
+    }
+
+    func partial(_ input: AllTypesPartial) async throws -> Webwire.Result<AllTypes?, Webwire.Null> {
+        let output = try await client.request(service: "Types", method: "partial", data: try JSONEncoder().encode(input))
+        return try JSONDecoder().decode(Webwire.Result<AllTypes?, Webwire.Null>.self, from: output)
+    }
+
+    func list(_ input: [AllTypes]) async throws -> [String: AllTypes] {
+        let output = try await client.request(service: "Types", method: "list", data: try JSONEncoder().encode(input))
+        return try JSONDecoder().decode([String: AllTypes].self, from: output)
+    }
+
+    func dates(_ input: [Webwire.Date]) async throws -> [String: Webwire.DateTime] {
+        let output = try await client.request(service: "Types", method: "dates", data: try JSONEncoder().encode(input))
+        return try JSONDecoder().decode([String: Webwire.DateTime].self, from: output)
+    }
+
+    func wait(_ input: Webwire.Duration) async throws -> Data? {
+        let output = try await client.request(service: "Types", method: "wait", data: try JSONEncoder().encode(input))
+        return try JSONDecoder().decode(Data?.self, from: output)
+    }
+}
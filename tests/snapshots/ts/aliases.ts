@@ -0,0 +1,68 @@
+// GENERATED CODE - DO NOT EDIT!
+
+import * as webwire from './webwire'
+
+
+export namespace shop {
+
+    export interface Customer {
+        emails: Array<shop.Email>,
+        orders: shop.Page<shop.Order>,
+        latest?: shop.Order,
+        by_id: Map<webwire.UUID, shop.Order>,
+    }
+
+    export type Email = string & { readonly __brand: "shop.Email" }
+
+    export function newEmail(value: string): Email {
+        return value as Email
+    }
+
+    export interface Order {
+        id: shop.OrderId,
+        user_id: shop.UserId,
+        email: shop.Email,
+        quantity: shop.Quantity,
+        created_at: shop.Timestamp,
+    }
+
+    export type OrderId = webwire.UUID & { readonly __brand: "shop.OrderId" }
+
+    export function newOrderId(value: webwire.UUID): OrderId {
+        return value as OrderId
+    }
+
+    export type Page<T> = Array<T>
+
+    export type Quantity = number & { readonly __brand: "shop.Quantity" }
+
+    export function newQuantity(value: number): Quantity {
+        return value as Quantity
+    }
+
+    export type Timestamp = webwire.DateTime
+
+    export type UserId = webwire.UUID
+
+    export interface Orders {
+        get(input: shop.OrderId): webwire.Response<webwire.Option<shop.Order>>,
+        list(input: shop.UserId): webwire.Response<shop.Page<shop.Order>>,
+        set_email(input: shop.Email): webwire.Response<void>,
+    }
+
+    export class OrdersConsumer implements Orders {
+        _client: webwire.Client
+        constructor(client: webwire.Client) {
+            this._client = client
+        }
+        async get(input: shop.OrderId): webwire.Response<webwire.Option<shop.Order>> {
+            return await this._client.request('shop.Orders', 'get', input)
+        }
+        async list(input: shop.UserId): webwire.Response<shop.Page<shop.Order>> {
+            return await this._client.request('shop.Orders', 'list', input)
+        }
+        async set_email(input: shop.Email): webwire.Response<void> {
+            return await this._client.request('shop.Orders', 'set_email', input)
+        }
+    }
+}
@@ -0,0 +1,84 @@
+// GENERATED CODE - DO NOT EDIT!
+
+import * as webwire from './webwire'
+
+
+export interface User {
+    id: webwire.UUID,
+    name: string,
+}
+
+/** @deprecated */
+export interface Legacy {
+    ping(): webwire.Response<void>,
+}
+
+/** @deprecated */
+export class LegacyConsumer implements Legacy {
+    _client: webwire.Client
+    constructor(client: webwire.Client) {
+        this._client = client
+    }
+    async ping(): webwire.Response<void> {
+        return await this._client.request('Legacy', 'ping')
+    }
+}
+
+export interface Plain {
+    ping(): webwire.Response<void>,
+}
+
+export class PlainConsumer implements Plain {
+    _client: webwire.Client
+    constructor(client: webwire.Client) {
+        this._client = client
+    }
+    async ping(): webwire.Response<void> {
+        return await this._client.request('Plain', 'ping')
+    }
+}
+
+export interface Users {
+    get(input: webwire.UUID): webwire.Response<User>,
+    /** @deprecated Use `search` instead */
+    get_by_name(input: string): webwire.Response<User>,
+    search(input: string): webwire.Response<Array<User>>,
+    touch(input: webwire.UUID): void,
+}
+
+export class UsersConsumer implements Users {
+    _client: webwire.Client
+    constructor(client: webwire.Client) {
+        this._client = client
+    }
+    async get(input: webwire.UUID): webwire.Response<User> {
+        return await this._client.request('Users', 'get', input)
+    }
+    /** @deprecated Use `search` instead */
+    async get_by_name(input: string): webwire.Response<User> {
+        return await this._client.request('Users', 'get_by_name', input)
+    }
+    async search(input: string): webwire.Response<Array<User>> {
+        return await this._client.request('Users', 'search', input)
+    }
+    touch(input: webwire.UUID): void {
+        this._client.notify('Users', 'touch', input)
+    }
+}
+
+export namespace admin {
+
+    export interface Audit {
+        list(): webwire.Response<Array<User>>,
+    }
+
+    export class AuditConsumer implements Audit {
+        _client: webwire.Client
+        constructor(client: webwire.Client) {
+            this._client = client
+        }
+        async list(): webwire.Response<Array<User>> {
+            return await this._client.request('admin.Audit', 'list')
+        }
+    }
+}
@@ -0,0 +1,23 @@
+// GENERATED CODE - DO NOT EDIT!
+
+import * as webwire from './webwire'
+
+
+export interface Bookmark {
+    url: string,
+    title: string,
+}
+
+export interface Bookmarks {
+    list(): webwire.Response<Array<Bookmark>>,
+}
+
+export class BookmarksConsumer implements Bookmarks {
+    _client: webwire.Client
+    constructor(client: webwire.Client) {
+        this._client = client
+    }
+    async list(): webwire.Response<Array<Bookmark>> {
+        return await this._client.request('Bookmarks', 'list')
+    }
+}
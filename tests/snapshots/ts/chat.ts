@@ -0,0 +1,43 @@
+// GENERATED CODE - DO NOT EDIT!
+
+import * as webwire from './webwire'
+
+
+export namespace chat {
+
+    export interface Message {
+        text: string,
+    }
+
+    export type _SendErrorVariants = "PermissionDenied"
+    export type SendError =
+        | "PermissionDenied"
+
+    export interface Client {
+        on_message(input: chat.Message): webwire.Response<void>,
+    }
+
+    export class ClientConsumer implements Client {
+        _client: webwire.Client
+        constructor(client: webwire.Client) {
+            this._client = client
+        }
+        async on_message(input: chat.Message): webwire.Response<void> {
+            return await this._client.request('chat.Client', 'on_message', input)
+        }
+    }
+
+    export interface Server {
+        send(input: chat.Message): webwire.Response<webwire.Result<null, chat.SendError>>,
+    }
+
+    export class ServerConsumer implements Server {
+        _client: webwire.Client
+        constructor(client: webwire.Client) {
+            this._client = client
+        }
+        async send(input: chat.Message): webwire.Response<webwire.Result<null, chat.SendError>> {
+            return await this._client.request('chat.Server', 'send', input)
+        }
+    }
+}
@@ -0,0 +1,48 @@
+// GENERATED CODE - DO NOT EDIT!
+
+import * as webwire from './webwire'
+
+
+export interface Message {
+    author: string,
+    text: string,
+}
+
+export interface Chat {
+    send(input: Message): webwire.Response<void>,
+    history(): webwire.Response<Array<Message>>,
+}
+
+export class ChatConsumer implements Chat {
+    _client: webwire.Client
+    constructor(client: webwire.Client) {
+        this._client = client
+    }
+    async send(input: Message): webwire.Response<void> {
+        return await this._client.request('Chat', 'send', input)
+    }
+    async history(): webwire.Response<Array<Message>> {
+        return await this._client.request('Chat', 'history')
+    }
+}
+
+export interface ChatEvents {
+    on_message(input: Message): webwire.Response<void>,
+    ping(): webwire.Response<boolean>,
+}
+
+export function ChatEventsProvider(service: ChatEvents): webwire.Provider {
+    return {
+        service: 'ChatEvents',
+        async call(method: string, input?: any): Promise<unknown> {
+            switch (method) {
+            case 'on_message':
+                return await service.on_message(input)
+            case 'ping':
+                return await service.ping()
+            default:
+                throw new Error(`Unknown method ${method}`)
+            }
+        },
+    }
+}
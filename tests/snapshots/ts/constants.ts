@@ -0,0 +1,20 @@
+// GENERATED CODE - DO NOT EDIT!
+
+import * as webwire from './webwire'
+
+
+export const API_VERSION: string = "1.2.0"
+export const DEBUG: boolean = false
+export const MAX_PAGE_SIZE: number = 500
+export const PI: number = 3.14
+
+export namespace user {
+
+    export const MAX_NAME_LENGTH: number = 64
+    export const MIN_NAME_LENGTH: number = 2
+
+    export interface User {
+        name: string,
+        age: number,
+    }
+}
@@ -0,0 +1,81 @@
+// GENERATED CODE - DO NOT EDIT!
+
+import * as webwire from './webwire'
+
+
+export interface Label {
+    id: webwire.UUID,
+    text: string,
+}
+
+export function newLabel(value: Omit<Label, "text"> & Partial<Pick<Label, "text">>): Label {
+    return {
+        ...value,
+        text: value.text === undefined ? "unnamed \"label\"" : value.text,
+    }
+}
+
+export type _OrderVariants = "Ascending" | "Descending"
+export type Order =
+    | "Ascending"
+    | "Descending"
+
+export interface Page<T> {
+    items: Array<T>,
+    size_limit: number,
+}
+
+export function newPage<T>(value: Omit<Page<T>, "size_limit"> & Partial<Pick<Page<T>, "size_limit">>): Page<T> {
+    return {
+        ...value,
+        size_limit: value.size_limit === undefined ? 10 : value.size_limit,
+    }
+}
+
+export interface PageSize {
+    limit: number,
+}
+
+export function newPageSize(value: Omit<PageSize, "limit"> & Partial<Pick<PageSize, "limit">>): PageSize {
+    return {
+        ...value,
+        limit: value.limit === undefined ? 20 : value.limit,
+    }
+}
+
+export interface Query {
+    search: string,
+    limit: number,
+    offset: number,
+    order: Order,
+    exact: boolean,
+    boost: number,
+    min_score: number,
+    tags?: Array<string>,
+}
+
+export function newQuery(value: Omit<Query, "limit" | "offset" | "order" | "exact" | "boost" | "min_score"> & Partial<Pick<Query, "limit" | "offset" | "order" | "exact" | "boost" | "min_score">>): Query {
+    return {
+        ...value,
+        limit: value.limit === undefined ? 50 : value.limit,
+        offset: value.offset === undefined ? 0 : value.offset,
+        order: value.order === undefined ? "Descending" : value.order,
+        exact: value.exact === undefined ? false : value.exact,
+        boost: value.boost === undefined ? 1 : value.boost,
+        min_score: value.min_score === undefined ? -0.5 : value.min_score,
+    }
+}
+
+export interface Search {
+    search(input: Query): webwire.Response<Array<Label>>,
+}
+
+export class SearchConsumer implements Search {
+    _client: webwire.Client
+    constructor(client: webwire.Client) {
+        this._client = client
+    }
+    async search(input: Query): webwire.Response<Array<Label>> {
+        return await this._client.request('Search', 'search', input)
+    }
+}
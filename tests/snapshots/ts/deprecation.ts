@@ -0,0 +1,91 @@
+// GENERATED CODE - DO NOT EDIT!
+
+import * as webwire from './webwire'
+
+
+export interface Account {
+    id: webwire.UUID,
+    name: string,
+    /** @deprecated Since 1.4 */
+    email: string,
+    emails: Array<string>,
+    /** @deprecated Logins are migrated to accounts */
+    login?: Login,
+    state: AccountState,
+    /** @deprecated */
+    legacy_state: AccountState,
+}
+
+export function newAccount(value: Omit<Account, "state" | "legacy_state"> & Partial<Pick<Account, "state" | "legacy_state">>): Account {
+    return {
+        ...value,
+        state: value.state === undefined ? "Active" : value.state,
+        legacy_state: value.legacy_state === undefined ? "Disabled" : value.legacy_state,
+    }
+}
+
+export interface AccountRead {
+    id: webwire.UUID,
+    name: string,
+    /** @deprecated Since 1.4 */
+    email: string,
+}
+
+export type _AccountStateVariants = "Active" | "Disabled" | "Suspended"
+export type AccountState =
+    | "Active"
+    /** @deprecated Use `Suspended` */
+    | "Disabled"
+    | "Suspended"
+
+export type _CredentialsVariants = "Token" | "Password"
+export type Credentials =
+    | { [P in Exclude<_CredentialsVariants, "Token">]?: never } & { Token: string }
+    /** @deprecated Since 1.2 */
+    | { [P in Exclude<_CredentialsVariants, "Password">]?: never } & { Password: Login }
+
+export type _ExtendedCredentialsVariants = "Certificate" | "Token" | "Password"
+export type ExtendedCredentials =
+    | { [P in Exclude<_ExtendedCredentialsVariants, "Certificate">]?: never } & { Certificate: string }
+    | { [P in Exclude<_ExtendedCredentialsVariants, "Token">]?: never } & { Token: string }
+    /** @deprecated Since 1.2 */
+    | { [P in Exclude<_ExtendedCredentialsVariants, "Password">]?: never } & { Password: Login }
+
+export type LegacyLogin = Login
+
+/** @deprecated Since 1.2: Use `Account` instead */
+export interface Login {
+    name: string,
+    password: string,
+}
+
+/** @deprecated */
+export type Username = string & { readonly __brand: "Username" }
+
+export function newUsername(value: string): Username {
+    return value as Username
+}
+
+export interface Accounts {
+    get(input: webwire.UUID): webwire.Response<Account>,
+    /** @deprecated Since 1.2 */
+    login(input: Login): webwire.Response<Account>,
+    rename(input: Username): webwire.Response<Account>,
+}
+
+export class AccountsConsumer implements Accounts {
+    _client: webwire.Client
+    constructor(client: webwire.Client) {
+        this._client = client
+    }
+    async get(input: webwire.UUID): webwire.Response<Account> {
+        return await this._client.request('Accounts', 'get', input)
+    }
+    /** @deprecated Since 1.2 */
+    async login(input: Login): webwire.Response<Account> {
+        return await this._client.request('Accounts', 'login', input)
+    }
+    async rename(input: Username): webwire.Response<Account> {
+        return await this._client.request('Accounts', 'rename', input)
+    }
+}
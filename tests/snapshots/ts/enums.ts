@@ -0,0 +1,45 @@
+// GENERATED CODE - DO NOT EDIT!
+
+import * as webwire from './webwire'
+
+
+export type _BaseVariants = "A" | "B"
+export type Base =
+    | "A"
+    | { [P in Exclude<_BaseVariants, "B">]?: never } & { B: string }
+
+export type _EmptyVariants = never
+export type Empty = never
+
+export type _GenericBaseVariants = "Value" | "Missing"
+export type GenericBase<T> =
+    | { [P in Exclude<_GenericBaseVariants, "Value">]?: never } & { Value: T }
+    | "Missing"
+
+export namespace errors {
+
+    export type _ExtendedVariants = "C" | "A" | "B"
+    export type Extended =
+        | { [P in Exclude<_ExtendedVariants, "C">]?: never } & { C: number }
+        | "A"
+        | { [P in Exclude<_ExtendedVariants, "B">]?: never } & { B: string }
+
+    export type _ExtendedGenericVariants = "Other" | "Value" | "Missing"
+    export type ExtendedGeneric =
+        | "Other"
+        | { [P in Exclude<_ExtendedGenericVariants, "Value">]?: never } & { Value: number }
+        | "Missing"
+
+    export type _ExtendedTwiceVariants = "D" | "C" | "A" | "B"
+    export type ExtendedTwice =
+        | "D"
+        | { [P in Exclude<_ExtendedTwiceVariants, "C">]?: never } & { C: number }
+        | "A"
+        | { [P in Exclude<_ExtendedTwiceVariants, "B">]?: never } & { B: string }
+
+    export interface Holder {
+        empty: webwire.Option<Empty>,
+        extended: errors.Extended,
+        generic: errors.ExtendedGeneric,
+    }
+}
@@ -0,0 +1,55 @@
+// GENERATED CODE - DO NOT EDIT!
+
+import * as webwire from './webwire'
+
+
+export interface Entity<ID> extends Timestamps {
+    id: ID,
+    version: number,
+}
+
+export function newEntity<ID>(value: Omit<Entity<ID>, "version"> & Partial<Pick<Entity<ID>, "version">>): Entity<ID> {
+    return {
+        ...value,
+        version: value.version === undefined ? 1 : value.version,
+    }
+}
+
+export interface Timestamps {
+    created_at: webwire.DateTime,
+    updated_at: webwire.DateTime,
+}
+
+export namespace user {
+
+    export interface User extends Entity<webwire.UUID> {
+        name: string,
+        email?: string,
+    }
+
+    export function newUser(value: Omit<User, "version"> & Partial<Pick<User, "version">>): User {
+        return {
+            ...value,
+            version: value.version === undefined ? 1 : value.version,
+        }
+    }
+
+    export interface UserSummary {
+        id: webwire.UUID,
+        name: string,
+    }
+
+    export interface Users {
+        get(input: webwire.UUID): webwire.Response<user.UserSummary>,
+    }
+
+    export class UsersConsumer implements Users {
+        _client: webwire.Client
+        constructor(client: webwire.Client) {
+            this._client = client
+        }
+        async get(input: webwire.UUID): webwire.Response<user.UserSummary> {
+            return await this._client.request('user.Users', 'get', input)
+        }
+    }
+}
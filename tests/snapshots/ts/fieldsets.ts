@@ -0,0 +1,53 @@
+// GENERATED CODE - DO NOT EDIT!
+
+import * as webwire from './webwire'
+
+
+export interface User {
+    id: webwire.UUID,
+    name: string,
+    email: string,
+    password: string,
+    bio?: string,
+}
+
+export interface UserPublic {
+    id: webwire.UUID,
+    name: string,
+    bio?: string,
+}
+
+export interface UserSignup {
+    name: string,
+    email: string,
+    password: string,
+    bio?: string,
+}
+
+export interface UserUpdate {
+    name?: string,
+    bio?: string,
+    password?: string,
+}
+
+export interface Users {
+    get(input: webwire.UUID): webwire.Response<UserPublic>,
+    update(input: UserUpdate): webwire.Response<void>,
+    signup(input: UserSignup): webwire.Response<UserPublic>,
+}
+
+export class UsersConsumer implements Users {
+    _client: webwire.Client
+    constructor(client: webwire.Client) {
+        this._client = client
+    }
+    async get(input: webwire.UUID): webwire.Response<UserPublic> {
+        return await this._client.request('Users', 'get', input)
+    }
+    async update(input: UserUpdate): webwire.Response<void> {
+        return await this._client.request('Users', 'update', input)
+    }
+    async signup(input: UserSignup): webwire.Response<UserPublic> {
+        return await this._client.request('Users', 'signup', input)
+    }
+}
@@ -0,0 +1,59 @@
+// GENERATED CODE - DO NOT EDIT!
+
+import * as webwire from './webwire'
+
+
+export type _EitherVariants = "Left" | "Right"
+export type Either<L, R> =
+    | { [P in Exclude<_EitherVariants, "Left">]?: never } & { Left: L }
+    | { [P in Exclude<_EitherVariants, "Right">]?: never } & { Right: R }
+
+export interface Listing {
+    users: Page<User>,
+    pair: Pair<string, Array<number>>,
+    either: Either<User, Page<string>>,
+    tree: Tree<User>,
+}
+
+export interface Page<T> {
+    items: Array<T>,
+    count: number,
+    first?: T,
+}
+
+export interface Pair<A, B> {
+    first: A,
+    second: B,
+}
+
+export type _TreeVariants = "Leaf" | "Many" | "Maybe" | "Keyed" | "Paged" | "Split" | "Empty"
+export type Tree<T> =
+    | { [P in Exclude<_TreeVariants, "Leaf">]?: never } & { Leaf: T }
+    | { [P in Exclude<_TreeVariants, "Many">]?: never } & { Many: Array<T> }
+    | { [P in Exclude<_TreeVariants, "Maybe">]?: never } & { Maybe: webwire.Option<T> }
+    | { [P in Exclude<_TreeVariants, "Keyed">]?: never } & { Keyed: Map<string, T> }
+    | { [P in Exclude<_TreeVariants, "Paged">]?: never } & { Paged: Page<T> }
+    | { [P in Exclude<_TreeVariants, "Split">]?: never } & { Split: Either<T, number> }
+    | "Empty"
+
+export interface User {
+    name: string,
+}
+
+export interface Users {
+    list(input: number): webwire.Response<Page<User>>,
+    pair(input: Pair<User, number>): webwire.Response<Either<User, string>>,
+}
+
+export class UsersConsumer implements Users {
+    _client: webwire.Client
+    constructor(client: webwire.Client) {
+        this._client = client
+    }
+    async list(input: number): webwire.Response<Page<User>> {
+        return await this._client.request('Users', 'list', input)
+    }
+    async pair(input: Pair<User, number>): webwire.Response<Either<User, string>> {
+        return await this._client.request('Users', 'pair', input)
+    }
+}
@@ -0,0 +1,99 @@
+// GENERATED CODE - DO NOT EDIT!
+
+import * as webwire from './webwire'
+
+
+export namespace user {
+
+    export type _GetErrorVariants = "PermissionDenied" | "DoesNotExist"
+    export type GetError =
+        | "PermissionDenied"
+        | "DoesNotExist"
+
+    export type _ListErrorVariants = "PermissionDenied"
+    export type ListError =
+        | "PermissionDenied"
+
+    export interface Name {
+        prefix: string,
+        first_name: string,
+        middle_name: string,
+        last_name: string,
+        suffix: string,
+        full_name: string,
+    }
+
+    export interface NameUpdate {
+        prefix?: string,
+        first_name?: string,
+        middle_name?: string,
+        last_name?: string,
+        suffix?: string,
+    }
+
+    export interface User {
+        id: webwire.UUID,
+        email: string,
+        name: user.Name,
+        password: string,
+        is_admin: boolean,
+    }
+
+    export interface UserList {
+        count: number,
+        users: Array<user.UserRead>,
+        permissions: Map<webwire.UUID, string>,
+    }
+
+    export interface UserListRequest {
+        offset?: number,
+        limit?: number,
+    }
+
+    export interface UserRead {
+        id: webwire.UUID,
+        email: string,
+        is_admin: boolean,
+        name: user.Name,
+    }
+
+    export interface UserRequest {
+        email: string,
+    }
+
+    export type _UserStateVariants = "Active" | "Inactive" | "Banned"
+    export type UserState =
+        | "Active"
+        | "Inactive"
+        | "Banned"
+
+    export interface UserWrite {
+        id: webwire.UUID,
+        email?: string,
+        is_admin?: boolean,
+        name?: user.Name,
+        password?: string,
+    }
+
+    export interface ExampleService {
+        get_version(): webwire.Response<string>,
+        user_get(input: user.UserRequest): webwire.Response<webwire.Result<user.UserRead, user.GetError>>,
+        user_list(input: user.UserListRequest): webwire.Response<webwire.Result<user.UserList, user.ListError>>,
+    }
+
+    export class ExampleServiceConsumer implements ExampleService {
+        _client: webwire.Client
+        constructor(client: webwire.Client) {
+            this._client = client
+        }
+        async get_version(): webwire.Response<string> {
+            return await this._client.request('user.ExampleService', 'get_version')
+        }
+        async user_get(input: user.UserRequest): webwire.Response<webwire.Result<user.UserRead, user.GetError>> {
+            return await this._client.request('user.ExampleService', 'user_get', input)
+        }
+        async user_list(input: user.UserListRequest): webwire.Response<webwire.Result<user.UserList, user.ListError>> {
+            return await this._client.request('user.ExampleService', 'user_list', input)
+        }
+    }
+}
@@ -0,0 +1,91 @@
+// GENERATED CODE - DO NOT EDIT!
+
+import * as webwire from './webwire'
+
+
+export interface Page<T> {
+    items: Array<T>,
+    cursor: PageCursor<T>,
+}
+
+export interface PageCursor<T> {
+    first: T,
+    next?: string,
+}
+
+export interface User {
+    id: webwire.UUID,
+    address: UserAddress,
+    status: UserStatus,
+    tags: Array<UserTags>,
+}
+
+export function newUser(value: Omit<User, "status"> & Partial<Pick<User, "status">>): User {
+    return {
+        ...value,
+        status: value.status === undefined ? "Active" : value.status,
+    }
+}
+
+export interface UserAddress {
+    street: string,
+    zip: string,
+    geo?: UserAddressGeo,
+}
+
+export interface UserAddressGeo {
+    lat: number,
+    lng: number,
+}
+
+export type _UserStatusVariants = "Active" | "Banned"
+export type UserStatus =
+    | "Active"
+    | { [P in Exclude<_UserStatusVariants, "Banned">]?: never } & { Banned: UserStatusBanned }
+
+export interface UserStatusBanned {
+    reason: string,
+    until?: webwire.DateTime,
+}
+
+export interface UserTags {
+    name: string,
+    color?: string,
+}
+
+export interface UsersSearchFilter {
+    name?: string,
+    status?: UserStatus,
+}
+
+export interface UsersSearchInput {
+    filter: UsersSearchFilter,
+}
+
+export interface UsersSearchOutput {
+    id: webwire.UUID,
+    name: string,
+}
+
+export interface UsersStatsOutput {
+    total: number,
+    active: number,
+}
+
+export interface Users {
+    search(filter: UsersSearchFilter): webwire.Response<Array<UsersSearchOutput>>,
+    stats(): webwire.Response<UsersStatsOutput>,
+}
+
+export class UsersConsumer implements Users {
+    _client: webwire.Client
+    constructor(client: webwire.Client) {
+        this._client = client
+    }
+    async search(filter: UsersSearchFilter): webwire.Response<Array<UsersSearchOutput>> {
+        return await this._client.request('Users', 'search', { filter })
+    }
+    async stats(): webwire.Response<UsersStatsOutput> {
+        return await this._client.request('Users', 'stats')
+    }
+}
@@ -0,0 +1,85 @@
+// GENERATED CODE - DO NOT EDIT!
+
+import * as webwire from './webwire'
+
+
+export type _GetErrorVariants = "NotFound"
+export type GetError =
+    | "NotFound"
+
+export interface User {
+    id: webwire.UUID,
+    name: string,
+}
+
+export interface UsersUserGetInput {
+    id: webwire.UUID,
+    include_deleted?: boolean,
+}
+
+export interface UsersUserRenameInput {
+    id: webwire.UUID,
+    name: string,
+    notify_user: boolean,
+    reason?: string,
+}
+
+export function newUsersUserRenameInput(value: Omit<UsersUserRenameInput, "notify_user"> & Partial<Pick<UsersUserRenameInput, "notify_user">>): UsersUserRenameInput {
+    return {
+        ...value,
+        notify_user: value.notify_user === undefined ? false : value.notify_user,
+    }
+}
+
+export interface UsersUserSeenInput {
+    id: webwire.UUID,
+}
+
+export interface Users {
+    user_get(id: webwire.UUID, include_deleted?: boolean): webwire.Response<webwire.Result<User, GetError>>,
+    user_rename(id: webwire.UUID, name: string, notify_user?: boolean, reason?: string): webwire.Response<void>,
+    user_count(): webwire.Response<number>,
+    user_seen(id: webwire.UUID): void,
+}
+
+export class UsersConsumer implements Users {
+    _client: webwire.Client
+    constructor(client: webwire.Client) {
+        this._client = client
+    }
+    async user_get(id: webwire.UUID, include_deleted?: boolean): webwire.Response<webwire.Result<User, GetError>> {
+        return await this._client.request('Users', 'user_get', { id, include_deleted })
+    }
+    async user_rename(id: webwire.UUID, name: string, notify_user?: boolean, reason?: string): webwire.Response<void> {
+        return await this._client.request('Users', 'user_rename', { id, name, notify_user, reason })
+    }
+    async user_count(): webwire.Response<number> {
+        return await this._client.request('Users', 'user_count')
+    }
+    user_seen(id: webwire.UUID): void {
+        this._client.notify('Users', 'user_seen', { id })
+    }
+}
+
+export namespace admin {
+
+    export interface UsersUserDeleteInput {
+        id: webwire.UUID,
+        reason?: string,
+        force: boolean,
+    }
+
+    export interface Users {
+        user_delete(id: webwire.UUID, reason: string | undefined, force: boolean): webwire.Response<void>,
+    }
+
+    export class UsersConsumer implements Users {
+        _client: webwire.Client
+        constructor(client: webwire.Client) {
+            this._client = client
+        }
+        async user_delete(id: webwire.UUID, reason: string | undefined, force: boolean): webwire.Response<void> {
+            return await this._client.request('admin.Users', 'user_delete', { id, reason, force })
+        }
+    }
+}
@@ -0,0 +1,46 @@
+// GENERATED CODE - DO NOT EDIT!
+
+import * as webwire from './webwire'
+
+
+export interface Root {
+    id: webwire.UUID,
+}
+
+export namespace a {
+
+    export interface A {
+        root: Root,
+        b: a.b.B,
+    }
+
+    export namespace b {
+
+        export interface B {
+            value: string,
+        }
+
+        export interface C {
+            a: a.A,
+            root: Root,
+        }
+
+        export interface Nested {
+            get(input: a.A): webwire.Response<webwire.Option<a.b.C>>,
+            root(): webwire.Response<Root>,
+        }
+
+        export class NestedConsumer implements Nested {
+            _client: webwire.Client
+            constructor(client: webwire.Client) {
+                this._client = client
+            }
+            async get(input: a.A): webwire.Response<webwire.Option<a.b.C>> {
+                return await this._client.request('a.b.Nested', 'get', input)
+            }
+            async root(): webwire.Response<Root> {
+                return await this._client.request('a.b.Nested', 'root')
+            }
+        }
+    }
+}
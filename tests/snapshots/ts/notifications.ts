@@ -0,0 +1,60 @@
+// GENERATED CODE - DO NOT EDIT!
+
+import * as webwire from './webwire'
+
+
+export type _LevelVariants = "Debug" | "Info" | "Error"
+export type Level =
+    | "Debug"
+    | "Info"
+    | "Error"
+
+export interface LogEntry {
+    level: Level,
+    message: string,
+}
+
+export interface Events {
+    message(input: string): void,
+    reload(): void,
+}
+
+export function EventsProvider(service: Events): webwire.Provider {
+    return {
+        service: 'Events',
+        async call(method: string, input?: any): Promise<unknown> {
+            switch (method) {
+            case 'message':
+                service.message(input)
+                return
+            case 'reload':
+                service.reload()
+                return
+            default:
+                throw new Error(`Unknown method ${method}`)
+            }
+        },
+    }
+}
+
+export interface Telemetry {
+    log(input: LogEntry): void,
+    heartbeat(): void,
+    flush(): webwire.Response<void>,
+}
+
+export class TelemetryConsumer implements Telemetry {
+    _client: webwire.Client
+    constructor(client: webwire.Client) {
+        this._client = client
+    }
+    log(input: LogEntry): void {
+        this._client.notify('Telemetry', 'log', input)
+    }
+    heartbeat(): void {
+        this._client.notify('Telemetry', 'heartbeat')
+    }
+    async flush(): webwire.Response<void> {
+        return await this._client.request('Telemetry', 'flush')
+    }
+}
@@ -0,0 +1,82 @@
+// GENERATED CODE - DO NOT EDIT!
+
+import * as webwire from './webwire'
+
+
+export interface Chunk {
+    data: webwire.Bytes,
+}
+
+export interface Event {
+    topic: string,
+    payload: string,
+}
+
+export interface Filter {
+    topic: string,
+}
+
+export interface Receipt {
+    size: number,
+}
+
+export interface Events {
+    watch(input: Filter): AsyncIterable<Event>,
+    tail(): AsyncIterable<Event>,
+    upload(input: AsyncIterable<Chunk>): webwire.Response<Receipt>,
+    echo(input: AsyncIterable<Event>): AsyncIterable<Event>,
+    ping(): webwire.Response<void>,
+}
+
+export class EventsConsumer implements Events {
+    _client: webwire.Client
+    constructor(client: webwire.Client) {
+        this._client = client
+    }
+    async *watch(input: Filter): AsyncIterable<Event> {
+        yield* await this._client.request('Events', 'watch', input)
+    }
+    async *tail(): AsyncIterable<Event> {
+        yield* await this._client.request('Events', 'tail')
+    }
+    async upload(input: AsyncIterable<Chunk>): webwire.Response<Receipt> {
+        const items: Array<Chunk> = []
+        for await (const item of input) {
+            items.push(item)
+        }
+        return await this._client.request('Events', 'upload', items)
+    }
+    async *echo(input: AsyncIterable<Event>): AsyncIterable<Event> {
+        const items: Array<Event> = []
+        for await (const item of input) {
+            items.push(item)
+        }
+        yield* await this._client.request('Events', 'echo', items)
+    }
+    async ping(): webwire.Response<void> {
+        return await this._client.request('Events', 'ping')
+    }
+}
+
+export interface Notifications {
+    subscribe(input: Filter): AsyncIterable<Event>,
+}
+
+export function NotificationsProvider(service: Notifications): webwire.Provider {
+    return {
+        service: 'Notifications',
+        async call(method: string, input?: any): Promise<unknown> {
+            switch (method) {
+            case 'subscribe': {
+                const output: Array<unknown> = []
+                for await (const item of service.subscribe(input)) {
+                    output.push(item)
+                }
+                return output
+            }
+            default:
+                throw new Error(`Unknown method ${method}`)
+            }
+        },
+    }
+}
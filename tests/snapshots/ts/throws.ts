@@ -0,0 +1,110 @@
+// GENERATED CODE - DO NOT EDIT!
+
+import * as webwire from './webwire'
+
+
+export type _GetErrorVariants = "NotFound" | "PermissionDenied" | "RateLimited"
+export type GetError =
+    | "NotFound"
+    | "PermissionDenied"
+    | { [P in Exclude<_GetErrorVariants, "RateLimited">]?: never } & { RateLimited: number }
+
+export type _PromptsConfirmErrorVariants = "Dismissed"
+export type PromptsConfirmError =
+    | "Dismissed"
+
+export interface User {
+    id: webwire.UUID,
+    name: string,
+}
+
+export type _UsersRenameErrorVariants = "NotFound" | "NameTaken"
+export type UsersRenameError =
+    | "NotFound"
+    | "NameTaken"
+
+export interface UsersRenameInput {
+    id: webwire.UUID,
+    name: string,
+}
+
+export class PromptsConfirmException extends Error {
+    readonly error: PromptsConfirmError
+    constructor(error: PromptsConfirmError) {
+        super(`Prompts.confirm failed: ${JSON.stringify(error)}`)
+        this.name = 'PromptsConfirmException'
+        this.error = error
+    }
+}
+
+export interface Prompts {
+    confirm(input: string): webwire.Response<boolean>,
+}
+
+export function PromptsProvider(service: Prompts): webwire.Provider {
+    return {
+        service: 'Prompts',
+        async call(method: string, input?: any): Promise<unknown> {
+            switch (method) {
+            case 'confirm':
+                try {
+                    return { Ok: await service.confirm(input) }
+                } catch (e) {
+                    if (e instanceof PromptsConfirmException) {
+                        return { Err: e.error }
+                    }
+                    throw e
+                }
+            default:
+                throw new Error(`Unknown method ${method}`)
+            }
+        },
+    }
+}
+
+export class UsersGetException extends Error {
+    readonly error: GetError
+    constructor(error: GetError) {
+        super(`Users.get failed: ${JSON.stringify(error)}`)
+        this.name = 'UsersGetException'
+        this.error = error
+    }
+}
+
+export class UsersRenameException extends Error {
+    readonly error: UsersRenameError
+    constructor(error: UsersRenameError) {
+        super(`Users.rename failed: ${JSON.stringify(error)}`)
+        this.name = 'UsersRenameException'
+        this.error = error
+    }
+}
+
+export interface Users {
+    get(input: webwire.UUID): webwire.Response<User>,
+    rename(id: webwire.UUID, name: string): webwire.Response<void>,
+    list(): webwire.Response<Array<User>>,
+}
+
+export class UsersConsumer implements Users {
+    _client: webwire.Client
+    constructor(client: webwire.Client) {
+        this._client = client
+    }
+    async get(input: webwire.UUID): webwire.Response<User> {
+        const result: webwire.Result<User, GetError> = await this._client.request('Users', 'get', input)
+        if ('Err' in result) {
+            throw new UsersGetException(result.Err)
+        }
+        return result.Ok
+    }
+    async rename(id: webwire.UUID, name: string): webwire.Response<void> {
+        const result: webwire.Result<null, UsersRenameError> = await this._client.request('Users', 'rename', { id, name })
+        if ('Err' in result) {
+            throw new UsersRenameException(result.Err)
+        }
+    }
+    async list(): webwire.Response<Array<User>> {
+        return await this._client.request('Users', 'list')
+    }
+}
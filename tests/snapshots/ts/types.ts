@@ -0,0 +1,75 @@
+// GENERATED CODE - DO NOT EDIT!
+
+import * as webwire from './webwire'
+
+
+export interface AllTypes {
+    boolean: boolean,
+    integer: number,
+    small: number,
+    float: number,
+    string: string,
+    uuid: webwire.UUID,
+    date: webwire.Date,
+    time: webwire.Time,
+    date_time: webwire.DateTime,
+    decimal: webwire.Decimal,
+    bytes: webwire.Bytes,
+    duration: webwire.Duration,
+    int8: number,
+    int16: number,
+    int32: number,
+    int64: number,
+    uint8: number,
+    uint16: number,
+    uint32: number,
+    uint64: number,
+    list: Array<string>,
+    map: Map<string, number>,
+    int_map: Map<number, Array<string>>,
+    nested_list: Array<Array<number>>,
+    option: webwire.Option<string>,
+    nested_option: webwire.Option<webwire.Option<number>>,
+    result: webwire.Result<webwire.Option<string>, Array<number>>,
+    optional?: number,
+}
+
+export interface AllTypesPartial {
+    integer: number,
+    date?: webwire.Date,
+    nested_option?: webwire.Option<webwire.Option<number>>,
+}
+
+export interface Types {
+    echo(input: AllTypes): webwire.Response<AllTypes>,
+    nothing(): webwire.Response<void>,
+    partial(input: AllTypesPartial): webwire.Response<webwire.Result<webwire.Option<AllTypes>, null>>,
+    list(input: Array<AllTypes>): webwire.Response<Map<webwire.UUID, AllTypes>>,
+    dates(input: Array<webwire.Date>): webwire.Response<Map<string, webwire.DateTime>>,
+    wait(input: webwire.Duration): webwire.Response<webwire.Option<webwire.Bytes>>,
+}
+
+export class TypesConsumer implements Types {
+    _client: webwire.Client
+    constructor(client: webwire.Client) {
+        this._client = client
+    }
+    async echo(input: AllTypes): webwire.Response<AllTypes> {
+        return await this._client.request('Types', 'echo', input)
+    }
+    async nothing(): webwire.Response<void> {
+        return await this._client.request('Types', 'nothing')
+    }
+    async partial(input: AllTypesPartial): webwire.Response<webwire.Result<webwire.Option<AllTypes>, null>> {
+        return await this._client.request('Types', 'partial', input)
+    }
+    async list(input: Array<AllTypes>): webwire.Response<Map<webwire.UUID, AllTypes>> {
+        return await this._client.request('Types', 'list', input)
+    }
+    async dates(input: Array<webwire.Date>): webwire.Response<Map<string, webwire.DateTime>> {
+        return await this._client.request('Types', 'dates', input)
+    }
+    async wait(input: webwire.Duration): webwire.Response<webwire.Option<webwire.Bytes>> {
+        return await this._client.request('Types', 'wait', input)
+    }
+}